@@ -0,0 +1,85 @@
+//! Monitor a launch from the terminal
+//!
+//! ```bash
+//! XRPLSALE_API_KEY=... cargo run --example launch_dashboard --features tui -- proj_abc123
+//! ```
+//!
+//! Press `q` or `Esc` to quit and `r` to refresh immediately.
+
+use futures::StreamExt;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use xrplsale::{
+    display::{AmountFormat, Locale},
+    tui::{Dashboard, DashboardState},
+    webhook_tail::WebhookTail,
+    Client,
+};
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let project_id = std::env::args()
+        .nth(1)
+        .ok_or("usage: launch_dashboard <project-id>")?;
+    let client = Client::from_env()?;
+
+    let mut state = DashboardState::new(project_id.as_str());
+    let format = AmountFormat::new().precision(2).locale(Locale::from_env());
+    // Fused so `select!` can keep polling it after the tail ends on an error
+    let mut events = Box::pin(
+        WebhookTail::new(client.clone())
+            .project(project_id.as_str())
+            .stream()
+            .fuse(),
+    );
+    let mut refresh = tokio::time::interval(REFRESH_INTERVAL);
+    let mut keys = spawn_key_reader();
+
+    let mut terminal = ratatui::init();
+    let result = loop {
+        tokio::select! {
+            _ = refresh.tick() => {
+                let _ = state.refresh(&client).await;
+            }
+            Some(event) = events.next() => {
+                if let Ok(event) = event {
+                    state.apply_event(&event);
+                }
+            }
+            Some(key) = keys.recv() => match key {
+                KeyCode::Char('q') | KeyCode::Esc => break Ok(()),
+                KeyCode::Char('r') => refresh.reset_immediately(),
+                _ => {}
+            },
+        }
+
+        if let Err(e) = terminal
+            .draw(|frame| frame.render_widget(Dashboard::new(&state).format(format), frame.area()))
+        {
+            break Err(e);
+        }
+    };
+    ratatui::restore();
+
+    Ok(result?)
+}
+
+/// Read key presses on a blocking thread, since crossterm's reader blocks
+fn spawn_key_reader() -> mpsc::UnboundedReceiver<KeyCode> {
+    let (sender, receiver) = mpsc::unbounded_channel();
+    std::thread::spawn(move || loop {
+        match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => {
+                if sender.send(key.code).is_err() {
+                    break;
+                }
+            }
+            Ok(_) => {}
+            Err(_) => break,
+        }
+    });
+    receiver
+}
@@ -0,0 +1,281 @@
+//! Arrow export of sale data
+//!
+//! Converts SDK records into Arrow [`RecordBatch`]es with a declared schema, for direct
+//! hand-off to Polars, DataFusion, or Parquet writers without going through
+//! intermediate JSON files.
+//!
+//! ```rust
+//! use futures::TryStreamExt;
+//! use xrplsale::{arrow::record_batches, types::investments::Investment};
+//!
+//! # async fn export(
+//! #     investments: impl futures::Stream<Item = xrplsale::Result<Investment>>,
+//! # ) -> xrplsale::Result<()> {
+//! let batches: Vec<_> = record_batches(investments, 10_000).try_collect().await?;
+//! let rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Comparison series export the same way through
+//! [`ProjectComparison::stats_points`](crate::types::launch_analytics::ProjectComparison::stats_points).
+//!
+//! Columns are matched to record fields by name. Fields missing from a record become
+//! nulls; fields not in the schema are ignored. Amounts are decimal strings in the API
+//! and stay `Utf8` columns so no precision is lost.
+
+use crate::{
+    error::{Error, Result},
+    models::Investment,
+    types::launch_analytics::StatsPoint,
+};
+use arrow_array::{
+    builder::{
+        BooleanBuilder, Float64Builder, Int64Builder, StringBuilder, TimestampMillisecondBuilder,
+        UInt64Builder,
+    },
+    ArrayRef, RecordBatch,
+};
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use futures::{stream, Stream, StreamExt};
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::Arc;
+
+pub use arrow_array;
+pub use arrow_schema;
+
+/// A record type with a declared Arrow schema
+pub trait ArrowRecord: Serialize {
+    /// Schema of the record batches built from this type
+    fn schema() -> SchemaRef;
+}
+
+impl ArrowRecord for Investment {
+    /// `id`, `project_id`, `investor_account`, `amount_xrp`, `token_amount`, `status`,
+    /// and `created_at` (UTC milliseconds)
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Utf8, false),
+            Field::new("project_id", DataType::Utf8, true),
+            Field::new("investor_account", DataType::Utf8, true),
+            Field::new("amount_xrp", DataType::Utf8, true),
+            Field::new("token_amount", DataType::Utf8, true),
+            Field::new("status", DataType::Utf8, true),
+            Field::new(
+                "created_at",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+                true,
+            ),
+        ]))
+    }
+}
+
+impl ArrowRecord for StatsPoint {
+    /// `project_id`, `offset`, `time` (UTC milliseconds), and one column per metric:
+    /// `amount_raised`, `tokens_sold`, `investments`, and `investors`
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("project_id", DataType::Utf8, false),
+            Field::new("offset", DataType::UInt64, false),
+            Field::new(
+                "time",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+                false,
+            ),
+            Field::new("amount_raised", DataType::Utf8, true),
+            Field::new("tokens_sold", DataType::Utf8, true),
+            Field::new("investments", DataType::Utf8, true),
+            Field::new("investors", DataType::Utf8, true),
+        ]))
+    }
+}
+
+/// Convert records into a single record batch using their declared schema
+pub fn to_record_batch<T: ArrowRecord>(records: &[T]) -> Result<RecordBatch> {
+    to_record_batch_with_schema(records, T::schema())
+}
+
+/// Convert records into a single record batch with a custom schema
+///
+/// Supported column types are `Utf8`, `Int64`, `UInt64`, `Float64`, `Boolean`, and
+/// millisecond `Timestamp`s parsed from RFC 3339 strings. Numeric columns also accept
+/// decimal strings.
+pub fn to_record_batch_with_schema<T: Serialize>(
+    records: &[T],
+    schema: SchemaRef,
+) -> Result<RecordBatch> {
+    let rows = records
+        .iter()
+        .map(|record| serde_json::to_value(record).map_err(|e| Error::Parse(e.to_string())))
+        .collect::<Result<Vec<_>>>()?;
+
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| column(field, &rows))
+        .collect::<Result<Vec<_>>>()?;
+
+    RecordBatch::try_new(schema, columns).map_err(|e| Error::Parse(e.to_string()))
+}
+
+/// Convert a stream of records into record batches of up to `batch_size` rows
+///
+/// Errors in the input stream are passed through in place and end the batch being
+/// built: the records before an error are emitted as a batch, then the error, and the
+/// records after it start a new batch.
+pub fn record_batches<S, T>(stream: S, batch_size: usize) -> impl Stream<Item = Result<RecordBatch>>
+where
+    S: Stream<Item = Result<T>>,
+    T: ArrowRecord,
+{
+    stream.chunks(batch_size.max(1)).flat_map(|chunk| {
+        let mut batches = Vec::new();
+        let mut records = Vec::new();
+        for item in chunk {
+            match item {
+                Ok(record) => records.push(record),
+                Err(e) => {
+                    if !records.is_empty() {
+                        batches.push(to_record_batch(&std::mem::take(&mut records)));
+                    }
+                    batches.push(Err(e));
+                }
+            }
+        }
+        if !records.is_empty() {
+            batches.push(to_record_batch(&records));
+        }
+        stream::iter(batches)
+    })
+}
+
+fn column(field: &Field, rows: &[Value]) -> Result<ArrayRef> {
+    let values = rows
+        .iter()
+        .map(|row| row.get(field.name()).filter(|value| !value.is_null()));
+
+    let array: ArrayRef = match field.data_type() {
+        DataType::Utf8 => {
+            let mut builder = StringBuilder::new();
+            for value in values {
+                builder.append_option(value.map(|value| match value {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                }));
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::Int64 => {
+            let mut builder = Int64Builder::new();
+            for value in values {
+                builder.append_option(value.and_then(|v| number(v, Value::as_i64)));
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::UInt64 => {
+            let mut builder = UInt64Builder::new();
+            for value in values {
+                builder.append_option(value.and_then(|v| number(v, Value::as_u64)));
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::Float64 => {
+            let mut builder = Float64Builder::new();
+            for value in values {
+                builder.append_option(value.and_then(|v| number(v, Value::as_f64)));
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::Boolean => {
+            let mut builder = BooleanBuilder::new();
+            for value in values {
+                builder.append_option(value.and_then(Value::as_bool));
+            }
+            Arc::new(builder.finish())
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, timezone) => {
+            let mut builder =
+                TimestampMillisecondBuilder::new().with_timezone_opt(timezone.clone());
+            for value in values {
+                builder.append_option(
+                    value
+                        .and_then(Value::as_str)
+                        .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+                        .map(|t| t.timestamp_millis()),
+                );
+            }
+            Arc::new(builder.finish())
+        }
+        other => {
+            return Err(Error::Configuration(format!(
+                "Unsupported Arrow type {} for column '{}'",
+                other,
+                field.name()
+            )))
+        }
+    };
+
+    Ok(array)
+}
+
+/// Read a JSON number, or a decimal string holding one
+fn number<N: std::str::FromStr>(value: &Value, as_number: fn(&Value) -> Option<N>) -> Option<N> {
+    match value {
+        Value::String(s) => s.trim().parse().ok(),
+        other => as_number(other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn investment(id: &str) -> Result<Investment> {
+        serde_json::from_value(json!({ "id": id, "amount_xrp": "100" }))
+            .map_err(|e| Error::Parse(e.to_string()))
+    }
+
+    #[tokio::test]
+    async fn errors_end_the_batch_without_dropping_earlier_rows() {
+        let items = vec![
+            investment("inv_1"),
+            investment("inv_2"),
+            Err(Error::HttpClient("connection reset".to_string())),
+            investment("inv_3"),
+            investment("inv_4"),
+        ];
+        let batches: Vec<_> = record_batches(stream::iter(items), 10).collect().await;
+
+        let rows: Vec<_> = batches
+            .iter()
+            .map(|batch| batch.as_ref().map(RecordBatch::num_rows).ok())
+            .collect();
+        assert_eq!(rows, [Some(2), None, Some(2)]);
+        assert!(matches!(batches[1], Err(Error::HttpClient(_))));
+    }
+
+    #[test]
+    fn exports_comparison_points() {
+        let comparison: crate::types::launch_analytics::ProjectComparison =
+            serde_json::from_value(json!({
+                "interval": "1d",
+                "series": [{
+                    "project_id": "proj_1",
+                    "sale_start": "2030-01-01T00:00:00Z",
+                    "points": [
+                        { "offset": 0, "time": "2030-01-01T00:00:00Z", "values": { "amount_raised": "10" } },
+                        { "offset": 1, "time": "2030-01-02T00:00:00Z", "values": { "amount_raised": "25" } }
+                    ]
+                }]
+            }))
+            .unwrap();
+
+        let points: Vec<_> = comparison.stats_points().collect();
+        let batch = to_record_batch(&points).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.column_by_name("time").unwrap().null_count(), 0);
+        assert_eq!(batch.column_by_name("investors").unwrap().null_count(), 2);
+    }
+}
@@ -0,0 +1,300 @@
+//! Command-line interface to the XRPL.Sale API
+//!
+//! Authenticates from the same environment variables as
+//! [`Client::from_env`](xrplsale::Client::from_env), e.g. `XRPLSALE_API_KEY` and
+//! `XRPLSALE_ENVIRONMENT`:
+//!
+//! ```text
+//! xrplsale projects list --status active
+//! xrplsale project create -f project.toml
+//! xrplsale investments export --project proj_abc123 > investments.csv
+//! xrplsale webhooks tail
+//! ```
+
+use clap::{Parser, Subcommand, ValueEnum};
+use futures::StreamExt;
+use serde::Serialize;
+use serde_json::Value;
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+use xrplsale::{
+    types::projects::CreateProjectRequest, webhook_tail::WebhookTail, Client, Error, Result,
+};
+
+/// Columns shown for projects in table output
+const PROJECT_COLUMNS: &[&str] = &["id", "name", "status", "token_symbol", "total_supply"];
+
+/// Columns of investment exports, matching the Arrow schema
+const INVESTMENT_COLUMNS: &[&str] = &[
+    "id",
+    "project_id",
+    "investor_account",
+    "amount_xrp",
+    "token_amount",
+    "status",
+    "created_at",
+];
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "xrplsale",
+    version,
+    about = "Manage XRPL.Sale projects from the command line"
+)]
+struct Cli {
+    /// Output format
+    #[arg(long, short, global = true, value_enum, default_value_t = Output::Table)]
+    output: Output,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Output {
+    /// Aligned columns for reading
+    Table,
+    /// Pretty-printed JSON for scripts
+    Json,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// List and create projects
+    #[command(subcommand, visible_alias = "project")]
+    Projects(ProjectsCommand),
+    /// Export investments
+    #[command(subcommand, visible_alias = "investment")]
+    Investments(InvestmentsCommand),
+    /// Watch webhook events
+    #[command(subcommand, visible_alias = "webhook")]
+    Webhooks(WebhooksCommand),
+}
+
+#[derive(Debug, Subcommand)]
+enum ProjectsCommand {
+    /// List projects
+    List {
+        /// Only list projects with this status, e.g. `active`
+        #[arg(long)]
+        status: Option<String>,
+        /// Page to fetch
+        #[arg(long, default_value_t = 1)]
+        page: u32,
+        /// Projects per page
+        #[arg(long, default_value_t = 20)]
+        limit: u32,
+    },
+    /// Create a project from a TOML, YAML, or JSON definition
+    Create {
+        /// Project definition, in the shape of `CreateProjectRequest`
+        #[arg(long, short)]
+        file: PathBuf,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum InvestmentsCommand {
+    /// Write every investment in a project to stdout
+    Export {
+        /// The project ID
+        #[arg(long)]
+        project: String,
+        /// Export format
+        #[arg(long, value_enum, default_value_t = ExportFormat::Csv)]
+        format: ExportFormat,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ExportFormat {
+    Csv,
+    Json,
+}
+
+#[derive(Debug, Subcommand)]
+enum WebhooksCommand {
+    /// Print webhook events as they are delivered
+    Tail {
+        /// Only show events of this type, e.g. `investment.created`
+        #[arg(long = "event")]
+        events: Vec<String>,
+        /// Only show events for this project
+        #[arg(long)]
+        project: Option<String>,
+        /// Also show events delivered in the last N minutes
+        #[arg(long, value_name = "MINUTES")]
+        since: Option<i64>,
+    },
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<()> {
+    let client = Client::from_env()?;
+
+    match cli.command {
+        Command::Projects(ProjectsCommand::List {
+            status,
+            page,
+            limit,
+        }) => {
+            let response = client
+                .projects()
+                .list(status.as_deref(), Some(page), Some(limit), None, None)
+                .await?;
+            print_records(
+                &response.data.unwrap_or_default(),
+                PROJECT_COLUMNS,
+                cli.output,
+            )
+        }
+        Command::Projects(ProjectsCommand::Create { file }) => {
+            let request = read_project(&file)?;
+            let project = client.projects().create(request).await?;
+            print_records(&[project], PROJECT_COLUMNS, cli.output)
+        }
+        Command::Investments(InvestmentsCommand::Export { project, format }) => {
+            let mut investments = Vec::new();
+            let mut page = 1;
+            loop {
+                let response = client
+                    .investments()
+                    .get_by_project(project.as_str(), Some(page), Some(100))
+                    .await?;
+                let next_page = response.next_page();
+                investments.extend(response.data.unwrap_or_default());
+                match next_page {
+                    Some(next) => page = next,
+                    None => break,
+                }
+            }
+
+            match format {
+                ExportFormat::Csv => print!("{}", to_csv(&investments, INVESTMENT_COLUMNS)?),
+                ExportFormat::Json => print_json(&investments)?,
+            }
+            Ok(())
+        }
+        Command::Webhooks(WebhooksCommand::Tail {
+            events,
+            project,
+            since,
+        }) => {
+            let mut tail = WebhookTail::new(client);
+            for event_type in events {
+                tail = tail.event(event_type);
+            }
+            if let Some(project) = project {
+                tail = tail.project(project.as_str());
+            }
+            if let Some(minutes) = since {
+                tail = tail.since(chrono::Utc::now() - chrono::Duration::minutes(minutes));
+            }
+
+            let mut events = Box::pin(tail.stream());
+            while let Some(event) = events.next().await {
+                let event = event?;
+                match cli.output {
+                    Output::Json => println!("{}", to_json(&event)?),
+                    Output::Table => println!("{}  {}  {}", event.id, event.event_type, event.data),
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Parse a project definition, choosing the format from the file extension
+fn read_project(path: &Path) -> Result<CreateProjectRequest> {
+    config::Config::builder()
+        .add_source(config::File::from(path))
+        .build()
+        .and_then(|c| c.try_deserialize())
+        .map_err(|e| Error::Configuration(format!("{}: {}", path.display(), e)))
+}
+
+fn print_records<T: Serialize>(records: &[T], columns: &[&str], output: Output) -> Result<()> {
+    match output {
+        Output::Json => print_json(records),
+        Output::Table => {
+            let rows = rows(records, columns)?;
+            let mut widths: Vec<usize> = columns.iter().map(|column| column.len()).collect();
+            for row in &rows {
+                for (width, cell) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(cell.chars().count());
+                }
+            }
+
+            let header: Vec<String> = columns.iter().map(|column| column.to_uppercase()).collect();
+            for row in std::iter::once(&header).chain(&rows) {
+                let cells: Vec<String> = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                    .collect();
+                println!("{}", cells.join("  ").trim_end());
+            }
+            Ok(())
+        }
+    }
+}
+
+fn print_json<T: Serialize + ?Sized>(value: &T) -> Result<()> {
+    let json = serde_json::to_string_pretty(value).map_err(|e| Error::Parse(e.to_string()))?;
+    println!("{}", json);
+    Ok(())
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<String> {
+    serde_json::to_string(value).map_err(|e| Error::Parse(e.to_string()))
+}
+
+fn to_csv<T: Serialize>(records: &[T], columns: &[&str]) -> Result<String> {
+    let mut csv = columns.join(",") + "\n";
+    for row in rows(records, columns)? {
+        let cells: Vec<String> = row.iter().map(|cell| csv_field(cell)).collect();
+        csv.push_str(&cells.join(","));
+        csv.push('\n');
+    }
+    Ok(csv)
+}
+
+fn csv_field(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+/// Render the chosen fields of each record as text
+fn rows<T: Serialize>(records: &[T], columns: &[&str]) -> Result<Vec<Vec<String>>> {
+    records
+        .iter()
+        .map(|record| {
+            let value = serde_json::to_value(record).map_err(|e| Error::Parse(e.to_string()))?;
+            Ok(columns
+                .iter()
+                .map(|column| match value.get(column) {
+                    None | Some(Value::Null) => String::new(),
+                    Some(Value::String(text)) => text.clone(),
+                    Some(other) => other.to_string(),
+                })
+                .collect())
+        })
+        .collect()
+}
@@ -0,0 +1,239 @@
+//! Response caching for GET requests
+//!
+//! With a [`CacheConfig`] installed on the client, `GET` responses are cached together
+//! with their `ETag`. While an entry is fresh it is served without touching the network;
+//! once it expires the client revalidates it with `If-None-Match` and serves the cached
+//! body again when the API answers `304 Not Modified`.
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use xrplsale::{cache::CacheConfig, Client};
+//!
+//! # fn main() -> xrplsale::Result<()> {
+//! let client = Client::builder()
+//!     .api_key("your-api-key")
+//!     .cache(
+//!         CacheConfig::in_memory(1_000)
+//!             .default_ttl(Duration::from_secs(30))
+//!             .endpoint_ttl("/projects/:id/stats", Duration::from_secs(5)),
+//!     )
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Entries are keyed by the full request URL. A successful `POST`, `PUT`, `PATCH`, or
+//! `DELETE` evicts the entry cached for the same URL.
+
+use crate::{runtime::Instant, ApiVersion};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use url::Url;
+
+/// A cached response body and its validator
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    /// Raw response body
+    pub body: String,
+    /// `ETag` returned with the response, if any
+    pub etag: Option<String>,
+    /// When the response was stored or last revalidated
+    pub stored_at: Instant,
+    /// How long the entry may be served without revalidation
+    pub ttl: Duration,
+}
+
+impl CachedResponse {
+    /// Whether the entry can be served without revalidating it
+    pub fn is_fresh(&self) -> bool {
+        self.is_fresh_at(Instant::now())
+    }
+
+    /// Whether the entry can be served at `now` without revalidating it
+    pub fn is_fresh_at(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.stored_at) < self.ttl
+    }
+}
+
+/// Storage backend for cached responses
+pub trait ResponseCache: Send + Sync {
+    /// Look up an entry
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    /// Insert or replace an entry
+    fn put(&self, key: &str, response: CachedResponse);
+
+    /// Remove an entry
+    fn remove(&self, key: &str);
+
+    /// Remove all entries
+    fn clear(&self);
+}
+
+/// In-memory least-recently-used response cache
+///
+/// Once `capacity` entries are stored, inserting a new key evicts the entry that was
+/// used least recently.
+#[derive(Debug)]
+pub struct InMemoryCache {
+    capacity: usize,
+    state: Mutex<LruState>,
+}
+
+#[derive(Debug, Default)]
+struct LruState {
+    entries: HashMap<String, (CachedResponse, u64)>,
+    clock: u64,
+}
+
+impl InMemoryCache {
+    /// Create a cache holding at most `capacity` entries
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            state: Mutex::new(LruState::default()),
+        }
+    }
+
+    /// Number of cached entries
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl ResponseCache for InMemoryCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        let mut state = self.lock();
+        state.clock += 1;
+        let clock = state.clock;
+
+        state.entries.get_mut(key).map(|(response, last_used)| {
+            *last_used = clock;
+            response.clone()
+        })
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        let mut state = self.lock();
+        state.clock += 1;
+        let clock = state.clock;
+
+        if !state.entries.contains_key(key) && state.entries.len() >= self.capacity {
+            let oldest = state
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                state.entries.remove(&oldest);
+            }
+        }
+
+        state.entries.insert(key.to_string(), (response, clock));
+    }
+
+    fn remove(&self, key: &str) {
+        self.lock().entries.remove(key);
+    }
+
+    fn clear(&self) {
+        self.lock().entries.clear();
+    }
+}
+
+/// Response cache settings for a client
+#[derive(Clone)]
+pub struct CacheConfig {
+    store: Arc<dyn ResponseCache>,
+    default_ttl: Duration,
+    endpoint_ttls: HashMap<String, Duration>,
+}
+
+impl CacheConfig {
+    /// Use a custom cache backend
+    ///
+    /// Entries get a default TTL of zero, meaning they are always revalidated with
+    /// `If-None-Match` before being served.
+    pub fn new<C: ResponseCache + 'static>(store: C) -> Self {
+        Self {
+            store: Arc::new(store),
+            default_ttl: Duration::ZERO,
+            endpoint_ttls: HashMap::new(),
+        }
+    }
+
+    /// Use an [`InMemoryCache`] holding at most `capacity` entries
+    pub fn in_memory(capacity: usize) -> Self {
+        Self::new(InMemoryCache::new(capacity))
+    }
+
+    /// Set how long responses are served without revalidation
+    pub fn default_ttl(mut self, ttl: Duration) -> Self {
+        self.default_ttl = ttl;
+        self
+    }
+
+    /// Override the TTL for one endpoint
+    ///
+    /// Endpoints are path templates relative to the API base URL, with identifiers
+    /// written as `:id`, e.g. `/projects/:id` or `/projects/featured`.
+    pub fn endpoint_ttl<S: Into<String>>(mut self, endpoint: S, ttl: Duration) -> Self {
+        self.endpoint_ttls.insert(endpoint.into(), ttl);
+        self
+    }
+
+    /// Get the underlying cache backend
+    pub fn store(&self) -> &dyn ResponseCache {
+        self.store.as_ref()
+    }
+
+    pub(crate) fn ttl_for(&self, endpoint: &str) -> Duration {
+        self.endpoint_ttls
+            .get(endpoint)
+            .copied()
+            .unwrap_or(self.default_ttl)
+    }
+
+    /// Cache key of a GET request
+    ///
+    /// Responses differ between API versions, so each requested version has its own
+    /// entry.
+    pub(crate) fn key(url: &Url, api_version: Option<&ApiVersion>) -> String {
+        match api_version {
+            Some(version) => format!("{} [{}]", url, version),
+            None => url.to_string(),
+        }
+    }
+
+    /// Drop the entries of a URL for every known API version and `api_version`
+    pub(crate) fn invalidate(&self, url: &Url, api_version: Option<&ApiVersion>) {
+        self.store.remove(&Self::key(url, None));
+        self.store.remove(&Self::key(url, Some(&ApiVersion::V1)));
+        #[cfg(feature = "api-v2")]
+        self.store.remove(&Self::key(url, Some(&ApiVersion::V2)));
+        if let Some(version @ ApiVersion::Other(_)) = api_version {
+            self.store.remove(&Self::key(url, Some(version)));
+        }
+    }
+}
+
+impl std::fmt::Debug for CacheConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CacheConfig")
+            .field("default_ttl", &self.default_ttl)
+            .field("endpoint_ttls", &self.endpoint_ttls)
+            .finish_non_exhaustive()
+    }
+}
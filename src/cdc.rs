@@ -0,0 +1,184 @@
+//! Mapping webhook events to database change records
+//!
+//! [`CdcMapper`] turns platform webhook events into generic upsert and delete records
+//! (table, key, payload), so events can be written to any database with a small
+//! adapter instead of a handler per event type:
+//!
+//! ```rust
+//! use xrplsale::{cdc::{CdcMapper, ChangeOperation}, WebhookEvent};
+//!
+//! # fn handle(payload: &str) -> Result<(), Box<dyn std::error::Error>> {
+//! let mapper = CdcMapper::default();
+//! let event: WebhookEvent = serde_json::from_str(payload)?;
+//!
+//! if let Some(change) = mapper.map(&event)? {
+//!     match change.operation {
+//!         ChangeOperation::Upsert => { /* INSERT ... ON CONFLICT (key) DO UPDATE */ }
+//!         ChangeOperation::Delete => { /* DELETE ... WHERE key */ }
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The default mapping covers the platform's event families:
+//!
+//! | Event types | Table | Key |
+//! |-------------|-------|-----|
+//! | `project.*` | `projects` | `id` |
+//! | `investment.*` | `investments` | `id` |
+//! | `tier.*` | `project_tiers` | `project_id`, `tier` |
+//! | `escrow.*` | `escrows` | `id` |
+//!
+//! Events whose type ends in `.deleted` produce deletes; all others produce upserts
+//! of the event's `data` object.
+
+use crate::{
+    error::{Error, Result},
+    webhook::WebhookEvent,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Kind of change to apply
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeOperation {
+    /// Insert the row, or update it if the key exists
+    Upsert,
+    /// Delete the row with the key
+    Delete,
+}
+
+/// A storage-agnostic change derived from a webhook event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangeRecord {
+    /// Target table or collection
+    pub table: String,
+    /// Primary key columns and their values
+    pub key: BTreeMap<String, Value>,
+    /// Whether to upsert or delete
+    pub operation: ChangeOperation,
+    /// The full event data, used as the row for upserts
+    pub payload: Value,
+    /// The event type the change came from
+    pub event_type: String,
+}
+
+#[derive(Debug, Clone)]
+struct TableMapping {
+    event_prefix: String,
+    table: String,
+    key_fields: Vec<String>,
+}
+
+/// Converts webhook events into [`ChangeRecord`]s
+#[derive(Debug, Clone)]
+pub struct CdcMapper {
+    mappings: Vec<TableMapping>,
+    delete_suffixes: Vec<String>,
+}
+
+impl Default for CdcMapper {
+    /// A mapper for the platform's project, investment, tier, and escrow events
+    fn default() -> Self {
+        Self::new()
+            .table("project.", "projects", &["id"])
+            .table("investment.", "investments", &["id"])
+            .table("tier.", "project_tiers", &["project_id", "tier"])
+            .table("escrow.", "escrows", &["id"])
+    }
+}
+
+impl CdcMapper {
+    /// Create a mapper with no table mappings
+    pub fn new() -> Self {
+        Self {
+            mappings: Vec::new(),
+            delete_suffixes: vec![".deleted".to_string()],
+        }
+    }
+
+    /// Map events whose type starts with `event_prefix` to `table`
+    ///
+    /// `key_fields` name the fields of the event data forming the primary key. A
+    /// mapping replaces any earlier mapping for the same prefix; the longest matching
+    /// prefix wins.
+    pub fn table<P: Into<String>, T: Into<String>>(
+        mut self,
+        event_prefix: P,
+        table: T,
+        key_fields: &[&str],
+    ) -> Self {
+        let event_prefix = event_prefix.into();
+        self.mappings
+            .retain(|mapping| mapping.event_prefix != event_prefix);
+        self.mappings.push(TableMapping {
+            event_prefix,
+            table: table.into(),
+            key_fields: key_fields.iter().map(|field| field.to_string()).collect(),
+        });
+        self
+    }
+
+    /// Treat event types ending in `suffix` as deletes (in addition to `.deleted`)
+    pub fn delete_suffix<S: Into<String>>(mut self, suffix: S) -> Self {
+        self.delete_suffixes.push(suffix.into());
+        self
+    }
+
+    /// Convert an event into a change record
+    ///
+    /// Returns `Ok(None)` for event types without a mapping, and [`Error::Parse`] if
+    /// the event data lacks a key field.
+    pub fn map(&self, event: &WebhookEvent) -> Result<Option<ChangeRecord>> {
+        self.map_parts(&event.event_type, &event.data)
+    }
+
+    /// [`map`](Self::map) for an event type and data from another source
+    pub(crate) fn map_parts(&self, event_type: &str, data: &Value) -> Result<Option<ChangeRecord>> {
+        let Some(mapping) = self
+            .mappings
+            .iter()
+            .filter(|mapping| event_type.starts_with(&mapping.event_prefix))
+            .max_by_key(|mapping| mapping.event_prefix.len())
+        else {
+            return Ok(None);
+        };
+
+        let key = mapping
+            .key_fields
+            .iter()
+            .map(|field| {
+                data.get(field)
+                    .filter(|value| !value.is_null())
+                    .map(|value| (field.clone(), value.clone()))
+                    .ok_or_else(|| {
+                        Error::Parse(format!(
+                            "{} event is missing key field '{}'",
+                            event_type, field
+                        ))
+                    })
+            })
+            .collect::<Result<_>>()?;
+
+        let operation = if self
+            .delete_suffixes
+            .iter()
+            .any(|suffix| event_type.ends_with(suffix.as_str()))
+        {
+            ChangeOperation::Delete
+        } else {
+            ChangeOperation::Upsert
+        };
+
+        Ok(Some(ChangeRecord {
+            table: mapping.table.clone(),
+            key,
+            operation,
+            payload: data.clone(),
+            event_type: event_type.to_string(),
+        }))
+    }
+}
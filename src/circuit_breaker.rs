@@ -0,0 +1,252 @@
+//! Circuit breaking for failing endpoints
+//!
+//! During an API incident, retries from many clients make recovery slower. With a
+//! [`CircuitBreakerConfig`] installed, the client tracks failures per endpoint and,
+//! once an endpoint fails `failure_threshold` times in a row, fails requests to it
+//! immediately with [`Error::CircuitOpen`] instead of sending them. The error is
+//! retryable, and its `retry_after` says when the circuit half-opens.
+//!
+//! After `open_duration` the circuit is half-open: up to `half_open_probes` requests
+//! are let through. If they all succeed the circuit closes; any failure opens it again.
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use xrplsale::{circuit_breaker::CircuitBreakerConfig, Client};
+//!
+//! # fn main() -> xrplsale::Result<()> {
+//! let client = Client::builder()
+//!     .api_key("your-api-key")
+//!     .circuit_breaker(
+//!         CircuitBreakerConfig::new()
+//!             .failure_threshold(5)
+//!             .open_duration(Duration::from_secs(30)),
+//!     )
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Server errors (5xx) and transport failures count as failures. Client errors such as
+//! 404 or 429 do not, since they say nothing about the endpoint's health. Endpoints are
+//! tracked per HTTP method and path template (`GET /projects/:id`).
+
+use crate::{
+    error::{Error, Result},
+    runtime::{Clock, Instant},
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Circuit breaker settings
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    failure_threshold: u32,
+    open_duration: Duration,
+    half_open_probes: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(30),
+            half_open_probes: 1,
+        }
+    }
+}
+
+impl CircuitBreakerConfig {
+    /// Create settings with the defaults: open after 5 consecutive failures, stay
+    /// open for 30 seconds, then let 1 probe request through
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how many consecutive failures open the circuit
+    pub fn failure_threshold(mut self, failures: u32) -> Self {
+        self.failure_threshold = failures.max(1);
+        self
+    }
+
+    /// Set how long the circuit stays open before probing
+    pub fn open_duration(mut self, duration: Duration) -> Self {
+        self.open_duration = duration;
+        self
+    }
+
+    /// Set how many successful probes close a half-open circuit
+    pub fn half_open_probes(mut self, probes: u32) -> Self {
+        self.half_open_probes = probes.max(1);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CircuitState {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    HalfOpen { in_flight: u32, succeeded: u32 },
+}
+
+/// Per-endpoint circuit state shared by all clones of a client
+#[derive(Debug)]
+pub(crate) struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    circuits: Mutex<HashMap<String, CircuitState>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(config: CircuitBreakerConfig, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            config,
+            circuits: Mutex::new(HashMap::new()),
+            clock,
+        }
+    }
+
+    /// Ask to send a request to `endpoint`, failing fast if its circuit is open
+    pub(crate) fn acquire(&self, endpoint: &str) -> Result<CircuitPermit<'_>> {
+        let mut circuits = self.lock();
+        let state = circuits
+            .entry(endpoint.to_string())
+            .or_insert(CircuitState::Closed { failures: 0 });
+
+        if let CircuitState::Open { until } = *state {
+            let now = self.clock.now();
+            if now < until {
+                return Err(circuit_open(
+                    endpoint,
+                    Some(until.saturating_duration_since(now)),
+                ));
+            }
+            *state = CircuitState::HalfOpen {
+                in_flight: 0,
+                succeeded: 0,
+            };
+        }
+
+        let probe = match state {
+            CircuitState::HalfOpen {
+                in_flight,
+                succeeded,
+            } => {
+                if *in_flight + *succeeded >= self.config.half_open_probes {
+                    // Probes are in flight; their outcome decides when to retry
+                    return Err(circuit_open(endpoint, None));
+                }
+                *in_flight += 1;
+                true
+            }
+            _ => false,
+        };
+
+        Ok(CircuitPermit {
+            breaker: self,
+            endpoint: endpoint.to_string(),
+            probe,
+            recorded: false,
+        })
+    }
+
+    fn record(&self, endpoint: &str, probe: bool, success: Option<bool>) {
+        let mut circuits = self.lock();
+        let Some(state) = circuits.get_mut(endpoint) else {
+            return;
+        };
+
+        *state = match (*state, success) {
+            (CircuitState::Closed { .. }, Some(true)) => CircuitState::Closed { failures: 0 },
+            (CircuitState::Closed { failures }, Some(false)) => {
+                if failures + 1 >= self.config.failure_threshold {
+                    log::warn!(
+                        "Opening circuit for {} after {} failures",
+                        endpoint,
+                        failures + 1
+                    );
+                    self.open()
+                } else {
+                    CircuitState::Closed {
+                        failures: failures + 1,
+                    }
+                }
+            }
+            (
+                CircuitState::HalfOpen {
+                    in_flight,
+                    succeeded,
+                },
+                success,
+            ) if probe => match success {
+                Some(true) if succeeded + 1 >= self.config.half_open_probes => {
+                    log::info!("Closing circuit for {}", endpoint);
+                    CircuitState::Closed { failures: 0 }
+                }
+                Some(true) => CircuitState::HalfOpen {
+                    in_flight: in_flight.saturating_sub(1),
+                    succeeded: succeeded + 1,
+                },
+                Some(false) => {
+                    log::warn!("Probe failed, reopening circuit for {}", endpoint);
+                    self.open()
+                }
+                None => CircuitState::HalfOpen {
+                    in_flight: in_flight.saturating_sub(1),
+                    succeeded,
+                },
+            },
+            (state, _) => state,
+        };
+    }
+
+    fn open(&self) -> CircuitState {
+        CircuitState::Open {
+            until: self.clock.now() + self.config.open_duration,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, CircuitState>> {
+        self.circuits
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// The error for a request refused by an open or half-open circuit
+fn circuit_open(endpoint: &str, retry_after: Option<Duration>) -> Error {
+    Error::CircuitOpen {
+        endpoint: endpoint.to_string(),
+        retry_after,
+    }
+}
+
+/// Permission to send one request; report its outcome with [`record`](Self::record)
+///
+/// Dropping a permit without recording (e.g. when the request is cancelled) releases
+/// it without counting a success or failure.
+pub(crate) struct CircuitPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    endpoint: String,
+    probe: bool,
+    recorded: bool,
+}
+
+impl CircuitPermit<'_> {
+    /// Record the outcome of the request
+    pub(crate) fn record(mut self, success: bool) {
+        self.recorded = true;
+        self.breaker
+            .record(&self.endpoint, self.probe, Some(success));
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        if !self.recorded {
+            self.breaker.record(&self.endpoint, self.probe, None);
+        }
+    }
+}
@@ -1,18 +1,52 @@
 //! HTTP client for the XRPL.Sale API
 
+// Request handling runs inside long-lived services; errors must surface as `Error`s
+#![deny(clippy::unwrap_used)]
+
+#[cfg(feature = "cache")]
+use crate::cache::{CacheConfig, CachedResponse};
+#[cfg(feature = "metrics")]
+use crate::metrics::ClientMetrics;
+#[cfg(feature = "cache")]
+use reqwest::{
+    header::{ETAG, IF_NONE_MATCH},
+    StatusCode,
+};
 use crate::{
+    circuit_breaker::{CircuitBreaker, CircuitBreakerConfig},
+    deprecation::{DeprecationCallback, DeprecationNotice, DeprecationTracker},
     error::{Error, Result},
-    services::{AnalyticsService, AuthService, InvestmentsService, ProjectsService, WebhooksService},
+    logging,
+    middleware::{Middleware, MiddlewareStack, Next},
+    oauth::{ClientCredentials, TokenProvider},
+    priority::{Priority, PriorityLimiter},
+    query,
+    rate_limit::{self, RateLimitGuard, RateLimiter},
+    response::{ApiResponse, ByteStream, ResponseMeta, API_VERSION_HEADER},
+    runtime::{self, Clock, Instant},
+    services::{
+        AirdropsService, AllocationsService, AnalyticsService, AuthService, EligibilityService,
+        GovernanceService, InvestmentsService, LiquidityService, MarketService,
+        NotificationsService, ProjectsService, RealtimeService, SandboxService, StakingService,
+        UsageService, WebhooksService,
+    },
     webhook::WebhookSignatureValidator,
-    Environment,
+    ApiVersion, Environment,
+};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, ACCEPT, USER_AGENT},
+    Method, Request, RequestBuilder, Response,
 };
-use reqwest::{header::HeaderMap, Method, RequestBuilder, Response};
 use serde::{de::DeserializeOwned, Serialize};
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+use tokio_util::sync::CancellationToken;
 use url::Url;
 
 /// Configuration for the XRPL.Sale client
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ClientConfig {
     /// API key for authentication
     pub api_key: String,
@@ -28,8 +62,53 @@ pub struct ClientConfig {
     pub retry_delay: Duration,
     /// Webhook secret for signature verification
     pub webhook_secret: Option<String>,
+    /// Secret for HMAC request signing (see [`crate::signing`])
+    pub signing_secret: Option<String>,
     /// Enable debug logging
     pub debug: bool,
+    /// HTTP methods whose request and response bodies are included in debug logs
+    pub log_bodies: Vec<Method>,
+    /// Maximum idle connections kept per host (unlimited if `None`)
+    pub pool_max_idle_per_host: Option<usize>,
+    /// How long idle pooled connections are kept (90 seconds if `None`)
+    pub pool_idle_timeout: Option<Duration>,
+    /// TCP keep-alive interval (disabled if `None`)
+    pub tcp_keepalive: Option<Duration>,
+    /// Use HTTP/2 without ALPN negotiation
+    pub http2_prior_knowledge: bool,
+    /// Negotiate gzip and brotli response compression
+    pub compression: bool,
+    /// API version sent in the `X-API-Version` header (the API's default if `None`)
+    pub api_version: Option<ApiVersion>,
+}
+
+impl std::fmt::Debug for ClientConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientConfig")
+            .field("api_key", &crate::logging::REDACTED)
+            .field("environment", &self.environment)
+            .field("base_url", &self.base_url)
+            .field("timeout", &self.timeout)
+            .field("max_retries", &self.max_retries)
+            .field("retry_delay", &self.retry_delay)
+            .field(
+                "webhook_secret",
+                &self.webhook_secret.as_ref().map(|_| crate::logging::REDACTED),
+            )
+            .field(
+                "signing_secret",
+                &self.signing_secret.as_ref().map(|_| crate::logging::REDACTED),
+            )
+            .field("debug", &self.debug)
+            .field("log_bodies", &self.log_bodies)
+            .field("pool_max_idle_per_host", &self.pool_max_idle_per_host)
+            .field("pool_idle_timeout", &self.pool_idle_timeout)
+            .field("tcp_keepalive", &self.tcp_keepalive)
+            .field("http2_prior_knowledge", &self.http2_prior_knowledge)
+            .field("compression", &self.compression)
+            .field("api_version", &self.api_version)
+            .finish()
+    }
 }
 
 impl Default for ClientConfig {
@@ -42,15 +121,160 @@ impl Default for ClientConfig {
             max_retries: 3,
             retry_delay: Duration::from_secs(1),
             webhook_secret: None,
+            signing_secret: None,
             debug: false,
+            log_bodies: Vec::new(),
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            tcp_keepalive: None,
+            http2_prior_knowledge: false,
+            compression: true,
+            api_version: None,
+        }
+    }
+}
+
+/// Per-request overrides applied with [`Client::with_options`]
+///
+/// Every request made through the returned client (including those made by services
+/// obtained from it) uses these settings.
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Timeout for each attempt, replacing [`ClientConfig::timeout`]
+    pub timeout: Option<Duration>,
+    /// Point in time by which the request, including retries, must finish
+    ///
+    /// A [`runtime::Instant`], which is `std::time::Instant` outside the browser.
+    pub deadline: Option<Instant>,
+    /// Token that aborts the request when cancelled
+    pub cancellation: Option<CancellationToken>,
+    /// Scheduling class (see [`crate::priority`])
+    pub priority: Priority,
+    /// Sent as the `Idempotency-Key` header, so the API applies a mutation at most
+    /// once however often it is retried
+    pub idempotency_key: Option<String>,
+    /// API version, replacing [`ClientConfig::api_version`]
+    pub api_version: Option<ApiVersion>,
+}
+
+impl RequestOptions {
+    /// Create options with no overrides
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the timeout for each attempt
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set a deadline covering all attempts and retry delays
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Abort the request when `token` is cancelled
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Set the scheduling class
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Send an idempotency key with the request
+    pub fn idempotency_key<S: Into<String>>(mut self, key: S) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// Request a specific API version
+    pub fn api_version(mut self, version: ApiVersion) -> Self {
+        self.api_version = Some(version);
+        self
+    }
+}
+
+/// Curated defaults for common deployment shapes, applied with [`ClientBuilder::profile`]
+///
+/// | Setting | `HighThroughputSync` | `Interactive` | `Serverless` |
+/// |---------|----------------------|---------------|--------------|
+/// | Timeout | 60s | 10s | 15s |
+/// | Max retries | 5 | 1 | 2 |
+/// | Retry delay | 500ms | 200ms | 250ms |
+/// | Idle connections per host | 32 | 4 | 0 |
+/// | Idle connection timeout | 90s | 30s | - |
+/// | TCP keep-alive | 60s | - | - |
+/// | Circuit breaker | default | fail fast | - |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Profile {
+    /// Long-running batch or sync jobs: patient timeouts, aggressive retries, large pool
+    HighThroughputSync,
+    /// User-facing tools: short timeouts and few retries so errors surface quickly
+    Interactive,
+    /// Short-lived functions: no idle connections kept across invocations
+    Serverless,
+}
+
+impl std::str::FromStr for Profile {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "high_throughput_sync" | "high_throughput" | "sync" => Ok(Profile::HighThroughputSync),
+            "interactive" => Ok(Profile::Interactive),
+            "serverless" => Ok(Profile::Serverless),
+            _ => Err(Error::Configuration(format!("Unknown profile: {}", s))),
         }
     }
 }
 
 /// Builder for creating a XRPL.Sale client
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct ClientBuilder {
     config: ClientConfig,
+    middleware: MiddlewareStack,
+    #[cfg(feature = "metrics")]
+    metrics: Option<ClientMetrics>,
+    #[cfg(feature = "cache")]
+    cache: Option<CacheConfig>,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    max_concurrent_requests: Option<usize>,
+    rate_limiter: Option<Arc<dyn RateLimiter>>,
+    client_credentials: Option<ClientCredentials>,
+    deprecation_callback: Option<DeprecationCallback>,
+    proxy: Option<ProxyConfig>,
+    #[cfg(not(target_arch = "wasm32"))]
+    root_certificates: Vec<reqwest::Certificate>,
+    http_client: Option<reqwest::Client>,
+    clock: Option<Arc<dyn Clock>>,
+}
+
+/// HTTP proxy settings
+#[derive(Clone)]
+struct ProxyConfig {
+    url: String,
+    basic_auth: Option<(String, String)>,
+}
+
+impl std::fmt::Debug for ProxyConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProxyConfig")
+            .field("url", &self.url)
+            .field(
+                "basic_auth",
+                &self
+                    .basic_auth
+                    .as_ref()
+                    .map(|(username, _)| (username, crate::logging::REDACTED)),
+            )
+            .finish()
+    }
 }
 
 impl ClientBuilder {
@@ -59,6 +283,135 @@ impl ClientBuilder {
         Self::default()
     }
 
+    /// Create a builder configured from environment variables
+    ///
+    /// | Variable | Setting |
+    /// |----------|---------|
+    /// | `XRPLSALE_PROFILE` | `high_throughput_sync`, `interactive`, or `serverless` |
+    /// | `XRPLSALE_API_KEY` | API key |
+    /// | `XRPLSALE_ENVIRONMENT` | `production`, `testnet`, `devnet`, or an API URL |
+    /// | `XRPLSALE_BASE_URL` | Custom base URL |
+    /// | `XRPLSALE_WEBHOOK_SECRET` | Webhook secret |
+    /// | `XRPLSALE_SIGNING_SECRET` | Request signing secret |
+    /// | `XRPLSALE_TIMEOUT_SECS` | Request timeout in seconds |
+    /// | `XRPLSALE_MAX_RETRIES` | Maximum retry attempts |
+    /// | `XRPLSALE_RETRY_DELAY_MS` | Base delay between retries in milliseconds |
+    /// | `XRPLSALE_DEBUG` | `true`/`false` (also `1`/`0`, `yes`/`no`, `on`/`off`) |
+    /// | `XRPLSALE_COMPRESSION` | `true`/`false`, as for `XRPLSALE_DEBUG` |
+    /// | `XRPLSALE_API_VERSION` | API version, e.g. `v1` |
+    ///
+    /// Unset or empty variables keep their defaults. Malformed values return
+    /// [`Error::Configuration`] naming the variable. The profile is applied first, so
+    /// the other variables override its defaults.
+    pub fn from_env() -> Result<Self> {
+        let mut builder = Self::new();
+
+        if let Some(profile) = env_var("XRPLSALE_PROFILE")? {
+            let profile = profile.parse().map_err(|_| {
+                Error::Configuration(format!("XRPLSALE_PROFILE: unknown profile '{}'", profile))
+            })?;
+            builder = builder.profile(profile);
+        }
+
+        if let Some(api_key) = env_var("XRPLSALE_API_KEY")? {
+            builder = builder.api_key(api_key);
+        }
+        if let Some(environment) = env_var("XRPLSALE_ENVIRONMENT")? {
+            let environment = environment.parse().map_err(|_| {
+                Error::Configuration(format!(
+                    "XRPLSALE_ENVIRONMENT: unknown environment '{}'",
+                    environment
+                ))
+            })?;
+            builder = builder.environment(environment);
+        }
+        if let Some(base_url) = env_var("XRPLSALE_BASE_URL")? {
+            Url::parse(&base_url).map_err(|e| {
+                Error::Configuration(format!("XRPLSALE_BASE_URL: invalid URL '{}': {}", base_url, e))
+            })?;
+            builder = builder.base_url(base_url);
+        }
+        if let Some(secret) = env_var("XRPLSALE_WEBHOOK_SECRET")? {
+            builder = builder.webhook_secret(secret);
+        }
+        if let Some(secret) = env_var("XRPLSALE_SIGNING_SECRET")? {
+            builder = builder.signing_secret(secret);
+        }
+        if let Some(secs) = parse_env_var::<u64>("XRPLSALE_TIMEOUT_SECS")? {
+            builder = builder.timeout(Duration::from_secs(secs));
+        }
+        if let Some(retries) = parse_env_var::<usize>("XRPLSALE_MAX_RETRIES")? {
+            builder = builder.max_retries(retries);
+        }
+        if let Some(millis) = parse_env_var::<u64>("XRPLSALE_RETRY_DELAY_MS")? {
+            builder = builder.retry_delay(Duration::from_millis(millis));
+        }
+        if let Some(debug) = env_flag("XRPLSALE_DEBUG")? {
+            builder = builder.debug(debug);
+        }
+        if let Some(compression) = env_flag("XRPLSALE_COMPRESSION")? {
+            builder = builder.compression(compression);
+        }
+        if let Some(version) = env_var("XRPLSALE_API_VERSION")? {
+            let version = version.parse().map_err(|_| {
+                Error::Configuration(format!(
+                    "XRPLSALE_API_VERSION: unsupported API version '{}'",
+                    version
+                ))
+            })?;
+            builder = builder.api_version(version);
+        }
+
+        Ok(builder)
+    }
+
+    /// Apply curated defaults for a deployment shape
+    ///
+    /// Settings made after this call override the profile's values, so call it first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use xrplsale::{Client, Profile};
+    /// # fn main() -> xrplsale::Result<()> {
+    /// let client = Client::builder()
+    ///     .profile(Profile::Serverless)
+    ///     .api_key("your-api-key")
+    ///     .timeout(Duration::from_secs(5))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn profile(self, profile: Profile) -> Self {
+        match profile {
+            Profile::HighThroughputSync => self
+                .timeout(Duration::from_secs(60))
+                .max_retries(5)
+                .retry_delay(Duration::from_millis(500))
+                .pool_max_idle_per_host(32)
+                .pool_idle_timeout(Duration::from_secs(90))
+                .tcp_keepalive(Duration::from_secs(60))
+                .circuit_breaker(CircuitBreakerConfig::new()),
+            Profile::Interactive => self
+                .timeout(Duration::from_secs(10))
+                .max_retries(1)
+                .retry_delay(Duration::from_millis(200))
+                .pool_max_idle_per_host(4)
+                .pool_idle_timeout(Duration::from_secs(30))
+                .circuit_breaker(
+                    CircuitBreakerConfig::new()
+                        .failure_threshold(3)
+                        .open_duration(Duration::from_secs(10)),
+                ),
+            Profile::Serverless => self
+                .timeout(Duration::from_secs(15))
+                .max_retries(2)
+                .retry_delay(Duration::from_millis(250))
+                .pool_max_idle_per_host(0),
+        }
+    }
+
     /// Set the API key
     pub fn api_key<S: Into<String>>(mut self, api_key: S) -> Self {
         self.config.api_key = api_key.into();
@@ -77,6 +430,15 @@ impl ClientBuilder {
         self
     }
 
+    /// Request a specific API version on every call
+    ///
+    /// Sent in the `X-API-Version` header; see [`ApiVersion`]. Override it for some
+    /// requests with [`Client::with_api_version`].
+    pub fn api_version(mut self, version: ApiVersion) -> Self {
+        self.config.api_version = Some(version);
+        self
+    }
+
     /// Set the request timeout
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.config.timeout = timeout;
@@ -95,25 +457,353 @@ impl ClientBuilder {
         self
     }
 
+    /// Read the time and sleep through a custom clock
+    ///
+    /// Retry backoff, rate limit holds, request deadlines, circuit breaker cooldowns,
+    /// cache freshness, and OAuth token expiry all follow this clock. Tests pass a
+    /// [`MockClock`](crate::runtime::MockClock) to run them without waiting; deadlines
+    /// should then be computed from the mock's `now()`.
+    pub fn clock<C: Clock>(mut self, clock: C) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
     /// Set the webhook secret
     pub fn webhook_secret<S: Into<String>>(mut self, webhook_secret: S) -> Self {
         self.config.webhook_secret = Some(webhook_secret.into());
         self
     }
 
+    /// Authenticate with OAuth2 client credentials instead of an API key
+    ///
+    /// Access tokens are fetched and refreshed automatically; see
+    /// [`oauth`](crate::oauth). A token set with [`Client::set_auth_token`] still takes
+    /// precedence.
+    pub fn client_credentials(mut self, credentials: ClientCredentials) -> Self {
+        self.client_credentials = Some(credentials);
+        self
+    }
+
+    /// Call `callback` whenever a response reports that its endpoint is deprecated
+    ///
+    /// A warning is logged the first time each endpoint reports a deprecation whether
+    /// or not a callback is set; see [`deprecation`](crate::deprecation).
+    pub fn on_deprecation<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&DeprecationNotice) + Send + Sync + 'static,
+    {
+        self.deprecation_callback = Some(DeprecationCallback(Arc::new(callback)));
+        self
+    }
+
+    /// Sign every request with HMAC-SHA256 using this secret
+    ///
+    /// Adds `X-Signature` and `X-Timestamp` headers as described in
+    /// [`signing`](crate::signing). Required by the platform's privileged endpoints.
+    pub fn signing_secret<S: Into<String>>(mut self, signing_secret: S) -> Self {
+        self.config.signing_secret = Some(signing_secret.into());
+        self
+    }
+
     /// Enable debug logging
+    ///
+    /// Credentials are always redacted from debug logs; request and response bodies are
+    /// only logged for methods enabled with [`log_bodies`](Self::log_bodies).
     pub fn debug(mut self, debug: bool) -> Self {
         self.config.debug = debug;
         self
     }
 
+    /// Include (redacted) request and response bodies in debug logs for this HTTP method
+    pub fn log_bodies(mut self, method: Method) -> Self {
+        if !self.config.log_bodies.contains(&method) {
+            self.config.log_bodies.push(method);
+        }
+        self
+    }
+
+    /// Limit the idle connections kept open per host
+    ///
+    /// For server workloads, a value close to the expected request concurrency
+    /// (e.g. 32) avoids both reconnect churn and hoarding sockets.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.config.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Set how long idle pooled connections are kept before closing
+    ///
+    /// Keep this below any idle timeout of load balancers between you and the API;
+    /// 60 seconds is a safe choice for most deployments.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.config.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Enable TCP keep-alive with the given interval
+    ///
+    /// Recommended for long-running services (e.g. 60 seconds) so dead connections
+    /// are detected before a request is sent on them.
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.config.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Speak HTTP/2 directly instead of negotiating it
+    ///
+    /// Multiplexes concurrent requests over a single connection. Only enable this when
+    /// every hop to the API supports HTTP/2.
+    pub fn http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.config.http2_prior_knowledge = enabled;
+        self
+    }
+
+    /// Negotiate gzip and brotli response compression (enabled by default)
+    ///
+    /// Responses are decompressed transparently. Disable it to read raw traffic
+    /// through a debugging proxy.
+    pub fn compression(mut self, enabled: bool) -> Self {
+        self.config.compression = enabled;
+        self
+    }
+
+    /// Add a middleware to the request chain
+    ///
+    /// Middleware runs in the order it is added, wrapping every request the client sends.
+    pub fn with_middleware<M: Middleware>(mut self, middleware: M) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Record request metrics through the given handle
+    #[cfg(feature = "metrics")]
+    pub fn metrics(mut self, metrics: ClientMetrics) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Cache GET responses using the given settings
+    #[cfg(feature = "cache")]
+    pub fn cache(mut self, cache: CacheConfig) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Fail fast on endpoints that keep returning server errors
+    ///
+    /// See [`circuit_breaker`](crate::circuit_breaker) for how circuits open and close.
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Limit how many requests are in flight at once
+    ///
+    /// Requests over the limit are queued and started in [`Priority`] order. The limit
+    /// is shared by every clone of the client.
+    pub fn max_concurrent_requests(mut self, max: usize) -> Self {
+        self.max_concurrent_requests = Some(max.max(1));
+        self
+    }
+
+    /// Spend every request attempt from a client-side rate budget
+    ///
+    /// Attempts over the budget wait for it to refill, up to the request's deadline.
+    /// The limiter is shared by every clone of the client. See
+    /// [`rate_limit`](crate::rate_limit) for the limiters provided.
+    pub fn rate_limiter<L: RateLimiter>(mut self, limiter: L) -> Self {
+        self.rate_limiter = Some(Arc::new(limiter));
+        self
+    }
+
+    /// Send all requests through an HTTP or HTTPS proxy
+    pub fn proxy<S: Into<String>>(mut self, url: S) -> Self {
+        let basic_auth = self.proxy.take().and_then(|proxy| proxy.basic_auth);
+        self.proxy = Some(ProxyConfig {
+            url: url.into(),
+            basic_auth,
+        });
+        self
+    }
+
+    /// Authenticate to the proxy set with [`proxy`](Self::proxy) using basic auth
+    pub fn proxy_basic_auth<U: Into<String>, P: Into<String>>(
+        mut self,
+        username: U,
+        password: P,
+    ) -> Self {
+        let url = self.proxy.take().map(|proxy| proxy.url).unwrap_or_default();
+        self.proxy = Some(ProxyConfig {
+            url,
+            basic_auth: Some((username.into(), password.into())),
+        });
+        self
+    }
+
+    /// Trust an additional root certificate, e.g. a corporate CA
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pem = std::fs::read("corporate-ca.pem")?;
+    /// let client = xrplsale::Client::builder()
+    ///     .api_key("your-api-key")
+    ///     .proxy("http://proxy.internal:3128")
+    ///     .proxy_basic_auth("user", "password")
+    ///     .add_root_certificate(reqwest::Certificate::from_pem(&pem)?)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Use a pre-built `reqwest::Client` instead of constructing one
+    ///
+    /// The SDK's timeout, proxy, TLS, and connection pool settings are not applied to
+    /// it; configure them on the `reqwest::Client` itself. The SDK still sets its `Accept` and
+    /// `User-Agent` headers on each request.
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
     /// Build the client
     pub fn build(self) -> Result<Client> {
-        if self.config.api_key.is_empty() {
-            return Err(Error::Configuration("API key is required".to_string()));
+        if self.config.api_key.is_empty() && self.client_credentials.is_none() {
+            return Err(Error::Configuration(
+                "API key or client credentials are required".to_string(),
+            ));
+        }
+
+        Client::from_builder(self)
+    }
+
+    /// Drop the API key, client credentials, and signing secret, so a client built from
+    /// a shared template authenticates only with what is set afterwards
+    pub(crate) fn without_credentials(mut self) -> Self {
+        self.config.api_key.clear();
+        self.config.signing_secret = None;
+        self.client_credentials = None;
+        self
+    }
+
+    /// The clock set with [`clock`](Self::clock), or the system clock
+    pub(crate) fn configured_clock(&self) -> Arc<dyn Clock> {
+        self.clock.clone().unwrap_or_else(runtime::system_clock)
+    }
+
+    /// Build the HTTP client described by the transport settings, or return the
+    /// pre-built one
+    pub(crate) fn http_transport(&self) -> Result<reqwest::Client> {
+        let config = &self.config;
+
+        if let Some(http_client) = &self.http_client {
+            if self.has_transport_options() || !config.compression {
+                return Err(Error::Configuration(
+                    "Proxy, TLS, connection pool, and compression options cannot be combined with a pre-built HTTP client"
+                        .to_string(),
+                ));
+            }
+            return Ok(http_client.clone());
+        }
+
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+        let user_agent = HeaderValue::from_str(&crate::user_agent())
+            .map_err(|e| Error::Configuration(format!("Invalid User-Agent header: {}", e)))?;
+        headers.insert(USER_AGENT, user_agent);
+
+        self.build_transport(headers)
+    }
+
+    /// Build a native HTTP client with the transport settings
+    #[cfg(not(target_arch = "wasm32"))]
+    fn build_transport(&self, headers: HeaderMap) -> Result<reqwest::Client> {
+        let config = &self.config;
+        let mut http_builder = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .default_headers(headers)
+            .gzip(config.compression)
+            .brotli(config.compression);
+
+        if let Some(proxy) = &self.proxy {
+            let mut reqwest_proxy = reqwest::Proxy::all(&proxy.url).map_err(|e| {
+                Error::Configuration(format!("Invalid proxy URL '{}': {}", proxy.url, e))
+            })?;
+            if let Some((username, password)) = &proxy.basic_auth {
+                reqwest_proxy = reqwest_proxy.basic_auth(username, password);
+            }
+            http_builder = http_builder.proxy(reqwest_proxy);
+        }
+        for certificate in &self.root_certificates {
+            http_builder = http_builder.add_root_certificate(certificate.clone());
+        }
+        if let Some(max) = config.pool_max_idle_per_host {
+            http_builder = http_builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = config.pool_idle_timeout {
+            http_builder = http_builder.pool_idle_timeout(timeout);
+        }
+        if let Some(interval) = config.tcp_keepalive {
+            http_builder = http_builder.tcp_keepalive(interval);
+        }
+        if config.http2_prior_knowledge {
+            http_builder = http_builder.http2_prior_knowledge();
+        }
+
+        http_builder
+            .build()
+            .map_err(|e| Error::HttpClient(e.to_string()))
+    }
+
+    /// Build a browser HTTP client
+    ///
+    /// The browser's fetch API owns connections, TLS, proxies, and compression.
+    #[cfg(target_arch = "wasm32")]
+    fn build_transport(&self, headers: HeaderMap) -> Result<reqwest::Client> {
+        if self.has_transport_options() {
+            return Err(Error::Configuration(
+                "Proxy, TLS, and connection pool options are not available on wasm32".to_string(),
+            ));
         }
 
-        Client::with_config(self.config)
+        reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .map_err(|e| Error::HttpClient(e.to_string()))
+    }
+
+    /// Whether any proxy, TLS, or connection pool option is set
+    fn has_transport_options(&self) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        let root_certificates = !self.root_certificates.is_empty();
+        #[cfg(target_arch = "wasm32")]
+        let root_certificates = false;
+
+        self.proxy.is_some()
+            || root_certificates
+            || self.config.pool_max_idle_per_host.is_some()
+            || self.config.pool_idle_timeout.is_some()
+            || self.config.tcp_keepalive.is_some()
+            || self.config.http2_prior_knowledge
+    }
+
+    /// Whether a response cache is configured
+    #[cfg(feature = "cache")]
+    pub(crate) fn has_cache(&self) -> bool {
+        self.cache.is_some()
+    }
+
+    /// Add a static label to the configured metrics handle, if any
+    #[cfg(feature = "metrics")]
+    pub(crate) fn metrics_label(mut self, key: &str, value: &str) -> Self {
+        self.metrics = self.metrics.map(|metrics| metrics.with_label(key, value));
+        self
     }
 }
 
@@ -121,11 +811,34 @@ impl ClientBuilder {
 ///
 /// The client provides access to all platform services including projects,
 /// investments, analytics, webhooks, and authentication.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Client {
     config: Arc<ClientConfig>,
     http_client: reqwest::Client,
     auth_token: Arc<tokio::sync::RwLock<Option<String>>>,
+    middleware: Arc<MiddlewareStack>,
+    #[cfg(feature = "metrics")]
+    metrics: Option<ClientMetrics>,
+    #[cfg(feature = "cache")]
+    cache: Option<CacheConfig>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    limiter: Option<Arc<PriorityLimiter>>,
+    rate_limiter: Option<Arc<dyn RateLimiter>>,
+    token_provider: Option<Arc<TokenProvider>>,
+    rate_limits: RateLimitGuard,
+    deprecations: Arc<DeprecationTracker>,
+    options: RequestOptions,
+    clock: Arc<dyn Clock>,
+}
+
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("config", &self.config)
+            .field("options", &self.options)
+            .field("middleware", &self.middleware)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Client {
@@ -134,25 +847,182 @@ impl Client {
         ClientBuilder::new()
     }
 
+    /// Create a client configured from environment variables
+    ///
+    /// See [`ClientBuilder::from_env`] for the variables read. `XRPLSALE_API_KEY` is
+    /// required.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # fn main() -> xrplsale::Result<()> {
+    /// let client = xrplsale::Client::from_env()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<Self> {
+        ClientBuilder::from_env()?.build()
+    }
+
     /// Create a client with the given configuration
     pub fn with_config(config: ClientConfig) -> Result<Self> {
-        let mut headers = HeaderMap::new();
-        headers.insert("Accept", "application/json".parse().unwrap());
-        headers.insert("User-Agent", crate::user_agent().parse().unwrap());
+        Self::from_builder(ClientBuilder {
+            config,
+            ..Default::default()
+        })
+    }
 
-        let http_client = reqwest::Client::builder()
-            .timeout(config.timeout)
-            .default_headers(headers)
-            .build()
-            .map_err(|e| Error::HttpClient(e.to_string()))?;
+    fn from_builder(builder: ClientBuilder) -> Result<Self> {
+        let http_client = builder.http_transport()?;
+        Ok(Self::with_transport(builder, http_client))
+    }
 
-        Ok(Self {
+    /// Assemble a client around an existing HTTP client, ignoring the builder's
+    /// transport settings
+    pub(crate) fn with_transport(builder: ClientBuilder, http_client: reqwest::Client) -> Self {
+        let clock = builder.configured_clock();
+        let config = builder.config;
+
+        Self {
             config: Arc::new(config),
             http_client,
             auth_token: Arc::new(tokio::sync::RwLock::new(None)),
+            middleware: Arc::new(builder.middleware),
+            #[cfg(feature = "metrics")]
+            metrics: builder.metrics,
+            #[cfg(feature = "cache")]
+            cache: builder.cache,
+            circuit_breaker: builder
+                .circuit_breaker
+                .map(|config| Arc::new(CircuitBreaker::new(config, clock.clone()))),
+            limiter: builder
+                .max_concurrent_requests
+                .map(|max| Arc::new(PriorityLimiter::new(max))),
+            rate_limiter: builder.rate_limiter,
+            token_provider: builder
+                .client_credentials
+                .map(|credentials| Arc::new(TokenProvider::new(credentials, clock.clone()))),
+            rate_limits: RateLimitGuard::with_clock(clock.clone()),
+            deprecations: Arc::new(DeprecationTracker::new(builder.deprecation_callback)),
+            options: RequestOptions::default(),
+            clock,
+        }
+    }
+
+    /// Get a client whose requests use the given per-request options
+    ///
+    /// The returned client shares its connection pool, auth token, and middleware with
+    /// this one. Dropping a request future also cancels the request.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use xrplsale::{CancellationToken, Client, RequestOptions};
+    /// # use std::time::Duration;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// // Quick lookup with a tight timeout
+    /// let project = client
+    ///     .with_timeout(Duration::from_secs(2))
+    ///     .projects()
+    ///     .get("proj_abc123")
+    ///     .await?;
+    ///
+    /// // Cancellable request
+    /// let token = CancellationToken::new();
+    /// let projects = client
+    ///     .with_options(RequestOptions::new().cancellation(token.clone()))
+    ///     .projects();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self {
+            options,
+            ..self.clone()
+        }
+    }
+
+    /// Get a client whose request attempts time out after `timeout`
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        self.with_options(RequestOptions {
+            timeout: Some(timeout),
+            ..self.options.clone()
+        })
+    }
+
+    /// Get a client whose requests, including retries, must finish by `deadline`
+    pub fn with_deadline(&self, deadline: Instant) -> Self {
+        self.with_options(RequestOptions {
+            deadline: Some(deadline),
+            ..self.options.clone()
+        })
+    }
+
+    /// Get a client whose requests are scheduled with the given priority
+    ///
+    /// See [`priority`](crate::priority) for how priorities are applied.
+    pub fn with_priority(&self, priority: Priority) -> Self {
+        self.with_options(RequestOptions {
+            priority,
+            ..self.options.clone()
+        })
+    }
+
+    /// Get a client whose requests carry an idempotency key
+    ///
+    /// Use a fresh client, and key, for each logical operation: retries of the same
+    /// operation must reuse its key, and different operations must not share one.
+    pub fn with_idempotency_key<S: Into<String>>(&self, key: S) -> Self {
+        self.with_options(RequestOptions {
+            idempotency_key: Some(key.into()),
+            ..self.options.clone()
         })
     }
 
+    /// Get a client whose requests use a different API version
+    ///
+    /// Lets code move to a newer version one call site at a time:
+    ///
+    /// ```rust
+    /// # use xrplsale::{ApiVersion, Client};
+    /// # fn main() -> xrplsale::Result<()> {
+    /// let client = Client::builder()
+    ///     .api_key("test")
+    ///     .api_version(ApiVersion::V1)
+    ///     .build()?;
+    /// let preview = client.with_api_version(ApiVersion::Other("2026-09-preview".to_string()));
+    /// assert_eq!(preview.api_version(), Some(&ApiVersion::Other("2026-09-preview".to_string())));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_api_version(&self, version: ApiVersion) -> Self {
+        self.with_options(RequestOptions {
+            api_version: Some(version),
+            ..self.options.clone()
+        })
+    }
+
+    /// Get a client whose GET requests skip the response cache
+    ///
+    /// For reads that must reach the API each time, such as polling for new data.
+    pub fn without_cache(&self) -> Self {
+        Self {
+            #[cfg(feature = "cache")]
+            cache: None,
+            ..self.clone()
+        }
+    }
+
+    /// The API version this client's requests ask for, if any
+    pub fn api_version(&self) -> Option<&ApiVersion> {
+        self.options
+            .api_version
+            .as_ref()
+            .or(self.config.api_version.as_ref())
+    }
+
     /// Get the base URL for API requests
     pub fn base_url(&self) -> &str {
         self.config
@@ -161,6 +1031,22 @@ impl Client {
             .unwrap_or_else(|| self.config.environment.base_url())
     }
 
+    /// Get the API environment
+    pub fn environment(&self) -> &Environment {
+        &self.config.environment
+    }
+
+    /// The clock this client waits on and checks expirations against
+    pub(crate) fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    /// The underlying HTTP client, for calls to services other than the API
+    #[cfg(feature = "xrpl")]
+    pub(crate) fn http(&self) -> &reqwest::Client {
+        &self.http_client
+    }
+
     /// Set the authentication token
     pub async fn set_auth_token<S: Into<String>>(&self, token: Option<S>) {
         let mut auth_token = self.auth_token.write().await;
@@ -182,6 +1068,16 @@ impl Client {
         InvestmentsService::new(self.clone())
     }
 
+    /// Get the airdrops service
+    pub fn airdrops(&self) -> AirdropsService {
+        AirdropsService::new(self.clone())
+    }
+
+    /// Get the allocations service
+    pub fn allocations(&self) -> AllocationsService {
+        AllocationsService::new(self.clone())
+    }
+
     /// Get the analytics service
     pub fn analytics(&self) -> AnalyticsService {
         AnalyticsService::new(self.clone())
@@ -197,6 +1093,73 @@ impl Client {
         AuthService::new(self.clone())
     }
 
+    /// Get the liquidity service
+    pub fn liquidity(&self) -> LiquidityService {
+        LiquidityService::new(self.clone())
+    }
+
+    /// Get the secondary-market data service
+    pub fn market(&self) -> MarketService {
+        MarketService::new(self.clone())
+    }
+
+    /// Get the notifications service
+    pub fn notifications(&self) -> NotificationsService {
+        NotificationsService::new(self.clone())
+    }
+
+    /// Get the sale tier eligibility service
+    pub fn eligibility(&self) -> EligibilityService {
+        EligibilityService::new(self.clone())
+    }
+
+    /// Get the governance service
+    pub fn governance(&self) -> GovernanceService {
+        GovernanceService::new(self.clone())
+    }
+
+    /// Get the staking service
+    pub fn staking(&self) -> StakingService {
+        StakingService::new(self.clone())
+    }
+
+    /// Get the realtime updates service
+    pub fn realtime(&self) -> RealtimeService {
+        RealtimeService::new(self.clone())
+    }
+
+    /// Get the usage service
+    pub fn usage(&self) -> UsageService {
+        UsageService::new(self.clone())
+    }
+
+    /// Get the sandbox service for simulating sale activity
+    ///
+    /// Fails with [`Error::Configuration`] unless the client targets
+    /// [`Environment::Testnet`].
+    pub fn sandbox(&self) -> Result<SandboxService> {
+        SandboxService::new(self.clone())
+    }
+
+    /// Get the GraphQL service
+    #[cfg(feature = "graphql")]
+    pub fn graphql(&self) -> crate::services::GraphqlService {
+        crate::services::GraphqlService::new(self.clone())
+    }
+
+    /// Get the service for reconciling platform records with the XRP Ledger
+    #[cfg(feature = "xrpl")]
+    pub fn reconcile(&self) -> crate::services::ReconcileService {
+        crate::services::ReconcileService::new(self.clone())
+    }
+
+    /// Get the rate limits observed by this client
+    ///
+    /// See [`rate_limit`](crate::rate_limit) for details.
+    pub fn rate_limits(&self) -> RateLimitGuard {
+        self.rate_limits.clone()
+    }
+
     /// Create a webhook signature validator
     pub fn webhook_validator(&self) -> Option<WebhookSignatureValidator> {
         self.config
@@ -206,47 +1169,356 @@ impl Client {
     }
 
     /// Make a GET request
-    pub async fn get<T>(&self, path: &str, query: Option<&HashMap<String, String>>) -> Result<T>
+    ///
+    /// Use [`Client::get_with_query`] to add query parameters.
+    pub async fn get<T>(&self, path: &str) -> Result<T>
     where
         T: DeserializeOwned,
     {
-        let mut url = self.build_url(path)?;
-        
-        if let Some(query_params) = query {
-            let mut query_pairs = url.query_pairs_mut();
-            for (key, value) in query_params {
-                query_pairs.append_pair(key, value);
-            }
-        }
-
-        let request = self.http_client.get(url);
-        self.execute_request(request).await
+        self.get_url(self.build_url(path)?).await
     }
 
-    /// Make a POST request
-    pub async fn post<T, B>(&self, path: &str, body: Option<&B>) -> Result<T>
+    /// Make a GET request with typed query parameters
+    ///
+    /// `query` is any `Serialize` struct or map. `None` fields are left out, and
+    /// sequences repeat the parameter once per element, e.g. `status=active&status=upcoming`.
+    /// Fails with [`Error::Configuration`] if a field is a nested struct.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use xrplsale::Client;
+    /// #[derive(serde::Serialize)]
+    /// struct ProposalQuery {
+    ///     status: Vec<&'static str>,
+    ///     limit: Option<u32>,
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let query = ProposalQuery { status: vec!["open", "pending"], limit: None };
+    /// let proposals: serde_json::Value = client
+    ///     .get_with_query("/projects/proj_abc123/proposals", &query)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_with_query<T, Q>(&self, path: &str, query: &Q) -> Result<T>
     where
         T: DeserializeOwned,
-        B: Serialize,
+        Q: Serialize + ?Sized,
     {
-        let url = self.build_url(path)?;
-        let mut request = self.http_client.post(url);
+        self.get_url(self.typed_query_url(path, query)?).await
+    }
 
-        if let Some(body) = body {
-            request = request.json(body);
+    /// Make a GET request to a full URL, through the response cache if configured
+    async fn get_url<T>(&self, url: Url) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.cache {
+            return self.cancellable(self.get_cached(url, cache)).await;
+        }
+
+        let request = self.http_client.get(url);
+        self.execute_request(request).await
+    }
+
+    /// Make a GET request, returning the response metadata with the body
+    ///
+    /// Always goes to the API, bypassing the response cache.
+    pub async fn get_with_meta<T>(&self, path: &str) -> Result<ApiResponse<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let request = self.http_client.get(self.build_url(path)?);
+        self.execute_request_with_meta(request).await
+    }
+
+    /// Make a GET request for a list of items
+    ///
+    /// Accepts both a bare JSON array and a `{"data": [...]}` envelope, so endpoints
+    /// returning a handful of items parse whichever form the API sends. Pagination
+    /// metadata in the envelope is ignored; use [`Client::get`] with
+    /// [`PaginatedResponse`](crate::types::common::PaginatedResponse) for paged listings.
+    pub async fn get_list<T>(&self, path: &str) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+    {
+        list_items(self.get(path).await?)
+    }
+
+    /// Make a GET request for a list of items with typed query parameters
+    ///
+    /// Combines [`Client::get_list`] and [`Client::get_with_query`].
+    pub async fn get_list_with_query<T, Q>(&self, path: &str, query: &Q) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+        Q: Serialize + ?Sized,
+    {
+        list_items(self.get_with_query(path, query).await?)
+    }
+
+    /// Make a GET request, streaming the raw body instead of deserializing it
+    ///
+    /// For large downloads such as documents and exports, which would otherwise be
+    /// buffered whole. Retries cover the request up to the response headers; an error
+    /// while reading the body is yielded by the stream. The client's timeout still
+    /// applies to the whole download.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - API path of the download
+    pub async fn get_bytes_stream(&self, path: &str) -> Result<ByteStream> {
+        let request = self.http_client.get(self.build_url(path)?);
+        self.open_stream(request).await
+    }
+
+    /// Open a `text/event-stream` response for server-sent events
+    ///
+    /// `last_event_id` is sent as `Last-Event-ID` so the server resumes after it.
+    pub(crate) async fn get_event_stream<Q>(
+        &self,
+        path: &str,
+        query: &Q,
+        last_event_id: Option<&str>,
+    ) -> Result<ByteStream>
+    where
+        Q: Serialize + ?Sized,
+    {
+        let mut request = self
+            .http_client
+            .get(self.typed_query_url(path, query)?)
+            .header(ACCEPT, "text/event-stream");
+        if let Some(id) = last_event_id {
+            request = request.header("Last-Event-ID", id);
+        }
+        self.open_stream(request).await
+    }
+
+    /// Send a request and hand back its body unread
+    async fn open_stream(&self, request: RequestBuilder) -> Result<ByteStream> {
+        self.cancellable(async {
+            let started = self.clock.now();
+            let (method, response) = self.dispatch(request).await?;
+
+            if !response.status().is_success() {
+                return Err(self.response_error(response, &method).await);
+            }
+
+            Ok(ByteStream::new(
+                response,
+                self.clock.now().saturating_duration_since(started),
+                self.clock.utc_now(),
+            ))
+        })
+        .await
+    }
+
+    /// Make a GET request through the response cache
+    #[cfg(feature = "cache")]
+    async fn get_cached<T>(&self, url: Url, cache: &CacheConfig) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let key = CacheConfig::key(&url, self.api_version());
+        let cached = cache.store().get(&key);
+
+        if let Some(entry) = cached
+            .as_ref()
+            .filter(|entry| entry.is_fresh_at(self.clock.now()))
+        {
+            return self.parse_body(&entry.body);
         }
 
+        let ttl = cache.ttl_for(&endpoint_template(url.path(), &self.base_path()));
+        let mut request = self.http_client.get(url);
+        if let Some(etag) = cached.as_ref().and_then(|entry| entry.etag.as_deref()) {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+
+        let request = self.prepare_request(request).await?;
+        let response = self.send_request(request).await?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some(mut entry) = cached {
+                entry.stored_at = self.clock.now();
+                entry.ttl = ttl;
+                cache.store().put(&key, entry.clone());
+                return self.parse_body(&entry.body);
+            }
+        }
+
+        if !response.status().is_success() {
+            return self.handle_response(response, &Method::GET).await;
+        }
+
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|h| h.to_str().ok())
+            .map(|etag| etag.to_string());
+        let text = response.text().await.map_err(|e| Error::HttpClient(e.to_string()))?;
+
+        if etag.is_some() || !ttl.is_zero() {
+            cache.store().put(
+                &key,
+                CachedResponse {
+                    body: text.clone(),
+                    etag,
+                    stored_at: self.clock.now(),
+                    ttl,
+                },
+            );
+        }
+
+        self.parse_body(&text)
+    }
+
+    /// Make a POST request
+    pub async fn post<T, B>(&self, path: &str, body: Option<&B>) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let request = self.json_request(Method::POST, path, body)?;
         self.execute_request(request).await
     }
 
+    /// Make a POST request, returning the response metadata with the body
+    pub async fn post_with_meta<T, B>(&self, path: &str, body: Option<&B>) -> Result<ApiResponse<T>>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let request = self.json_request(Method::POST, path, body)?;
+        self.execute_request_with_meta(request).await
+    }
+
     /// Make a PUT request
     pub async fn put<T, B>(&self, path: &str, body: Option<&B>) -> Result<T>
     where
         T: DeserializeOwned,
         B: Serialize,
+    {
+        let request = self.json_request(Method::PUT, path, body)?;
+        self.execute_request(request).await
+    }
+
+    /// Make a PUT request, returning the response metadata with the body
+    pub async fn put_with_meta<T, B>(&self, path: &str, body: Option<&B>) -> Result<ApiResponse<T>>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let request = self.json_request(Method::PUT, path, body)?;
+        self.execute_request_with_meta(request).await
+    }
+
+    /// Make a PATCH request
+    pub async fn patch<T, B>(&self, path: &str, body: Option<&B>) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let request = self.json_request(Method::PATCH, path, body)?;
+        self.execute_request(request).await
+    }
+
+    /// Make a PATCH request, returning the response metadata with the body
+    pub async fn patch_with_meta<T, B>(
+        &self,
+        path: &str,
+        body: Option<&B>,
+    ) -> Result<ApiResponse<T>>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let request = self.json_request(Method::PATCH, path, body)?;
+        self.execute_request_with_meta(request).await
+    }
+
+    /// Make a DELETE request
+    pub async fn delete<T>(&self, path: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let url = self.build_url(path)?;
+        let request = self.http_client.delete(url);
+        self.execute_request(request).await
+    }
+
+    /// Make a DELETE request, returning the response metadata with the body
+    pub async fn delete_with_meta<T>(&self, path: &str) -> Result<ApiResponse<T>>
+    where
+        T: DeserializeOwned,
     {
         let url = self.build_url(path)?;
-        let mut request = self.http_client.put(url);
+        let request = self.http_client.delete(url);
+        self.execute_request_with_meta(request).await
+    }
+
+    /// Make a DELETE request with a JSON body, e.g. to remove several items at once
+    pub async fn delete_with_body<T, B>(&self, path: &str, body: Option<&B>) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let request = self.json_request(Method::DELETE, path, body)?;
+        self.execute_request(request).await
+    }
+
+    /// Make a HEAD request, returning only the response metadata
+    ///
+    /// Useful for existence checks: a missing resource fails with
+    /// [`Error::NotFound`].
+    pub async fn head(&self, path: &str) -> Result<ResponseMeta> {
+        let request = self.http_client.head(self.build_url(path)?);
+        self.execute_request_with_meta::<()>(request)
+            .await
+            .map(|response| response.meta)
+    }
+
+    /// Make a request with any method and body
+    ///
+    /// An escape hatch for endpoints the typed methods don't cover, such as
+    /// `OPTIONS`. Query parameters can be included in `path`. Goes through the same
+    /// authentication, retries, and rate limiting as every other request, but bypasses
+    /// the response cache.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use reqwest::Method;
+    /// use xrplsale::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let removed: serde_json::Value = client
+    ///     .request(
+    ///         Method::DELETE,
+    ///         "/projects/proj_abc123/whitelist",
+    ///         Some(&serde_json::json!({ "accounts": ["rAlice", "rBob"] })),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn request<T, B>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let mut request = self.http_client.request(method, self.build_url(path)?);
 
         if let Some(body) = body {
             request = request.json(body);
@@ -255,74 +1527,298 @@ impl Client {
         self.execute_request(request).await
     }
 
-    /// Make a PATCH request
-    pub async fn patch<T, B>(&self, path: &str, body: Option<&B>) -> Result<T>
+    /// Send an [`ApiRequest`](crate::service::ApiRequest), keeping the JSON body and metadata
+    #[cfg(feature = "tower")]
+    pub(crate) async fn send_api_request(
+        &self,
+        request: crate::service::ApiRequest,
+    ) -> Result<ApiResponse<serde_json::Value>> {
+        let mut url = self.build_url(request.path())?;
+        if !request.query_pairs().is_empty() {
+            url.query_pairs_mut().extend_pairs(request.query_pairs());
+        }
+
+        let mut builder = self.http_client.request(request.method().clone(), url);
+        if let Some(body) = request.body() {
+            builder = builder.json(body);
+        }
+
+        self.execute_request_with_meta(builder).await
+    }
+
+    /// Build a request with an optional JSON body
+    fn json_request<B>(&self, method: Method, path: &str, body: Option<&B>) -> Result<RequestBuilder>
     where
-        T: DeserializeOwned,
         B: Serialize,
     {
         let url = self.build_url(path)?;
-        let mut request = self.http_client.patch(url);
+        let mut request = self.http_client.request(method, url);
 
         if let Some(body) = body {
             request = request.json(body);
         }
 
-        self.execute_request(request).await
+        Ok(request)
     }
 
-    /// Make a DELETE request
-    pub async fn delete<T>(&self, path: &str) -> Result<T>
+    /// Execute an HTTP request with retry logic
+    async fn execute_request<T>(&self, request: RequestBuilder) -> Result<T>
     where
         T: DeserializeOwned,
     {
-        let url = self.build_url(path)?;
-        let request = self.http_client.delete(url);
-        self.execute_request(request).await
+        self.execute_request_with_meta(request)
+            .await
+            .map(ApiResponse::into_inner)
     }
 
-    /// Execute an HTTP request with retry logic
-    async fn execute_request<T>(&self, mut request: RequestBuilder) -> Result<T>
+    /// Execute an HTTP request with retry logic, keeping the response metadata
+    async fn execute_request_with_meta<T>(&self, request: RequestBuilder) -> Result<ApiResponse<T>>
     where
         T: DeserializeOwned,
     {
-        // Add authentication headers
+        self.cancellable(async {
+            let started = self.clock.now();
+            let (method, response) = self.dispatch(request).await?;
+
+            let (status, headers) = (response.status(), response.headers().clone());
+            let data = self.handle_response(response, &method).await?;
+
+            Ok(ApiResponse {
+                data,
+                meta: ResponseMeta::new(
+                    status,
+                    headers,
+                    self.clock.now().saturating_duration_since(started),
+                    self.clock.utc_now(),
+                ),
+            })
+        })
+        .await
+    }
+
+    /// Sign and send a request with retry logic, returning the unread response
+    async fn dispatch(&self, request: RequestBuilder) -> Result<(Method, Response)> {
+        let request = self.prepare_request(request).await?;
+        let method = request.method().clone();
+        #[cfg(feature = "cache")]
+        let url = request.url().clone();
+
+        let response = self.send_request(request).await?;
+
+        if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+            if let Some(provider) = &self.token_provider {
+                provider.invalidate().await;
+            }
+        }
+
+        #[cfg(feature = "cache")]
+        if method != Method::GET && response.status().is_success() {
+            if let Some(cache) = &self.cache {
+                cache.invalidate(&url, self.api_version());
+            }
+        }
+
+        Ok((method, response))
+    }
+
+    /// Run a request future, aborting it if the cancellation token fires
+    async fn cancellable<T, F>(&self, request: F) -> Result<T>
+    where
+        F: std::future::Future<Output = Result<T>>,
+    {
+        match &self.options.cancellation {
+            Some(token) => tokio::select! {
+                _ = token.cancelled() => Err(Error::HttpClient("Request cancelled".to_string())),
+                result = request => result,
+            },
+            None => request.await,
+        }
+    }
+
+    /// Add authentication headers and build the request
+    async fn prepare_request(&self, mut request: RequestBuilder) -> Result<Request> {
         if let Some(token) = self.get_auth_token().await {
             request = request.bearer_auth(token);
+        } else if let Some(provider) = &self.token_provider {
+            let token = provider.token(&self.http_client, self.base_url()).await?;
+            request = request.bearer_auth(token);
         } else {
             request = request.header("X-API-Key", &self.config.api_key);
         }
 
+        if let Some(key) = &self.options.idempotency_key {
+            request = request.header("Idempotency-Key", key);
+        }
+        if let Some(version) = self.api_version() {
+            request = request.header(API_VERSION_HEADER, version.as_str());
+        }
+
+        // Set explicitly so pre-built HTTP clients without these defaults still send them
+        request = request.header("User-Agent", crate::user_agent());
+
+        let mut request = request.build().map_err(|e| Error::HttpClient(e.to_string()))?;
+        // Requests that ask for another media type (e.g. event streams) keep their own
+        request
+            .headers_mut()
+            .entry(ACCEPT)
+            .or_insert(HeaderValue::from_static("application/json"));
+
+        Ok(request)
+    }
+
+    /// Send a request through the middleware chain, retrying transport failures
+    async fn send_request(&self, request: Request) -> Result<Response> {
+        let log_bodies = self.logs_bodies(request.method());
         let mut last_error = None;
 
         for attempt in 0..=self.config.max_retries {
-            let req = request
+            let mut req = request
                 .try_clone()
                 .ok_or_else(|| Error::HttpClient("Failed to clone request".to_string()))?;
 
-            match req.send().await {
+            let (method, endpoint) = (
+                req.method().clone(),
+                endpoint_template(req.url().path(), &self.base_path()),
+            );
+
+            let priority = self.options.priority;
+            if let Some(hold) = self.rate_limits.hold_for(&method, &endpoint, priority) {
+                let hold = match self.options.deadline {
+                    Some(deadline) => hold.min(deadline.saturating_duration_since(self.clock.now())),
+                    None => hold,
+                };
+                if self.config.debug {
+                    log::debug!(
+                        "Holding {:?} request to {} {} for {:?} (rate limited)",
+                        priority,
+                        method,
+                        endpoint,
+                        hold
+                    );
+                }
+                self.clock.sleep(hold).await;
+            }
+            if let Some(rate_limiter) = &self.rate_limiter {
+                while let Some(wait) = rate_limiter.try_acquire(priority).await? {
+                    let wait = match self.options.deadline {
+                        Some(deadline) => {
+                            let left = deadline.saturating_duration_since(self.clock.now());
+                            if left.is_zero() {
+                                break;
+                            }
+                            wait.min(left)
+                        }
+                        None => wait,
+                    };
+                    self.clock.sleep(wait).await;
+                }
+            }
+            let slot = match &self.limiter {
+                Some(limiter) => Some(limiter.acquire(priority).await),
+                None => None,
+            };
+
+            let remaining = match self.options.deadline {
+                Some(deadline) => match deadline.checked_duration_since(self.clock.now()) {
+                    Some(remaining) if !remaining.is_zero() => Some(remaining),
+                    _ => {
+                        return Err(last_error.unwrap_or_else(|| {
+                            Error::HttpClient("Request deadline exceeded".to_string())
+                        }))
+                    }
+                },
+                None => None,
+            };
+            let timeout = match (self.options.timeout, remaining) {
+                (Some(timeout), Some(remaining)) => Some(timeout.min(remaining)),
+                (timeout, remaining) => timeout.or(remaining),
+            };
+            #[cfg(not(target_arch = "wasm32"))]
+            if timeout.is_some() {
+                *req.timeout_mut() = timeout;
+            }
+            #[cfg(target_arch = "wasm32")]
+            let timeout = timeout.unwrap_or(self.config.timeout);
+
+            if self.config.debug {
+                logging::log_request(&req, attempt, log_bodies);
+            }
+
+            let permit = match &self.circuit_breaker {
+                Some(breaker) => Some(breaker.acquire(&format!("{} {}", method, endpoint))?),
+                None => None,
+            };
+
+            let started = self.clock.now();
+
+            let mut next = Next::new(&self.http_client, self.middleware.as_slice());
+            if let Some(secret) = &self.config.signing_secret {
+                next = next.signed(secret, self.clock.as_ref());
+            }
+            let result = next.run(req);
+            #[cfg(not(target_arch = "wasm32"))]
+            let result = result.await;
+            #[cfg(target_arch = "wasm32")]
+            let result = runtime::timeout(timeout, result)
+                .await
+                .unwrap_or_else(|| Err(Error::HttpClient("Request timed out".to_string())));
+
+            if let Some(permit) = permit {
+                let healthy = matches!(&result, Ok(response) if !response.status().is_server_error());
+                permit.record(healthy);
+            }
+
+            #[cfg(feature = "metrics")]
+            if let Some(metrics) = &self.metrics {
+                let status = result.as_ref().ok().map(|r| r.status().as_u16());
+                metrics.record_request(
+                    &method,
+                    &endpoint,
+                    status,
+                    self.clock.now().saturating_duration_since(started),
+                );
+                if result.is_err() && attempt < self.config.max_retries {
+                    metrics.record_retry(&method, &endpoint);
+                }
+            }
+
+            match result {
                 Ok(response) => {
                     if self.config.debug {
-                        log::debug!(
-                            "HTTP {} {} -> {}",
-                            response.request().map(|r| r.method()).unwrap_or(&Method::GET),
-                            response.request().map(|r| r.url()).unwrap().as_str(),
-                            response.status()
+                        logging::log_response(
+                            &response,
+                            self.clock.now().saturating_duration_since(started),
                         );
                     }
 
-                    return self.handle_response(response).await;
+                    self.rate_limits.observe(
+                        &method,
+                        &endpoint,
+                        response.status(),
+                        response.headers(),
+                    );
+                    self.deprecations.observe(&method, &endpoint, response.headers());
+
+                    return Ok(response);
                 }
                 Err(e) => {
-                    last_error = Some(Error::HttpClient(e.to_string()));
-
+                    drop(slot);
                     if attempt < self.config.max_retries {
                         let delay = self.config.retry_delay * 2_u32.pow(attempt as u32);
+                        let past_deadline = self
+                            .options
+                            .deadline
+                            .is_some_and(|deadline| self.clock.now() + delay >= deadline);
+                        if past_deadline {
+                            return Err(e);
+                        }
                         if self.config.debug {
                             log::debug!("Request failed, retrying in {:?}: {}", delay, e);
                         }
-                        tokio::time::sleep(delay).await;
+                        self.clock.sleep(delay).await;
                     }
+
+                    last_error = Some(e);
                 }
             }
         }
@@ -330,54 +1826,94 @@ impl Client {
         Err(last_error.unwrap_or_else(|| Error::HttpClient("Unknown error".to_string())))
     }
 
+    /// Whether bodies for this method should be included in debug logs
+    fn logs_bodies(&self, method: &Method) -> bool {
+        self.config.debug && self.config.log_bodies.contains(method)
+    }
+
     /// Handle HTTP response
-    async fn handle_response<T>(&self, response: Response) -> Result<T>
+    async fn handle_response<T>(&self, response: Response, method: &Method) -> Result<T>
     where
         T: DeserializeOwned,
     {
-        let status = response.status();
-        let url = response.url().clone();
-
-        if status.is_success() {
+        if response.status().is_success() {
             let text = response.text().await.map_err(|e| Error::HttpClient(e.to_string()))?;
-            
-            if text.is_empty() {
-                // Handle empty responses for endpoints that return no content
-                return serde_json::from_str("null").map_err(|e| Error::Parse(e.to_string()));
+
+            if self.logs_bodies(method) {
+                log::debug!("Response body: {}", logging::redact_body(text.as_bytes()));
             }
 
-            serde_json::from_str(&text).map_err(|e| {
-                if self.config.debug {
-                    log::debug!("Failed to parse response: {}", text);
-                }
-                Error::Parse(e.to_string())
-            })
+            self.parse_body(&text)
         } else {
-            let text = response.text().await.unwrap_or_default();
-            
-            match status.as_u16() {
-                400 => Err(Error::BadRequest(text)),
-                401 => Err(Error::Unauthorized(text)),
-                404 => Err(Error::NotFound(text)),
-                429 => {
-                    let retry_after = response
-                        .headers()
-                        .get("retry-after")
-                        .and_then(|h| h.to_str().ok())
-                        .and_then(|s| s.parse().ok());
-                    
-                    Err(Error::RateLimit { 
-                        message: text, 
-                        retry_after 
-                    })
-                }
-                _ => Err(Error::Api {
-                    status: status.as_u16(),
-                    message: text,
-                    url: url.to_string(),
-                }),
+            Err(self.response_error(response, method).await)
+        }
+    }
+
+    /// Turn an unsuccessful response into an error
+    async fn response_error(&self, response: Response, method: &Method) -> Error {
+        let status = response.status();
+        let url = response.url().clone();
+        let retry_after = rate_limit::retry_after(response.headers());
+        let text = response.text().await.unwrap_or_default();
+
+        if self.logs_bodies(method) {
+            log::debug!("Response body: {}", logging::redact_body(text.as_bytes()));
+        }
+
+        match status.as_u16() {
+            400 => Error::BadRequest(text),
+            401 => Error::Unauthorized(text),
+            404 => Error::NotFound(text),
+            429 => Error::RateLimit {
+                message: text,
+                retry_after: retry_after.map(|d| d.as_secs()),
+            },
+            _ => Error::Api {
+                status: status.as_u16(),
+                message: text,
+                url: url.to_string(),
+            },
+        }
+    }
+
+    /// Deserialize a successful response body
+    fn parse_body<T>(&self, text: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        if text.is_empty() {
+            // Handle empty responses for endpoints that return no content
+            return serde_json::from_str("null").map_err(|e| Error::Parse(e.to_string()));
+        }
+
+        serde_json::from_str(text).map_err(|e| {
+            if self.config.debug {
+                log::debug!("Failed to parse response ({} bytes): {}", text.len(), e);
             }
+            Error::Parse(e.to_string())
+        })
+    }
+
+    /// Path component of the base URL, e.g. `/v1`
+    fn base_path(&self) -> String {
+        Url::parse(self.base_url())
+            .map(|url| url.path().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Build a full URL from a path and typed query parameters
+    fn typed_query_url<Q>(&self, path: &str, query: &Q) -> Result<Url>
+    where
+        Q: Serialize + ?Sized,
+    {
+        let mut url = self.build_url(path)?;
+        let pairs = query::to_pairs(query)?;
+
+        if !pairs.is_empty() {
+            url.query_pairs_mut().extend_pairs(pairs);
         }
+
+        Ok(url)
     }
 
     /// Build a full URL from a path
@@ -388,4 +1924,192 @@ impl Client {
         base.join(path.trim_start_matches('/'))
             .map_err(|e| Error::Configuration(format!("Invalid path: {}", e)))
     }
-}
\ No newline at end of file
+
+    /// Turn a `Location` header into a path relative to the base URL
+    ///
+    /// Accepts absolute URLs as well as absolute and relative paths.
+    pub(crate) fn relative_path(&self, location: &str) -> Result<String> {
+        let base = Url::parse(self.base_url())
+            .map_err(|e| Error::Configuration(format!("Invalid base URL: {}", e)))?;
+        let url = base
+            .join(location)
+            .map_err(|e| Error::Parse(format!("Invalid Location header '{}': {}", location, e)))?;
+
+        let base_path = base.path().trim_end_matches('/');
+        let path = url.path().strip_prefix(base_path).unwrap_or(url.path());
+        Ok(match url.query() {
+            Some(query) => format!("{}?{}", path, query),
+            None => path.to_string(),
+        })
+    }
+}
+
+/// Turn a request path into a low-cardinality endpoint template
+///
+/// `base_path` (e.g. `/v1`) is stripped, and identifier segments are replaced with
+/// `:id`, e.g. `/v1/projects/proj_abc123/stats` becomes `/projects/:id/stats`. See
+/// [`is_id_segment`] for what counts as an identifier.
+pub(crate) fn endpoint_template(path: &str, base_path: &str) -> String {
+    let path = path
+        .strip_prefix(base_path.trim_end_matches('/'))
+        .unwrap_or(path);
+
+    let segments: Vec<&str> = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| if is_id_segment(segment) { ":id" } else { segment })
+        .collect();
+
+    format!("/{}", segments.join("/"))
+}
+
+/// Whether a path segment identifies a resource rather than naming part of the route
+///
+/// Matches numbers, UUIDs, transaction hashes, prefixed IDs such as `proj_abc123`,
+/// and XRPL classic addresses. Route words are kept even when they contain an
+/// underscore or a digit, like `cap_table` or `v2`.
+fn is_id_segment(segment: &str) -> bool {
+    let is_hex = |s: &str| s.chars().all(|c| c.is_ascii_hexdigit());
+
+    let number = !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit());
+    let uuid = segment.len() == 36
+        && segment.split('-').map(str::len).eq([8, 4, 4, 4, 12])
+        && is_hex(&segment.replace('-', ""));
+    let hash = segment.len() == 64 && is_hex(segment);
+
+    number || uuid || hash || is_prefixed_id(segment) || is_classic_address(segment)
+}
+
+/// `prefix_suffix` with a short lowercase prefix and an alphanumeric suffix that
+/// contains a digit, e.g. `proj_abc123` or `evt_1`
+fn is_prefixed_id(segment: &str) -> bool {
+    let Some((prefix, suffix)) = segment.split_once('_') else {
+        return false;
+    };
+    (2..=10).contains(&prefix.len())
+        && prefix.chars().all(|c| c.is_ascii_lowercase())
+        && !suffix.is_empty()
+        && suffix.chars().all(|c| c.is_ascii_alphanumeric())
+        && suffix.chars().any(|c| c.is_ascii_digit())
+}
+
+/// An XRPL classic address: `r` followed by base58 characters, 25 to 35 in all
+fn is_classic_address(segment: &str) -> bool {
+    const BASE58: &str = "rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+
+    segment.starts_with('r')
+        && (25..=35).contains(&segment.len())
+        && segment.chars().all(|c| BASE58.contains(c))
+        && segment.chars().any(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+/// Extract the items of a list response, bare or wrapped in a `data` envelope
+fn list_items<T>(body: serde_json::Value) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    let items = match body {
+        serde_json::Value::Object(mut envelope) => envelope
+            .remove("data")
+            .ok_or_else(|| Error::Parse("Expected a list or a data envelope".to_string()))?,
+        body => body,
+    };
+    if items.is_null() {
+        return Ok(Vec::new());
+    }
+    serde_json::from_value(items).map_err(|e| Error::Parse(e.to_string()))
+}
+
+/// Read an environment variable, treating empty values as unset
+fn env_var(name: &str) -> Result<Option<String>> {
+    match std::env::var(name) {
+        Ok(value) if value.trim().is_empty() => Ok(None),
+        Ok(value) => Ok(Some(value.trim().to_string())),
+        Err(std::env::VarError::NotPresent) => Ok(None),
+        Err(std::env::VarError::NotUnicode(_)) => Err(Error::Configuration(format!(
+            "{}: value is not valid UTF-8",
+            name
+        ))),
+    }
+}
+
+/// Read a boolean environment variable, treating empty values as unset
+fn env_flag(name: &str) -> Result<Option<bool>> {
+    env_var(name)?
+        .map(|value| match value.to_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => Ok(true),
+            "0" | "false" | "no" | "off" => Ok(false),
+            _ => Err(Error::Configuration(format!(
+                "{}: expected true or false, got '{}'",
+                name, value
+            ))),
+        })
+        .transpose()
+}
+
+/// Read and parse an environment variable, treating empty values as unset
+fn parse_env_var<T>(name: &str) -> Result<Option<T>>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    env_var(name)?
+        .map(|value| {
+            value.parse().map_err(|e| {
+                Error::Configuration(format!("{}: invalid value '{}': {}", name, value, e))
+            })
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn templates_identifier_segments() {
+        let cases = [
+            ("/v1/projects/proj_abc123/stats", "/projects/:id/stats"),
+            ("/v1/projects/proj_abc123/tiers/2", "/projects/:id/tiers/:id"),
+            (
+                "/v1/investments/9f1c2d3e-4b5a-6789-abcd-ef0123456789",
+                "/investments/:id",
+            ),
+            (
+                "/v1/investors/rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh/investments",
+                "/investors/:id/investments",
+            ),
+            (
+                "/v1/transactions/E3FE6EA3D48F0C2B639448020EA4F03D4F4F8FFDB243A852A0F59177921B4879",
+                "/transactions/:id",
+            ),
+            ("/v1/webhooks/events/evt_1", "/webhooks/events/:id"),
+        ];
+        for (path, template) in cases {
+            assert_eq!(endpoint_template(path, "/v1"), template, "{}", path);
+        }
+    }
+
+    #[test]
+    fn keeps_route_words() {
+        let cases = [
+            ("/v1/projects/proj_abc123/cap_table", "/projects/:id/cap_table"),
+            ("/v1/v2/projects", "/v2/projects"),
+            ("/v1/oauth2/token", "/oauth2/token"),
+            ("/v1/projects/featured", "/projects/featured"),
+            ("/v1/projects/proj_abc123/launch-checklist", "/projects/:id/launch-checklist"),
+            ("/v1/airdrops/recipients", "/airdrops/recipients"),
+            ("/v1/api-keys", "/api-keys"),
+        ];
+        for (path, template) in cases {
+            assert_eq!(endpoint_template(path, "/v1"), template, "{}", path);
+        }
+    }
+
+    #[test]
+    fn strips_only_the_base_path() {
+        assert_eq!(endpoint_template("/projects/123", ""), "/projects/:id");
+        assert_eq!(endpoint_template("/api/v1/usage", "/api/v1/"), "/usage");
+        assert_eq!(endpoint_template("/v1", "/v1"), "/");
+    }
+}
@@ -0,0 +1,263 @@
+//! Loading client configuration from TOML or YAML files
+//!
+//! Files use the same settings as [`ClientBuilder`](crate::ClientBuilder), with
+//! durations given in seconds or milliseconds. Settings under `environments.<name>`
+//! override the top-level ones when `environment` selects that name:
+//!
+//! ```toml
+//! api_key = "your-api-key"
+//! environment = "testnet"
+//! timeout_secs = 30
+//! max_retries = 3
+//!
+//! [environments.testnet]
+//! api_key = "your-testnet-api-key"
+//! debug = true
+//!
+//! [environments.staging]
+//! base_url = "https://api.staging.example.com/v1"
+//! ```
+//!
+//! The format is chosen from the file extension (`.toml`, `.yaml`, or `.yml`).
+
+use crate::{
+    client::ClientConfig,
+    error::{Error, Result},
+    Environment,
+};
+use reqwest::Method;
+use serde::Deserialize;
+use std::{collections::HashMap, path::Path, time::Duration};
+
+/// Settings that may appear at the top level or in an environment section
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileSettings {
+    api_key: Option<String>,
+    base_url: Option<String>,
+    timeout_secs: Option<u64>,
+    max_retries: Option<usize>,
+    retry_delay_ms: Option<u64>,
+    webhook_secret: Option<String>,
+    signing_secret: Option<String>,
+    debug: Option<bool>,
+    log_bodies: Option<Vec<String>>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout_secs: Option<u64>,
+    tcp_keepalive_secs: Option<u64>,
+    http2_prior_knowledge: Option<bool>,
+    compression: Option<bool>,
+    api_version: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FileConfig {
+    environment: Option<String>,
+    #[serde(default)]
+    environments: HashMap<String, FileSettings>,
+    #[serde(flatten)]
+    settings: FileSettings,
+    #[serde(flatten)]
+    unknown: HashMap<String, serde::de::IgnoredAny>,
+}
+
+impl ClientConfig {
+    /// Load a configuration from a TOML or YAML file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to a `.toml`, `.yaml`, or `.yml` file
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use xrplsale::{Client, ClientConfig};
+    ///
+    /// # fn main() -> xrplsale::Result<()> {
+    /// let config = ClientConfig::from_file("xrplsale.toml")?;
+    /// let client = Client::with_config(config)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let invalid =
+            |e: &dyn std::fmt::Display| Error::Configuration(format!("{}: {}", path.display(), e));
+
+        let file: FileConfig = config::Config::builder()
+            .add_source(config::File::from(path))
+            .build()
+            .and_then(|c| c.try_deserialize())
+            .map_err(|e| invalid(&e))?;
+
+        if let Some(key) = file.unknown.keys().next() {
+            return Err(invalid(&format!("unknown setting '{}'", key)));
+        }
+
+        let mut config = ClientConfig::default();
+
+        let section = file
+            .environment
+            .as_ref()
+            .and_then(|name| file.environments.get(name));
+
+        if let Some(name) = &file.environment {
+            config.environment = match name.parse() {
+                Ok(environment) => environment,
+                // A section with its own base URL defines a custom environment
+                Err(_) => match section.and_then(|s| s.base_url.as_deref()) {
+                    Some(base_url) => Environment::custom(base_url),
+                    None => return Err(invalid(&format!("unknown environment '{}'", name))),
+                },
+            };
+        }
+
+        file.settings.apply(&mut config)?;
+        if let Some(section) = section {
+            section.apply(&mut config)?;
+        }
+
+        Ok(config)
+    }
+}
+
+impl FileSettings {
+    fn apply(&self, config: &mut ClientConfig) -> Result<()> {
+        if let Some(api_key) = &self.api_key {
+            config.api_key = api_key.clone();
+        }
+        if let Some(base_url) = &self.base_url {
+            config.base_url = Some(base_url.clone());
+        }
+        if let Some(secs) = self.timeout_secs {
+            config.timeout = Duration::from_secs(secs);
+        }
+        if let Some(retries) = self.max_retries {
+            config.max_retries = retries;
+        }
+        if let Some(millis) = self.retry_delay_ms {
+            config.retry_delay = Duration::from_millis(millis);
+        }
+        if let Some(secret) = &self.webhook_secret {
+            config.webhook_secret = Some(secret.clone());
+        }
+        if let Some(secret) = &self.signing_secret {
+            config.signing_secret = Some(secret.clone());
+        }
+        if let Some(debug) = self.debug {
+            config.debug = debug;
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            config.pool_max_idle_per_host = Some(max);
+        }
+        if let Some(secs) = self.pool_idle_timeout_secs {
+            config.pool_idle_timeout = Some(Duration::from_secs(secs));
+        }
+        if let Some(secs) = self.tcp_keepalive_secs {
+            config.tcp_keepalive = Some(Duration::from_secs(secs));
+        }
+        if let Some(enabled) = self.http2_prior_knowledge {
+            config.http2_prior_knowledge = enabled;
+        }
+        if let Some(enabled) = self.compression {
+            config.compression = enabled;
+        }
+        if let Some(version) = &self.api_version {
+            config.api_version = Some(version.parse().map_err(|e| {
+                let reason = match e {
+                    Error::Configuration(reason) => reason,
+                    other => other.to_string(),
+                };
+                Error::Configuration(format!(
+                    "api_version: unsupported API version '{}': {}",
+                    version, reason
+                ))
+            })?);
+        }
+        if let Some(methods) = &self.log_bodies {
+            config.log_bodies = methods
+                .iter()
+                .map(|method| {
+                    Method::from_bytes(method.to_uppercase().as_bytes()).map_err(|_| {
+                        Error::Configuration(format!(
+                            "log_bodies: invalid HTTP method '{}'",
+                            method
+                        ))
+                    })
+                })
+                .collect::<Result<_>>()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Write `contents` to a fresh temporary file with the given extension
+    fn write(extension: &str, contents: &str) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("xrplsale-{}.{}", uuid::Uuid::new_v4(), extension));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn load(extension: &str, contents: &str) -> Result<ClientConfig> {
+        let path = write(extension, contents);
+        let config = ClientConfig::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        config
+    }
+
+    #[test]
+    fn environment_section_overrides_top_level_settings() {
+        let config = load(
+            "toml",
+            r#"
+api_key = "live-key"
+environment = "testnet"
+timeout_secs = 30
+max_retries = 3
+
+[environments.testnet]
+api_key = "testnet-key"
+max_retries = 1
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.environment, Environment::Testnet);
+        assert_eq!(config.api_key, "testnet-key");
+        assert_eq!(config.timeout, Duration::from_secs(30));
+        assert_eq!(config.max_retries, 1);
+    }
+
+    #[test]
+    fn yaml_section_with_a_base_url_defines_a_custom_environment() {
+        let config = load(
+            "yaml",
+            "environment: staging\nenvironments:\n  staging:\n    base_url: https://api.staging.example.com/v1\n    log_bodies: [post]\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.environment,
+            Environment::custom("https://api.staging.example.com/v1")
+        );
+        assert_eq!(config.log_bodies, [Method::POST]);
+    }
+
+    #[test]
+    fn rejects_invalid_settings_with_the_reason() {
+        let error = load("toml", "api_key = \"k\"\ntimeout = 30\n").unwrap_err();
+        assert!(error.to_string().contains("unknown setting 'timeout'"));
+
+        let error = load("toml", "environment = \"moon\"\n").unwrap_err();
+        assert!(error.to_string().contains("unknown environment 'moon'"));
+
+        let error = load("toml", "api_version = \" \"\n").unwrap_err();
+        assert!(error.to_string().contains("API version must not be empty"));
+    }
+}
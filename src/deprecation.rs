@@ -0,0 +1,255 @@
+//! Deprecation notices reported by the API
+//!
+//! When an endpoint is scheduled for removal the API adds `Deprecation` and `Sunset`
+//! headers, or a `Warning` with warn-code 299, to its responses. Other warnings are
+//! not deprecations and are ignored. The client logs a warning the first time each
+//! endpoint reports one, and passes every notice to the callback registered with
+//! [`ClientBuilder::on_deprecation`](crate::ClientBuilder::on_deprecation).
+//!
+//! ```rust
+//! use xrplsale::Client;
+//!
+//! # fn main() -> xrplsale::Result<()> {
+//! let client = Client::builder()
+//!     .api_key("your-api-key")
+//!     .on_deprecation(|notice| {
+//!         eprintln!("{} {} is deprecated", notice.method, notice.endpoint);
+//!     })
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::{header::HeaderMap, Method};
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+
+/// Deprecation details for an endpoint, taken from response headers
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeprecationNotice {
+    /// HTTP method of the request
+    pub method: Method,
+    /// Endpoint template, e.g. `/projects/:id/stats`
+    pub endpoint: String,
+    /// Value of the `Deprecation` header (`true` or the date it took effect)
+    pub deprecation: Option<String>,
+    /// Value of the `Sunset` header: when the endpoint will be removed
+    pub sunset: Option<String>,
+    /// Migration documentation: the target of a `Link` with `rel="deprecation"` or
+    /// `rel="sunset"`, if any
+    pub link: Option<String>,
+    /// Values of `Warning` headers with warn-code 299 (persistent warnings)
+    pub warnings: Vec<String>,
+}
+
+impl DeprecationNotice {
+    /// Read a notice from response headers, if they contain one
+    pub fn from_headers(method: &Method, endpoint: &str, headers: &HeaderMap) -> Option<Self> {
+        let value = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let warnings: Vec<String> = headers
+            .get_all("warning")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .filter(|warning| warning.split_whitespace().next() == Some(PERSISTENT_WARNING))
+            .map(str::to_string)
+            .collect();
+
+        let deprecation = value("deprecation");
+        let sunset = value("sunset");
+        if deprecation.is_none() && sunset.is_none() && warnings.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            method: method.clone(),
+            endpoint: endpoint.to_string(),
+            deprecation,
+            sunset,
+            link: deprecation_link(headers),
+            warnings,
+        })
+    }
+}
+
+/// Warn-code of the `Warning` headers the API uses for deprecations
+const PERSISTENT_WARNING: &str = "299";
+
+/// Target of the first `Link` entry whose relation is `deprecation` or `sunset`
+fn deprecation_link(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all("link")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(link_entries)
+        .find_map(|entry| {
+            let (target, params) = entry.trim().strip_prefix('<')?.split_once('>')?;
+            let documents = params
+                .split(';')
+                .filter_map(|param| param.split_once('='))
+                .any(|(name, value)| {
+                    name.trim().eq_ignore_ascii_case("rel")
+                        && value
+                            .trim()
+                            .trim_matches('"')
+                            .split_whitespace()
+                            .any(|rel| {
+                                rel.eq_ignore_ascii_case("deprecation")
+                                    || rel.eq_ignore_ascii_case("sunset")
+                            })
+                });
+            documents.then(|| target.to_string())
+        })
+}
+
+/// Split a `Link` header value into its comma-separated entries
+///
+/// Commas inside a `<target>` or a quoted parameter don't separate entries.
+fn link_entries(value: &str) -> Vec<&str> {
+    let mut entries = Vec::new();
+    let (mut start, mut in_target, mut in_quotes) = (0, false, false);
+    for (i, c) in value.char_indices() {
+        match c {
+            '<' if !in_quotes => in_target = true,
+            '>' if !in_quotes => in_target = false,
+            '"' if !in_target => in_quotes = !in_quotes,
+            ',' if !in_target && !in_quotes => {
+                entries.push(&value[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    entries.push(&value[start..]);
+    entries
+}
+
+/// Callback invoked with every deprecation notice
+#[derive(Clone)]
+pub(crate) struct DeprecationCallback(pub(crate) Arc<dyn Fn(&DeprecationNotice) + Send + Sync>);
+
+impl std::fmt::Debug for DeprecationCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DeprecationCallback")
+    }
+}
+
+/// Logs each deprecated endpoint once and forwards notices to the callback
+#[derive(Debug)]
+pub(crate) struct DeprecationTracker {
+    callback: Option<DeprecationCallback>,
+    warned: Mutex<HashSet<String>>,
+}
+
+impl DeprecationTracker {
+    pub(crate) fn new(callback: Option<DeprecationCallback>) -> Self {
+        Self {
+            callback,
+            warned: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub(crate) fn observe(&self, method: &Method, endpoint: &str, headers: &HeaderMap) {
+        let Some(notice) = DeprecationNotice::from_headers(method, endpoint, headers) else {
+            return;
+        };
+
+        let first = self
+            .warned
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(format!("{} {}", method, endpoint));
+        if first {
+            log::warn!(
+                "XRPL.Sale API endpoint {} {} is deprecated (deprecation: {}, sunset: {}){}{}",
+                notice.method,
+                notice.endpoint,
+                notice.deprecation.as_deref().unwrap_or("-"),
+                notice.sunset.as_deref().unwrap_or("-"),
+                notice
+                    .link
+                    .as_deref()
+                    .map(|link| format!(", see {}", link))
+                    .unwrap_or_default(),
+                notice
+                    .warnings
+                    .iter()
+                    .map(|warning| format!("; {}", warning))
+                    .collect::<String>(),
+            );
+        }
+
+        if let Some(callback) = &self.callback {
+            (callback.0)(&notice);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    fn notice(pairs: &[(&'static str, &'static str)]) -> Option<DeprecationNotice> {
+        DeprecationNotice::from_headers(&Method::GET, "/projects/:id", &headers(pairs))
+    }
+
+    #[test]
+    fn ignores_warnings_that_are_not_deprecations() {
+        assert_eq!(notice(&[("warning", "110 - \"Response is stale\"")]), None);
+
+        let deprecated = notice(&[
+            ("warning", "110 - \"Response is stale\""),
+            (
+                "warning",
+                "299 api.xrpl.sale \"Deprecated, use /v2/projects\"",
+            ),
+        ])
+        .unwrap();
+        assert_eq!(
+            deprecated.warnings,
+            ["299 api.xrpl.sale \"Deprecated, use /v2/projects\""]
+        );
+    }
+
+    #[test]
+    fn takes_the_link_with_a_deprecation_relation() {
+        let deprecated = notice(&[
+            ("sunset", "Sat, 01 Nov 2031 00:00:00 GMT"),
+            (
+                "link",
+                "<https://api.xrpl.sale/v1/projects?page=2>; rel=\"next\", \
+                 <https://docs.xrpl.sale/migrate,v2>; rel=\"sunset\"; title=\"Moving, soon\"",
+            ),
+        ])
+        .unwrap();
+        assert_eq!(
+            deprecated.link.as_deref(),
+            Some("https://docs.xrpl.sale/migrate,v2")
+        );
+
+        let deprecated = notice(&[
+            ("deprecation", "true"),
+            (
+                "link",
+                "<https://api.xrpl.sale/v1/projects?page=2>; rel=next",
+            ),
+        ])
+        .unwrap();
+        assert_eq!(deprecated.link, None);
+    }
+}
@@ -0,0 +1,296 @@
+//! Human-readable formatting for command-line and terminal interfaces
+//!
+//! [`AmountFormat`] renders XRP and token amounts with locale-specific separators
+//! and a chosen precision, and [`humanize`] turns durations into short forms such as
+//! `3d 4h`. [`Project::display`] and [`Investment::display`] combine them into one-line
+//! summaries:
+//!
+//! ```rust
+//! use xrplsale::display::{AmountFormat, Locale};
+//!
+//! let format = AmountFormat::new().precision(2);
+//! assert_eq!(format.format("1234567.891").as_deref(), Some("1,234,567.89"));
+//!
+//! let german = format.locale(Locale::DE);
+//! assert_eq!(german.format("1234567.891").as_deref(), Some("1.234.567,89"));
+//!
+//! let duration = chrono::Duration::days(3) + chrono::Duration::hours(4);
+//! assert_eq!(xrplsale::display::humanize(duration), "3d 4h");
+//! ```
+//!
+//! Amounts are formatted from their decimal strings with exact arithmetic, so no
+//! precision is lost to floating point.
+
+use crate::types::{
+    investments::Investment,
+    projects::{parse_amount, Project, AMOUNT_SCALE},
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::fmt;
+
+/// Separators used when formatting numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Locale {
+    /// Placed between groups of three digits, e.g. `,` in `1,000`
+    pub grouping: char,
+    /// Placed before the fractional digits, e.g. `.` in `0.5`
+    pub decimal: char,
+}
+
+impl Locale {
+    /// `1,234.5`, as in English, Chinese, and Japanese
+    pub const EN: Locale = Locale::new(',', '.');
+    /// `1.234,5`, as in German, Italian, Spanish, and Portuguese
+    pub const DE: Locale = Locale::new('.', ',');
+    /// `1 234,5` with a narrow no-break space, as in French, Russian, and Polish
+    pub const FR: Locale = Locale::new('\u{202f}', ',');
+    /// `1’234.5`, as in Swiss German
+    pub const CH: Locale = Locale::new('\u{2019}', '.');
+
+    /// Custom separators
+    pub const fn new(grouping: char, decimal: char) -> Self {
+        Self { grouping, decimal }
+    }
+
+    /// Separators for a locale tag such as `de-DE`, `fr_CA.UTF-8`, or `ja`
+    ///
+    /// Returns `None` for languages this module has no conventions for.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let tag = tag.split(['.', '@']).next().unwrap_or_default();
+        let mut parts = tag.split(['-', '_']);
+        let language = parts.next().unwrap_or_default().to_ascii_lowercase();
+        let region = parts.next().unwrap_or_default().to_ascii_uppercase();
+
+        match (language.as_str(), region.as_str()) {
+            ("de" | "it", "CH" | "LI") => Some(Self::CH),
+            ("c" | "posix" | "en" | "ja" | "ko" | "zh" | "th" | "he" | "hi", _) => Some(Self::EN),
+            ("de" | "it" | "es" | "pt" | "nl" | "da" | "id" | "tr" | "el", _) => Some(Self::DE),
+            ("fr" | "ru" | "pl" | "cs" | "sk" | "uk" | "sv" | "nb" | "no" | "fi" | "hu", _) => {
+                Some(Self::FR)
+            }
+            _ => None,
+        }
+    }
+
+    /// Separators for the user's locale, from `LC_ALL`, `LC_NUMERIC`, or `LANG`
+    ///
+    /// Falls back to [`Locale::EN`] if none is set to a known locale.
+    pub fn from_env() -> Self {
+        ["LC_ALL", "LC_NUMERIC", "LANG"]
+            .iter()
+            .filter_map(|name| std::env::var(name).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|tag| Self::from_tag(&tag))
+            .unwrap_or_default()
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::EN
+    }
+}
+
+/// How to format XRP and token amounts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmountFormat {
+    locale: Locale,
+    precision: u32,
+    min_precision: u32,
+}
+
+impl AmountFormat {
+    /// English separators with up to six decimal places, the precision of XRP drops
+    pub fn new() -> Self {
+        Self {
+            locale: Locale::EN,
+            precision: 6,
+            min_precision: 0,
+        }
+    }
+
+    /// Use the separators of a locale
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Round to at most this many decimal places (capped at fifteen)
+    ///
+    /// Trailing zeros are dropped down to the [minimum](Self::min_precision).
+    pub fn precision(mut self, precision: u32) -> Self {
+        self.precision = precision.min(AMOUNT_SCALE);
+        self.min_precision = self.min_precision.min(self.precision);
+        self
+    }
+
+    /// Always show at least this many decimal places, e.g. `2` for `1,000.00`
+    pub fn min_precision(mut self, min_precision: u32) -> Self {
+        self.min_precision = min_precision.min(AMOUNT_SCALE);
+        self.precision = self.precision.max(self.min_precision);
+        self
+    }
+
+    /// Format a decimal amount such as `"1234.5"`, rounding half up
+    ///
+    /// Returns `None` if the amount is not a non-negative decimal number with at most
+    /// fifteen decimal places.
+    pub fn format(&self, amount: &str) -> Option<String> {
+        let scale = 10u128.pow(AMOUNT_SCALE - self.precision);
+        let rounded = parse_amount(amount)?.checked_add(scale / 2)? / scale;
+        let precision_scale = 10u128.pow(self.precision);
+
+        let mut formatted = group(
+            &(rounded / precision_scale).to_string(),
+            self.locale.grouping,
+        );
+        let fraction = format!(
+            "{:0width$}",
+            rounded % precision_scale,
+            width = self.precision as usize
+        );
+        let trimmed = fraction.trim_end_matches('0');
+        let fraction = &fraction[..trimmed.len().max(self.min_precision as usize)];
+        if !fraction.is_empty() {
+            formatted.push(self.locale.decimal);
+            formatted.push_str(fraction);
+        }
+        Some(formatted)
+    }
+}
+
+impl Default for AmountFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Insert the grouping separator between every three digits
+fn group(digits: &str, grouping: char) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 * 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(grouping);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// A duration as its two largest units, e.g. `3d 4h`, `12m 5s`, or `45s`
+///
+/// Negative durations are formatted by their length; the sign is dropped.
+pub fn humanize(duration: chrono::Duration) -> String {
+    let seconds = duration.num_seconds().unsigned_abs();
+    let units = [
+        (seconds / 86_400, "d"),
+        (seconds / 3_600 % 24, "h"),
+        (seconds / 60 % 60, "m"),
+        (seconds % 60, "s"),
+    ];
+
+    let Some(largest) = units.iter().position(|(value, _)| *value > 0) else {
+        return "0s".to_string();
+    };
+    units[largest..]
+        .iter()
+        .take(2)
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{}{}", value, unit))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Where a sale window stands relative to `now`, e.g. `sale ends in 3d 4h`
+pub fn sale_countdown(start: DateTime<Utc>, end: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    if now < start {
+        format!("sale starts in {}", humanize(start - now))
+    } else if now < end {
+        format!("sale ends in {}", humanize(end - now))
+    } else {
+        format!("sale ended {} ago", humanize(now - end))
+    }
+}
+
+/// A one-line summary of a model, returned by `display` methods
+///
+/// Amounts use [`AmountFormat::new`] unless another format is chosen with
+/// [`format`](Self::format).
+#[derive(Debug, Clone, Copy)]
+pub struct Summary<'a, T> {
+    value: &'a T,
+    format: AmountFormat,
+}
+
+impl<T> Summary<'_, T> {
+    /// Format amounts with `format`
+    pub fn format(mut self, format: AmountFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    fn amount(&self, amount: &str) -> String {
+        self.format
+            .format(amount)
+            .unwrap_or_else(|| amount.to_string())
+    }
+}
+
+impl Project {
+    /// Summarize the project, e.g. `Example (EXM): 1,000,000 tokens, active, sale ends in 3d 4h`
+    pub fn display(&self) -> Summary<'_, Self> {
+        Summary {
+            value: self,
+            format: AmountFormat::new(),
+        }
+    }
+}
+
+impl fmt::Display for Summary<'_, Project> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let project = self.value;
+        write!(
+            f,
+            "{} ({}): {} tokens, {}, {}",
+            project.name,
+            project.token_symbol,
+            self.amount(&project.total_supply),
+            label(&project.status),
+            sale_countdown(project.sale_start_date, project.sale_end_date, Utc::now())
+        )
+    }
+}
+
+impl Investment {
+    /// Summarize the investment, e.g. `100 XRP for 100,000 tokens, confirmed 2h 5m ago`
+    pub fn display(&self) -> Summary<'_, Self> {
+        Summary {
+            value: self,
+            format: AmountFormat::new(),
+        }
+    }
+}
+
+impl fmt::Display for Summary<'_, Investment> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let investment = self.value;
+        write!(
+            f,
+            "{} XRP for {} tokens, {} {} ago",
+            self.amount(&investment.amount_xrp),
+            self.amount(&investment.token_amount),
+            label(&investment.status),
+            humanize(Utc::now() - investment.created_at)
+        )
+    }
+}
+
+/// The wire name of a status, e.g. `active`
+pub(crate) fn label<T: Serialize>(status: &T) -> String {
+    match serde_json::to_value(status) {
+        Ok(serde_json::Value::String(label)) => label,
+        Ok(other) => other.to_string(),
+        Err(_) => String::new(),
+    }
+}
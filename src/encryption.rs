@@ -0,0 +1,526 @@
+//! At-rest encryption for persistent stores
+//!
+//! Cached responses, queued calls, and webhook events can contain investor PII. When
+//! they are written to disk or a shared store, encrypt them with AES-256-GCM first:
+//!
+//! - wrap a [`ResponseCache`] backend in an [`EncryptedCache`]
+//! - wrap a [`WebhookStore`] in an [`EncryptedWebhookStore`]
+//! - open an outbox with `Outbox::open_encrypted` (with the `outbox` feature)
+//!
+//! Each ciphertext is bound to the record it belongs to (the cache key, delivery ID, or
+//! outbox entry ID), so a ciphertext copied to another record fails to decrypt.
+//!
+//! Keys come from a caller-supplied [`KeyProvider`], typically backed by a KMS or
+//! secrets manager. Every ciphertext records the ID of the key that produced it, so
+//! keys can be rotated: entries written with a previous key are still readable and are
+//! re-encrypted with the current key the next time they are read.
+//!
+//! ```rust
+//! use xrplsale::{
+//!     cache::{CacheConfig, InMemoryCache},
+//!     encryption::{EncryptedCache, EncryptionKey, StaticKeys},
+//!     Client,
+//! };
+//!
+//! # fn main() -> xrplsale::Result<()> {
+//! # let key_bytes = [0u8; 32];
+//! let keys = StaticKeys::new(EncryptionKey::new("2024-10", key_bytes));
+//!
+//! let client = Client::builder()
+//!     .api_key("your-api-key")
+//!     .cache(CacheConfig::new(EncryptedCache::new(InMemoryCache::new(1_000), keys)))
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    cache::{CachedResponse, ResponseCache},
+    error::{Error, Result},
+    webhook::WebhookEvent,
+    webhook_store::{EventQuery, StoredEvent, WebhookStore},
+};
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+    Aes256Gcm, Key, Nonce,
+};
+use async_trait::async_trait;
+use std::{collections::HashMap, sync::Arc};
+
+/// Format version written as the first byte of every ciphertext
+///
+/// Version 1 ciphertexts were not bound to a context and are no longer accepted.
+const FORMAT_VERSION: u8 = 2;
+/// Length of an AES-GCM nonce in bytes
+const NONCE_LEN: usize = 12;
+
+/// A 256-bit encryption key and its ID
+#[derive(Clone)]
+pub struct EncryptionKey {
+    id: String,
+    bytes: [u8; 32],
+}
+
+impl EncryptionKey {
+    /// Create a key
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Stable identifier recorded with each ciphertext (at most 255 bytes)
+    /// * `bytes` - The 256-bit key material
+    pub fn new<S: Into<String>>(id: S, bytes: [u8; 32]) -> Self {
+        Self {
+            id: id.into(),
+            bytes,
+        }
+    }
+
+    /// The key's ID
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+impl std::fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptionKey")
+            .field("id", &self.id)
+            .field("bytes", &crate::logging::REDACTED)
+            .finish()
+    }
+}
+
+/// Source of encryption keys
+pub trait KeyProvider: Send + Sync {
+    /// The key used to encrypt new data
+    fn current_key(&self) -> EncryptionKey;
+
+    /// Look up a key by ID to decrypt existing data
+    fn key(&self, id: &str) -> Option<EncryptionKey>;
+}
+
+/// A fixed set of keys: one current key plus previous keys kept for decryption
+#[derive(Debug, Clone)]
+pub struct StaticKeys {
+    current: EncryptionKey,
+    previous: HashMap<String, EncryptionKey>,
+}
+
+impl StaticKeys {
+    /// Use `current` for all new data
+    pub fn new(current: EncryptionKey) -> Self {
+        Self {
+            current,
+            previous: HashMap::new(),
+        }
+    }
+
+    /// Keep a rotated-out key available for reading existing data
+    pub fn with_previous(mut self, key: EncryptionKey) -> Self {
+        self.previous.insert(key.id.clone(), key);
+        self
+    }
+}
+
+impl KeyProvider for StaticKeys {
+    fn current_key(&self) -> EncryptionKey {
+        self.current.clone()
+    }
+
+    fn key(&self, id: &str) -> Option<EncryptionKey> {
+        if id == self.current.id {
+            Some(self.current.clone())
+        } else {
+            self.previous.get(id).cloned()
+        }
+    }
+}
+
+/// Encrypts and decrypts byte strings with keys from a [`KeyProvider`]
+///
+/// Ciphertexts are laid out as `version | key ID length | key ID | nonce | sealed data`.
+/// The header before the nonce is authenticated along with the caller's context.
+#[derive(Clone)]
+pub struct Encryptor {
+    keys: Arc<dyn KeyProvider>,
+}
+
+impl Encryptor {
+    /// Create an encryptor using the given keys
+    pub fn new<K: KeyProvider + 'static>(keys: K) -> Self {
+        Self {
+            keys: Arc::new(keys),
+        }
+    }
+
+    /// Encrypt data with the current key
+    ///
+    /// `context` is authenticated but not stored: the ciphertext only decrypts with the
+    /// same context. Pass what the data is stored under, such as its cache key or
+    /// record ID, so a ciphertext moved to another record is rejected.
+    pub fn encrypt(&self, plaintext: &[u8], context: &[u8]) -> Result<Vec<u8>> {
+        let key = self.keys.current_key();
+        let key_id = key.id.as_bytes();
+        let key_id_len = u8::try_from(key_id.len()).map_err(|_| {
+            Error::Configuration("Encryption key IDs must be at most 255 bytes".to_string())
+        })?;
+
+        let mut out = Vec::with_capacity(2 + key_id.len() + NONCE_LEN + plaintext.len() + 16);
+        out.push(FORMAT_VERSION);
+        out.push(key_id_len);
+        out.extend_from_slice(key_id);
+
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let sealed = cipher(&key)
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: plaintext,
+                    aad: &associated_data(&out, context),
+                },
+            )
+            .map_err(|_| Error::Configuration("Encryption failed".to_string()))?;
+
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(&sealed);
+        Ok(out)
+    }
+
+    /// Decrypt data produced by [`encrypt`](Self::encrypt) with any known key
+    ///
+    /// Fails if `context` differs from the one the data was encrypted with.
+    pub fn decrypt(&self, ciphertext: &[u8], context: &[u8]) -> Result<Vec<u8>> {
+        let (key_id, nonce, sealed) = split(ciphertext)?;
+        let key = self
+            .keys
+            .key(key_id)
+            .ok_or_else(|| Error::Configuration(format!("Unknown encryption key '{}'", key_id)))?;
+        let header = &ciphertext[..2 + key_id.len()];
+
+        cipher(&key)
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: sealed,
+                    aad: &associated_data(header, context),
+                },
+            )
+            .map_err(|_| Error::Parse("Ciphertext failed authentication".to_string()))
+    }
+
+    /// Whether data was encrypted with a key other than the current one
+    pub fn needs_rotation(&self, ciphertext: &[u8]) -> bool {
+        match split(ciphertext) {
+            Ok((key_id, _, _)) => key_id != self.keys.current_key().id,
+            Err(_) => true,
+        }
+    }
+}
+
+impl std::fmt::Debug for Encryptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Encryptor").finish_non_exhaustive()
+    }
+}
+
+/// Authenticate the header along with the caller's context, so neither the key ID nor
+/// the record the data belongs to can be changed
+fn associated_data(header: &[u8], context: &[u8]) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(header.len() + context.len());
+    aad.extend_from_slice(header);
+    aad.extend_from_slice(context);
+    aad
+}
+
+fn cipher(key: &EncryptionKey) -> Aes256Gcm {
+    Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key.bytes))
+}
+
+/// Split a ciphertext into key ID, nonce, and sealed data
+fn split(ciphertext: &[u8]) -> Result<(&str, &[u8], &[u8])> {
+    let malformed = || Error::Parse("Malformed ciphertext".to_string());
+
+    let (&version, rest) = ciphertext.split_first().ok_or_else(malformed)?;
+    if version != FORMAT_VERSION {
+        return Err(Error::Parse(format!(
+            "Unsupported ciphertext version {}",
+            version
+        )));
+    }
+    let (&key_id_len, rest) = rest.split_first().ok_or_else(malformed)?;
+    if rest.len() < key_id_len as usize + NONCE_LEN {
+        return Err(malformed());
+    }
+    let (key_id, rest) = rest.split_at(key_id_len as usize);
+    let (nonce, sealed) = rest.split_at(NONCE_LEN);
+    let key_id = std::str::from_utf8(key_id).map_err(|_| malformed())?;
+
+    Ok((key_id, nonce, sealed))
+}
+
+/// A [`ResponseCache`] that encrypts response bodies before storing them
+///
+/// Bodies are stored hex-encoded and bound to their cache key, so a body copied to
+/// another key does not decrypt. Entries that cannot be decrypted, for example because
+/// their key is no longer available, are treated as cache misses and removed.
+#[derive(Debug)]
+pub struct EncryptedCache<C> {
+    inner: C,
+    encryptor: Encryptor,
+}
+
+impl<C: ResponseCache> EncryptedCache<C> {
+    /// Wrap a cache backend
+    pub fn new<K: KeyProvider + 'static>(inner: C, keys: K) -> Self {
+        Self {
+            inner,
+            encryptor: Encryptor::new(keys),
+        }
+    }
+
+    fn seal(&self, key: &str, response: &CachedResponse) -> Option<CachedResponse> {
+        match self
+            .encryptor
+            .encrypt(response.body.as_bytes(), key.as_bytes())
+        {
+            Ok(ciphertext) => Some(CachedResponse {
+                body: hex::encode(ciphertext),
+                ..response.clone()
+            }),
+            Err(e) => {
+                log::warn!("Not caching response: {}", e);
+                None
+            }
+        }
+    }
+}
+
+impl<C: ResponseCache> ResponseCache for EncryptedCache<C> {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        let stored = self.inner.get(key)?;
+        let opened = hex::decode(&stored.body)
+            .map_err(|e| Error::Parse(e.to_string()))
+            .and_then(|ciphertext| {
+                let plaintext = self.encryptor.decrypt(&ciphertext, key.as_bytes())?;
+                let body = String::from_utf8(plaintext).map_err(|e| Error::Parse(e.to_string()))?;
+                Ok((body, self.encryptor.needs_rotation(&ciphertext)))
+            });
+
+        match opened {
+            Ok((body, needs_rotation)) => {
+                let response = CachedResponse { body, ..stored };
+                if needs_rotation {
+                    if let Some(resealed) = self.seal(key, &response) {
+                        self.inner.put(key, resealed);
+                    }
+                }
+                Some(response)
+            }
+            Err(e) => {
+                log::warn!("Dropping unreadable cache entry: {}", e);
+                self.inner.remove(key);
+                None
+            }
+        }
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        if let Some(sealed) = self.seal(key, &response) {
+            self.inner.put(key, sealed);
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        self.inner.remove(key);
+    }
+
+    fn clear(&self) {
+        self.inner.clear();
+    }
+}
+
+/// A [`WebhookStore`] that encrypts event data before storing it
+///
+/// Each event's `data` is replaced by a hex-encoded ciphertext bound to the event's
+/// delivery ID. The delivery ID, event type, and the data's `project_id` stay readable
+/// so the store can still deduplicate and filter events. Events stored before the
+/// wrapper was added are returned as they are.
+#[derive(Debug)]
+pub struct EncryptedWebhookStore<S> {
+    inner: S,
+    encryptor: Encryptor,
+}
+
+impl<S: WebhookStore> EncryptedWebhookStore<S> {
+    /// Wrap a webhook store
+    pub fn new<K: KeyProvider + 'static>(inner: S, keys: K) -> Self {
+        Self {
+            inner,
+            encryptor: Encryptor::new(keys),
+        }
+    }
+
+    fn seal(&self, event: &WebhookEvent) -> Result<WebhookEvent> {
+        let plaintext = serde_json::to_vec(&event.data).map_err(|e| Error::Parse(e.to_string()))?;
+        let ciphertext = self.encryptor.encrypt(&plaintext, event.id.as_bytes())?;
+
+        let mut data = serde_json::Map::new();
+        if let Some(project_id) = event.data.get("project_id") {
+            data.insert("project_id".to_string(), project_id.clone());
+        }
+        data.insert(SEALED_FIELD.to_string(), hex::encode(ciphertext).into());
+        Ok(WebhookEvent {
+            data: data.into(),
+            ..event.clone()
+        })
+    }
+
+    fn open(&self, mut stored: StoredEvent) -> Result<StoredEvent> {
+        let Some(sealed) = stored.event.data.get(SEALED_FIELD).and_then(|s| s.as_str()) else {
+            return Ok(stored);
+        };
+        let invalid =
+            |e: String| Error::Parse(format!("Invalid stored event {}: {}", stored.event.id, e));
+        let ciphertext = hex::decode(sealed).map_err(|e| invalid(e.to_string()))?;
+        let plaintext = self
+            .encryptor
+            .decrypt(&ciphertext, stored.event.id.as_bytes())?;
+        stored.event.data =
+            serde_json::from_slice(&plaintext).map_err(|e| invalid(e.to_string()))?;
+        Ok(stored)
+    }
+}
+
+/// Field of a sealed event's data holding the ciphertext
+const SEALED_FIELD: &str = "sealed";
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<S: WebhookStore> WebhookStore for EncryptedWebhookStore<S> {
+    async fn insert(&self, event: &WebhookEvent) -> Result<bool> {
+        self.inner.insert(&self.seal(event)?).await
+    }
+
+    async fn get(&self, id: &str) -> Result<Option<StoredEvent>> {
+        self.inner
+            .get(id)
+            .await?
+            .map(|stored| self.open(stored))
+            .transpose()
+    }
+
+    async fn list(&self, query: &EventQuery) -> Result<Vec<StoredEvent>> {
+        self.inner
+            .list(query)
+            .await?
+            .into_iter()
+            .map(|stored| self.open(stored))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cache::InMemoryCache, webhook_store::InMemoryWebhookStore};
+    use serde_json::json;
+
+    fn keys() -> StaticKeys {
+        StaticKeys::new(EncryptionKey::new("test", [7; 32]))
+    }
+
+    fn response(body: &str) -> CachedResponse {
+        CachedResponse {
+            body: body.to_string(),
+            etag: None,
+            stored_at: std::time::Instant::now(),
+            ttl: std::time::Duration::from_secs(60),
+        }
+    }
+
+    #[test]
+    fn ciphertexts_only_decrypt_with_their_context() {
+        let encryptor = Encryptor::new(keys());
+        let ciphertext = encryptor.encrypt(b"investor", b"a").unwrap();
+
+        assert_eq!(encryptor.decrypt(&ciphertext, b"a").unwrap(), b"investor");
+        assert!(encryptor.decrypt(&ciphertext, b"b").is_err());
+    }
+
+    #[test]
+    fn swapped_cache_entries_fail_to_decrypt() {
+        let cache = EncryptedCache::new(InMemoryCache::new(10), keys());
+        let backend = &cache.inner;
+        cache.put("GET /projects/a", response("alice"));
+        cache.put("GET /projects/b", response("bob"));
+        assert_eq!(cache.get("GET /projects/a").unwrap().body, "alice");
+
+        let a = backend.get("GET /projects/a").unwrap();
+        let b = backend.get("GET /projects/b").unwrap();
+        backend.put("GET /projects/a", b);
+        backend.put("GET /projects/b", a);
+
+        assert!(cache.get("GET /projects/a").is_none());
+        assert!(cache.get("GET /projects/b").is_none());
+    }
+
+    #[tokio::test]
+    async fn swapped_webhook_events_fail_to_decrypt() {
+        let store = EncryptedWebhookStore::new(InMemoryWebhookStore::new(), keys());
+        let backend = &store.inner;
+        for (id, investor) in [("evt_1", "alice"), ("evt_2", "bob")] {
+            let event = WebhookEvent {
+                id: id.to_string(),
+                event_type: "investment.created".to_string(),
+                data: json!({ "project_id": "proj_1", "investor": investor }),
+            };
+            store.insert(&event).await.unwrap();
+        }
+
+        let stored = backend.get("evt_1").await.unwrap().unwrap();
+        assert_eq!(stored.event.data["project_id"], "proj_1");
+        assert!(stored.event.data.get("investor").is_none());
+        let opened = store.get("evt_1").await.unwrap().unwrap();
+        assert_eq!(opened.event.data["investor"], "alice");
+
+        let swapped = WebhookEvent {
+            id: "evt_3".to_string(),
+            ..stored.event
+        };
+        backend.insert(&swapped).await.unwrap();
+        assert!(store.get("evt_3").await.is_err());
+    }
+
+    #[cfg(all(feature = "outbox", not(target_arch = "wasm32")))]
+    #[tokio::test]
+    async fn swapped_outbox_entries_fail_to_decrypt() {
+        use crate::{outbox::Outbox, Client};
+        use reqwest::Method;
+
+        let path = std::env::temp_dir().join(format!("outbox-{}.json", uuid::Uuid::new_v4()));
+        let client = Client::builder().api_key("t").build().unwrap();
+        let outbox = Outbox::open_encrypted(client.clone(), &path, keys()).unwrap();
+        for investor in ["alice", "bob"] {
+            let body = json!({ "investor": investor });
+            outbox
+                .enqueue(Method::POST, "/investments", Some(&body))
+                .await
+                .unwrap();
+        }
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains("alice"));
+        let reopened = Outbox::open_encrypted(client.clone(), &path, keys()).unwrap();
+        assert_eq!(reopened.pending(), outbox.pending());
+        assert!(Outbox::open(client.clone(), &path).is_err());
+
+        let mut stored: Vec<serde_json::Value> = serde_json::from_str(&contents).unwrap();
+        let first = stored[0]["sealed"].take();
+        stored[0]["sealed"] = stored[1]["sealed"].take();
+        stored[1]["sealed"] = first;
+        std::fs::write(&path, serde_json::to_string(&stored).unwrap()).unwrap();
+
+        assert!(Outbox::open_encrypted(client, &path, keys()).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,352 @@
+//! Stable error codes for programmatic error handling
+//!
+//! Error messages returned by the API are meant for humans and may change wording at
+//! any time. [`ErrorCode`] mirrors the platform's documented machine-readable codes, so
+//! downstream code can match on them safely:
+//!
+//! ```rust
+//! # use xrplsale::{Client, ErrorCode};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let client = Client::builder().api_key("test").build()?;
+//! match client.projects().get("proj_abc123").await {
+//!     Ok(project) => println!("Found project: {}", project.name),
+//!     Err(e) if e.code() == ErrorCode::ProjectNotFound => println!("No such project"),
+//!     Err(e) => return Err(e.into()),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{io, time::Duration};
+
+/// Machine-readable error codes documented by the XRPL.Sale platform
+///
+/// New codes may be added in minor releases; codes this SDK version does not know
+/// about are preserved in [`ErrorCode::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// The request body failed validation
+    ValidationFailed,
+    /// A query or path parameter is invalid
+    InvalidParameter,
+    /// No valid credentials were supplied
+    Unauthorized,
+    /// The API key is unknown or has been revoked
+    InvalidApiKey,
+    /// The session token has expired
+    TokenExpired,
+    /// The credentials lack permission for this operation
+    Forbidden,
+    /// The requested resource does not exist
+    NotFound,
+    /// The requested project does not exist
+    ProjectNotFound,
+    /// The requested investment does not exist
+    InvestmentNotFound,
+    /// The request conflicts with the current state of the resource
+    Conflict,
+    /// The selected tier has no tokens left
+    TierSoldOut,
+    /// The project's sale is not currently accepting investments
+    SaleNotActive,
+    /// The investor's allocation does not cover the requested amount
+    InsufficientAllocation,
+    /// The investor must complete KYC first
+    KycRequired,
+    /// The investor is not on the project's whitelist
+    WhitelistRequired,
+    /// Too many requests were made in a short period
+    RateLimited,
+    /// The platform hit an unexpected error
+    InternalError,
+    /// The platform is temporarily unavailable
+    ServiceUnavailable,
+    /// The request never reached the platform (client-side)
+    Network,
+    /// The response could not be parsed (client-side)
+    InvalidResponse,
+    /// The client is misconfigured or was given invalid input (client-side)
+    Configuration,
+    /// A local store, such as a webhook event database, failed (client-side)
+    Storage,
+    /// A code not known to this version of the SDK
+    Unknown(String),
+}
+
+impl ErrorCode {
+    /// The wire representation of this code, e.g. `"tier_sold_out"`
+    pub fn as_str(&self) -> &str {
+        match self {
+            ErrorCode::ValidationFailed => "validation_failed",
+            ErrorCode::InvalidParameter => "invalid_parameter",
+            ErrorCode::Unauthorized => "unauthorized",
+            ErrorCode::InvalidApiKey => "invalid_api_key",
+            ErrorCode::TokenExpired => "token_expired",
+            ErrorCode::Forbidden => "forbidden",
+            ErrorCode::NotFound => "not_found",
+            ErrorCode::ProjectNotFound => "project_not_found",
+            ErrorCode::InvestmentNotFound => "investment_not_found",
+            ErrorCode::Conflict => "conflict",
+            ErrorCode::TierSoldOut => "tier_sold_out",
+            ErrorCode::SaleNotActive => "sale_not_active",
+            ErrorCode::InsufficientAllocation => "insufficient_allocation",
+            ErrorCode::KycRequired => "kyc_required",
+            ErrorCode::WhitelistRequired => "whitelist_required",
+            ErrorCode::RateLimited => "rate_limited",
+            ErrorCode::InternalError => "internal_error",
+            ErrorCode::ServiceUnavailable => "service_unavailable",
+            ErrorCode::Network => "network",
+            ErrorCode::InvalidResponse => "invalid_response",
+            ErrorCode::Configuration => "configuration",
+            ErrorCode::Storage => "storage",
+            ErrorCode::Unknown(code) => code,
+        }
+    }
+
+    /// Parse a wire code, falling back to [`ErrorCode::Unknown`]
+    pub fn from_code(code: &str) -> Self {
+        match code.to_lowercase().as_str() {
+            "validation_failed" | "validation_error" => ErrorCode::ValidationFailed,
+            "invalid_parameter" => ErrorCode::InvalidParameter,
+            "unauthorized" => ErrorCode::Unauthorized,
+            "invalid_api_key" => ErrorCode::InvalidApiKey,
+            "token_expired" => ErrorCode::TokenExpired,
+            "forbidden" => ErrorCode::Forbidden,
+            "not_found" => ErrorCode::NotFound,
+            "project_not_found" => ErrorCode::ProjectNotFound,
+            "investment_not_found" => ErrorCode::InvestmentNotFound,
+            "conflict" => ErrorCode::Conflict,
+            "tier_sold_out" => ErrorCode::TierSoldOut,
+            "sale_not_active" => ErrorCode::SaleNotActive,
+            "insufficient_allocation" => ErrorCode::InsufficientAllocation,
+            "kyc_required" => ErrorCode::KycRequired,
+            "whitelist_required" => ErrorCode::WhitelistRequired,
+            "rate_limited" | "rate_limit_exceeded" => ErrorCode::RateLimited,
+            "internal_error" => ErrorCode::InternalError,
+            "service_unavailable" => ErrorCode::ServiceUnavailable,
+            "network" => ErrorCode::Network,
+            "invalid_response" => ErrorCode::InvalidResponse,
+            "configuration" => ErrorCode::Configuration,
+            "storage" => ErrorCode::Storage,
+            _ => ErrorCode::Unknown(code.to_string()),
+        }
+    }
+
+    /// Extract the error code from an API error body
+    ///
+    /// Accepts both `{"code": "..."}` and `{"error": {"code": "..."}}` shapes.
+    pub(crate) fn from_body(body: &str) -> Option<Self> {
+        body_field(body, "code").map(|code| Self::from_code(&code))
+    }
+
+    fn from_status(status: u16) -> Self {
+        match status {
+            400 | 422 => ErrorCode::ValidationFailed,
+            401 => ErrorCode::Unauthorized,
+            403 => ErrorCode::Forbidden,
+            404 => ErrorCode::NotFound,
+            409 => ErrorCode::Conflict,
+            429 => ErrorCode::RateLimited,
+            503 => ErrorCode::ServiceUnavailable,
+            500..=599 => ErrorCode::InternalError,
+            _ => ErrorCode::Unknown(status.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for ErrorCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::from_code(s))
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Ok(Self::from_code(&code))
+    }
+}
+
+impl Error {
+    /// Get the stable error code for this error
+    ///
+    /// For API errors this is the code reported in the response body, falling back to
+    /// one derived from the HTTP status. Errors raised on the client side map to
+    /// [`ErrorCode::Network`], [`ErrorCode::InvalidResponse`],
+    /// [`ErrorCode::Configuration`], or [`ErrorCode::Storage`]; an open circuit maps to
+    /// [`ErrorCode::ServiceUnavailable`].
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::BadRequest(body) => ErrorCode::from_body(body).unwrap_or(ErrorCode::ValidationFailed),
+            Error::Unauthorized(body) => ErrorCode::from_body(body).unwrap_or(ErrorCode::Unauthorized),
+            Error::NotFound(body) => ErrorCode::from_body(body).unwrap_or(ErrorCode::NotFound),
+            Error::RateLimit { message, .. } => {
+                ErrorCode::from_body(message).unwrap_or(ErrorCode::RateLimited)
+            }
+            Error::Api { status, message, .. } => {
+                ErrorCode::from_body(message).unwrap_or_else(|| ErrorCode::from_status(*status))
+            }
+            Error::HttpClient(_) => ErrorCode::Network,
+            Error::CircuitOpen { .. } => ErrorCode::ServiceUnavailable,
+            Error::Parse(_) | Error::UnsupportedWebhookVersion { .. } => {
+                ErrorCode::InvalidResponse
+            }
+            Error::Configuration(_) | Error::InvalidEnvironment(_) => ErrorCode::Configuration,
+            Error::Storage(_) => ErrorCode::Storage,
+        }
+    }
+}
+
+impl Error {
+    /// The HTTP status the API responded with, if the error came from a response
+    ///
+    /// Input rejected before sending is reported as [`Error::Configuration`], which
+    /// has no status.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            Error::BadRequest(_) => Some(400),
+            Error::Unauthorized(_) => Some(401),
+            Error::NotFound(_) => Some(404),
+            Error::RateLimit { .. } => Some(429),
+            Error::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// The request ID reported in the API error body, for support tickets and log
+    /// correlation
+    ///
+    /// Accepts both `{"request_id": "..."}` and `{"error": {"request_id": "..."}}`
+    /// shapes.
+    pub fn request_id(&self) -> Option<String> {
+        match self {
+            Error::BadRequest(body) | Error::Unauthorized(body) | Error::NotFound(body) => {
+                body_field(body, "request_id")
+            }
+            Error::RateLimit { message, .. } | Error::Api { message, .. } => {
+                body_field(message, "request_id")
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the same request may succeed if sent again later
+    ///
+    /// True for network failures, rate limiting, server errors, and an open circuit.
+    /// Wait at least [`Error::retry_after`] before retrying when it is set.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.code(),
+            ErrorCode::Network
+                | ErrorCode::RateLimited
+                | ErrorCode::ServiceUnavailable
+                | ErrorCode::InternalError
+        )
+    }
+
+    /// Whether the credentials are missing, invalid, expired, or lack permission
+    pub fn is_auth_error(&self) -> bool {
+        matches!(
+            self.code(),
+            ErrorCode::Unauthorized
+                | ErrorCode::InvalidApiKey
+                | ErrorCode::TokenExpired
+                | ErrorCode::Forbidden
+        )
+    }
+
+    /// Whether the selected tier has no tokens left
+    pub fn is_tier_sold_out(&self) -> bool {
+        self.code() == ErrorCode::TierSoldOut
+    }
+
+    /// Whether the project's sale is not currently accepting investments
+    pub fn is_sale_not_active(&self) -> bool {
+        self.code() == ErrorCode::SaleNotActive
+    }
+
+    /// Whether the investor's allocation does not cover the requested amount
+    pub fn is_insufficient_allocation(&self) -> bool {
+        self.code() == ErrorCode::InsufficientAllocation
+    }
+
+    /// Whether the investor must complete KYC before investing
+    pub fn is_kyc_required(&self) -> bool {
+        self.code() == ErrorCode::KycRequired
+    }
+
+    /// Whether the investor must be whitelisted before investing
+    pub fn is_whitelist_required(&self) -> bool {
+        self.code() == ErrorCode::WhitelistRequired
+    }
+
+    /// How long the API asked the client to wait before retrying, or how long an open
+    /// circuit stays open
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Error::RateLimit { retry_after, .. } => retry_after.map(Duration::from_secs),
+            Error::CircuitOpen { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+/// Wraps the error with the closest [`io::ErrorKind`], for logging pipelines that
+/// group failures by kind
+///
+/// The original error is kept and can be recovered with [`io::Error::into_inner`].
+impl From<Error> for io::Error {
+    fn from(error: Error) -> Self {
+        let kind = match error.code() {
+            ErrorCode::Network if error.to_string().to_lowercase().contains("timed out") => {
+                io::ErrorKind::TimedOut
+            }
+            ErrorCode::Network | ErrorCode::ServiceUnavailable => io::ErrorKind::ConnectionAborted,
+            ErrorCode::RateLimited => io::ErrorKind::WouldBlock,
+            ErrorCode::Unauthorized
+            | ErrorCode::InvalidApiKey
+            | ErrorCode::TokenExpired
+            | ErrorCode::Forbidden
+            | ErrorCode::KycRequired
+            | ErrorCode::WhitelistRequired => io::ErrorKind::PermissionDenied,
+            ErrorCode::NotFound | ErrorCode::ProjectNotFound | ErrorCode::InvestmentNotFound => {
+                io::ErrorKind::NotFound
+            }
+            ErrorCode::Conflict => io::ErrorKind::AlreadyExists,
+            ErrorCode::ValidationFailed
+            | ErrorCode::InvalidParameter
+            | ErrorCode::Configuration => io::ErrorKind::InvalidInput,
+            ErrorCode::InvalidResponse => io::ErrorKind::InvalidData,
+            _ => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, error)
+    }
+}
+
+/// Read a string field from an API error body, at the top level or under `error`
+fn body_field(body: &str, field: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(body).ok()?;
+    value
+        .get(field)
+        .or_else(|| value.get("error").and_then(|error| error.get(field)))
+        .and_then(|value| value.as_str())
+        .map(str::to_string)
+}
@@ -0,0 +1,328 @@
+//! Sample data for tests and examples
+//!
+//! Enable the `fixtures` feature (usually as a dev-dependency) to build valid models
+//! without hand-rolling JSON:
+//!
+//! ```rust
+//! use xrplsale::{
+//!     fixtures,
+//!     types::{investments::Investment, projects::Project},
+//! };
+//!
+//! let project = Project::fake();
+//! let investment = Investment::fake();
+//! assert_eq!(project.id, fixtures::PROJECT_ID);
+//!
+//! let event = fixtures::webhook_event("investment.created").unwrap();
+//! assert_eq!(event.event_type, "investment.created");
+//! ```
+//!
+//! The fakes share IDs, so an investment, its project, and the webhook samples all
+//! refer to each other. For property tests, the feature also implements proptest's
+//! `Arbitrary` for the models and IDs:
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use xrplsale::types::projects::Project;
+//!
+//! proptest! {
+//!     #[test]
+//!     fn round_trips(project in any::<Project>()) {
+//!         let json = serde_json::to_string(&project).unwrap();
+//!         let parsed: Project = serde_json::from_str(&json).unwrap();
+//!         prop_assert_eq!(parsed.id, project.id);
+//!     }
+//! }
+//! ```
+
+use crate::{
+    models::{Investment, Project},
+    webhook::WebhookEvent,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+/// ID of the fake project
+pub const PROJECT_ID: &str = "proj_fixture01";
+/// ID of the fake investment
+pub const INVESTMENT_ID: &str = "inv_fixture01";
+/// Account of the fake investor
+pub const INVESTOR_ACCOUNT: &str = "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh";
+/// Account issuing the fake project's token
+pub const ISSUER_ACCOUNT: &str = "rPT1Sjq2YGrBMTttX4GZHjKu9dyfzbpAYe";
+
+/// Webhook event types with a sample payload
+pub const WEBHOOK_EVENT_TYPES: &[&str] = &[
+    "project.launched",
+    "investment.created",
+    "investment.confirmed",
+    "tier.completed",
+    "escrow.released",
+    "proposal.created",
+    "vote.cast",
+    "liquidity.pool_created",
+];
+
+impl Project {
+    /// An active project with three tiers
+    pub fn fake() -> Self {
+        from_fixture("Project", project_json())
+    }
+}
+
+impl Investment {
+    /// A confirmed investment in [`Project::fake`]
+    pub fn fake() -> Self {
+        from_fixture("Investment", investment_json())
+    }
+}
+
+/// API representation of [`Project::fake`]
+pub fn project_json() -> Value {
+    json!({
+        "id": PROJECT_ID,
+        "name": "Fixture Token Sale",
+        "description": "A project used in tests",
+        "status": "active",
+        "token_symbol": "FIX",
+        "total_supply": "1000000",
+        "tiers": [
+            { "tier": 1, "price_per_token": "0.001", "total_tokens": "250000" },
+            { "tier": 2, "price_per_token": "0.0015", "total_tokens": "250000" },
+            { "tier": 3, "price_per_token": "0.002", "total_tokens": "500000" }
+        ],
+        "sale_start_date": "2026-01-01T00:00:00Z",
+        "sale_end_date": "2026-02-01T00:00:00Z",
+        "created_at": "2025-12-01T12:00:00Z",
+        "updated_at": "2026-01-01T00:00:00Z"
+    })
+}
+
+/// API representation of [`Investment::fake`]
+pub fn investment_json() -> Value {
+    json!({
+        "id": INVESTMENT_ID,
+        "project_id": PROJECT_ID,
+        "investor_account": INVESTOR_ACCOUNT,
+        "amount_xrp": "100",
+        "token_amount": "100000",
+        "status": "confirmed",
+        "created_at": "2026-01-02T09:30:00Z"
+    })
+}
+
+/// Sample webhook payload for an event type in [`WEBHOOK_EVENT_TYPES`]
+pub fn webhook_payload(event_type: &str) -> Option<String> {
+    let data = match event_type {
+        "project.launched" => project_json(),
+        "investment.created" => {
+            let mut investment = investment_json();
+            investment["status"] = json!("pending");
+            investment
+        }
+        "investment.confirmed" => investment_json(),
+        "tier.completed" => json!({
+            "project_id": PROJECT_ID,
+            "tier": 1,
+            "price_per_token": "0.001",
+            "total_tokens": "250000"
+        }),
+        "escrow.released" => json!({
+            "id": "esc_fixture01",
+            "project_id": PROJECT_ID,
+            "owner_account": ISSUER_ACCOUNT,
+            "destination_account": INVESTOR_ACCOUNT,
+            "sequence": 42,
+            "amount_xrp": "5000",
+            "release_condition": "sale_end",
+            "status": "released",
+            "create_tx_hash": "A1B2C3D4E5F60718293A4B5C6D7E8F90A1B2C3D4E5F60718293A4B5C6D7E8F90",
+            "settle_tx_hash": "0F1E2D3C4B5A69788796A5B4C3D2E1F00F1E2D3C4B5A69788796A5B4C3D2E1F0",
+            "settled_at": "2026-02-02T00:00:00Z"
+        }),
+        "proposal.created" => json!({
+            "id": "prop_fixture01",
+            "project_id": PROJECT_ID,
+            "title": "Release the first tranche",
+            "description": "The MVP milestone has shipped",
+            "status": "open",
+            "opens_at": "2026-02-05T00:00:00Z",
+            "closes_at": "2026-02-12T00:00:00Z",
+            "quorum_percentage": "20"
+        }),
+        "vote.cast" => json!({
+            "id": "vote_fixture01",
+            "proposal_id": "prop_fixture01",
+            "account": INVESTOR_ACCOUNT,
+            "choice": "for",
+            "weight": "100000",
+            "cast_at": "2026-02-06T15:00:00Z"
+        }),
+        "liquidity.pool_created" => json!({
+            "project_id": PROJECT_ID,
+            "amm_account": "rLHzPsX6oXkzU2qL12kHCH8G8cnZv1rBJh",
+            "asset": { "currency": "FIX", "issuer": ISSUER_ACCOUNT },
+            "quote": { "currency": "XRP" },
+            "asset_amount": "100000",
+            "quote_amount": "150",
+            "lp_token": {
+                "currency": "03930D02208264E2E40EC1B0C09E4DB96EE197B1",
+                "issuer": "rLHzPsX6oXkzU2qL12kHCH8G8cnZv1rBJh"
+            },
+            "lp_token_supply": "3872.98",
+            "trading_fee": 500
+        }),
+        _ => return None,
+    };
+
+    Some(
+        json!({
+            "id": format!("evt_{}", event_type.replace('.', "_")),
+            "event_type": event_type,
+            "created_at": "2026-02-06T15:00:01Z",
+            "data": data
+        })
+        .to_string(),
+    )
+}
+
+/// Sample webhook event for an event type in [`WEBHOOK_EVENT_TYPES`]
+pub fn webhook_event(event_type: &str) -> Option<WebhookEvent> {
+    webhook_payload(event_type).map(|payload| {
+        serde_json::from_str(&payload)
+            .unwrap_or_else(|e| panic!("{} fixture does not match WebhookEvent: {}", event_type, e))
+    })
+}
+
+fn from_fixture<T: DeserializeOwned>(name: &str, value: Value) -> T {
+    serde_json::from_value(value)
+        .unwrap_or_else(|e| panic!("{} fixture does not match the model: {}", name, e))
+}
+
+mod arbitrary {
+    use super::{from_fixture, investment_json, project_json};
+    use crate::{
+        ids::{InvestmentId, ProjectId, WebhookId},
+        models::{Investment, Project},
+    };
+    use chrono::{DateTime, Utc};
+    use proptest::prelude::*;
+    use serde_json::json;
+
+    macro_rules! arbitrary_id {
+        ($name:ident, $prefix:literal) => {
+            impl Arbitrary for $name {
+                type Parameters = ();
+                type Strategy = BoxedStrategy<Self>;
+
+                fn arbitrary_with(_: ()) -> Self::Strategy {
+                    "[a-z0-9]{6,16}"
+                        .prop_map(|suffix| $name::new(format!("{}_{}", $prefix, suffix)))
+                        .boxed()
+                }
+            }
+        };
+    }
+
+    arbitrary_id!(ProjectId, "proj");
+    arbitrary_id!(InvestmentId, "inv");
+    arbitrary_id!(WebhookId, "wh");
+
+    /// Classic address alphabet: base58 without `0`, `O`, `I`, and `l`
+    fn account() -> impl Strategy<Value = String> {
+        "r[1-9A-HJ-NP-Za-km-z]{24,33}"
+    }
+
+    /// Positive decimal string with up to six fractional digits
+    fn amount() -> impl Strategy<Value = String> {
+        (1u64..1_000_000_000, 0u32..1_000_000).prop_map(|(whole, fraction)| {
+            if fraction == 0 {
+                whole.to_string()
+            } else {
+                format!("{}.{:06}", whole, fraction)
+                    .trim_end_matches('0')
+                    .to_string()
+            }
+        })
+    }
+
+    /// Timestamp between 2024 and 2030
+    fn timestamp() -> impl Strategy<Value = DateTime<Utc>> {
+        (1_704_067_200i64..1_893_456_000)
+            .prop_map(|secs| DateTime::from_timestamp(secs, 0).unwrap_or_default())
+    }
+
+    impl Arbitrary for Project {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            (
+                any::<ProjectId>(),
+                "[A-Z][a-z]{2,12}( [A-Z][a-z]{2,12}){0,2}",
+                "[A-Z]{3,5}",
+                prop_oneof![Just("upcoming"), Just("active"), Just("completed")],
+                prop::collection::vec((amount(), 1u64..100_000_000), 1..=5),
+                timestamp(),
+                1i64..=90,
+            )
+                .prop_map(|(id, name, symbol, status, tiers, start, days)| {
+                    let total_supply: u64 = tiers.iter().map(|(_, tokens)| tokens).sum();
+                    let mut project = project_json();
+                    project["id"] = json!(id);
+                    project["name"] = json!(name);
+                    project["token_symbol"] = json!(symbol);
+                    project["status"] = json!(status);
+                    project["total_supply"] = json!(total_supply.to_string());
+                    project["tiers"] = tiers
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, (price, tokens))| {
+                            json!({
+                                "tier": index + 1,
+                                "price_per_token": price,
+                                "total_tokens": tokens.to_string(),
+                            })
+                        })
+                        .collect();
+                    project["created_at"] = json!(start - chrono::Duration::days(30));
+                    project["updated_at"] = json!(start);
+                    project["sale_start_date"] = json!(start);
+                    project["sale_end_date"] = json!(start + chrono::Duration::days(days));
+                    from_fixture("Project", project)
+                })
+                .boxed()
+        }
+    }
+
+    impl Arbitrary for Investment {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            (
+                any::<InvestmentId>(),
+                any::<ProjectId>(),
+                account(),
+                amount(),
+                amount(),
+                prop_oneof![Just("pending"), Just("confirmed")],
+                timestamp(),
+            )
+                .prop_map(
+                    |(id, project_id, account, amount_xrp, token_amount, status, created_at)| {
+                        let mut investment = investment_json();
+                        investment["id"] = json!(id);
+                        investment["project_id"] = json!(project_id);
+                        investment["investor_account"] = json!(account);
+                        investment["amount_xrp"] = json!(amount_xrp);
+                        investment["token_amount"] = json!(token_amount);
+                        investment["status"] = json!(status);
+                        investment["created_at"] = json!(created_at);
+                        from_fixture("Investment", investment)
+                    },
+                )
+                .boxed()
+        }
+    }
+}
@@ -0,0 +1,236 @@
+//! Strongly typed resource IDs
+//!
+//! IDs are plain strings on the wire, but wrapping them in distinct types stops a
+//! project ID from being passed where an investment ID is expected. Service methods
+//! take `impl Into<ProjectId>`, so an ID read from one response can be passed straight
+//! to the next call:
+//!
+//! ```rust
+//! use xrplsale::{ids::ProjectId, Client};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let client = Client::builder().api_key("test").build()?;
+//! let project_id: ProjectId = "proj_abc123".parse()?;
+//! let stats = client.projects().stats(&project_id).await?;
+//! let token = client.projects().token(project_id).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Services percent-encode IDs when building request paths, so an ID can never reach
+//! another endpoint. Deserialized IDs must not be empty; [`parse`](str::parse) also
+//! rejects IDs with whitespace or URL delimiters, which no API ID contains.
+//!
+//! Raw `&str` and `String` values are still accepted wherever an ID is expected, through
+//! the deprecated `str-ids` feature. It is enabled by default for one release; disable
+//! it to migrate early, and build IDs with `new` or `parse` instead.
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{
+    borrow::{Borrow, Cow},
+    fmt,
+    str::FromStr,
+};
+
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident, $label:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Wrap an ID without validating it
+            ///
+            /// Use [`parse`](str::parse) to reject empty or malformed IDs.
+            pub fn new<S: Into<String>>(id: S) -> Self {
+                Self(id.into())
+            }
+
+            /// The ID as a string slice
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// The ID percent-encoded as a single URL path segment
+            pub fn path_segment(&self) -> Cow<'_, str> {
+                path_segment(&self.0)
+            }
+
+            /// Unwrap the ID into its string
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Error;
+
+            /// Parse an ID, rejecting values that could not be used in a request path
+            fn from_str(s: &str) -> Result<Self> {
+                validate(s, $label)?;
+                Ok(Self(s.to_string()))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            /// Read an ID, rejecting empty strings
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let id = String::deserialize(deserializer)?;
+                if id.is_empty() {
+                    return Err(serde::de::Error::custom(format!("empty {} ID", $label)));
+                }
+                Ok(Self(id))
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(id: &$name) -> Self {
+                id.clone()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        #[cfg(feature = "str-ids")]
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self::new(id)
+            }
+        }
+
+        #[cfg(feature = "str-ids")]
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self(id)
+            }
+        }
+
+        #[cfg(feature = "str-ids")]
+        impl From<&String> for $name {
+            fn from(id: &String) -> Self {
+                Self(id.clone())
+            }
+        }
+    };
+}
+
+id_type!(
+    /// ID of a project, e.g. `proj_abc123`
+    ProjectId,
+    "project"
+);
+
+id_type!(
+    /// ID of an investment
+    InvestmentId,
+    "investment"
+);
+
+id_type!(
+    /// ID of a webhook subscription
+    WebhookId,
+    "webhook"
+);
+
+fn validate(id: &str, label: &str) -> Result<()> {
+    if id.is_empty() {
+        return Err(Error::Configuration(format!("Empty {} ID", label)));
+    }
+    // `.` and `..` are resolved as relative path segments even when percent-encoded
+    if matches!(id, "." | "..")
+        || id
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || matches!(c, '/' | '?' | '#' | '%'))
+    {
+        return Err(Error::Configuration(format!(
+            "Invalid {} ID: {:?}",
+            label, id
+        )));
+    }
+    Ok(())
+}
+
+/// Percent-encode `value` as a single URL path segment
+///
+/// Only unreserved characters (letters, digits, `-`, `.`, `_`, and `~`) are kept as is.
+pub(crate) fn path_segment(value: &str) -> Cow<'_, str> {
+    let unreserved = |b: u8| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~');
+    if value.bytes().all(unreserved) {
+        return Cow::Borrowed(value);
+    }
+
+    let mut encoded = String::with_capacity(value.len() * 3);
+    for b in value.bytes() {
+        if unreserved(b) {
+            encoded.push(char::from(b));
+        } else {
+            encoded.push_str(&format!("%{:02X}", b));
+        }
+    }
+    Cow::Owned(encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_empty_ids_when_deserializing() {
+        assert!(serde_json::from_str::<ProjectId>(r#""""#).is_err());
+        let id: ProjectId = serde_json::from_str(r#""proj_abc123""#).unwrap();
+        assert_eq!(id, "proj_abc123");
+    }
+
+    #[test]
+    fn encodes_ids_as_single_path_segments() {
+        assert!(matches!(
+            ProjectId::new("proj_abc-1.2~").path_segment(),
+            Cow::Borrowed("proj_abc-1.2~")
+        ));
+        assert_eq!(
+            InvestmentId::new("inv 1/../webhooks?x#é").path_segment(),
+            "inv%201%2F..%2Fwebhooks%3Fx%23%C3%A9"
+        );
+        assert!("..".parse::<WebhookId>().is_err());
+    }
+}
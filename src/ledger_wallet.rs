@@ -0,0 +1,257 @@
+//! Signing with a Ledger hardware wallet
+//!
+//! [`LedgerSigner`] implements [`WalletSigner`] on top of the XRP app of a Ledger
+//! device, so prepared transactions are signed on the device and private keys never
+//! enter the process. It talks to the device through any
+//! [`ledger-transport`](https://docs.rs/ledger-transport) transport, such as USB HID
+//! from `ledger-transport-hid`:
+//!
+//! ```rust,ignore
+//! use ledger_transport_hid::{hidapi::HidApi, TransportNativeHID};
+//! use xrplsale::ledger_wallet::{LedgerSigner, DEFAULT_PATH};
+//!
+//! let transport = TransportNativeHID::new(&HidApi::new()?)?;
+//! let ledger = LedgerSigner::connect(transport, DEFAULT_PATH).await?;
+//! println!("Signing as {}", ledger.account());
+//!
+//! let prepared = client.staking().prepare_stake("prog_123", &ledger.account(), "5000").await?;
+//! client.staking().submit(&prepared.sign(&ledger).await?).await?;
+//! ```
+//!
+//! Every signature has to be approved on the device. The XRP app only signs XRPL
+//! transactions: payloads that don't encode one, such as plain-text messages, are
+//! refused by the device with [`Error::Configuration`].
+
+use crate::{
+    error::{Error, Result},
+    wallet::WalletSigner,
+};
+use async_trait::async_trait;
+use ledger_transport::{APDUCommand, Exchange};
+use std::fmt::Display;
+
+/// First account of the XRP app, as used by Ledger Live
+pub const DEFAULT_PATH: &str = "44'/144'/0'/0/0";
+
+/// Prefix of a single-signing payload; the app expects the transaction without it
+const SIGNING_PREFIX: [u8; 4] = *b"STX\0";
+
+/// Instruction class of the XRP app
+const CLA: u8 = 0xE0;
+const INS_GET_PUBLIC_KEY: u8 = 0x02;
+const INS_SIGN: u8 = 0x04;
+
+/// `P1` of the first signing chunk
+const P1_FIRST: u8 = 0x00;
+/// `P1` of the following signing chunks
+const P1_NEXT: u8 = 0x80;
+/// Added to `P1` while more signing chunks follow
+const P1_MORE: u8 = 0x01;
+
+/// Maximum payload of one APDU the app accepts
+const MAX_CHUNK: usize = 150;
+
+/// Hardened BIP32 path component
+const HARDENED: u32 = 0x8000_0000;
+
+/// Status words the device answers with
+const SW_OK: u16 = 0x9000;
+const SW_REJECTED: u16 = 0x6985;
+const SW_INVALID_DATA: u16 = 0x6A80;
+const SW_APP_NOT_OPEN: [u16; 3] = [0x6D00, 0x6E00, 0x6E01];
+const SW_LOCKED: u16 = 0x5515;
+
+/// Signature scheme of the key on the device
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LedgerCurve {
+    /// secp256k1, the XRPL's default
+    #[default]
+    Secp256k1,
+    /// Ed25519
+    Ed25519,
+}
+
+impl LedgerCurve {
+    /// `P2` selecting the curve
+    fn p2(self) -> u8 {
+        match self {
+            LedgerCurve::Secp256k1 => 0x40,
+            LedgerCurve::Ed25519 => 0x80,
+        }
+    }
+}
+
+/// An XRPL account on a Ledger hardware wallet
+#[derive(Debug)]
+pub struct LedgerSigner<T> {
+    transport: T,
+    path: Vec<u32>,
+    curve: LedgerCurve,
+    account: String,
+    public_key: String,
+}
+
+impl<T> LedgerSigner<T>
+where
+    T: Exchange + Send + Sync,
+    T::Error: Display,
+{
+    /// Use the secp256k1 account at a BIP32 path, e.g. [`DEFAULT_PATH`]
+    ///
+    /// Reads the account's address and public key from the device, which must be
+    /// unlocked with the XRP app open.
+    pub async fn connect(transport: T, path: &str) -> Result<Self> {
+        Self::connect_with_curve(transport, path, LedgerCurve::Secp256k1).await
+    }
+
+    /// Use the account at a BIP32 path with the given signature scheme
+    pub async fn connect_with_curve(transport: T, path: &str, curve: LedgerCurve) -> Result<Self> {
+        let path = parse_path(path)?;
+        let response = exchange(
+            &transport,
+            INS_GET_PUBLIC_KEY,
+            0x00,
+            curve.p2(),
+            encode_path(&path),
+        )
+        .await?;
+
+        let invalid = || Error::Parse("Invalid public key response from the Ledger".to_string());
+        let (&key_len, rest) = response.split_first().ok_or_else(invalid)?;
+        let public_key = rest.get(..usize::from(key_len)).ok_or_else(invalid)?;
+        let rest = &rest[usize::from(key_len)..];
+        let (&address_len, rest) = rest.split_first().ok_or_else(invalid)?;
+        let address = rest.get(..usize::from(address_len)).ok_or_else(invalid)?;
+
+        Ok(Self {
+            public_key: hex::encode_upper(public_key),
+            account: String::from_utf8(address.to_vec()).map_err(|_| invalid())?,
+            transport,
+            path,
+            curve,
+        })
+    }
+
+    /// The signature scheme of the account
+    pub fn curve(&self) -> LedgerCurve {
+        self.curve
+    }
+
+    /// Send a transaction to the device for approval, returning the DER signature
+    async fn sign_transaction(&self, tx_blob: &[u8]) -> Result<Vec<u8>> {
+        if tx_blob.is_empty() {
+            return Err(Error::Configuration(
+                "Nothing to sign on the Ledger".to_string(),
+            ));
+        }
+
+        let path = encode_path(&self.path);
+        let first_len = tx_blob.len().min(MAX_CHUNK - path.len());
+        let mut chunks = vec![[path, tx_blob[..first_len].to_vec()].concat()];
+        chunks.extend(tx_blob[first_len..].chunks(MAX_CHUNK).map(<[u8]>::to_vec));
+
+        let last = chunks.len() - 1;
+        let mut signature = Vec::new();
+        for (i, chunk) in chunks.into_iter().enumerate() {
+            let p1 = if i == 0 { P1_FIRST } else { P1_NEXT };
+            let p1 = if i < last { p1 | P1_MORE } else { p1 };
+            signature = exchange(&self.transport, INS_SIGN, p1, self.curve.p2(), chunk).await?;
+        }
+        Ok(signature)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl<T> WalletSigner for LedgerSigner<T>
+where
+    T: Exchange + Send + Sync,
+    T::Error: Display,
+{
+    fn account(&self) -> String {
+        self.account.clone()
+    }
+
+    fn public_key(&self) -> String {
+        self.public_key.clone()
+    }
+
+    async fn sign(&self, message: &[u8]) -> Result<String> {
+        let tx_blob = message.strip_prefix(&SIGNING_PREFIX).unwrap_or(message);
+        let signature = self.sign_transaction(tx_blob).await?;
+        Ok(hex::encode_upper(signature))
+    }
+}
+
+/// Send one command and return the answer's data, mapping the device's status
+async fn exchange<T>(transport: &T, ins: u8, p1: u8, p2: u8, data: Vec<u8>) -> Result<Vec<u8>>
+where
+    T: Exchange + Send + Sync,
+    T::Error: Display,
+{
+    let command = APDUCommand {
+        cla: CLA,
+        ins,
+        p1,
+        p2,
+        data,
+    };
+    let answer = transport
+        .exchange(&command)
+        .await
+        .map_err(|e| Error::Configuration(format!("Ledger transport error: {}", e)))?;
+
+    match answer.retcode() {
+        SW_OK => Ok(answer.data().to_vec()),
+        SW_REJECTED => Err(Error::Unauthorized(
+            "Rejected on the Ledger device".to_string(),
+        )),
+        SW_INVALID_DATA => Err(Error::Configuration(
+            "The Ledger XRP app can't sign this payload; it only signs XRPL transactions"
+                .to_string(),
+        )),
+        SW_LOCKED => Err(Error::Configuration("Unlock the Ledger device".to_string())),
+        code if SW_APP_NOT_OPEN.contains(&code) => Err(Error::Configuration(
+            "Open the XRP app on the Ledger device".to_string(),
+        )),
+        code => Err(Error::Configuration(format!(
+            "Ledger device returned status {:04X}",
+            code
+        ))),
+    }
+}
+
+/// Parse a BIP32 path such as `44'/144'/0'/0/0`
+fn parse_path(path: &str) -> Result<Vec<u32>> {
+    let invalid = || Error::Configuration(format!("'{}' is not a valid BIP32 path", path));
+    let components: Vec<u32> = path
+        .trim_start_matches("m/")
+        .split('/')
+        .map(|component| {
+            let (index, hardened) = match component.strip_suffix(['\'', 'h']) {
+                Some(index) => (index, true),
+                None => (component, false),
+            };
+            let index: u32 = index.parse().map_err(|_| invalid())?;
+            match (hardened, index < HARDENED) {
+                (_, false) => Err(invalid()),
+                (true, true) => Ok(index | HARDENED),
+                (false, true) => Ok(index),
+            }
+        })
+        .collect::<Result<_>>()?;
+
+    if components.is_empty() || components.len() > 10 {
+        return Err(invalid());
+    }
+    Ok(components)
+}
+
+/// A path as the app expects it: its length, then each component big-endian
+fn encode_path(path: &[u32]) -> Vec<u8> {
+    let mut encoded = vec![path.len() as u8];
+    for component in path {
+        encoded.extend_from_slice(&component.to_be_bytes());
+    }
+    encoded
+}
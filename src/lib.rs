@@ -14,14 +14,15 @@
 //! - 📈 **Analytics & Reporting** - Comprehensive data insights
 //! - 🛡️ **Type Safety** - Strongly typed API with comprehensive error handling
 //! - 🔄 **Auto-retry Logic** - Resilient API calls with exponential backoff
+//! - 🗄️ **Response Caching** - Optional ETag-aware caching of GET requests
+//! - 📉 **Metrics** - Optional Prometheus-compatible request metrics via the `metrics` facade
 //! - 🧩 **Framework Integration** - Optional integrations for Axum, Actix-web, and Warp
 //! - 📝 **Rich Documentation** - Comprehensive docs with examples
 //!
 //! ## Quick Start
 //!
 //! ```rust
-//! use xrplsale::{Client, Environment, CreateProjectRequest, ProjectTier};
-//! use std::collections::HashMap;
+//! use xrplsale::prelude::*;
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -55,36 +56,154 @@
 
 use std::sync::Arc;
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "cache")]
+pub mod cache;
+pub mod cdc;
+pub mod circuit_breaker;
 pub mod client;
+#[cfg(feature = "config-support")]
+mod config_file;
+pub mod deprecation;
+#[cfg(feature = "display")]
+pub mod display;
+#[cfg(feature = "encryption")]
+pub mod encryption;
 pub mod error;
+pub mod error_code;
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+pub mod ids;
+#[cfg(feature = "ledger-wallet")]
+pub mod ledger_wallet;
+pub mod logging;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod middleware;
 pub mod models;
+#[cfg(feature = "xrpl")]
+pub mod multisig;
+pub mod oauth;
+pub mod operation;
+#[cfg(all(feature = "outbox", not(target_arch = "wasm32")))]
+pub mod outbox;
+#[cfg(feature = "xrpl")]
+pub mod payment_watcher;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod pipeline;
+pub mod pool;
+pub mod prelude;
+pub mod priority;
+#[cfg(feature = "axum-proxy")]
+pub mod proxy;
+mod query;
+pub mod rate_limit;
+#[cfg(feature = "replay")]
+pub mod replay;
+pub mod reporting;
+pub mod response;
+pub mod runtime;
+#[cfg(feature = "tower")]
+pub mod service;
 pub mod services;
+pub mod signing;
+mod sse;
+#[cfg(feature = "synced-store")]
+pub mod synced_store;
+#[cfg(feature = "xrpl")]
+pub mod trustlines;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod types;
+pub mod wallet;
 pub mod webhook;
+#[cfg(feature = "ed25519")]
+pub mod webhook_ed25519;
+pub mod webhook_forwarding;
+pub mod webhook_rotation;
+pub mod webhook_store;
+pub mod webhook_tail;
+pub mod workflows;
+#[cfg(feature = "xrpl")]
+mod xrpl_rpc;
 
 #[cfg(feature = "axum-integration")]
 pub mod integrations;
 
 // Re-exports for convenience
-pub use client::{Client, ClientBuilder};
+pub use client::{Client, ClientBuilder, ClientConfig, Profile, RequestOptions};
 pub use error::{Error, Result};
+pub use error_code::ErrorCode;
+pub use ids::{InvestmentId, ProjectId, WebhookId};
+pub use middleware::{Middleware, Next};
+pub use priority::Priority;
+pub use response::{ApiResponse, ByteStream, ResponseMeta};
+pub use tokio_util::sync::CancellationToken;
+
+// Models are re-exported at the root only for backwards compatibility and will stop being
+// in 2.0. rustc doesn't warn on items reached through a `pub use`, so the migration to
+// `xrplsale::prelude` or `xrplsale::types` is documented in CHANGELOG.md instead;
+// disabling the `root-reexports` feature shows every import that still needs moving.
+#[cfg(feature = "root-reexports")]
+#[doc(hidden)]
 pub use models::*;
 pub use webhook::{WebhookEvent, WebhookSignatureValidator};
+#[cfg(feature = "ed25519")]
+pub use webhook_ed25519::Ed25519WebhookValidator;
+pub use webhook_forwarding::{ForwardedWebhookVerifier, WebhookForwarder};
+pub use webhook_rotation::{RotatingWebhookValidator, WebhookVerifier};
 
 /// XRPL.Sale API environments
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Not `Copy`, since [`Environment::Custom`] owns its URLs; clone it where needed.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Environment {
     /// Production environment
     Production,
     /// Testnet environment for testing
     Testnet,
+    /// Devnet environment backed by the XRPL devnet
+    Devnet,
+    /// A self-hosted or staging deployment
+    Custom {
+        /// Base URL for REST requests, e.g. `https://api.staging.example.com/v1`
+        api_url: String,
+        /// URL for WebSocket connections, e.g. `wss://ws.staging.example.com/v1`
+        ws_url: String,
+    },
 }
 
 impl Environment {
+    /// Create a custom environment, deriving the WebSocket URL from the API URL
+    ///
+    /// `https://` becomes `wss://` (and `http://` becomes `ws://`), with `/ws` appended.
+    pub fn custom<S: Into<String>>(api_url: S) -> Self {
+        let api_url = api_url.into().trim_end_matches('/').to_string();
+        let ws_url = match api_url.strip_prefix("https://") {
+            Some(rest) => format!("wss://{}/ws", rest),
+            None => format!("ws://{}/ws", api_url.trim_start_matches("http://")),
+        };
+        Environment::Custom { api_url, ws_url }
+    }
+
     /// Get the base URL for this environment
-    pub fn base_url(&self) -> &'static str {
+    pub fn base_url(&self) -> &str {
         match self {
             Environment::Production => "https://api.xrpl.sale/v1",
             Environment::Testnet => "https://api-testnet.xrpl.sale/v1",
+            Environment::Devnet => "https://api-devnet.xrpl.sale/v1",
+            Environment::Custom { api_url, .. } => api_url,
+        }
+    }
+
+    /// Get the WebSocket URL for this environment
+    pub fn ws_url(&self) -> &str {
+        match self {
+            Environment::Production => "wss://api.xrpl.sale/v1/ws",
+            Environment::Testnet => "wss://api-testnet.xrpl.sale/v1/ws",
+            Environment::Devnet => "wss://api-devnet.xrpl.sale/v1/ws",
+            Environment::Custom { ws_url, .. } => ws_url,
         }
     }
 }
@@ -100,6 +219,8 @@ impl std::fmt::Display for Environment {
         match self {
             Environment::Production => write!(f, "production"),
             Environment::Testnet => write!(f, "testnet"),
+            Environment::Devnet => write!(f, "devnet"),
+            Environment::Custom { api_url, .. } => write!(f, "{}", api_url),
         }
     }
 }
@@ -107,15 +228,81 @@ impl std::fmt::Display for Environment {
 impl std::str::FromStr for Environment {
     type Err = Error;
 
+    /// Parse an environment name, or an `http(s)://` API URL for a custom environment
     fn from_str(s: &str) -> Result<Self> {
         match s.to_lowercase().as_str() {
             "production" | "prod" => Ok(Environment::Production),
             "testnet" | "test" => Ok(Environment::Testnet),
+            "devnet" | "dev" => Ok(Environment::Devnet),
+            lower if lower.starts_with("https://") || lower.starts_with("http://") => {
+                Ok(Environment::custom(s))
+            }
             _ => Err(Error::InvalidEnvironment(s.to_string())),
         }
     }
 }
 
+/// XRPL.Sale API versions, selected with the `X-API-Version` header
+///
+/// The major version is also part of the base URL path (`/v1`). Within it, the header
+/// opts into newer behaviour; without it the API answers as its oldest supported
+/// version. Set it for the whole client with
+/// [`ClientBuilder::api_version`](client::ClientBuilder::api_version) or for some
+/// requests with [`Client::with_api_version`].
+///
+/// `V2` requires the `api-v2` feature, which also compiles in the model fields that
+/// only v2 responses carry, so code is migrated to them deliberately.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApiVersion {
+    /// The original API
+    V1,
+    /// Version 2
+    #[cfg(feature = "api-v2")]
+    V2,
+    /// A version this SDK does not know, such as a preview, sent as is
+    Other(String),
+}
+
+impl ApiVersion {
+    /// Value sent in the `X-API-Version` header
+    pub fn as_str(&self) -> &str {
+        match self {
+            ApiVersion::V1 => "v1",
+            #[cfg(feature = "api-v2")]
+            ApiVersion::V2 => "v2",
+            ApiVersion::Other(version) => version,
+        }
+    }
+}
+
+impl std::fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for ApiVersion {
+    type Err = Error;
+
+    /// Parse `v1` or `v2` (with or without the `v`), or any other non-empty version
+    ///
+    /// `v2` fails with [`Error::Configuration`] unless the `api-v2` feature is enabled.
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "" => Err(Error::Configuration("API version must not be empty".to_string())),
+            "v1" | "1" => Ok(ApiVersion::V1),
+            #[cfg(feature = "api-v2")]
+            "v2" | "2" => Ok(ApiVersion::V2),
+            #[cfg(not(feature = "api-v2"))]
+            "v2" | "2" => Err(Error::Configuration(
+                "API version v2 requires the `api-v2` feature".to_string(),
+            )),
+            _ => Ok(ApiVersion::Other(s.trim().to_string())),
+        }
+    }
+}
+
 /// SDK version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -0,0 +1,241 @@
+//! Debug logging with secret redaction
+//!
+//! When [`ClientConfig::debug`](crate::client::ClientConfig::debug) is enabled the client
+//! logs every request and response at `debug` level. Credentials never appear in these
+//! logs: API keys, bearer tokens, signatures, and webhook secrets are replaced with
+//! `[REDACTED]` in headers, query strings, and JSON bodies.
+//!
+//! Bodies are not logged by default. Opt in per HTTP method with
+//! [`ClientBuilder::log_bodies`](crate::client::ClientBuilder::log_bodies); logged bodies
+//! are still redacted.
+
+use reqwest::{header::HeaderMap, Request, Response};
+use std::time::Duration;
+use url::Url;
+
+/// Placeholder written in place of secret values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Header names whose values are always redacted
+const SENSITIVE_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "x-api-key",
+    "x-signature",
+    "x-xrpl-sale-signature",
+];
+
+/// Query and JSON field names whose values are redacted, in `snake_case`
+const SENSITIVE_FIELDS: &[&str] = &[
+    "apikey",
+    "secret",
+    "signature",
+    "token",
+    "password",
+    "passphrase",
+    "seed",
+    "mnemonic",
+];
+
+/// Field name suffixes whose values are redacted, such as `client_secret`,
+/// `refresh_token`, `private_key`, or `wallet_seed`
+const SENSITIVE_SUFFIXES: &[&str] = &[
+    "_key",
+    "_secret",
+    "_signature",
+    "_password",
+    "_passphrase",
+    "_token",
+    "_seed",
+    "_mnemonic",
+];
+
+/// Names matching a suffix above that carry no secret
+const PUBLIC_FIELDS: &[&str] = &["price_per_token", "public_key", "idempotency_key"];
+
+pub(crate) fn is_sensitive_header(name: &str) -> bool {
+    SENSITIVE_HEADERS.contains(&name.to_lowercase().as_str())
+}
+
+pub(crate) fn is_sensitive_field(name: &str) -> bool {
+    let name = snake_case(name);
+    if PUBLIC_FIELDS.contains(&name.as_str()) {
+        return false;
+    }
+    SENSITIVE_FIELDS.contains(&name.as_str())
+        || SENSITIVE_SUFFIXES
+            .iter()
+            .any(|suffix| name.ends_with(suffix))
+}
+
+/// A field name in `snake_case`, so `accessToken`, `Access-Token`, and `access_token`
+/// are matched alike
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    let mut previous: Option<char> = None;
+    for c in name.chars() {
+        if c == '-' || c == '.' || c == ' ' {
+            snake.push('_');
+        } else if c.is_uppercase() {
+            if previous.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit()) {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+        previous = Some(c);
+    }
+    snake
+}
+
+/// Render headers for logging with credentials redacted
+pub fn redact_headers(headers: &HeaderMap) -> String {
+    let rendered: Vec<String> = headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if is_sensitive_header(name.as_str()) {
+                match value.to_str() {
+                    Ok(v) if v.starts_with("Bearer ") => format!("Bearer {}", REDACTED),
+                    _ => REDACTED.to_string(),
+                }
+            } else {
+                value.to_str().unwrap_or("<binary>").to_string()
+            };
+            format!("{}: {}", name, value)
+        })
+        .collect();
+
+    format!("{{{}}}", rendered.join(", "))
+}
+
+/// Render a URL for logging with sensitive query parameters redacted
+pub fn redact_url(url: &Url) -> String {
+    if url.query().is_none() {
+        return url.to_string();
+    }
+
+    let mut redacted = url.clone();
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            let value = if is_sensitive_field(&key) {
+                REDACTED.to_string()
+            } else {
+                value.into_owned()
+            };
+            (key.into_owned(), value)
+        })
+        .collect();
+
+    redacted.query_pairs_mut().clear().extend_pairs(pairs);
+    redacted.to_string()
+}
+
+/// Render a request or response body for logging with secret fields redacted
+///
+/// Non-JSON bodies are summarised by size rather than logged verbatim, since they
+/// cannot be redacted reliably.
+pub fn redact_body(body: &[u8]) -> String {
+    match serde_json::from_slice::<serde_json::Value>(body) {
+        Ok(mut value) => {
+            redact_json(&mut value);
+            value.to_string()
+        }
+        Err(_) => format!("<{} bytes, not JSON>", body.len()),
+    }
+}
+
+pub(crate) fn redact_json(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_sensitive_field(key) && !value.is_object() && !value.is_array() {
+                    *value = serde_json::Value::String(REDACTED.to_string());
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
+
+/// Log an outgoing request
+pub(crate) fn log_request(request: &Request, attempt: usize, log_body: bool) {
+    log::debug!(
+        "HTTP {} {} (attempt {}) headers={}",
+        request.method(),
+        redact_url(request.url()),
+        attempt + 1,
+        redact_headers(request.headers())
+    );
+
+    if log_body {
+        if let Some(body) = request.body().and_then(|b| b.as_bytes()) {
+            log::debug!("Request body: {}", redact_body(body));
+        }
+    }
+}
+
+/// Log a received response (the body is logged separately once read)
+pub(crate) fn log_response(response: &Response, elapsed: Duration) {
+    log::debug!(
+        "HTTP {} {} in {:?} headers={}",
+        response.status(),
+        redact_url(response.url()),
+        elapsed,
+        redact_headers(response.headers())
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_secret_fields_in_any_case() {
+        for name in [
+            "token",
+            "api_key",
+            "apiKey",
+            "APIKey",
+            "X-Api-Key",
+            "access_token",
+            "accessToken",
+            "refresh_token",
+            "RefreshToken",
+            "id-token",
+            "private_key",
+            "privateKey",
+            "client_secret",
+            "webhookSecret",
+            "wallet_seed",
+            "walletSeed",
+            "password",
+            "signature",
+        ] {
+            assert!(is_sensitive_field(name), "{} should be redacted", name);
+        }
+    }
+
+    #[test]
+    fn keeps_sale_amounts() {
+        for name in [
+            "token_symbol",
+            "token_amount",
+            "tokenAmount",
+            "price_per_token",
+            "pricePerToken",
+            "total_tokens",
+            "public_key",
+            "seed_round",
+            "project_id",
+        ] {
+            assert!(!is_sensitive_field(name), "{} should be logged", name);
+        }
+    }
+}
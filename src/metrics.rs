@@ -0,0 +1,132 @@
+//! Client metrics recorded through the `metrics` facade
+//!
+//! Metrics are emitted to whichever [`metrics::Recorder`] is installed globally, so a
+//! Prometheus endpoint only needs an exporter such as `metrics-exporter-prometheus`:
+//!
+//! ```rust,ignore
+//! use xrplsale::{metrics::ClientMetrics, Client};
+//!
+//! metrics_exporter_prometheus::PrometheusBuilder::new().install()?;
+//!
+//! let metrics = ClientMetrics::new();
+//! metrics.describe();
+//!
+//! let client = Client::builder()
+//!     .api_key("your-api-key")
+//!     .metrics(metrics)
+//!     .build()?;
+//! ```
+//!
+//! The following series are recorded, all prefixed with `xrplsale_` by default:
+//!
+//! | Name | Type | Labels |
+//! |------|------|--------|
+//! | `requests_total` | counter | `method`, `endpoint`, `status` |
+//! | `request_duration_seconds` | histogram | `method`, `endpoint` |
+//! | `retries_total` | counter | `method`, `endpoint` |
+//! | `rate_limited_total` | counter | `method`, `endpoint` |
+//!
+//! Endpoints are recorded as path templates (`/projects/:id/stats`) to keep label
+//! cardinality bounded.
+
+use ::metrics::Label;
+use reqwest::Method;
+use std::time::Duration;
+
+/// Handle for recording client metrics
+#[derive(Debug, Clone)]
+pub struct ClientMetrics {
+    prefix: String,
+    labels: Vec<Label>,
+}
+
+impl Default for ClientMetrics {
+    fn default() -> Self {
+        Self {
+            prefix: "xrplsale".to_string(),
+            labels: Vec::new(),
+        }
+    }
+}
+
+impl ClientMetrics {
+    /// Create a metrics handle using the default `xrplsale` prefix
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the prefix used for metric names
+    pub fn with_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Add a static label attached to every recorded metric
+    pub fn with_label<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.labels.push(Label::new(key.into(), value.into()));
+        self
+    }
+
+    /// Register descriptions and units for all metrics with the installed recorder
+    pub fn describe(&self) {
+        ::metrics::describe_counter!(
+            self.name("requests_total"),
+            "Total number of API requests by endpoint and response status"
+        );
+        ::metrics::describe_histogram!(
+            self.name("request_duration_seconds"),
+            ::metrics::Unit::Seconds,
+            "API request latency"
+        );
+        ::metrics::describe_counter!(
+            self.name("retries_total"),
+            "Total number of retried API requests"
+        );
+        ::metrics::describe_counter!(
+            self.name("rate_limited_total"),
+            "Total number of API requests rejected with HTTP 429"
+        );
+    }
+
+    /// Record a completed request attempt
+    ///
+    /// `status` is `None` when the request failed before a response was received.
+    pub(crate) fn record_request(
+        &self,
+        method: &Method,
+        endpoint: &str,
+        status: Option<u16>,
+        latency: Duration,
+    ) {
+        let status = status
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| "error".to_string());
+
+        let mut labels = self.labels(method, endpoint);
+        ::metrics::histogram!(self.name("request_duration_seconds"), labels.clone())
+            .record(latency.as_secs_f64());
+
+        if status == "429" {
+            ::metrics::counter!(self.name("rate_limited_total"), labels.clone()).increment(1);
+        }
+
+        labels.push(Label::new("status", status));
+        ::metrics::counter!(self.name("requests_total"), labels).increment(1);
+    }
+
+    /// Record that a request is about to be retried
+    pub(crate) fn record_retry(&self, method: &Method, endpoint: &str) {
+        ::metrics::counter!(self.name("retries_total"), self.labels(method, endpoint)).increment(1);
+    }
+
+    fn name(&self, metric: &str) -> String {
+        format!("{}_{}", self.prefix, metric)
+    }
+
+    fn labels(&self, method: &Method, endpoint: &str) -> Vec<Label> {
+        let mut labels = self.labels.clone();
+        labels.push(Label::new("method", method.to_string()));
+        labels.push(Label::new("endpoint", endpoint.to_string()));
+        labels
+    }
+}
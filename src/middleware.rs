@@ -0,0 +1,136 @@
+//! Request/response middleware for the XRPL.Sale client
+
+use crate::{
+    error::{Error, Result},
+    runtime::Clock,
+    signing,
+};
+use async_trait::async_trait;
+use reqwest::{Request, Response};
+use std::sync::Arc;
+
+/// A hook that runs around every HTTP request sent by the client
+///
+/// Middleware can inspect or modify the outgoing request, short-circuit it with its own
+/// response, or post-process the response produced by the rest of the chain. Call
+/// [`Next::run`] to hand the request on to the next middleware and, eventually, to the
+/// network.
+///
+/// Middleware runs once per attempt, so with retries enabled a single API call may pass
+/// through the chain several times.
+///
+/// # Example
+///
+/// ```rust
+/// use reqwest::{header::HeaderValue, Request, Response};
+/// use xrplsale::{Client, Middleware, Next, Result};
+///
+/// struct TenantHeader(&'static str);
+///
+/// #[async_trait::async_trait]
+/// impl Middleware for TenantHeader {
+///     async fn handle(&self, mut request: Request, next: Next<'_>) -> Result<Response> {
+///         request
+///             .headers_mut()
+///             .insert("X-Tenant-Id", HeaderValue::from_static(self.0));
+///         next.run(request).await
+///     }
+/// }
+///
+/// # fn main() -> Result<()> {
+/// let client = Client::builder()
+///     .api_key("your-api-key")
+///     .with_middleware(TenantHeader("acme"))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait Middleware: Send + Sync + 'static {
+    /// Handle a request, delegating to `next` to continue the chain
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Response>;
+}
+
+/// The remainder of a middleware chain
+///
+/// Passed to [`Middleware::handle`]; running it forwards the request to the next
+/// middleware, or sends it over the wire once the chain is exhausted.
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    http_client: &'a reqwest::Client,
+    middleware: &'a [Arc<dyn Middleware>],
+    signing: Option<(&'a str, &'a dyn Clock)>,
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(http_client: &'a reqwest::Client, middleware: &'a [Arc<dyn Middleware>]) -> Self {
+        Self {
+            http_client,
+            middleware,
+            signing: None,
+        }
+    }
+
+    /// Sign the request with `secret` once it has passed every middleware
+    pub(crate) fn signed(mut self, secret: &'a str, clock: &'a dyn Clock) -> Self {
+        self.signing = Some((secret, clock));
+        self
+    }
+
+    /// Run the rest of the chain for this request
+    pub async fn run(self, mut request: Request) -> Result<Response> {
+        match self.middleware.split_first() {
+            Some((current, rest)) => {
+                current
+                    .handle(
+                        request,
+                        Next {
+                            middleware: rest,
+                            ..self
+                        },
+                    )
+                    .await
+            }
+            None => {
+                if let Some((secret, clock)) = self.signing {
+                    signing::sign_request(&mut request, secret, clock.utc_now())?;
+                }
+                self.http_client
+                    .execute(request)
+                    .await
+                    .map_err(|e| Error::HttpClient(e.to_string()))
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for Next<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Next")
+            .field("remaining", &self.middleware.len())
+            .finish()
+    }
+}
+
+/// Ordered list of middleware registered on a client
+#[derive(Clone, Default)]
+pub(crate) struct MiddlewareStack(Vec<Arc<dyn Middleware>>);
+
+impl MiddlewareStack {
+    pub(crate) fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.0.push(middleware);
+    }
+
+    pub(crate) fn as_slice(&self) -> &[Arc<dyn Middleware>] {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for MiddlewareStack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MiddlewareStack")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
@@ -0,0 +1,391 @@
+//! Transactions for multi-signed accounts
+//!
+//! Accounts controlled by a `SignerList`, such as treasuries, can't sign with a
+//! single wallet. Every signable transaction the SDK prepares (trustlines, investment
+//! payments, and staking transactions) can be output as an [`UnsignedTransaction`]:
+//! the transaction JSON plus [`SigningInstructions`] for whoever signs it. For
+//! multi-signing, [`Multisig`] collects a signature from each signer through
+//! [`WalletSigner::multisign`] and submits the combined transaction:
+//!
+//! ```rust,no_run
+//! use xrplsale::{
+//!     multisig::{Multisig, SigningMode},
+//!     wallet::WalletSigner,
+//!     Client,
+//! };
+//!
+//! # async fn run(
+//! #     client: &Client,
+//! #     alice: &dyn WalletSigner,
+//! #     bob: &dyn WalletSigner,
+//! # ) -> xrplsale::Result<()> {
+//! let prepared = client
+//!     .staking()
+//!     .prepare_stake("prog_123", "rTreasury...", "5000")
+//!     .await?;
+//! let unsigned = prepared.unsigned(SigningMode::multisign([alice.account(), bob.account()]))?;
+//!
+//! let mut multisig = Multisig::new(&unsigned)?;
+//! multisig.sign(alice).await?;
+//! multisig.sign(bob).await?;
+//!
+//! let submitted = multisig.submit(client).await?;
+//! println!("{}: {}", submitted.tx_hash, submitted.engine_result);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! An [`UnsignedTransaction`] serializes to JSON, so it can also be handed to signers
+//! offline and their signatures added with [`Multisig::add_signature`]. Multi-signed
+//! transactions are submitted straight to the ledger through an XRPL node; the
+//! platform picks them up from there.
+
+use crate::{
+    client::Client,
+    error::{Error, Result},
+    wallet::WalletSigner,
+    xrpl_rpc::XrplRpc,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+/// Alphabet of the XRPL's base58 encoding
+const ALPHABET: &[u8; 58] = b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+
+/// Option that picks the endpoint, named in configuration errors
+const RPC_URL_SETTING: &str = "Multisig::rpc_url";
+
+/// How a transaction will be signed
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum SigningMode {
+    /// With the sending account's own key
+    Single,
+    /// By these members of the sending account's signer list
+    Multisign {
+        /// Classic addresses of the signers
+        signers: Vec<String>,
+    },
+}
+
+impl SigningMode {
+    /// Multi-signing by these signers
+    pub fn multisign<I, S>(signers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        SigningMode::Multisign {
+            signers: signers.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// What a signer needs to know about an unsigned transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SigningInstructions {
+    /// Account the transaction is sent from
+    pub account: String,
+    /// How the transaction must be signed
+    #[serde(flatten)]
+    pub mode: SigningMode,
+    /// When the transaction's last ledger is expected to close, if it has one
+    #[serde(default, with = "crate::types::dates::option")]
+    pub expires_at: Option<DateTime<Utc>>,
+    /// The steps from this JSON to a submitted transaction, in order
+    pub steps: Vec<String>,
+}
+
+/// A transaction in the XRPL's JSON format, with instructions for signing it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnsignedTransaction {
+    /// The transaction, exactly as it must be signed
+    pub tx_json: Value,
+    /// Who signs it and how
+    pub instructions: SigningInstructions,
+}
+
+impl UnsignedTransaction {
+    /// Prepare a transaction for signing
+    ///
+    /// For multi-signing, `SigningPubKey` is emptied and a set `Fee` is multiplied by
+    /// one more than the number of signers, as the ledger charges. Fails with
+    /// [`Error::Configuration`] if the transaction has no `Account`, or the signers are
+    /// missing, invalid, repeated, or include the account itself.
+    pub fn new(mut tx_json: Value, mode: SigningMode) -> Result<Self> {
+        if !tx_json.is_object() {
+            return Err(Error::Parse(
+                "Transaction JSON must be an object".to_string(),
+            ));
+        }
+        let account = tx_json["Account"]
+            .as_str()
+            .ok_or_else(|| {
+                Error::Configuration("Set the transaction's Account before signing it".to_string())
+            })?
+            .to_string();
+
+        let autofilled = tx_json.get("Sequence").is_some();
+        let mut steps = Vec::new();
+        match &mode {
+            SigningMode::Single => {
+                if !autofilled {
+                    steps.push("Autofill Sequence, Fee, and LastLedgerSequence".to_string());
+                }
+                steps.push(format!("Sign with the key of {}", account));
+                steps.push("Submit the signed transaction blob".to_string());
+            }
+            SigningMode::Multisign { signers } => {
+                check_signers(&account, signers)?;
+                let multiplier = signers.len() as u64 + 1;
+                tx_json["SigningPubKey"] = json!("");
+                if let Some(fee) = tx_json.get("Fee") {
+                    let fee = fee
+                        .as_str()
+                        .and_then(|fee| fee.parse::<u64>().ok())
+                        .ok_or_else(|| Error::Parse(format!("Invalid transaction Fee {}", fee)))?;
+                    tx_json["Fee"] = json!((fee * multiplier).to_string());
+                } else {
+                    steps.push(format!("Set Fee to {} times the base fee", multiplier));
+                }
+                if !autofilled {
+                    steps.push("Autofill Sequence and LastLedgerSequence".to_string());
+                }
+                steps.push(format!(
+                    "Have each of {} sign this exact transaction for multi-signing",
+                    signers.join(", ")
+                ));
+                steps.push("Combine the signatures into Signers, sorted by account ID".to_string());
+                steps.push("Submit the combined transaction with submit_multisigned".to_string());
+            }
+        }
+
+        Ok(Self {
+            tx_json,
+            instructions: SigningInstructions {
+                account,
+                mode,
+                expires_at: None,
+                steps,
+            },
+        })
+    }
+}
+
+/// One signer's signature on a multi-signed transaction
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SignerEntry {
+    /// Classic address of the signer
+    pub account: String,
+    /// Hex-encoded public key of the signer
+    pub signing_pub_key: String,
+    /// Hex-encoded signature
+    pub txn_signature: String,
+}
+
+/// Result of submitting a multi-signed transaction
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct MultisignedSubmission {
+    /// Transaction hash
+    pub tx_hash: String,
+    /// Preliminary engine result, e.g. `tesSUCCESS`
+    pub engine_result: String,
+    /// Human-readable description of the engine result
+    pub engine_result_message: String,
+}
+
+/// Collects signatures on a multi-signed transaction and submits it
+#[derive(Debug, Clone)]
+pub struct Multisig {
+    tx_json: Value,
+    account: String,
+    signers: Vec<String>,
+    signatures: Vec<SignerEntry>,
+    rpc_url: Option<String>,
+}
+
+impl Multisig {
+    /// Start collecting signatures for a transaction prepared for multi-signing
+    ///
+    /// Fails with [`Error::Configuration`] if the transaction is for single signing or
+    /// has not been autofilled with a `Sequence` and `Fee`; every signer must sign
+    /// the final transaction.
+    pub fn new(unsigned: &UnsignedTransaction) -> Result<Self> {
+        let SigningMode::Multisign { signers } = &unsigned.instructions.mode else {
+            return Err(Error::Configuration(
+                "Transaction was prepared for single signing".to_string(),
+            ));
+        };
+        if unsigned.tx_json.get("Sequence").is_none() || unsigned.tx_json.get("Fee").is_none() {
+            return Err(Error::Configuration(
+                "Autofill the transaction's Sequence and Fee before collecting signatures"
+                    .to_string(),
+            ));
+        }
+
+        Ok(Self {
+            tx_json: unsigned.tx_json.clone(),
+            account: unsigned.instructions.account.clone(),
+            signers: signers.clone(),
+            signatures: Vec::new(),
+            rpc_url: None,
+        })
+    }
+
+    /// Submit through this XRPL JSON-RPC endpoint, e.g. `http://localhost:5005`
+    pub fn rpc_url<S: Into<String>>(mut self, rpc_url: S) -> Self {
+        self.rpc_url = Some(rpc_url.into());
+        self
+    }
+
+    /// Sign with one of the transaction's signers
+    pub async fn sign(&mut self, signer: &dyn WalletSigner) -> Result<()> {
+        let account = signer.account();
+        self.check_signer(&account)?;
+        let txn_signature = signer.multisign(&self.tx_json).await?;
+        self.signatures.push(SignerEntry {
+            account,
+            signing_pub_key: signer.public_key(),
+            txn_signature,
+        });
+        Ok(())
+    }
+
+    /// Add a signature collected elsewhere, e.g. from an offline signer
+    pub fn add_signature(&mut self, signature: SignerEntry) -> Result<()> {
+        self.check_signer(&signature.account)?;
+        self.signatures.push(signature);
+        Ok(())
+    }
+
+    /// Signatures collected so far
+    pub fn signatures(&self) -> &[SignerEntry] {
+        &self.signatures
+    }
+
+    /// Signers that have not signed yet
+    pub fn missing(&self) -> Vec<&str> {
+        self.signers
+            .iter()
+            .filter(|signer| !self.has_signed(signer))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// The transaction with its `Signers`, sorted by account ID as the ledger requires
+    ///
+    /// Fails with [`Error::Configuration`] if nobody has signed yet.
+    pub fn tx_json(&self) -> Result<Value> {
+        if self.signatures.is_empty() {
+            return Err(Error::Configuration(
+                "Multi-signed transaction has no signatures".to_string(),
+            ));
+        }
+
+        let mut signatures = self.signatures.clone();
+        signatures.sort_by_key(|signature| account_id(&signature.account));
+        let mut tx_json = self.tx_json.clone();
+        tx_json["Signers"] = signatures
+            .into_iter()
+            .map(|signature| json!({ "Signer": signature }))
+            .collect();
+        Ok(tx_json)
+    }
+
+    /// Submit the transaction with the signatures collected so far
+    ///
+    /// Uses the public XRPL node for the client's environment unless
+    /// [`rpc_url`](Self::rpc_url) is set. The engine result is preliminary; the
+    /// transaction is final once its ledger is validated.
+    pub async fn submit(&self, client: &Client) -> Result<MultisignedSubmission> {
+        let into_error =
+            |e: crate::xrpl_rpc::RpcError| e.into_error(&self.account, RPC_URL_SETTING);
+        let rpc = XrplRpc::new(client, self.rpc_url.as_deref()).map_err(into_error)?;
+        let result = rpc
+            .call("submit_multisigned", json!({ "tx_json": self.tx_json()? }))
+            .await
+            .map_err(into_error)?;
+
+        let text = |value: &Value| value.as_str().unwrap_or_default().to_string();
+        Ok(MultisignedSubmission {
+            tx_hash: text(&result["tx_json"]["hash"]),
+            engine_result: text(&result["engine_result"]),
+            engine_result_message: text(&result["engine_result_message"]),
+        })
+    }
+
+    fn check_signer(&self, account: &str) -> Result<()> {
+        if !self.signers.iter().any(|signer| signer == account) {
+            return Err(Error::Configuration(format!(
+                "{} is not a signer of this transaction",
+                account
+            )));
+        }
+        if self.has_signed(account) {
+            return Err(Error::Configuration(format!(
+                "{} has already signed",
+                account
+            )));
+        }
+        Ok(())
+    }
+
+    fn has_signed(&self, account: &str) -> bool {
+        self.signatures
+            .iter()
+            .any(|signature| signature.account == account)
+    }
+}
+
+fn check_signers(account: &str, signers: &[String]) -> Result<()> {
+    if signers.is_empty() {
+        return Err(Error::Configuration(
+            "Multi-signing needs at least one signer".to_string(),
+        ));
+    }
+    for (i, signer) in signers.iter().enumerate() {
+        if account_id(signer).is_none() {
+            return Err(Error::Configuration(format!(
+                "'{}' is not a valid XRPL account",
+                signer
+            )));
+        }
+        if signer == account {
+            return Err(Error::Configuration(format!(
+                "{} can't be a signer of its own transaction",
+                account
+            )));
+        }
+        if signers[..i].contains(signer) {
+            return Err(Error::Configuration(format!("{} is listed twice", signer)));
+        }
+    }
+    Ok(())
+}
+
+/// The 20-byte account ID a classic address encodes, if its checksum is valid
+fn account_id(address: &str) -> Option<[u8; 20]> {
+    let mut bytes = [0u8; 25];
+    for c in address.bytes() {
+        let mut carry = ALPHABET.iter().position(|&digit| digit == c)? as u32;
+        for byte in bytes.iter_mut().rev() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        if carry != 0 {
+            return None;
+        }
+    }
+
+    let checksum = Sha256::digest(Sha256::digest(&bytes[..21]));
+    if !address.starts_with('r') || bytes[0] != 0 || checksum[..4] != bytes[21..] {
+        return None;
+    }
+    bytes[1..21].try_into().ok()
+}
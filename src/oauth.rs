@@ -0,0 +1,184 @@
+//! OAuth2 client-credentials authentication
+//!
+//! Instead of a static API key, the client can authenticate with an OAuth2 client ID
+//! and secret. It obtains an access token from the platform's token endpoint on first
+//! use, caches it, and fetches a new one shortly before it expires or after the API
+//! rejects it with `401 Unauthorized`.
+//!
+//! ```rust
+//! use xrplsale::{oauth::ClientCredentials, Client};
+//!
+//! # fn main() -> xrplsale::Result<()> {
+//! let client = Client::builder()
+//!     .client_credentials(
+//!         ClientCredentials::new("client-id", "client-secret")
+//!             .scope("projects:read")
+//!             .scope("investments:read"),
+//!     )
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    error::{Error, Result},
+    runtime::{Clock, Instant},
+};
+use serde::Deserialize;
+use std::{sync::Arc, time::Duration};
+use url::Url;
+
+/// Tokens are refreshed this long before they expire
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// OAuth2 client credentials
+#[derive(Clone)]
+pub struct ClientCredentials {
+    client_id: String,
+    client_secret: String,
+    scopes: Vec<String>,
+    token_url: Option<String>,
+}
+
+impl ClientCredentials {
+    /// Create credentials for the given client
+    pub fn new<I: Into<String>, S: Into<String>>(client_id: I, client_secret: S) -> Self {
+        Self {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            scopes: Vec::new(),
+            token_url: None,
+        }
+    }
+
+    /// Request an additional scope
+    pub fn scope<S: Into<String>>(mut self, scope: S) -> Self {
+        self.scopes.push(scope.into());
+        self
+    }
+
+    /// Use a custom token endpoint instead of `<base URL>/oauth/token`
+    pub fn token_url<S: Into<String>>(mut self, token_url: S) -> Self {
+        self.token_url = Some(token_url.into());
+        self
+    }
+}
+
+impl std::fmt::Debug for ClientCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientCredentials")
+            .field("client_id", &self.client_id)
+            .field("client_secret", &crate::logging::REDACTED)
+            .field("scopes", &self.scopes)
+            .field("token_url", &self.token_url)
+            .finish()
+    }
+}
+
+/// Access token returned by the token endpoint
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccessToken {
+    /// The bearer token
+    pub access_token: String,
+    /// Token type, normally `Bearer`
+    pub token_type: String,
+    /// Lifetime in seconds
+    #[serde(default)]
+    pub expires_in: Option<u64>,
+    /// Granted scopes, space-separated
+    #[serde(default)]
+    pub scope: Option<String>,
+}
+
+#[derive(Debug)]
+struct CachedToken {
+    token: String,
+    refresh_at: Option<Instant>,
+}
+
+/// Cached access token for a set of client credentials
+#[derive(Debug)]
+pub(crate) struct TokenProvider {
+    credentials: ClientCredentials,
+    cached: tokio::sync::Mutex<Option<CachedToken>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl TokenProvider {
+    pub(crate) fn new(credentials: ClientCredentials, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            credentials,
+            cached: tokio::sync::Mutex::new(None),
+            clock,
+        }
+    }
+
+    /// Get a valid access token, fetching a new one if needed
+    pub(crate) async fn token(
+        &self,
+        http_client: &reqwest::Client,
+        base_url: &str,
+    ) -> Result<String> {
+        let mut cached = self.cached.lock().await;
+
+        if let Some(token) = cached.as_ref() {
+            if token.refresh_at.map_or(true, |at| self.clock.now() < at) {
+                return Ok(token.token.clone());
+            }
+        }
+
+        let token = self.fetch(http_client, base_url).await?;
+        let refresh_at = token.expires_in.map(|secs| {
+            self.clock.now() + Duration::from_secs(secs).saturating_sub(REFRESH_MARGIN)
+        });
+        *cached = Some(CachedToken {
+            token: token.access_token.clone(),
+            refresh_at,
+        });
+
+        Ok(token.access_token)
+    }
+
+    /// Drop the cached token so the next request fetches a new one
+    pub(crate) async fn invalidate(&self) {
+        *self.cached.lock().await = None;
+    }
+
+    async fn fetch(&self, http_client: &reqwest::Client, base_url: &str) -> Result<AccessToken> {
+        let token_url = match &self.credentials.token_url {
+            Some(url) => url.clone(),
+            None => format!("{}/oauth/token", base_url.trim_end_matches('/')),
+        };
+        let token_url = Url::parse(&token_url)
+            .map_err(|e| Error::Configuration(format!("Invalid token URL: {}", e)))?;
+
+        let scope = self.credentials.scopes.join(" ");
+        let mut form = vec![
+            ("grant_type", "client_credentials"),
+            ("client_id", self.credentials.client_id.as_str()),
+            ("client_secret", self.credentials.client_secret.as_str()),
+        ];
+        if !scope.is_empty() {
+            form.push(("scope", scope.as_str()));
+        }
+
+        let response = http_client
+            .post(token_url)
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| Error::HttpClient(e.to_string()))?;
+
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .map_err(|e| Error::HttpClient(e.to_string()))?;
+        if !status.is_success() {
+            return Err(Error::Unauthorized(text));
+        }
+
+        serde_json::from_str(&text)
+            .map_err(|e| Error::Parse(format!("Invalid token response: {}", e)))
+    }
+}
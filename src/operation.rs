@@ -0,0 +1,334 @@
+//! Long-running operations
+//!
+//! Endpoints that start background work, such as token distributions and airdrop
+//! executions, answer `202 Accepted` with a `Location` header pointing at the job's
+//! status. [`Operation`] wraps that handle so callers don't have to poll by hand:
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use xrplsale::{types::projects::ScheduleDistributionRequest, Client};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let client = Client::builder().api_key("test").build()?;
+//! let operation = client
+//!     .projects()
+//!     .start_distribution("proj_abc123", ScheduleDistributionRequest::default())
+//!     .await?;
+//! println!("Started operation {}", operation.id());
+//!
+//! let distribution = operation
+//!     .wait(Duration::from_secs(2), Duration::from_secs(300))
+//!     .await?;
+//! println!("Distribution {} is {}", distribution.id, distribution.status);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! An endpoint that finishes the work before answering (any other `2xx`) yields an
+//! operation that is already complete. A job started in an earlier process can be picked
+//! up again with [`Operation::resume`].
+
+use crate::{
+    client::Client,
+    error::{Error, Result},
+    error_code::ErrorCode,
+    rate_limit,
+    response::ApiResponse,
+};
+use chrono::{DateTime, Utc};
+use futures::{stream, Stream};
+use reqwest::{header::LOCATION, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{fmt, marker::PhantomData, time::Duration};
+
+/// State of a long-running operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationState {
+    /// Accepted but not started yet
+    Queued,
+    /// In progress
+    Running,
+    /// Finished successfully; the result is available
+    Succeeded,
+    /// Finished with an error
+    Failed,
+    /// Cancelled before it finished
+    Cancelled,
+    /// A state this version of the SDK does not know
+    #[serde(other)]
+    Unknown,
+}
+
+impl OperationState {
+    /// Whether the operation has finished and won't change again
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed | Self::Cancelled)
+    }
+}
+
+/// Why an operation failed
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OperationError {
+    /// Machine-readable error code
+    pub code: ErrorCode,
+    /// Human-readable description
+    pub message: String,
+}
+
+/// A snapshot of an operation's progress
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct OperationStatus {
+    /// Operation ID
+    pub id: String,
+    /// Current state
+    pub status: OperationState,
+    /// Percentage complete, if the operation reports it
+    #[serde(default)]
+    pub progress: Option<u8>,
+    /// The finished resource, once the operation succeeded
+    #[serde(default)]
+    pub result: Option<Value>,
+    /// Why the operation failed, if it did
+    #[serde(default)]
+    pub error: Option<OperationError>,
+    /// When the state last changed
+    #[serde(default, with = "crate::types::dates::option")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl OperationStatus {
+    fn new(id: String, status: OperationState, result: Option<Value>) -> Self {
+        Self {
+            id,
+            status,
+            progress: None,
+            result,
+            error: None,
+            updated_at: None,
+        }
+    }
+}
+
+/// Handle to background work started by the API, finishing with a `T`
+pub struct Operation<T> {
+    client: Client,
+    id: String,
+    status_path: String,
+    /// Set when the starting response already carried the outcome
+    completed: Option<OperationStatus>,
+    _result: PhantomData<fn() -> T>,
+}
+
+impl<T> Operation<T> {
+    /// Pick up an operation by ID, e.g. one started before a restart
+    pub fn resume<S: Into<String>>(client: Client, id: S) -> Self {
+        let id = id.into();
+        Self {
+            client,
+            status_path: format!("/operations/{}", id),
+            id,
+            completed: None,
+            _result: PhantomData,
+        }
+    }
+
+    /// Wrap the response of a request that starts an operation
+    ///
+    /// A `202 Accepted` response is followed through its `Location` header, falling
+    /// back to `/operations/{id}` from the body. Any other status means the work is
+    /// already done and the body is the result.
+    pub(crate) fn from_response(client: Client, response: ApiResponse<Value>) -> Result<Self> {
+        let ApiResponse { data, meta } = response;
+        let body_id = data.get("id").and_then(Value::as_str).map(str::to_string);
+
+        if meta.status != StatusCode::ACCEPTED {
+            let id = body_id.unwrap_or_default();
+            return Ok(Self {
+                client,
+                status_path: format!("/operations/{}", id),
+                completed: Some(OperationStatus::new(
+                    id.clone(),
+                    OperationState::Succeeded,
+                    Some(data),
+                )),
+                id,
+                _result: PhantomData,
+            });
+        }
+
+        let location = meta
+            .headers
+            .get(LOCATION)
+            .and_then(|value| value.to_str().ok());
+        let (id, status_path) = match (location, body_id) {
+            (Some(location), id) => {
+                let path = client.relative_path(location)?;
+                let id = id.unwrap_or_else(|| last_segment(&path));
+                (id, path)
+            }
+            (None, Some(id)) => (id.clone(), format!("/operations/{}", id)),
+            (None, None) => {
+                return Err(Error::Parse(
+                    "202 Accepted response has neither a Location header nor an operation ID"
+                        .to_string(),
+                ))
+            }
+        };
+
+        Ok(Self {
+            client,
+            id,
+            status_path,
+            completed: None,
+            _result: PhantomData,
+        })
+    }
+
+    /// Operation ID
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Path the operation's status is polled from, relative to the API base URL
+    pub fn status_path(&self) -> &str {
+        &self.status_path
+    }
+
+    /// Fetch the current status
+    pub async fn status(&self) -> Result<OperationStatus> {
+        Ok(self.poll().await?.0)
+    }
+
+    /// Stream the operation's status every `poll_interval` until it finishes
+    ///
+    /// The last item is the terminal status, or the error that stopped polling. Pin the
+    /// stream (e.g. with `Box::pin`) before polling it.
+    pub fn stream_status(
+        &self,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<OperationStatus>> + '_ {
+        stream::unfold(Some(false), move |state| async move {
+            let polled = state?;
+            if polled {
+                self.client.clock().sleep(poll_interval).await;
+            }
+
+            match self.poll().await {
+                Ok((status, _)) => {
+                    let next = (!status.status.is_terminal()).then_some(true);
+                    Some((Ok(status), next))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        })
+    }
+
+    /// Poll every `poll_interval` until the operation finishes, then return its result
+    ///
+    /// A `Retry-After` header on a status response overrides the interval for that
+    /// poll. Fails without sleeping further once the next poll would come after
+    /// `timeout`, and with the operation's error if it failed or was cancelled.
+    pub async fn wait(self, poll_interval: Duration, timeout: Duration) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let clock = self.client.clock();
+        let deadline = clock.now() + timeout;
+
+        loop {
+            let (status, retry_after) = self.poll().await?;
+            if status.status.is_terminal() {
+                return self.finish(status);
+            }
+
+            let delay = retry_after.unwrap_or(poll_interval);
+            if clock.now() + delay > deadline {
+                return Err(Error::HttpClient(format!(
+                    "Timed out after {:?} waiting for operation {}",
+                    timeout, self.id
+                )));
+            }
+            clock.sleep(delay).await;
+        }
+    }
+
+    /// Fetch the status, along with any `Retry-After` the API asked for
+    async fn poll(&self) -> Result<(OperationStatus, Option<Duration>)> {
+        if let Some(status) = &self.completed {
+            return Ok((status.clone(), None));
+        }
+
+        let response = self
+            .client
+            .get_with_meta::<OperationStatus>(&self.status_path)
+            .await?;
+        Ok((
+            response.data,
+            rate_limit::retry_after(&response.meta.headers),
+        ))
+    }
+
+    fn finish(&self, status: OperationStatus) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        match status.status {
+            OperationState::Succeeded => {
+                serde_json::from_value(status.result.unwrap_or(Value::Null)).map_err(|e| {
+                    Error::Parse(format!("Invalid result of operation {}: {}", self.id, e))
+                })
+            }
+            state => {
+                let error = status.error.unwrap_or_else(|| OperationError {
+                    code: ErrorCode::Unknown(String::new()),
+                    message: format!("Operation ended as {:?}", state),
+                });
+                Err(Error::Api {
+                    status: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    message: serde_json::json!({
+                        "code": error.code,
+                        "message": error.message,
+                    })
+                    .to_string(),
+                    url: self.status_path.clone(),
+                })
+            }
+        }
+    }
+}
+
+impl<T> Clone for Operation<T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            id: self.id.clone(),
+            status_path: self.status_path.clone(),
+            completed: self.completed.clone(),
+            _result: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Operation<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Operation")
+            .field("id", &self.id)
+            .field("status_path", &self.status_path)
+            .field("completed", &self.completed.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The last path segment, which status URLs end with the operation ID in
+fn last_segment(path: &str) -> String {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    path.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
@@ -0,0 +1,506 @@
+//! Durable queue for mutating calls made while offline
+//!
+//! Devices with unreliable connectivity send mutations through an [`Outbox`]. A call
+//! that fails because the API cannot be reached is saved to a file instead of being
+//! lost, and [`Outbox::flush`] replays the queue in order once the connection is back:
+//!
+//! ```rust,no_run
+//! use reqwest::Method;
+//! use serde_json::json;
+//! use xrplsale::{outbox::{Delivery, Outbox}, Client};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::builder().api_key("your-api-key").build()?;
+//! let outbox = Outbox::open(client, "/var/lib/kiosk/outbox.json")?;
+//!
+//! let body = json!({ "name": "Renamed project" });
+//! match outbox.send::<serde_json::Value, _>(Method::PUT, "/projects/proj_abc123", Some(&body)).await? {
+//!     Delivery::Sent(project) => println!("Saved {}", project["id"]),
+//!     Delivery::Queued(entry) => println!("Offline, queued as {}", entry.id),
+//! }
+//!
+//! // Later, e.g. on a timer or when the network comes back
+//! let report = outbox.flush().await?;
+//! for conflict in &report.conflicts {
+//!     eprintln!("{} {} was rejected: {}", conflict.entry.method, conflict.entry.path, conflict.error);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Every entry gets an idempotency key when it is queued, and each attempt to deliver
+//! it sends the same key, so the API applies it once even if a response was lost.
+//!
+//! Entries are delivered in the order they were queued. While entries are waiting,
+//! [`Outbox::send`] queues new calls behind them instead of overtaking them. Sending,
+//! queueing, discarding, and flushing take turns, so a call made during a flush can't
+//! be delivered before the entries ahead of it.
+//!
+//! A flush stops at the first entry that still cannot be delivered because of a network
+//! failure, rate limit, or server error, and tries it again next time. Entries the API
+//! rejects (a validation failure, a missing resource, or a conflict with the current
+//! state) are removed from the queue and returned in [`FlushReport::conflicts`].
+//! Authentication and configuration errors abort the flush and leave the queue as it
+//! is.
+//!
+//! The queue is a JSON file, rewritten after every change: the new contents are written
+//! to a temporary file, flushed to disk, and renamed over the old file, and the rename
+//! is flushed too, so a crash leaves either the old or the new queue. Writes run on
+//! tokio's blocking thread pool. Outboxes are not available on `wasm32`.
+//!
+//! By default the file holds request bodies as they were sent, which can include
+//! investor details. With the `encryption` feature, [`Outbox::open_encrypted`] seals
+//! each entry with keys from a [`KeyProvider`](crate::encryption::KeyProvider) and binds
+//! it to its entry ID; only entry IDs stay readable. Entries written by [`Outbox::open`]
+//! are read as they are and encrypted the next time the queue changes.
+
+#[cfg(feature = "encryption")]
+use crate::encryption::{Encryptor, KeyProvider};
+use crate::{
+    client::Client,
+    error::{Error, Result},
+    error_code::ErrorCode,
+};
+use chrono::{DateTime, Utc};
+use reqwest::Method;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard},
+};
+
+/// A queued call
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    /// Unique ID of the entry
+    pub id: String,
+    /// Key sent as `Idempotency-Key` on every delivery attempt
+    pub idempotency_key: String,
+    /// HTTP method, e.g. `POST`
+    pub method: String,
+    /// Path relative to the base URL, including any query string
+    pub path: String,
+    /// JSON request body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+    /// When the call was queued
+    pub queued_at: DateTime<Utc>,
+    /// Delivery attempts that failed and were kept for another try
+    #[serde(default)]
+    pub attempts: u32,
+    /// Error from the last failed attempt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+/// Outcome of [`Outbox::send`]
+#[derive(Debug, Clone)]
+pub enum Delivery<T> {
+    /// The API accepted the call and returned this response
+    Sent(T),
+    /// The call could not be delivered now and was queued
+    Queued(OutboxEntry),
+}
+
+/// Outcome of [`Outbox::flush`]
+#[derive(Debug, Default)]
+pub struct FlushReport {
+    /// Entries the API accepted, in delivery order
+    pub delivered: Vec<Delivered>,
+    /// Entries the API rejected; they have been removed from the queue
+    pub conflicts: Vec<Conflict>,
+    /// Entries still queued
+    pub remaining: usize,
+}
+
+/// A queued entry that was delivered
+#[derive(Debug, Clone)]
+pub struct Delivered {
+    /// The entry
+    pub entry: OutboxEntry,
+    /// The API's response
+    pub response: Value,
+}
+
+/// A queued entry that the API rejected
+#[derive(Debug)]
+pub struct Conflict {
+    /// The entry
+    pub entry: OutboxEntry,
+    /// Why it was rejected; check [`Error::code`] for e.g. [`ErrorCode::Conflict`]
+    pub error: Error,
+}
+
+/// An entry as written to the file
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum StoredEntry {
+    /// An encrypted entry: hex-encoded ciphertext bound to the entry ID
+    Sealed {
+        id: String,
+        sealed: String,
+    },
+    Plain(OutboxEntry),
+}
+
+/// File-backed queue of mutating calls
+#[derive(Debug)]
+pub struct Outbox {
+    client: Client,
+    path: PathBuf,
+    entries: Mutex<Vec<OutboxEntry>>,
+    /// Seals entries before they are written, if the outbox is encrypted
+    #[cfg(feature = "encryption")]
+    encryptor: Option<Encryptor>,
+    /// Held while calls are delivered or the queue changes, so calls go out and the
+    /// file is written in order
+    turn: tokio::sync::Mutex<()>,
+}
+
+impl Outbox {
+    /// Open the queue stored at `path`, or start an empty one if the file does not exist
+    ///
+    /// Fails if the file was written by an encrypted outbox.
+    pub fn open<P: AsRef<Path>>(client: Client, path: P) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let entries = read(&path)?
+            .into_iter()
+            .map(|stored| match stored {
+                StoredEntry::Plain(entry) => Ok(entry),
+                StoredEntry::Sealed { .. } => Err(Error::Configuration(format!(
+                    "Outbox {} is encrypted; open it with Outbox::open_encrypted",
+                    path.display()
+                ))),
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self::with_entries(client, path, entries))
+    }
+
+    /// Open the queue stored at `path`, encrypting entries at rest
+    ///
+    /// Entries are sealed with the current key of `keys`; entries sealed with a
+    /// previous key it still provides are re-sealed the next time the queue changes.
+    #[cfg(feature = "encryption")]
+    pub fn open_encrypted<P, K>(client: Client, path: P, keys: K) -> Result<Self>
+    where
+        P: AsRef<Path>,
+        K: KeyProvider + 'static,
+    {
+        let path = path.as_ref().to_path_buf();
+        let encryptor = Encryptor::new(keys);
+        let entries = read(&path)?
+            .into_iter()
+            .map(|stored| match stored {
+                StoredEntry::Plain(entry) => Ok(entry),
+                StoredEntry::Sealed { id, sealed } => unseal(&encryptor, &id, &sealed),
+            })
+            .collect::<Result<_>>()?;
+
+        let mut outbox = Self::with_entries(client, path, entries);
+        outbox.encryptor = Some(encryptor);
+        Ok(outbox)
+    }
+
+    fn with_entries(client: Client, path: PathBuf, entries: Vec<OutboxEntry>) -> Self {
+        Self {
+            client,
+            path,
+            entries: Mutex::new(entries),
+            #[cfg(feature = "encryption")]
+            encryptor: None,
+            turn: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Send a call now, or queue it if the API cannot be reached
+    ///
+    /// Calls are queued without being attempted while earlier entries are waiting, so
+    /// they are delivered in order; a running flush finishes first. Errors other than
+    /// connectivity problems are returned as usual.
+    pub async fn send<T, B>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+    ) -> Result<Delivery<T>>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let body = body
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| Error::Parse(e.to_string()))?;
+        let entry = self.new_entry(&method, path, body);
+
+        let _turn = self.turn.lock().await;
+        if !self.is_empty() {
+            return self.push(entry).await.map(Delivery::Queued);
+        }
+
+        match self.deliver::<T>(&entry).await {
+            Ok(response) => Ok(Delivery::Sent(response)),
+            Err(e) if e.is_retryable() => {
+                let mut entry = entry;
+                entry.attempts = 1;
+                entry.last_error = Some(e.to_string());
+                self.push(entry).await.map(Delivery::Queued)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Queue a call without attempting it
+    ///
+    /// Waits for a running send or flush, so the call lands behind the calls it made.
+    pub async fn enqueue<B: Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+    ) -> Result<OutboxEntry> {
+        let body = body
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| Error::Parse(e.to_string()))?;
+        let entry = self.new_entry(&method, path, body);
+
+        let _turn = self.turn.lock().await;
+        self.push(entry).await
+    }
+
+    /// Deliver queued entries in order
+    ///
+    /// Stops at the first entry that still cannot be delivered. Only one flush runs at
+    /// a time; a concurrent call waits for the running one and then continues with
+    /// whatever is left. Calls sent or queued meanwhile wait for the flush to finish.
+    pub async fn flush(&self) -> Result<FlushReport> {
+        let _turn = self.turn.lock().await;
+        let mut report = FlushReport::default();
+
+        loop {
+            let next = self.lock().first().cloned();
+            let Some(entry) = next else {
+                break;
+            };
+            match self.deliver::<Value>(&entry).await {
+                Ok(response) => {
+                    self.remove(&entry.id).await?;
+                    report.delivered.push(Delivered { entry, response });
+                }
+                Err(e) if e.is_retryable() => {
+                    self.update(&entry.id, |queued| {
+                        queued.attempts += 1;
+                        queued.last_error = Some(e.to_string());
+                    })
+                    .await?;
+                    break;
+                }
+                Err(e) if is_fatal(&e) => return Err(e),
+                Err(error) => {
+                    self.remove(&entry.id).await?;
+                    report.conflicts.push(Conflict { entry, error });
+                }
+            }
+        }
+
+        report.remaining = self.len();
+        Ok(report)
+    }
+
+    /// Entries waiting to be delivered, oldest first
+    pub fn pending(&self) -> Vec<OutboxEntry> {
+        self.lock().clone()
+    }
+
+    /// Number of entries waiting to be delivered
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether nothing is waiting to be delivered
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Drop a queued entry without delivering it
+    ///
+    /// Returns the entry, or `None` if no entry has this ID. Waits for a running send
+    /// or flush.
+    pub async fn discard(&self, id: &str) -> Result<Option<OutboxEntry>> {
+        let _turn = self.turn.lock().await;
+        self.remove(id).await
+    }
+
+    async fn deliver<T: DeserializeOwned>(&self, entry: &OutboxEntry) -> Result<T> {
+        let method = Method::from_bytes(entry.method.as_bytes())
+            .map_err(|e| Error::Configuration(format!("Invalid method {}: {}", entry.method, e)))?;
+        self.client
+            .with_idempotency_key(entry.idempotency_key.as_str())
+            .request(method, &entry.path, entry.body.as_ref())
+            .await
+    }
+
+    // The methods below change the queue; callers hold `turn`
+
+    async fn push(&self, entry: OutboxEntry) -> Result<OutboxEntry> {
+        let snapshot = {
+            let mut entries = self.lock();
+            entries.push(entry.clone());
+            entries.clone()
+        };
+        self.save(snapshot).await?;
+        Ok(entry)
+    }
+
+    async fn remove(&self, id: &str) -> Result<Option<OutboxEntry>> {
+        let (entry, snapshot) = {
+            let mut entries = self.lock();
+            let Some(index) = entries.iter().position(|entry| entry.id == id) else {
+                return Ok(None);
+            };
+            (entries.remove(index), entries.clone())
+        };
+        self.save(snapshot).await?;
+        Ok(Some(entry))
+    }
+
+    async fn update<F: FnOnce(&mut OutboxEntry)>(&self, id: &str, f: F) -> Result<()> {
+        let snapshot = {
+            let mut entries = self.lock();
+            let Some(entry) = entries.iter_mut().find(|entry| entry.id == id) else {
+                return Ok(());
+            };
+            f(entry);
+            entries.clone()
+        };
+        self.save(snapshot).await
+    }
+
+    /// Write the queue to disk without blocking the runtime
+    async fn save(&self, entries: Vec<OutboxEntry>) -> Result<()> {
+        let mut contents = self.encode(entries)?;
+        contents.push(b'\n');
+
+        let path = self.path.clone();
+        tokio::task::spawn_blocking(move || write_durably(&path, &contents))
+            .await
+            .map_err(|e| Error::Configuration(format!("Outbox write did not finish: {}", e)))?
+            .map_err(|e| {
+                Error::Configuration(format!(
+                    "Failed to write outbox {}: {}",
+                    self.path.display(),
+                    e
+                ))
+            })
+    }
+
+    fn encode(&self, entries: Vec<OutboxEntry>) -> Result<Vec<u8>> {
+        #[cfg(feature = "encryption")]
+        let stored = match &self.encryptor {
+            Some(encryptor) => entries
+                .iter()
+                .map(|entry| seal(encryptor, entry))
+                .collect::<Result<Vec<_>>>()?,
+            None => entries.into_iter().map(StoredEntry::Plain).collect(),
+        };
+        #[cfg(not(feature = "encryption"))]
+        let stored: Vec<_> = entries.into_iter().map(StoredEntry::Plain).collect();
+
+        serde_json::to_vec_pretty(&stored).map_err(|e| Error::Parse(e.to_string()))
+    }
+
+    fn new_entry(&self, method: &Method, path: &str, body: Option<Value>) -> OutboxEntry {
+        OutboxEntry {
+            id: uuid::Uuid::new_v4().to_string(),
+            idempotency_key: uuid::Uuid::new_v4().to_string(),
+            method: method.to_string(),
+            path: path.to_string(),
+            body,
+            queued_at: self.client.clock().utc_now(),
+            attempts: 0,
+            last_error: None,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<OutboxEntry>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Read the entries stored at `path`, or none if the file does not exist
+fn read(path: &Path) -> Result<Vec<StoredEntry>> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents)
+            .map_err(|e| Error::Parse(format!("Invalid outbox {}: {}", path.display(), e))),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(Error::Configuration(format!(
+            "Failed to read outbox {}: {}",
+            path.display(),
+            e
+        ))),
+    }
+}
+
+#[cfg(feature = "encryption")]
+fn seal(encryptor: &Encryptor, entry: &OutboxEntry) -> Result<StoredEntry> {
+    let plaintext = serde_json::to_vec(entry).map_err(|e| Error::Parse(e.to_string()))?;
+    let ciphertext = encryptor.encrypt(&plaintext, entry.id.as_bytes())?;
+    Ok(StoredEntry::Sealed {
+        id: entry.id.clone(),
+        sealed: hex::encode(ciphertext),
+    })
+}
+
+#[cfg(feature = "encryption")]
+fn unseal(encryptor: &Encryptor, id: &str, sealed: &str) -> Result<OutboxEntry> {
+    let invalid = |e: String| Error::Parse(format!("Invalid outbox entry {}: {}", id, e));
+    let ciphertext = hex::decode(sealed).map_err(|e| invalid(e.to_string()))?;
+    let plaintext = encryptor.decrypt(&ciphertext, id.as_bytes())?;
+    serde_json::from_slice(&plaintext).map_err(|e| invalid(e.to_string()))
+}
+
+/// Replace the file at `path` with `contents` so that a crash leaves either the old or
+/// the new contents
+///
+/// The contents are written to a temporary file and flushed to disk before it is renamed
+/// over `path`; the directory is then flushed so the rename itself survives a crash.
+fn write_durably(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty());
+    if let Some(parent) = parent {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut temporary = path.to_path_buf().into_os_string();
+    temporary.push(".tmp");
+    let mut file = std::fs::File::create(&temporary)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+    std::fs::rename(&temporary, path)?;
+
+    // Directories can't be opened for syncing on Windows, where renames are durable once
+    // they return
+    #[cfg(unix)]
+    std::fs::File::open(parent.unwrap_or(Path::new(".")))?.sync_all()?;
+    Ok(())
+}
+
+/// Whether no queued call can succeed until the client is fixed
+fn is_fatal(error: &Error) -> bool {
+    matches!(
+        error.code(),
+        ErrorCode::Unauthorized
+            | ErrorCode::InvalidApiKey
+            | ErrorCode::TokenExpired
+            | ErrorCode::Configuration
+    )
+}
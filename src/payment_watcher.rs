@@ -0,0 +1,384 @@
+//! Local confirmation of investment payments
+//!
+//! [`PaymentWatcher`] follows a project's deposit address on the XRP Ledger and
+//! reports each payment as soon as it is validated, matched to the investment
+//! whose destination tag it carries. That is a ledger close after the investor
+//! signs, well before the platform's `investment.confirmed` webhook arrives:
+//!
+//! ```rust,no_run
+//! use futures::StreamExt;
+//! use xrplsale::{
+//!     payment_watcher::{PaymentEvent, PaymentWatcher},
+//!     types::investments::CreateInvestmentRequest,
+//!     Client,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let client = Client::builder().api_key("test").build()?;
+//! let watcher = PaymentWatcher::new(client.clone(), "rDepositAddress...");
+//!
+//! // Prepare the investment, then tell the investor to pay with tag 40213
+//! let investment = client.investments().create(CreateInvestmentRequest {
+//!     project_id: "proj_abc123".to_string(),
+//!     amount_xrp: "100".to_string(),
+//!     investor_account: "rInvestorAddress...".to_string(),
+//!     ..Default::default()
+//! }).await?;
+//! watcher.expect(40213, investment);
+//!
+//! let mut events = Box::pin(watcher.watch());
+//! while let Some(event) = events.next().await {
+//!     if let PaymentEvent::Confirmed(confirmation) = event? {
+//!         println!(
+//!             "Investment {} paid in {}",
+//!             confirmation.investment.id, confirmation.payment.hash
+//!         );
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The watcher reads validated ledgers over JSON-RPC, from the public node for the
+//! client's environment unless [`rpc_url`](PaymentWatcher::rpc_url) is set. The
+//! platform stays the source of truth: a local confirmation is a hint for the UI,
+//! and the investment's status still changes when the platform has seen the payment.
+//!
+//! [`build_investment_payment`] builds the `Payment` itself, for investors who send
+//! it from their own tooling, such as a multi-signed treasury.
+
+use crate::{
+    client::Client,
+    error::{Error, Result},
+    multisig::{SigningMode, UnsignedTransaction},
+    types::{investments::Investment, reconcile::LedgerPayment},
+    xrpl_rpc::{self, RpcError, XrplRpc},
+};
+use futures::{stream, Stream};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+/// Roughly one ledger close
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(4);
+
+/// Page size of `account_tx` requests
+const PAGE_SIZE: u32 = 200;
+
+/// Option that picks the endpoint, named in configuration errors
+const RPC_URL_SETTING: &str = "PaymentWatcher::rpc_url";
+
+/// Something the watcher saw on the deposit address
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum PaymentEvent {
+    /// A payment carried the destination tag of an expected investment
+    Confirmed(PaymentConfirmation),
+    /// A payment whose destination tag matches no expected investment
+    Unmatched(LedgerPayment),
+}
+
+/// A validated payment for an expected investment
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct PaymentConfirmation {
+    /// The investment the destination tag was registered for
+    pub investment: Investment,
+    /// The payment on the ledger
+    pub payment: LedgerPayment,
+    /// The invested amount in drops, if the investment's amount could be read
+    pub expected_drops: Option<u64>,
+}
+
+impl PaymentConfirmation {
+    /// Whether the payment delivered exactly the invested amount
+    pub fn amount_matches(&self) -> bool {
+        self.expected_drops == Some(self.payment.delivered_drops)
+    }
+}
+
+/// An unsigned XRP `Payment` to a deposit address
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InvestmentPayment {
+    /// Always `Payment`
+    pub transaction_type: String,
+    /// Account paying; left for the signer to fill in if unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    /// The deposit address
+    pub destination: String,
+    /// Tag identifying the investment
+    pub destination_tag: u32,
+    /// Amount in drops
+    pub amount: String,
+}
+
+impl InvestmentPayment {
+    /// Set the paying account
+    pub fn account<S: Into<String>>(mut self, account: S) -> Self {
+        self.account = Some(account.into());
+        self
+    }
+
+    /// The transaction with instructions for signing it, e.g. by a multi-signed account
+    ///
+    /// Fails with [`Error::Configuration`] if the account is not set.
+    pub fn unsigned(&self, mode: SigningMode) -> Result<UnsignedTransaction> {
+        let tx_json = serde_json::to_value(self).map_err(|e| Error::Parse(e.to_string()))?;
+        UnsignedTransaction::new(tx_json, mode)
+    }
+}
+
+/// Build the `Payment` of `amount_xrp` that funds the investment behind
+/// `destination_tag`
+///
+/// Fails with [`Error::Configuration`] if `amount_xrp` is not a positive decimal amount
+/// with at most six decimal places.
+pub fn build_investment_payment(
+    deposit_address: &str,
+    amount_xrp: &str,
+    destination_tag: u32,
+) -> Result<InvestmentPayment> {
+    let drops = xrpl_rpc::xrp_to_drops(amount_xrp)
+        .filter(|&drops| drops > 0)
+        .ok_or_else(|| {
+            Error::Configuration(format!(
+                "Investment amount '{}' must be a positive XRP amount",
+                amount_xrp
+            ))
+        })?;
+
+    Ok(InvestmentPayment {
+        transaction_type: "Payment".to_string(),
+        account: None,
+        destination: deposit_address.to_string(),
+        destination_tag,
+        amount: drops.to_string(),
+    })
+}
+
+/// Watches a deposit address for payments to prepared investments
+///
+/// Cheap to clone; clones share the expected investments, so investments can be
+/// added from other tasks while [`watch`](Self::watch) runs.
+#[derive(Debug, Clone)]
+pub struct PaymentWatcher {
+    client: Client,
+    address: String,
+    rpc_url: Option<String>,
+    poll_interval: Duration,
+    from_ledger: Option<u32>,
+    expected: Arc<Mutex<HashMap<u32, Investment>>>,
+}
+
+impl PaymentWatcher {
+    /// Create a watcher for payments to `deposit_address`
+    pub fn new<S: Into<String>>(client: Client, deposit_address: S) -> Self {
+        Self {
+            client,
+            address: deposit_address.into(),
+            rpc_url: None,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            from_ledger: None,
+            expected: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Use this XRPL JSON-RPC endpoint, e.g. `http://localhost:5005`
+    pub fn rpc_url<S: Into<String>>(mut self, rpc_url: S) -> Self {
+        self.rpc_url = Some(rpc_url.into());
+        self
+    }
+
+    /// How often to check for newly validated ledgers (default: 4 seconds)
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Start at this ledger instead of the latest validated one, e.g. to catch up
+    /// after a restart
+    pub fn from_ledger(mut self, ledger_index: u32) -> Self {
+        self.from_ledger = Some(ledger_index);
+        self
+    }
+
+    /// Expect a payment for `investment` carrying `destination_tag`
+    ///
+    /// Replaces any investment already expected with the same tag. The investment is
+    /// forgotten once its payment is confirmed.
+    pub fn expect(&self, destination_tag: u32, investment: Investment) {
+        self.lock().insert(destination_tag, investment);
+    }
+
+    /// Stop expecting a payment with `destination_tag`, returning its investment
+    pub fn forget(&self, destination_tag: u32) -> Option<Investment> {
+        self.lock().remove(&destination_tag)
+    }
+
+    /// Number of investments still waiting for their payment
+    pub fn pending(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Stream payments to the deposit address as their ledgers are validated
+    ///
+    /// Only successful XRP payments are reported, with the amount actually
+    /// delivered. Errors reaching the node are yielded without ending the stream,
+    /// which retries on the next poll; configuration errors and an invalid address
+    /// end it. Pin the stream (e.g. with `Box::pin`) before polling it.
+    pub fn watch(&self) -> impl Stream<Item = Result<PaymentEvent>> {
+        let watch = XrplRpc::new(&self.client, self.rpc_url.as_deref())
+            .map(|rpc| Watch {
+                watcher: self.clone(),
+                rpc,
+                next_ledger: self.from_ledger,
+                polled: false,
+                events: VecDeque::new(),
+            })
+            .map_err(|e| e.into_error(&self.address, RPC_URL_SETTING));
+
+        stream::unfold(Some(watch), |watch| async move {
+            let mut watch = match watch? {
+                Ok(watch) => watch,
+                Err(e) => return Some((Err(e), None)),
+            };
+            match watch.next_event().await {
+                Ok(event) => Some((Ok(event), Some(Ok(watch)))),
+                Err(e @ Error::HttpClient(_)) => Some((Err(e), Some(Ok(watch)))),
+                Err(e) => Some((Err(e), None)),
+            }
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<u32, Investment>> {
+        self.expected
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// State of a running watch
+struct Watch {
+    watcher: PaymentWatcher,
+    rpc: XrplRpc,
+    /// First ledger not yet searched
+    next_ledger: Option<u32>,
+    polled: bool,
+    events: VecDeque<PaymentEvent>,
+}
+
+impl Watch {
+    /// Poll the node until a payment arrives
+    async fn next_event(&mut self) -> Result<PaymentEvent> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Ok(event);
+            }
+            if self.polled {
+                let interval = self.watcher.poll_interval;
+                self.watcher.client.clock().sleep(interval).await;
+            }
+            self.polled = true;
+            self.poll().await?;
+        }
+    }
+
+    /// Queue the payments validated since the last poll
+    async fn poll(&mut self) -> Result<()> {
+        let address = self.watcher.address.clone();
+        let Some(start) = self.next_ledger else {
+            let result = self
+                .rpc
+                .call("ledger", json!({ "ledger_index": "validated" }))
+                .await
+                .map_err(|e| e.into_error(&address, RPC_URL_SETTING))?;
+            self.next_ledger = ledger_index(&result["ledger_index"])
+                .or_else(|| ledger_index(&result["ledger"]["ledger_index"]))
+                .map(|validated| validated + 1);
+            return Ok(());
+        };
+
+        // Searched in full before anything is queued, so a failed page is retried
+        // without reporting a payment twice
+        let mut payments = Vec::new();
+        let mut searched_to = None;
+        let mut marker: Option<Value> = None;
+        loop {
+            let mut params = json!({
+                "account": address,
+                "ledger_index_min": start,
+                "ledger_index_max": -1,
+                "limit": PAGE_SIZE,
+                "forward": true,
+            });
+            if let Some(marker) = marker.take() {
+                params["marker"] = marker;
+            }
+            let result = match self.rpc.call("account_tx", params).await {
+                Ok(result) => result,
+                // Not funded yet, so nothing has been paid to it
+                Err(RpcError::Ledger(code)) if code == "actNotFound" => return Ok(()),
+                Err(e) => return Err(e.into_error(&address, RPC_URL_SETTING)),
+            };
+
+            payments.extend(
+                result["transactions"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|entry| xrpl_rpc::payment(entry, &address)),
+            );
+            searched_to = ledger_index(&result["ledger_index_max"]).or(searched_to);
+            marker = result
+                .get("marker")
+                .filter(|marker| !marker.is_null())
+                .cloned();
+            if marker.is_none() {
+                break;
+            }
+        }
+
+        let last_payment = payments.iter().map(|payment| payment.ledger_index).max();
+        if let Some(searched_to) = searched_to.or(last_payment) {
+            self.next_ledger = Some((searched_to + 1).max(start));
+        }
+
+        let mut expected = self.watcher.lock();
+        for payment in payments {
+            let investment = payment
+                .destination_tag
+                .and_then(|tag| expected.remove(&tag));
+            self.events.push_back(match investment {
+                Some(investment) => PaymentEvent::Confirmed(PaymentConfirmation {
+                    expected_drops: amount_drops(&investment),
+                    investment,
+                    payment,
+                }),
+                None => PaymentEvent::Unmatched(payment),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// A ledger index, which nodes send as a number or a string
+fn ledger_index(value: &Value) -> Option<u32> {
+    match value {
+        Value::Number(index) => index.as_u64()?.try_into().ok(),
+        Value::String(index) => index.parse().ok(),
+        _ => None,
+    }
+}
+
+/// The invested amount in drops, read without assuming the model's field types
+fn amount_drops(investment: &Investment) -> Option<u64> {
+    let json = serde_json::to_value(investment).ok()?;
+    xrpl_rpc::xrp_to_drops(json.get("amount_xrp")?.as_str()?)
+}
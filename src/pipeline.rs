@@ -0,0 +1,159 @@
+//! Piping SDK streams into sinks with batching
+//!
+//! [`PipelineExt`] adds combinators to any [`Stream`] (such as
+//! [`ProjectsService::stream_all`](crate::services::ProjectsService::stream_all) or a
+//! stream of received webhook events) for feeding a [`futures::Sink`]: a channel,
+//! a file writer, or a message queue producer.
+//!
+//! Items are grouped into batches of up to `max_size`. A partial batch is flushed
+//! once `flush_interval` has passed since its first item, so slow streams still
+//! deliver promptly.
+//!
+//! Pipelines run on tokio's timer and are not available on `wasm32`.
+//!
+//! ```rust
+//! use futures::{channel::mpsc, stream};
+//! use std::time::Duration;
+//! use xrplsale::pipeline::{BatchConfig, PipelineExt};
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let (tx, _rx) = mpsc::unbounded::<Vec<u32>>();
+//! let config = BatchConfig::new(100, Duration::from_secs(1));
+//!
+//! let forwarded = stream::iter(0..250).forward_batches(tx, config).await.unwrap();
+//! assert_eq!(forwarded, 250);
+//! # }
+//! ```
+
+use futures::{Sink, SinkExt, Stream, StreamExt};
+use std::time::Duration;
+
+/// Batch size and flush interval for pipeline combinators
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchConfig {
+    /// Maximum number of items per batch
+    pub max_size: usize,
+    /// Longest time a partial batch is held before it is flushed
+    pub flush_interval: Duration,
+}
+
+impl BatchConfig {
+    /// Create a batch configuration
+    pub fn new(max_size: usize, flush_interval: Duration) -> Self {
+        Self {
+            max_size: max_size.max(1),
+            flush_interval,
+        }
+    }
+}
+
+impl Default for BatchConfig {
+    /// Batches of up to 100 items, flushed at least every second
+    fn default() -> Self {
+        Self::new(100, Duration::from_secs(1))
+    }
+}
+
+/// Batching and sink-forwarding combinators for streams
+pub trait PipelineExt: Stream + Sized {
+    /// Group items into batches according to `config`
+    fn batched(self, config: BatchConfig) -> futures::stream::BoxStream<'static, Vec<Self::Item>>
+    where
+        Self: Send + 'static,
+        Self::Item: Send,
+    {
+        let state = (Box::pin(self), false);
+        futures::stream::unfold(state, move |(mut stream, finished)| async move {
+            if finished {
+                return None;
+            }
+
+            let mut batch = Vec::with_capacity(config.max_size);
+            let mut deadline = tokio::time::Instant::now();
+            loop {
+                let next = if batch.is_empty() {
+                    stream.next().await
+                } else {
+                    match tokio::time::timeout_at(deadline, stream.next()).await {
+                        Ok(next) => next,
+                        Err(_) => return Some((batch, (stream, false))),
+                    }
+                };
+
+                match next {
+                    Some(item) => {
+                        if batch.is_empty() {
+                            deadline = tokio::time::Instant::now() + config.flush_interval;
+                        }
+                        batch.push(item);
+                        if batch.len() >= config.max_size {
+                            return Some((batch, (stream, false)));
+                        }
+                    }
+                    None if batch.is_empty() => return None,
+                    None => return Some((batch, (stream, true))),
+                }
+            }
+        })
+        .boxed()
+    }
+
+    /// Send each batch to a sink that accepts `Vec`s of items
+    ///
+    /// Returns the number of items forwarded.
+    fn forward_batches<Si>(
+        self,
+        sink: Si,
+        config: BatchConfig,
+    ) -> impl std::future::Future<Output = Result<usize, Si::Error>> + Send
+    where
+        Self: Send + 'static,
+        Self::Item: Send,
+        Si: Sink<Vec<Self::Item>> + Unpin + Send,
+    {
+        async move {
+            let mut sink = sink;
+            let mut forwarded = 0;
+            let mut batches = self.batched(config);
+            while let Some(batch) = batches.next().await {
+                forwarded += batch.len();
+                sink.send(batch).await?;
+            }
+            sink.close().await?;
+            Ok(forwarded)
+        }
+    }
+
+    /// Send items one by one to a sink, flushing it after every batch
+    ///
+    /// Useful for buffered sinks such as file writers or queue producers, where
+    /// flushing per item would be too costly. Returns the number of items forwarded.
+    fn forward_flushing<Si>(
+        self,
+        sink: Si,
+        config: BatchConfig,
+    ) -> impl std::future::Future<Output = Result<usize, Si::Error>> + Send
+    where
+        Self: Send + 'static,
+        Self::Item: Send,
+        Si: Sink<Self::Item> + Unpin + Send,
+    {
+        async move {
+            let mut sink = sink;
+            let mut forwarded = 0;
+            let mut batches = self.batched(config);
+            while let Some(batch) = batches.next().await {
+                forwarded += batch.len();
+                for item in batch {
+                    sink.feed(item).await?;
+                }
+                sink.flush().await?;
+            }
+            sink.close().await?;
+            Ok(forwarded)
+        }
+    }
+}
+
+impl<S: Stream> PipelineExt for S {}
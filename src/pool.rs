@@ -0,0 +1,192 @@
+//! Multi-tenant clients sharing one connection pool
+//!
+//! Services that call the API on behalf of many projects, each with its own API key,
+//! can use a [`ClientPool`] instead of building one client per tenant. Every tenant's
+//! [`Client`] shares the pool's HTTP client and connections, while credentials, auth
+//! tokens, circuit breakers, and observed rate limits stay separate.
+//!
+//! ```rust
+//! use xrplsale::{pool::ClientPool, Client, Profile};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let pool = ClientPool::new(Client::builder().profile(Profile::HighThroughputSync))?
+//!     .tenant_rate_limit(10);
+//!
+//! let acme = pool.client("acme", "acme-api-key");
+//! let project = acme.projects().get("proj_123").await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! With the `metrics` feature, each tenant's requests carry a `tenant` label. Response
+//! caching cannot be combined with a pool, since cached responses are keyed by URL and
+//! would be shared across tenants.
+
+use crate::{
+    client::{Client, ClientBuilder},
+    error::Result,
+    rate_limit::TokenBucket,
+};
+use std::{collections::HashMap, sync::Mutex};
+
+/// A set of per-tenant clients sharing one HTTP connection pool
+#[derive(Debug)]
+pub struct ClientPool {
+    template: ClientBuilder,
+    http_client: reqwest::Client,
+    rate_limit: Option<u32>,
+    tenants: Mutex<HashMap<String, (String, Client)>>,
+}
+
+impl ClientPool {
+    /// Create a pool whose clients use the settings of `template`
+    ///
+    /// The template's credentials are not passed on: its API key, OAuth client
+    /// credentials, and signing secret are dropped, so each tenant authenticates only
+    /// with its own API key. Fails with
+    /// [`Error::Configuration`](crate::Error::Configuration) if the template has a
+    /// response cache.
+    pub fn new(template: ClientBuilder) -> Result<Self> {
+        #[cfg(feature = "cache")]
+        if template.has_cache() {
+            return Err(crate::error::Error::Configuration(
+                "Response caching cannot be used with a client pool".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            http_client: template.http_transport()?,
+            template,
+            rate_limit: None,
+            tenants: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Limit each tenant to `requests_per_second`, with bursts of the same size
+    ///
+    /// Requests over the limit wait for capacity instead of failing. Applies to clients
+    /// created after this call.
+    pub fn tenant_rate_limit(mut self, requests_per_second: u32) -> Self {
+        self.rate_limit = Some(requests_per_second.max(1));
+        self
+    }
+
+    /// Get the client for a tenant, creating it on first use
+    ///
+    /// Later calls for the same tenant return the existing client; if `api_key` has
+    /// changed, the client is replaced.
+    pub fn client(&self, tenant_id: &str, api_key: &str) -> Client {
+        let mut tenants = self.lock();
+        if let Some((key, client)) = tenants.get(tenant_id) {
+            if key == api_key {
+                return client.clone();
+            }
+        }
+
+        let client = self.build(tenant_id, api_key);
+        tenants.insert(tenant_id.to_string(), (api_key.to_string(), client.clone()));
+        client
+    }
+
+    /// Get the client for a tenant if one has been created
+    pub fn get(&self, tenant_id: &str) -> Option<Client> {
+        self.lock().get(tenant_id).map(|(_, client)| client.clone())
+    }
+
+    /// Remove a tenant's client
+    ///
+    /// Clones of the client that are still held keep working.
+    pub fn remove(&self, tenant_id: &str) -> Option<Client> {
+        self.lock().remove(tenant_id).map(|(_, client)| client)
+    }
+
+    /// IDs of the tenants with a client
+    pub fn tenants(&self) -> Vec<String> {
+        self.lock().keys().cloned().collect()
+    }
+
+    fn build(&self, tenant_id: &str, api_key: &str) -> Client {
+        let mut builder = self.template.clone().without_credentials().api_key(api_key);
+        #[cfg(feature = "metrics")]
+        {
+            builder = builder.metrics_label("tenant", tenant_id);
+        }
+        if let Some(requests_per_second) = self.rate_limit {
+            let clock = builder.configured_clock();
+            builder =
+                builder.rate_limiter(TokenBucket::new(requests_per_second).shared_clock(clock));
+        }
+        log::debug!("Creating pooled client for tenant {}", tenant_id);
+
+        Client::with_transport(builder, self.http_client.clone())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, (String, Client)>> {
+        self.tenants
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oauth::ClientCredentials;
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    fn header_value(request: &wiremock::Request, name: &str) -> Option<String> {
+        request
+            .headers
+            .iter()
+            .find(|(header, _)| header.as_str().eq_ignore_ascii_case(name))
+            .map(|(_, values)| values.last().as_str().to_string())
+    }
+
+    #[tokio::test]
+    async fn tenants_send_their_own_credentials() {
+        let server = MockServer::start().await;
+        for tenant in ["acme", "globex"] {
+            Mock::given(method("GET"))
+                .and(path("/v1/usage"))
+                .and(header("X-API-Key", format!("{}-key", tenant).as_str()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+                .mount(&server)
+                .await;
+        }
+
+        // Credentials on the template would otherwise outrank each tenant's key
+        let template = Client::builder()
+            .base_url(format!("{}/v1/", server.uri()))
+            .max_retries(0)
+            .client_credentials(
+                ClientCredentials::new("template-id", "template-secret")
+                    .token_url(format!("{}/oauth/token", server.uri())),
+            )
+            .signing_secret("template-signing-secret");
+        let pool = ClientPool::new(template).unwrap();
+
+        for tenant in ["acme", "globex"] {
+            let client = pool.client(tenant, &format!("{}-key", tenant));
+            let _: serde_json::Value = client.get("/usage").await.unwrap();
+        }
+
+        let requests = server.received_requests().await.unwrap();
+        let keys: Vec<_> = requests
+            .iter()
+            .map(|request| header_value(request, "X-API-Key"))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![Some("acme-key".to_string()), Some("globex-key".to_string())]
+        );
+        assert!(requests.iter().all(|request| {
+            header_value(request, "Authorization").is_none()
+                && header_value(request, "X-Signature").is_none()
+                && request.url.path() != "/oauth/token"
+        }));
+    }
+}
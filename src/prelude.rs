@@ -0,0 +1,29 @@
+//! Commonly used types, re-exported for glob import
+//!
+//! ```rust
+//! use xrplsale::prelude::*;
+//!
+//! # fn main() -> Result<(), Error> {
+//! let client = Client::builder()
+//!     .api_key("your-api-key")
+//!     .environment(Environment::Testnet)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::client::{Client, ClientBuilder, Profile};
+pub use crate::error::Error;
+pub use crate::error_code::ErrorCode;
+pub use crate::ids::{InvestmentId, ProjectId, WebhookId};
+pub use crate::types::common::{PaginatedResponse, SortOrder};
+pub use crate::types::investments::{CreateInvestmentRequest, Investment, InvestmentSortField};
+pub use crate::types::projects::{
+    CreateProjectRequest, Project, ProjectSortField, ProjectStats, ProjectTier,
+    UpdateProjectRequest,
+};
+pub use crate::webhook::{WebhookEvent, WebhookSignatureValidator};
+#[cfg(feature = "ed25519")]
+pub use crate::webhook_ed25519::Ed25519WebhookValidator;
+pub use crate::webhook_rotation::{RotatingWebhookValidator, WebhookVerifier};
+pub use crate::Environment;
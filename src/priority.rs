@@ -0,0 +1,174 @@
+//! Request priorities
+//!
+//! A process that runs a bulk sync next to a user-facing dashboard shares one rate
+//! limit budget and one set of connections between both. Tagging requests with a
+//! [`Priority`] keeps the interactive traffic responsive:
+//!
+//! - With [`ClientBuilder::max_concurrent_requests`](crate::ClientBuilder::max_concurrent_requests),
+//!   queued requests are started in priority order, so interactive calls never wait
+//!   behind a backlog of bulk calls.
+//! - When the API has reported an endpoint as rate limited, background and bulk calls
+//!   wait for the limit to reset instead of being sent and rejected. Bulk calls also
+//!   leave the last few requests of each window to higher priorities.
+//!
+//! ```rust
+//! use xrplsale::{Client, Priority};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::builder()
+//!     .api_key("your-api-key")
+//!     .max_concurrent_requests(8)
+//!     .build()?;
+//!
+//! // Backfill job sharing the client with request handlers
+//! let backfill = client.with_priority(Priority::Bulk);
+//! let page = backfill.projects().completed(Some(1), Some(100)).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Requests are [`Priority::Interactive`] unless set otherwise.
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::oneshot;
+
+/// Requests left in a rate limit window that bulk calls leave to higher priorities
+pub(crate) const BULK_RESERVE: u32 = 5;
+
+/// Scheduling class of a request
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Priority {
+    /// A user is waiting on the result
+    #[default]
+    Interactive,
+    /// Work that should finish soon but has no one waiting on it
+    Background,
+    /// Large syncs and backfills that can yield to everything else
+    Bulk,
+}
+
+impl Priority {
+    fn rank(self) -> usize {
+        match self {
+            Priority::Interactive => 0,
+            Priority::Background => 1,
+            Priority::Bulk => 2,
+        }
+    }
+}
+
+impl std::str::FromStr for Priority {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> crate::error::Result<Self> {
+        match s.to_lowercase().as_str() {
+            "interactive" => Ok(Priority::Interactive),
+            "background" => Ok(Priority::Background),
+            "bulk" => Ok(Priority::Bulk),
+            _ => Err(crate::error::Error::Configuration(format!(
+                "Unknown priority: {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Concurrency limit that hands free slots to the highest-priority waiter
+#[derive(Debug)]
+pub(crate) struct PriorityLimiter {
+    state: Mutex<LimiterState>,
+}
+
+#[derive(Debug)]
+struct LimiterState {
+    available: usize,
+    waiters: [VecDeque<oneshot::Sender<()>>; 3],
+}
+
+impl PriorityLimiter {
+    pub(crate) fn new(max_concurrent: usize) -> Self {
+        Self {
+            state: Mutex::new(LimiterState {
+                available: max_concurrent.max(1),
+                waiters: Default::default(),
+            }),
+        }
+    }
+
+    /// Wait for a free slot; the slot is released when the permit is dropped
+    pub(crate) async fn acquire(self: &Arc<Self>, priority: Priority) -> PriorityPermit {
+        let receiver = {
+            let mut state = self.lock();
+            if state.available > 0 {
+                state.available -= 1;
+                return PriorityPermit {
+                    limiter: self.clone(),
+                };
+            }
+            let (sender, receiver) = oneshot::channel();
+            state.waiters[priority.rank()].push_back(sender);
+            receiver
+        };
+
+        let mut waiting = Waiting {
+            receiver,
+            limiter: self.clone(),
+        };
+        // Senders live in the limiter, which `waiting` keeps alive
+        let _ = (&mut waiting.receiver).await;
+
+        PriorityPermit {
+            limiter: self.clone(),
+        }
+    }
+
+    /// Pass a slot to the next waiter, or return it to the pool
+    fn release(&self) {
+        let mut state = self.lock();
+        for queue in state.waiters.iter_mut() {
+            while let Some(sender) = queue.pop_front() {
+                if sender.send(()).is_ok() {
+                    return;
+                }
+            }
+        }
+        state.available += 1;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LimiterState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// A queued acquisition; passes its slot on if dropped after being granted one
+struct Waiting {
+    receiver: oneshot::Receiver<()>,
+    limiter: Arc<PriorityLimiter>,
+}
+
+impl Drop for Waiting {
+    fn drop(&mut self) {
+        self.receiver.close();
+        if self.receiver.try_recv().is_ok() {
+            self.limiter.release();
+        }
+    }
+}
+
+/// A slot held for the duration of one request attempt
+#[derive(Debug)]
+pub(crate) struct PriorityPermit {
+    limiter: Arc<PriorityLimiter>,
+}
+
+impl Drop for PriorityPermit {
+    fn drop(&mut self) {
+        self.limiter.release();
+    }
+}
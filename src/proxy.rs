@@ -0,0 +1,519 @@
+//! Read-only API proxy routes for Axum
+//!
+//! [`router`] builds a mountable [`Router`] that serves public project data through the
+//! client, so browsers can load project pages without ever seeing the API key. The
+//! routes only read, responses are cached, and clients are rate limited:
+//!
+//! ```rust,no_run
+//! use axum::Router;
+//! use std::net::SocketAddr;
+//! use xrplsale::{proxy, Client};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::from_env()?;
+//! let app = Router::new().nest("/api/sale", proxy::router(client));
+//!
+//! let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
+//! axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Routes:
+//!
+//! * `GET /projects` - project list, with optional `status`, `page`, `limit`, `sort_by`,
+//!   and `sort_order` query parameters; `status` defaults to `active`
+//! * `GET /projects/:id` - project detail
+//! * `GET /projects/:id/stats` - sale statistics
+//!
+//! Only projects whose status is in [`ProxyConfig::statuses`] are served; any other
+//! project answers `404` as if it did not exist. Upstream errors are logged and never
+//! passed through: clients only see `404`, `502`, or `503` with a generic message.
+//!
+//! Clients are rate limited per IP when the app is served with
+//! `into_make_service_with_connect_info::<SocketAddr>()`, and share one limit otherwise.
+
+use crate::{
+    cache::{CachedResponse, InMemoryCache, ResponseCache},
+    client::Client,
+    error::Error,
+    runtime::Instant,
+    types::{
+        common::SortOrder,
+        projects::{Project, ProjectSortField},
+    },
+};
+use axum::{
+    extract::{ConnectInfo, Path, Query, Request, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    future::Future,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Rate limit buckets kept before idle ones are dropped
+const MAX_BUCKETS: usize = 10_000;
+
+/// Longest project ID accepted in a path
+const MAX_ID_LEN: usize = 64;
+
+/// Configuration for [`router_with_config`]
+#[derive(Debug, Clone)]
+pub struct ProxyConfig {
+    cache_ttl: Duration,
+    stats_ttl: Duration,
+    cache_capacity: usize,
+    rate_limit: Option<(u32, Duration)>,
+    max_page_size: u32,
+    statuses: Vec<String>,
+    trust_forwarded_for: bool,
+}
+
+impl ProxyConfig {
+    /// Cache projects for 30 seconds and stats for 5, allow 60 requests per client per
+    /// minute, and serve `active`, `upcoming`, and `completed` projects
+    pub fn new() -> Self {
+        Self {
+            cache_ttl: Duration::from_secs(30),
+            stats_ttl: Duration::from_secs(5),
+            cache_capacity: 1_000,
+            rate_limit: Some((60, Duration::from_secs(60))),
+            max_page_size: 50,
+            statuses: vec![
+                "active".to_string(),
+                "upcoming".to_string(),
+                "completed".to_string(),
+            ],
+            trust_forwarded_for: false,
+        }
+    }
+
+    /// Set how long project lists and details are cached
+    pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Set how long sale statistics are cached
+    pub fn stats_ttl(mut self, ttl: Duration) -> Self {
+        self.stats_ttl = ttl;
+        self
+    }
+
+    /// Set how many responses are cached
+    pub fn cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache_capacity = capacity;
+        self
+    }
+
+    /// Allow each client `requests` requests per `period`, refilled gradually
+    pub fn rate_limit(mut self, requests: u32, period: Duration) -> Self {
+        self.rate_limit = Some((requests.max(1), period));
+        self
+    }
+
+    /// Disable rate limiting, e.g. when it is enforced in front of the app
+    pub fn no_rate_limit(mut self) -> Self {
+        self.rate_limit = None;
+        self
+    }
+
+    /// Set the largest `limit` accepted for project lists
+    pub fn max_page_size(mut self, max_page_size: u32) -> Self {
+        self.max_page_size = max_page_size.max(1);
+        self
+    }
+
+    /// Set the project statuses that are served
+    pub fn statuses<I, S>(mut self, statuses: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.statuses = statuses.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Rate limit by the first address in `X-Forwarded-For`
+    ///
+    /// Only enable this behind a reverse proxy that sets the header, since clients can
+    /// send any value.
+    pub fn trust_forwarded_for(mut self, trust: bool) -> Self {
+        self.trust_forwarded_for = trust;
+        self
+    }
+}
+
+impl Default for ProxyConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Build the proxy routes with the default [`ProxyConfig`]
+pub fn router<S>(client: Client) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    router_with_config(client, ProxyConfig::default())
+}
+
+/// Build the proxy routes
+pub fn router_with_config<S>(client: Client, config: ProxyConfig) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let limiter = config
+        .rate_limit
+        .map(|(requests, period)| Arc::new(RateLimiter::new(requests, period)));
+    let trust_forwarded_for = config.trust_forwarded_for;
+    let limiter_client = client.clone();
+    let state = Arc::new(ProxyState {
+        client,
+        cache: InMemoryCache::new(config.cache_capacity),
+        config,
+    });
+
+    let routes = Router::new()
+        .route("/projects", get(list_projects))
+        .route("/projects/:id", get(get_project))
+        .route("/projects/:id/stats", get(get_stats))
+        .with_state(state);
+
+    match limiter {
+        Some(limiter) => routes.layer(middleware::from_fn(move |request, next| {
+            let now = limiter_client.clock().now();
+            rate_limit(limiter.clone(), trust_forwarded_for, now, request, next)
+        })),
+        None => routes,
+    }
+}
+
+struct ProxyState {
+    client: Client,
+    cache: InMemoryCache,
+    config: ProxyConfig,
+}
+
+impl ProxyState {
+    fn is_public(&self, project: &Project) -> bool {
+        match serde_json::to_value(&project.status) {
+            Ok(serde_json::Value::String(status)) => self.config.statuses.contains(&status),
+            _ => false,
+        }
+    }
+
+    /// Serve `key` from the cache, or fetch it and cache the JSON for `ttl`
+    async fn cached<T, F, Fut>(
+        &self,
+        key: &str,
+        ttl: Duration,
+        fetch: F,
+    ) -> Result<String, ProxyError>
+    where
+        T: Serialize,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = crate::Result<T>>,
+    {
+        let clock = self.client.clock();
+        if let Some(cached) = self
+            .cache
+            .get(key)
+            .filter(|cached| cached.is_fresh_at(clock.now()))
+        {
+            return Ok(cached.body);
+        }
+
+        let value = fetch().await.map_err(ProxyError::Upstream)?;
+        let body = serde_json::to_string(&value)
+            .map_err(|e| ProxyError::Upstream(Error::Parse(e.to_string())))?;
+        self.cache.put(
+            key,
+            CachedResponse {
+                body: body.clone(),
+                etag: None,
+                stored_at: clock.now(),
+                ttl,
+            },
+        );
+        Ok(body)
+    }
+
+    /// Fetch a project, failing with `404` unless its status is public
+    async fn public_project(&self, id: &str) -> Result<String, ProxyError> {
+        let body = self
+            .cached(
+                &format!("/projects/{}", id),
+                self.config.cache_ttl,
+                || async { self.client.projects().get(id).await },
+            )
+            .await?;
+        let project: Project = serde_json::from_str(&body)
+            .map_err(|e| ProxyError::Upstream(Error::Parse(e.to_string())))?;
+        if self.is_public(&project) {
+            Ok(body)
+        } else {
+            Err(ProxyError::NotFound)
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ListQuery {
+    status: Option<String>,
+    page: Option<u32>,
+    limit: Option<u32>,
+    sort_by: Option<ProjectSortField>,
+    sort_order: Option<SortOrder>,
+}
+
+async fn list_projects(
+    State(state): State<Arc<ProxyState>>,
+    query: Option<Query<ListQuery>>,
+) -> Result<Response, ProxyError> {
+    let Query(query) = query.ok_or(ProxyError::BadRequest("Invalid query parameters"))?;
+    let status = query.status.unwrap_or_else(|| "active".to_string());
+    if !state.config.statuses.contains(&status) {
+        return Err(ProxyError::BadRequest("Unknown status"));
+    }
+    let page = query.page.unwrap_or(1).max(1);
+    let limit = query
+        .limit
+        .unwrap_or(20)
+        .clamp(1, state.config.max_page_size);
+
+    let key = format!(
+        "/projects?status={}&page={}&limit={}&sort_by={}&sort_order={}",
+        status,
+        page,
+        limit,
+        query
+            .sort_by
+            .map(|field| field.as_str())
+            .unwrap_or_default(),
+        query
+            .sort_order
+            .map(|order| order.as_str())
+            .unwrap_or_default(),
+    );
+    let client = &state.client;
+    let body = state
+        .cached(&key, state.config.cache_ttl, || async move {
+            client
+                .projects()
+                .list(
+                    Some(&status),
+                    Some(page),
+                    Some(limit),
+                    query.sort_by,
+                    query.sort_order,
+                )
+                .await
+        })
+        .await?;
+    Ok(json_response(body, state.config.cache_ttl))
+}
+
+async fn get_project(
+    State(state): State<Arc<ProxyState>>,
+    Path(id): Path<String>,
+) -> Result<Response, ProxyError> {
+    validate_id(&id)?;
+    let body = state.public_project(&id).await?;
+    Ok(json_response(body, state.config.cache_ttl))
+}
+
+async fn get_stats(
+    State(state): State<Arc<ProxyState>>,
+    Path(id): Path<String>,
+) -> Result<Response, ProxyError> {
+    validate_id(&id)?;
+    state.public_project(&id).await?;
+    let client = &state.client;
+    let body = state
+        .cached(
+            &format!("/projects/{}/stats", id),
+            state.config.stats_ttl,
+            || async { client.projects().stats(id.as_str()).await },
+        )
+        .await?;
+    Ok(json_response(body, state.config.stats_ttl))
+}
+
+/// Reject IDs that could address anything but a single project
+fn validate_id(id: &str) -> Result<(), ProxyError> {
+    let valid = !id.is_empty()
+        && id.len() <= MAX_ID_LEN
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if valid {
+        Ok(())
+    } else {
+        Err(ProxyError::NotFound)
+    }
+}
+
+fn json_response(body: String, ttl: Duration) -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, "application/json".to_string()),
+            (
+                header::CACHE_CONTROL,
+                format!("public, max-age={}", ttl.as_secs()),
+            ),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+enum ProxyError {
+    NotFound,
+    BadRequest(&'static str),
+    Upstream(Error),
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: &'static str,
+}
+
+impl IntoResponse for ProxyError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            ProxyError::NotFound | ProxyError::Upstream(Error::NotFound(_)) => {
+                (StatusCode::NOT_FOUND, "Not found")
+            }
+            ProxyError::BadRequest(message) => (StatusCode::BAD_REQUEST, message),
+            ProxyError::Upstream(e) => {
+                log::warn!("API proxy request failed: {}", e);
+                match e {
+                    Error::RateLimit { .. } | Error::CircuitOpen { .. } => {
+                        (StatusCode::SERVICE_UNAVAILABLE, "Temporarily unavailable")
+                    }
+                    _ => (StatusCode::BAD_GATEWAY, "Upstream request failed"),
+                }
+            }
+        };
+        (status, Json(ErrorBody { error: message })).into_response()
+    }
+}
+
+async fn rate_limit(
+    limiter: Arc<RateLimiter>,
+    trust_forwarded_for: bool,
+    now: Instant,
+    request: Request,
+    next: Next,
+) -> Response {
+    let client_ip = trust_forwarded_for
+        .then(|| forwarded_for(request.headers()))
+        .flatten()
+        .or_else(|| {
+            request
+                .extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(address)| address.ip())
+        });
+
+    match limiter.acquire(client_ip, now) {
+        Ok(()) => next.run(request).await,
+        Err(retry_in) => {
+            let mut response = (
+                StatusCode::TOO_MANY_REQUESTS,
+                Json(ErrorBody {
+                    error: "Too many requests",
+                }),
+            )
+                .into_response();
+            let seconds = retry_in.as_secs_f64().ceil().max(1.0) as u64;
+            if let Ok(value) = HeaderValue::from_str(&seconds.to_string()) {
+                response.headers_mut().insert(header::RETRY_AFTER, value);
+            }
+            response
+        }
+    }
+}
+
+fn forwarded_for(headers: &HeaderMap) -> Option<IpAddr> {
+    headers
+        .get("x-forwarded-for")?
+        .to_str()
+        .ok()?
+        .split(',')
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Token buckets per client IP, with `None` for clients of unknown address
+struct RateLimiter {
+    capacity: f64,
+    per_second: f64,
+    buckets: Mutex<HashMap<Option<IpAddr>, Bucket>>,
+}
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl RateLimiter {
+    fn new(requests: u32, period: Duration) -> Self {
+        let capacity = f64::from(requests);
+        Self {
+            capacity,
+            per_second: capacity / period.as_secs_f64().max(f64::EPSILON),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Take a token for `client` at `now`, or return how long until one is available
+    fn acquire(&self, client: Option<IpAddr>, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self
+            .buckets
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if buckets.len() >= MAX_BUCKETS {
+            // A bucket idle long enough to refill is the same as no bucket
+            let refill_time = self.capacity / self.per_second;
+            buckets.retain(|_, bucket| {
+                now.saturating_duration_since(bucket.updated_at)
+                    .as_secs_f64()
+                    < refill_time
+            });
+        }
+
+        let bucket = buckets.entry(client).or_insert_with(|| Bucket {
+            tokens: self.capacity,
+            updated_at: now,
+        });
+        let refilled = now
+            .saturating_duration_since(bucket.updated_at)
+            .as_secs_f64()
+            * self.per_second;
+        bucket.tokens = (bucket.tokens + refilled).min(self.capacity);
+        bucket.updated_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) / self.per_second,
+            ))
+        }
+    }
+}
@@ -0,0 +1,728 @@
+//! Query string serialization for typed query parameters
+//!
+//! Services describe their query parameters as `Serialize` structs instead of building
+//! maps by hand. [`to_pairs`] flattens such a struct into the pairs appended to the
+//! URL: `None` fields are left out, and sequences repeat the key once per element, so
+//! `statuses: vec!["active", "upcoming"]` becomes `statuses=active&statuses=upcoming`.
+//!
+//! Pairs keep the order the fields are declared in, so URLs read the way the struct
+//! does and stay stable for caching and request signing.
+
+use crate::error::{Error, Result};
+use serde::ser::{self, Impossible, Serialize};
+use std::fmt;
+
+/// Flatten a struct or map into query pairs
+///
+/// Pairs come out in field order. Fails with [`Error::Configuration`] for values that
+/// have no query string form, such as nested structs.
+pub(crate) fn to_pairs<Q: Serialize + ?Sized>(query: &Q) -> Result<Vec<(String, String)>> {
+    let mut pairs = Vec::new();
+    query
+        .serialize(QuerySerializer { pairs: &mut pairs })
+        .map_err(|e| Error::Configuration(format!("Invalid query: {}", e)))?;
+    Ok(pairs)
+}
+
+type QueryResult<T = ()> = std::result::Result<T, QueryError>;
+
+/// Why a query couldn't be serialized
+#[derive(Debug)]
+struct QueryError(String);
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+impl ser::Error for QueryError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        QueryError(msg.to_string())
+    }
+}
+
+fn not_a_struct<T>(kind: &str) -> QueryResult<T> {
+    Err(QueryError(format!(
+        "expected a struct or map, got {}",
+        kind
+    )))
+}
+
+fn not_a_value<T>(key: &str) -> QueryResult<T> {
+    Err(QueryError(format!(
+        "'{}' must be a value or a list of values",
+        key
+    )))
+}
+
+/// Serializes the query itself: a struct or map whose fields become pairs
+struct QuerySerializer<'a> {
+    pairs: &'a mut Vec<(String, String)>,
+}
+
+impl<'a> ser::Serializer for QuerySerializer<'a> {
+    type Ok = ();
+    type Error = QueryError;
+    type SerializeSeq = Impossible<(), QueryError>;
+    type SerializeTuple = Impossible<(), QueryError>;
+    type SerializeTupleStruct = Impossible<(), QueryError>;
+    type SerializeTupleVariant = Impossible<(), QueryError>;
+    type SerializeMap = MapSerializer<'a>;
+    type SerializeStruct = MapSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), QueryError>;
+
+    fn serialize_bool(self, _: bool) -> QueryResult {
+        not_a_struct("a boolean")
+    }
+
+    fn serialize_i8(self, _: i8) -> QueryResult {
+        not_a_struct("a number")
+    }
+
+    fn serialize_i16(self, _: i16) -> QueryResult {
+        not_a_struct("a number")
+    }
+
+    fn serialize_i32(self, _: i32) -> QueryResult {
+        not_a_struct("a number")
+    }
+
+    fn serialize_i64(self, _: i64) -> QueryResult {
+        not_a_struct("a number")
+    }
+
+    fn serialize_u8(self, _: u8) -> QueryResult {
+        not_a_struct("a number")
+    }
+
+    fn serialize_u16(self, _: u16) -> QueryResult {
+        not_a_struct("a number")
+    }
+
+    fn serialize_u32(self, _: u32) -> QueryResult {
+        not_a_struct("a number")
+    }
+
+    fn serialize_u64(self, _: u64) -> QueryResult {
+        not_a_struct("a number")
+    }
+
+    fn serialize_f32(self, _: f32) -> QueryResult {
+        not_a_struct("a number")
+    }
+
+    fn serialize_f64(self, _: f64) -> QueryResult {
+        not_a_struct("a number")
+    }
+
+    fn serialize_char(self, _: char) -> QueryResult {
+        not_a_struct("a string")
+    }
+
+    fn serialize_str(self, _: &str) -> QueryResult {
+        not_a_struct("a string")
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> QueryResult {
+        not_a_struct("bytes")
+    }
+
+    fn serialize_none(self) -> QueryResult {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> QueryResult {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> QueryResult {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> QueryResult {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, _: &'static str) -> QueryResult {
+        not_a_struct("a string")
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> QueryResult {
+        value.serialize(self)
+    }
+
+    // A variant with one value, such as `SyncCursor::Since`, becomes a single pair
+    // named after the variant
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> QueryResult {
+        value.serialize(ValueSerializer {
+            key: variant,
+            pairs: self.pairs,
+            in_list: false,
+        })
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> QueryResult<Self::SerializeSeq> {
+        not_a_struct("a list")
+    }
+
+    fn serialize_tuple(self, _: usize) -> QueryResult<Self::SerializeTuple> {
+        not_a_struct("a list")
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> QueryResult<Self::SerializeTupleStruct> {
+        not_a_struct("a list")
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> QueryResult<Self::SerializeTupleVariant> {
+        not_a_struct("a list")
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> QueryResult<Self::SerializeMap> {
+        Ok(MapSerializer {
+            pairs: self.pairs,
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> QueryResult<Self::SerializeStruct> {
+        Ok(MapSerializer {
+            pairs: self.pairs,
+            key: None,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> QueryResult<Self::SerializeStructVariant> {
+        not_a_struct("a struct variant")
+    }
+}
+
+/// Collects the fields of the query struct, or the entries of a map (including
+/// `#[serde(flatten)]` structs, which serialize as maps)
+struct MapSerializer<'a> {
+    pairs: &'a mut Vec<(String, String)>,
+    key: Option<String>,
+}
+
+impl ser::SerializeStruct for MapSerializer<'_> {
+    type Ok = ();
+    type Error = QueryError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> QueryResult {
+        value.serialize(ValueSerializer {
+            key,
+            pairs: self.pairs,
+            in_list: false,
+        })
+    }
+
+    fn end(self) -> QueryResult {
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for MapSerializer<'_> {
+    type Ok = ();
+    type Error = QueryError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> QueryResult {
+        let mut pairs = Vec::new();
+        key.serialize(ValueSerializer {
+            key: "key",
+            pairs: &mut pairs,
+            in_list: true,
+        })?;
+        match pairs.pop() {
+            Some((_, key)) if pairs.is_empty() => {
+                self.key = Some(key);
+                Ok(())
+            }
+            _ => Err(QueryError(
+                "map keys must be strings or numbers".to_string(),
+            )),
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> QueryResult {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| QueryError("map value without a key".to_string()))?;
+        value.serialize(ValueSerializer {
+            key: &key,
+            pairs: self.pairs,
+            in_list: false,
+        })
+    }
+
+    fn end(self) -> QueryResult {
+        Ok(())
+    }
+}
+
+/// Serializes the value of one field: a scalar, a list of scalars, or nothing for
+/// `None`
+struct ValueSerializer<'a, 'k> {
+    key: &'k str,
+    pairs: &'a mut Vec<(String, String)>,
+    in_list: bool,
+}
+
+impl ValueSerializer<'_, '_> {
+    fn push(self, value: String) -> QueryResult {
+        self.pairs.push((self.key.to_string(), value));
+        Ok(())
+    }
+}
+
+impl<'a, 'k> ser::Serializer for ValueSerializer<'a, 'k> {
+    type Ok = ();
+    type Error = QueryError;
+    type SerializeSeq = ListSerializer<'a, 'k>;
+    type SerializeTuple = ListSerializer<'a, 'k>;
+    type SerializeTupleStruct = Impossible<(), QueryError>;
+    type SerializeTupleVariant = Impossible<(), QueryError>;
+    type SerializeMap = Impossible<(), QueryError>;
+    type SerializeStruct = Impossible<(), QueryError>;
+    type SerializeStructVariant = Impossible<(), QueryError>;
+
+    fn serialize_bool(self, value: bool) -> QueryResult {
+        self.push(value.to_string())
+    }
+
+    fn serialize_i8(self, value: i8) -> QueryResult {
+        self.push(value.to_string())
+    }
+
+    fn serialize_i16(self, value: i16) -> QueryResult {
+        self.push(value.to_string())
+    }
+
+    fn serialize_i32(self, value: i32) -> QueryResult {
+        self.push(value.to_string())
+    }
+
+    fn serialize_i64(self, value: i64) -> QueryResult {
+        self.push(value.to_string())
+    }
+
+    fn serialize_u8(self, value: u8) -> QueryResult {
+        self.push(value.to_string())
+    }
+
+    fn serialize_u16(self, value: u16) -> QueryResult {
+        self.push(value.to_string())
+    }
+
+    fn serialize_u32(self, value: u32) -> QueryResult {
+        self.push(value.to_string())
+    }
+
+    fn serialize_u64(self, value: u64) -> QueryResult {
+        self.push(value.to_string())
+    }
+
+    fn serialize_f32(self, value: f32) -> QueryResult {
+        self.push(value.to_string())
+    }
+
+    fn serialize_f64(self, value: f64) -> QueryResult {
+        self.push(value.to_string())
+    }
+
+    fn serialize_char(self, value: char) -> QueryResult {
+        self.push(value.to_string())
+    }
+
+    fn serialize_str(self, value: &str) -> QueryResult {
+        self.push(value.to_string())
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> QueryResult {
+        not_a_value(self.key)
+    }
+
+    fn serialize_none(self) -> QueryResult {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> QueryResult {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> QueryResult {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> QueryResult {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(self, _: &'static str, _: u32, variant: &'static str) -> QueryResult {
+        self.push(variant.to_string())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> QueryResult {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> QueryResult {
+        not_a_value(self.key)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> QueryResult<Self::SerializeSeq> {
+        if self.in_list {
+            return not_a_value(self.key);
+        }
+        Ok(ListSerializer {
+            key: self.key,
+            pairs: self.pairs,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> QueryResult<Self::SerializeTuple> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> QueryResult<Self::SerializeTupleStruct> {
+        not_a_value(self.key)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> QueryResult<Self::SerializeTupleVariant> {
+        not_a_value(self.key)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> QueryResult<Self::SerializeMap> {
+        not_a_value(self.key)
+    }
+
+    fn serialize_struct(self, _: &'static str, _: usize) -> QueryResult<Self::SerializeStruct> {
+        not_a_value(self.key)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> QueryResult<Self::SerializeStructVariant> {
+        not_a_value(self.key)
+    }
+}
+
+/// Repeats the key of a list field once per element
+struct ListSerializer<'a, 'k> {
+    key: &'k str,
+    pairs: &'a mut Vec<(String, String)>,
+}
+
+impl ListSerializer<'_, '_> {
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> QueryResult {
+        value.serialize(ValueSerializer {
+            key: self.key,
+            pairs: self.pairs,
+            in_list: true,
+        })
+    }
+}
+
+impl ser::SerializeSeq for ListSerializer<'_, '_> {
+    type Ok = ();
+    type Error = QueryError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> QueryResult {
+        self.element(value)
+    }
+
+    fn end(self) -> QueryResult {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for ListSerializer<'_, '_> {
+    type Ok = ();
+    type Error = QueryError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> QueryResult {
+        self.element(value)
+    }
+
+    fn end(self) -> QueryResult {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::Client, types::common::SyncCursor};
+    use chrono::{TimeZone, Utc};
+    use serde::Serialize;
+    use std::collections::BTreeMap;
+    use wiremock::{matchers::method, Mock, MockServer, Request, ResponseTemplate};
+
+    fn pairs<Q: Serialize + ?Sized>(query: &Q) -> Vec<(String, String)> {
+        to_pairs(query).unwrap()
+    }
+
+    fn expected(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Status {
+        Active,
+        ComingSoon,
+    }
+
+    #[test]
+    fn keeps_field_order_and_skips_none() {
+        #[derive(Serialize)]
+        struct Query {
+            zeta: u32,
+            status: Option<&'static str>,
+            alpha: bool,
+            page: Option<u32>,
+        }
+
+        let query = Query {
+            zeta: 1,
+            status: None,
+            alpha: true,
+            page: Some(2),
+        };
+        assert_eq!(
+            pairs(&query),
+            expected(&[("zeta", "1"), ("alpha", "true"), ("page", "2")])
+        );
+    }
+
+    #[test]
+    fn flattens_nested_options() {
+        #[derive(Serialize)]
+        struct Query {
+            cleared: Option<Option<u32>>,
+            set: Option<Option<u32>>,
+            tags: Option<Vec<&'static str>>,
+        }
+
+        let query = Query {
+            cleared: Some(None),
+            set: Some(Some(3)),
+            tags: None,
+        };
+        assert_eq!(pairs(&query), expected(&[("set", "3")]));
+    }
+
+    #[test]
+    fn repeats_list_keys() {
+        #[derive(Serialize)]
+        struct Query {
+            statuses: Vec<Status>,
+            ids: Vec<Option<u32>>,
+            none: Vec<u32>,
+            limit: u32,
+        }
+
+        let query = Query {
+            statuses: vec![Status::Active, Status::ComingSoon],
+            ids: vec![Some(1), None, Some(3)],
+            none: Vec::new(),
+            limit: 10,
+        };
+        assert_eq!(
+            pairs(&query),
+            expected(&[
+                ("statuses", "active"),
+                ("statuses", "coming_soon"),
+                ("ids", "1"),
+                ("ids", "3"),
+                ("limit", "10"),
+            ])
+        );
+    }
+
+    #[test]
+    fn serializes_enums() {
+        #[derive(Serialize)]
+        struct Query {
+            status: Status,
+            #[serde(flatten)]
+            cursor: SyncCursor,
+        }
+
+        let query = Query {
+            status: Status::ComingSoon,
+            cursor: SyncCursor::Cursor("c_42".to_string()),
+        };
+        assert_eq!(
+            pairs(&query),
+            expected(&[("status", "coming_soon"), ("cursor", "c_42")])
+        );
+
+        let since = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        assert_eq!(
+            pairs(&SyncCursor::Since(since)),
+            expected(&[("since", "2024-03-01T12:00:00Z")])
+        );
+    }
+
+    #[test]
+    fn accepts_maps_and_flattened_structs() {
+        #[derive(Serialize)]
+        struct Page {
+            page: u32,
+            limit: u32,
+        }
+
+        #[derive(Serialize)]
+        struct Query {
+            q: &'static str,
+            #[serde(flatten)]
+            page: Page,
+            sort: &'static str,
+        }
+
+        let query = Query {
+            q: "xrp",
+            page: Page { page: 1, limit: 20 },
+            sort: "name",
+        };
+        assert_eq!(
+            pairs(&query),
+            expected(&[
+                ("q", "xrp"),
+                ("page", "1"),
+                ("limit", "20"),
+                ("sort", "name")
+            ])
+        );
+
+        let map = BTreeMap::from([(2, "two"), (1, "one")]);
+        assert_eq!(pairs(&map), expected(&[("1", "one"), ("2", "two")]));
+        assert!(pairs(&()).is_empty());
+    }
+
+    #[test]
+    fn rejects_values_without_a_query_form() {
+        #[derive(Serialize)]
+        struct Range {
+            from: u32,
+        }
+
+        #[derive(Serialize)]
+        struct Nested {
+            range: Range,
+        }
+
+        #[derive(Serialize)]
+        struct ListOfLists {
+            grid: Vec<Vec<u32>>,
+        }
+
+        let error = to_pairs(&Nested {
+            range: Range { from: 1 },
+        })
+        .unwrap_err();
+        assert!(matches!(&error, Error::Configuration(message) if message.contains("'range'")));
+        assert!(to_pairs(&ListOfLists {
+            grid: vec![vec![1]]
+        })
+        .is_err());
+        assert!(to_pairs(&vec![1, 2]).is_err());
+        assert!(to_pairs("status=active").is_err());
+    }
+
+    #[tokio::test]
+    async fn escapes_values_in_the_url() {
+        #[derive(Serialize)]
+        struct Query {
+            q: &'static str,
+            tags: Vec<&'static str>,
+            since: chrono::DateTime<Utc>,
+        }
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(|request: &Request| {
+                request.url.query()
+                    == Some(
+                        "q=a%26b%3Dc+d%2Fe&tags=caf%C3%A9&tags=100%25&since=2024-03-01T12%3A00%3A00Z",
+                    )
+            })
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = Client::builder()
+            .api_key("test")
+            .base_url(format!("{}/v1/", server.uri()))
+            .max_retries(0)
+            .build()
+            .unwrap();
+        let query = Query {
+            q: "a&b=c d/e",
+            tags: vec!["café", "100%"],
+            since: Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap(),
+        };
+        let _: serde_json::Value = client.get_with_query("/search", &query).await.unwrap();
+    }
+}
@@ -0,0 +1,397 @@
+//! Rate limit tracking for interactive tools
+//!
+//! Every client records the rate limit state the API reports, from the
+//! `X-RateLimit-Remaining` and `X-RateLimit-Reset` headers and from `429` responses with
+//! `Retry-After`. [`RateLimitGuard`] answers "can I call this endpoint now, and if not,
+//! when?" so a UI can disable buttons and show a countdown instead of letting users
+//! trigger requests that will fail.
+//!
+//! ```rust
+//! use reqwest::Method;
+//! use xrplsale::{rate_limit::RateLimitStatus, Client};
+//!
+//! # fn main() -> xrplsale::Result<()> {
+//! let client = Client::builder().api_key("your-api-key").build()?;
+//! let guard = client.rate_limits();
+//!
+//! match guard.check(&Method::POST, "/projects/proj_123/launch") {
+//!     RateLimitStatus::Ready { .. } => { /* enable the button */ }
+//!     RateLimitStatus::Limited { retry_in } => {
+//!         println!("Try again in {}s", retry_in.as_secs());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! State is tracked per HTTP method and path template (`POST /projects/:id/launch`), and
+//! only reflects responses this client has seen.
+//!
+//! To stay under a limit before the API reports it, give the client a [`RateLimiter`]
+//! with [`ClientBuilder::rate_limiter`](crate::ClientBuilder::rate_limiter). A
+//! [`TokenBucket`] keeps the budget in the process; with the `redis-rate-limit`
+//! feature, `RedisTokenBucket` shares it between every process using the same API
+//! key.
+
+#[cfg(feature = "redis-rate-limit")]
+mod redis;
+#[cfg(feature = "redis-rate-limit")]
+pub use self::redis::RedisTokenBucket;
+
+use crate::{
+    client::endpoint_template,
+    error::Result,
+    priority::{Priority, BULK_RESERVE},
+    runtime::{self, Clock, Instant},
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::{header::HeaderMap, Method, StatusCode};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+/// Header carrying the number of requests left in the current window
+pub const REMAINING_HEADER: &str = "x-ratelimit-remaining";
+/// Header carrying when the current window resets
+pub const RESET_HEADER: &str = "x-ratelimit-reset";
+/// Header carrying how long to wait after a `429`
+pub const RETRY_AFTER_HEADER: &str = "retry-after";
+
+/// Whether an endpoint can be called now
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitStatus {
+    /// The endpoint can be called
+    Ready {
+        /// Requests left in the current window, if the API reported it
+        remaining: Option<u32>,
+    },
+    /// The endpoint is rate limited
+    Limited {
+        /// How long until the endpoint can be called again
+        retry_in: Duration,
+    },
+}
+
+impl RateLimitStatus {
+    /// Whether the endpoint can be called now
+    pub fn is_ready(&self) -> bool {
+        matches!(self, RateLimitStatus::Ready { .. })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct EndpointLimit {
+    remaining: Option<u32>,
+    resets_at: Option<Instant>,
+    blocked_until: Option<Instant>,
+}
+
+/// Shared view of the rate limits observed by a client
+///
+/// Cloning the guard is cheap; all clones see the same state.
+#[derive(Debug, Clone)]
+pub struct RateLimitGuard {
+    endpoints: Arc<Mutex<HashMap<String, EndpointLimit>>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl Default for RateLimitGuard {
+    fn default() -> Self {
+        Self::with_clock(runtime::system_clock())
+    }
+}
+
+impl RateLimitGuard {
+    /// A guard that reads the time from `clock`
+    pub(crate) fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            endpoints: Arc::default(),
+            clock,
+        }
+    }
+
+    /// Check whether an endpoint can be called now
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - HTTP method of the call
+    /// * `path` - Path relative to the API base URL, e.g. `/projects/proj_123`
+    pub fn check(&self, method: &Method, path: &str) -> RateLimitStatus {
+        self.status(&key(method, &endpoint_template(path, "")), 0)
+    }
+
+    /// How long until an endpoint can be called, or `None` if it can be called now
+    pub fn retry_in(&self, method: &Method, path: &str) -> Option<Duration> {
+        match self.check(method, path) {
+            RateLimitStatus::Ready { .. } => None,
+            RateLimitStatus::Limited { retry_in } => Some(retry_in),
+        }
+    }
+
+    /// Forget all observed rate limit state
+    pub fn reset(&self) {
+        self.lock().clear();
+    }
+
+    /// How long a request of the given priority should wait before being sent
+    ///
+    /// Interactive requests are never held. Background requests wait out known limits,
+    /// and bulk requests also wait when the window is down to its last few requests.
+    pub(crate) fn hold_for(
+        &self,
+        method: &Method,
+        endpoint: &str,
+        priority: Priority,
+    ) -> Option<Duration> {
+        let reserve = match priority {
+            Priority::Interactive => return None,
+            Priority::Background => 0,
+            Priority::Bulk => BULK_RESERVE,
+        };
+        match self.status(&key(method, endpoint), reserve) {
+            RateLimitStatus::Ready { .. } => None,
+            RateLimitStatus::Limited { retry_in } => Some(retry_in),
+        }
+    }
+
+    /// Status of an endpoint, treating it as limited once `reserve` or fewer requests
+    /// remain in the window
+    fn status(&self, key: &str, reserve: u32) -> RateLimitStatus {
+        let now = self.clock.now();
+        let limit = self.lock().get(key).copied().unwrap_or_default();
+
+        if let Some(until) = limit.blocked_until.filter(|until| *until > now) {
+            return RateLimitStatus::Limited {
+                retry_in: until - now,
+            };
+        }
+
+        match (limit.remaining, limit.resets_at) {
+            (Some(remaining), Some(resets_at)) if remaining <= reserve && resets_at > now => {
+                RateLimitStatus::Limited {
+                    retry_in: resets_at - now,
+                }
+            }
+            (_, Some(resets_at)) if resets_at <= now => RateLimitStatus::Ready { remaining: None },
+            (remaining, _) => RateLimitStatus::Ready { remaining },
+        }
+    }
+
+    /// Record the rate limit headers of a response
+    pub(crate) fn observe(
+        &self,
+        method: &Method,
+        endpoint: &str,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) {
+        let remaining = header_value(headers, REMAINING_HEADER);
+        let now = self.clock.now();
+        let now_unix = self.clock.utc_now().timestamp().max(0) as u64;
+        let resets_at =
+            header_value(headers, RESET_HEADER).map(|value| now + reset_duration(value, now_unix));
+        let retry_after = if status == StatusCode::TOO_MANY_REQUESTS {
+            Some(retry_after(headers).unwrap_or(Duration::from_secs(1)))
+        } else {
+            None
+        };
+
+        if remaining.is_none() && resets_at.is_none() && retry_after.is_none() {
+            return;
+        }
+
+        let mut endpoints = self.lock();
+        let limit = endpoints.entry(key(method, endpoint)).or_default();
+        if remaining.is_some() || resets_at.is_some() {
+            limit.remaining = remaining.map(|r| r.min(u32::MAX as u64) as u32);
+            limit.resets_at = resets_at;
+        }
+        if let Some(retry_after) = retry_after {
+            limit.blocked_until = Some(now + retry_after);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, EndpointLimit>> {
+        self.endpoints
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Client-side budget spent before each request attempt
+///
+/// Unlike [`RateLimitGuard`], which learns the API's limits from its responses, a
+/// limiter holds requests back so they don't exceed a rate in the first place.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait RateLimiter: Send + Sync + std::fmt::Debug + 'static {
+    /// Take one request from the budget, or return how long to wait before trying again
+    ///
+    /// Implementations may hold lower priorities back while higher ones could still go.
+    async fn try_acquire(&self, priority: Priority) -> Result<Option<Duration>>;
+}
+
+/// Share of a bucket that bulk requests leave to higher priorities
+const BULK_SHARE: f64 = 0.25;
+
+/// Tokens a bucket must hold before a request of `priority` may take one
+pub(crate) fn tokens_needed(priority: Priority, capacity: f64) -> f64 {
+    match priority {
+        Priority::Bulk => 1.0 + (capacity * BULK_SHARE).floor(),
+        Priority::Interactive | Priority::Background => 1.0,
+    }
+}
+
+/// An in-process token bucket
+///
+/// Refills at a steady rate up to its capacity, and each request takes one token. Bulk
+/// requests leave the last quarter of the bucket to higher priorities.
+///
+/// ```rust
+/// use xrplsale::{rate_limit::TokenBucket, Client};
+///
+/// # fn main() -> xrplsale::Result<()> {
+/// let client = Client::builder()
+///     .api_key("your-api-key")
+///     .rate_limiter(TokenBucket::new(10).burst(20))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TokenBucket {
+    rate: f64,
+    capacity: f64,
+    /// Tokens left, and when they were last refilled
+    state: Mutex<(f64, Instant)>,
+    clock: Arc<dyn Clock>,
+}
+
+impl TokenBucket {
+    /// Allow `requests_per_second` on average, in bursts of up to as many
+    pub fn new(requests_per_second: u32) -> Self {
+        let rate = f64::from(requests_per_second.max(1));
+        let clock = runtime::system_clock();
+        Self {
+            rate,
+            capacity: rate,
+            state: Mutex::new((rate, clock.now())),
+            clock,
+        }
+    }
+
+    /// Allow bursts of up to `capacity` requests
+    pub fn burst(self, capacity: u32) -> Self {
+        let capacity = f64::from(capacity.max(1));
+        Self {
+            capacity,
+            state: Mutex::new((capacity, self.clock.now())),
+            ..self
+        }
+    }
+
+    /// Refill by the time read from `clock` instead of the system clock
+    pub fn with_clock<C: Clock>(self, clock: C) -> Self {
+        self.shared_clock(Arc::new(clock))
+    }
+
+    pub(crate) fn shared_clock(self, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            state: Mutex::new((self.capacity, clock.now())),
+            clock,
+            ..self
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl RateLimiter for TokenBucket {
+    async fn try_acquire(&self, priority: Priority) -> Result<Option<Duration>> {
+        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+        let (tokens, refilled_at) = &mut *state;
+
+        let now = self.clock.now();
+        *tokens = (*tokens + now.saturating_duration_since(*refilled_at).as_secs_f64() * self.rate)
+            .min(self.capacity);
+        *refilled_at = now;
+
+        let needed = tokens_needed(priority, self.capacity);
+        if *tokens >= needed {
+            *tokens -= 1.0;
+            Ok(None)
+        } else {
+            Ok(Some(Duration::from_secs_f64(
+                (needed - *tokens) / self.rate,
+            )))
+        }
+    }
+}
+
+/// Parse the `Retry-After` header as a number of seconds
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    header_value(headers, RETRY_AFTER_HEADER).map(Duration::from_secs)
+}
+
+fn key(method: &Method, endpoint: &str) -> String {
+    format!("{} {}", method, endpoint)
+}
+
+fn header_value(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// Parse `X-RateLimit-Remaining`
+pub(crate) fn remaining(headers: &HeaderMap) -> Option<u64> {
+    header_value(headers, REMAINING_HEADER)
+}
+
+/// Parse `X-RateLimit-Reset` as the time from `now` until the window resets
+pub(crate) fn reset_after(headers: &HeaderMap, now: DateTime<Utc>) -> Option<Duration> {
+    let now_unix = now.timestamp().max(0) as u64;
+    header_value(headers, RESET_HEADER).map(|value| reset_duration(value, now_unix))
+}
+
+/// Interpret `X-RateLimit-Reset` as either a Unix timestamp or seconds from now
+fn reset_duration(value: u64, now_unix: u64) -> Duration {
+    let secs = if value > now_unix / 2 {
+        value.saturating_sub(now_unix)
+    } else {
+        value
+    };
+    Duration::from_secs(secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::MockClock;
+
+    #[tokio::test]
+    async fn token_bucket_refills_and_reserves_a_share_for_higher_priorities() {
+        let clock = MockClock::new();
+        let bucket = TokenBucket::new(4).with_clock(clock.clone());
+
+        // Bulk calls leave one of the four tokens
+        for _ in 0..3 {
+            assert_eq!(bucket.try_acquire(Priority::Bulk).await.unwrap(), None);
+        }
+        let wait = bucket.try_acquire(Priority::Bulk).await.unwrap();
+        assert_eq!(wait, Some(Duration::from_millis(250)));
+        assert_eq!(
+            bucket.try_acquire(Priority::Interactive).await.unwrap(),
+            None
+        );
+
+        let wait = bucket.try_acquire(Priority::Interactive).await.unwrap();
+        assert_eq!(wait, Some(Duration::from_millis(250)));
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(
+            bucket.try_acquire(Priority::Background).await.unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,137 @@
+//! Token bucket shared through Redis
+
+use super::{tokens_needed, RateLimiter};
+use crate::{
+    error::{Error, Result},
+    priority::Priority,
+};
+use async_trait::async_trait;
+use redis::{aio::ConnectionManager, Script};
+use std::time::Duration;
+
+/// Refills the bucket by the time elapsed on the Redis server, then takes a token
+///
+/// Returns how many milliseconds to wait, or 0 if a token was taken. Reading the time
+/// in the script keeps every process on the same clock.
+const TAKE_TOKEN: &str = r#"
+local rate = tonumber(ARGV[1])
+local capacity = tonumber(ARGV[2])
+local needed = tonumber(ARGV[3])
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+
+local state = redis.call('HMGET', KEYS[1], 'tokens', 'updated_at')
+local tokens = tonumber(state[1]) or capacity
+local updated_at = tonumber(state[2]) or now
+tokens = math.min(capacity, tokens + math.max(0, now - updated_at) * rate / 1000)
+
+local wait = 0
+if tokens >= needed then
+    tokens = tokens - 1
+else
+    wait = math.ceil((needed - tokens) * 1000 / rate)
+end
+
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'updated_at', now)
+redis.call('PEXPIRE', KEYS[1], math.ceil(capacity * 1000 / rate) + 1000)
+return wait
+"#;
+
+/// A token bucket kept in Redis, shared by every process using the same key
+///
+/// Use one key per API key, so workers calling the API with the same credentials
+/// spend the same budget. Behaves like [`TokenBucket`](super::TokenBucket), with the
+/// bucket refilled atomically on the Redis server:
+///
+/// ```rust,no_run
+/// use xrplsale::{rate_limit::RedisTokenBucket, Client};
+///
+/// # async fn build() -> xrplsale::Result<()> {
+/// let limiter = RedisTokenBucket::connect("redis://127.0.0.1/", "xrplsale:acme", 10)
+///     .await?
+///     .burst(20);
+/// let client = Client::builder()
+///     .api_key("your-api-key")
+///     .rate_limiter(limiter)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RedisTokenBucket {
+    connection: ConnectionManager,
+    key: String,
+    rate: f64,
+    capacity: f64,
+    script: Script,
+}
+
+impl std::fmt::Debug for RedisTokenBucket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedisTokenBucket")
+            .field("key", &self.key)
+            .field("rate", &self.rate)
+            .field("capacity", &self.capacity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RedisTokenBucket {
+    /// Connect to Redis at `url` and allow `requests_per_second` under `key`
+    pub async fn connect<S: Into<String>>(
+        url: &str,
+        key: S,
+        requests_per_second: u32,
+    ) -> Result<Self> {
+        let client = redis::Client::open(url).map_err(redis_error)?;
+        let connection = ConnectionManager::new(client).await.map_err(redis_error)?;
+        Ok(Self::new(connection, key, requests_per_second))
+    }
+
+    /// Allow `requests_per_second` under `key`, in bursts of up to as many
+    pub fn new<S: Into<String>>(
+        connection: ConnectionManager,
+        key: S,
+        requests_per_second: u32,
+    ) -> Self {
+        let rate = f64::from(requests_per_second.max(1));
+        Self {
+            connection,
+            key: key.into(),
+            rate,
+            capacity: rate,
+            script: Script::new(TAKE_TOKEN),
+        }
+    }
+
+    /// Allow bursts of up to `capacity` requests
+    ///
+    /// Every process sharing the key should use the same rate and capacity.
+    pub fn burst(mut self, capacity: u32) -> Self {
+        self.capacity = f64::from(capacity.max(1));
+        self
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl RateLimiter for RedisTokenBucket {
+    async fn try_acquire(&self, priority: Priority) -> Result<Option<Duration>> {
+        let mut connection = self.connection.clone();
+        let wait: u64 = self
+            .script
+            .key(&self.key)
+            .arg(self.rate)
+            .arg(self.capacity)
+            .arg(tokens_needed(priority, self.capacity))
+            .invoke_async(&mut connection)
+            .await
+            .map_err(redis_error)?;
+
+        Ok((wait > 0).then(|| Duration::from_millis(wait)))
+    }
+}
+
+fn redis_error(error: redis::RedisError) -> Error {
+    Error::Storage(format!("Redis rate limiter error: {}", error))
+}
@@ -0,0 +1,351 @@
+//! Recording and replaying API interactions
+//!
+//! [`Recorder`] is a middleware that captures every request and response a client
+//! makes and saves them as a JSON cassette. [`Replayer`] serves a cassette back
+//! without touching the network, so tests run offline in CI while still exercising
+//! the SDK's models against payloads the real API produced:
+//!
+//! ```rust,no_run
+//! use xrplsale::{replay::{Recorder, Replayer}, Client};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // Once, against the live API
+//! let recorder = Recorder::new();
+//! let client = Client::builder()
+//!     .api_key(std::env::var("XRPLSALE_API_KEY")?)
+//!     .with_middleware(recorder.clone())
+//!     .build()?;
+//! client.projects().active(Some(1), Some(10)).await?;
+//! recorder.save("tests/cassettes/active_projects.json")?;
+//!
+//! // In CI
+//! let client = Client::builder()
+//!     .api_key("test")
+//!     .with_middleware(Replayer::from_file("tests/cassettes/active_projects.json")?)
+//!     .build()?;
+//! let projects = client.projects().active(Some(1), Some(10)).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Cassettes are sanitized as they are recorded: credential headers are dropped, and
+//! secrets in query strings and JSON bodies are replaced with `[REDACTED]`, using the
+//! same rules as [debug logging](crate::logging).
+//!
+//! Requests are matched on method, path, and query parameters (in any order). Each
+//! recorded interaction is served once, in recording order, so repeated calls to the
+//! same endpoint replay the responses they got when recorded.
+
+use crate::{
+    error::{Error, Result},
+    logging::{self, REDACTED},
+    middleware::{Middleware, Next},
+};
+use async_trait::async_trait;
+use reqwest::{header::HeaderMap, Request, Response, ResponseBuilderExt, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+use url::Url;
+
+/// Headers describing the original transfer, which no longer apply once the body has
+/// been redacted and re-serialized
+const UNRECORDED_HEADERS: &[&str] = &[
+    "connection",
+    "content-encoding",
+    "content-length",
+    "transfer-encoding",
+];
+
+/// Recorded interactions, as stored on disk
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// Interactions in the order they were recorded
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Read a cassette from a JSON file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            Error::Configuration(format!("Failed to read cassette {}: {}", path.display(), e))
+        })?;
+        serde_json::from_str(&contents)
+            .map_err(|e| Error::Parse(format!("Invalid cassette {}: {}", path.display(), e)))
+    }
+
+    /// Write the cassette as pretty-printed JSON, creating parent directories
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let write_error = |e: std::io::Error| {
+            Error::Configuration(format!(
+                "Failed to write cassette {}: {}",
+                path.display(),
+                e
+            ))
+        };
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent).map_err(write_error)?;
+        }
+        let contents =
+            serde_json::to_string_pretty(self).map_err(|e| Error::Parse(e.to_string()))?;
+        std::fs::write(path, contents + "\n").map_err(write_error)
+    }
+}
+
+/// A request and the response it received
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The request sent
+    pub request: RecordedRequest,
+    /// The response received
+    pub response: RecordedResponse,
+}
+
+/// A sanitized request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// HTTP method, e.g. `GET`
+    pub method: String,
+    /// URL path, including any base path such as `/v1`
+    pub path: String,
+    /// Query parameters, sorted by name
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub query: Vec<(String, String)>,
+    /// JSON request body
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+impl RecordedRequest {
+    fn new(request: &Request) -> Self {
+        Self {
+            method: request.method().to_string(),
+            path: request.url().path().to_string(),
+            query: sanitized_query(request.url()),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .and_then(|bytes| serde_json::from_slice(bytes).ok())
+                .map(redacted),
+        }
+    }
+
+    fn matches(&self, other: &RecordedRequest) -> bool {
+        self.method == other.method && self.path == other.path && self.query == other.query
+    }
+}
+
+/// A sanitized response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers, without credentials
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// Body, if it was JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+    /// Body, if it was not JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+impl RecordedResponse {
+    fn new(status: StatusCode, headers: &HeaderMap, body: &[u8]) -> Self {
+        let headers = headers
+            .iter()
+            .filter(|(name, _)| {
+                !logging::is_sensitive_header(name.as_str())
+                    && !UNRECORDED_HEADERS.contains(&name.as_str())
+            })
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let (body, text) = match serde_json::from_slice(body) {
+            Ok(json) => (Some(redacted(json)), None),
+            Err(_) if body.is_empty() => (None, None),
+            Err(_) => (None, Some(String::from_utf8_lossy(body).into_owned())),
+        };
+
+        Self {
+            status: status.as_u16(),
+            headers,
+            body,
+            text,
+        }
+    }
+
+    fn to_response(&self, url: Url) -> Result<Response> {
+        let mut builder = http::Response::builder().status(self.status).url(url);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        let body = match (&self.body, &self.text) {
+            (Some(json), _) => json.to_string(),
+            (None, Some(text)) => text.clone(),
+            (None, None) => String::new(),
+        };
+
+        builder
+            .body(body)
+            .map(Response::from)
+            .map_err(|e| Error::Parse(format!("Invalid recorded response: {}", e)))
+    }
+}
+
+/// Middleware that records every interaction of the clients it is added to
+///
+/// Clones share one recording.
+#[derive(Debug, Clone, Default)]
+pub struct Recorder {
+    interactions: Arc<Mutex<Vec<Interaction>>>,
+}
+
+impl Recorder {
+    /// Create an empty recorder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The interactions recorded so far
+    pub fn cassette(&self) -> Cassette {
+        Cassette {
+            interactions: self.lock().clone(),
+        }
+    }
+
+    /// Save the interactions recorded so far to a JSON file
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.cassette().save(path)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Interaction>> {
+        self.interactions
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Middleware for Recorder {
+    async fn handle(&self, request: Request, next: Next<'_>) -> Result<Response> {
+        let recorded_request = RecordedRequest::new(&request);
+        let response = next.run(request).await?;
+
+        let (status, headers, url) = (
+            response.status(),
+            response.headers().clone(),
+            response.url().clone(),
+        );
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| Error::HttpClient(e.to_string()))?;
+
+        let mut rebuilt = http::Response::builder().status(status).url(url);
+        if let Some(rebuilt_headers) = rebuilt.headers_mut() {
+            *rebuilt_headers = headers.clone();
+        }
+        let rebuilt = rebuilt
+            .body(body.clone())
+            .map(Response::from)
+            .map_err(|e| Error::HttpClient(e.to_string()))?;
+
+        self.lock().push(Interaction {
+            request: recorded_request,
+            response: RecordedResponse::new(status, &headers, &body),
+        });
+        Ok(rebuilt)
+    }
+}
+
+/// Middleware that answers requests from a cassette instead of the network
+#[derive(Debug, Clone)]
+pub struct Replayer {
+    remaining: Arc<Mutex<Vec<Interaction>>>,
+}
+
+impl Replayer {
+    /// Replay a cassette
+    pub fn new(cassette: Cassette) -> Self {
+        Self {
+            remaining: Arc::new(Mutex::new(cassette.interactions)),
+        }
+    }
+
+    /// Replay a cassette saved with [`Recorder::save`]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Cassette::load(path).map(Self::new)
+    }
+
+    /// Interactions that have not been served yet
+    ///
+    /// Assert this is empty at the end of a test to check the code under test made
+    /// every recorded call.
+    pub fn remaining(&self) -> Vec<Interaction> {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Interaction>> {
+        self.remaining
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Middleware for Replayer {
+    async fn handle(&self, request: Request, _next: Next<'_>) -> Result<Response> {
+        let recorded_request = RecordedRequest::new(&request);
+
+        let interaction = {
+            let mut remaining = self.lock();
+            let index = remaining
+                .iter()
+                .position(|interaction| interaction.request.matches(&recorded_request))
+                .ok_or_else(|| {
+                    Error::Configuration(format!(
+                        "No recorded response left for {} {}",
+                        recorded_request.method,
+                        logging::redact_url(request.url())
+                    ))
+                })?;
+            remaining.remove(index)
+        };
+
+        interaction.response.to_response(request.url().clone())
+    }
+}
+
+fn sanitized_query(url: &Url) -> Vec<(String, String)> {
+    let mut query: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(key, value)| {
+            let value = if logging::is_sensitive_field(&key) {
+                REDACTED.to_string()
+            } else {
+                value.into_owned()
+            };
+            (key.into_owned(), value)
+        })
+        .collect();
+    query.sort();
+    query
+}
+
+fn redacted(mut value: Value) -> Value {
+    logging::redact_json(&mut value);
+    value
+}
@@ -0,0 +1,289 @@
+//! Reproducible analytics runs
+//!
+//! A [`ReportRun`] records every query made while producing a report: the endpoint,
+//! its parameters, the API version, and a checksum of the response. The run can be
+//! stored alongside the report and replayed later with the same pins; [`ReportRun::replay`]
+//! reports any query whose data has changed since the original run.
+//!
+//! ```rust
+//! use xrplsale::{reporting::ReportRun, Client};
+//!
+//! #[derive(serde::Serialize)]
+//! struct StatsQuery {
+//!     period: &'static str,
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let client = Client::builder().api_key("test").build()?;
+//! let mut run = ReportRun::new(&client, "q3-investor-report");
+//!
+//! let stats: serde_json::Value = run
+//!     .fetch(&client, "/analytics/projects/proj_123", &StatsQuery { period: "2024-Q3" })
+//!     .await?;
+//!
+//! // Persist the run next to the generated report
+//! let pins = serde_json::to_string(&run)?;
+//!
+//! // Later: re-execute with the same pins and check for drift
+//! let run: ReportRun = serde_json::from_str(&pins)?;
+//! let replay = run.replay(&client).await?;
+//! for drift in &replay.drifted {
+//!     println!("{} changed since {}", drift.path, run.recorded_at);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    client::Client,
+    error::{Error, Result},
+    query,
+};
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use url::{form_urlencoded, Url};
+
+/// A single pinned query within a report run
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PinnedQuery {
+    /// Path relative to the API base URL
+    pub path: String,
+    /// Query parameters, in the order they were sent
+    pub params: Vec<(String, String)>,
+    /// SHA-256 of the canonical JSON response, hex-encoded
+    pub checksum: String,
+}
+
+/// The recorded queries of an analytics run
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ReportRun {
+    /// Name of the report
+    pub name: String,
+    /// API version the run was made against (e.g. "v1", or "v1+v2" with an `X-API-Version`)
+    pub api_version: String,
+    /// When the run started
+    pub recorded_at: DateTime<Utc>,
+    /// Queries made during the run, in order
+    pub queries: Vec<PinnedQuery>,
+}
+
+/// A query whose response differs from the pinned run
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Drift {
+    /// Path relative to the API base URL
+    pub path: String,
+    /// Query parameters
+    pub params: Vec<(String, String)>,
+    /// Checksum recorded in the original run
+    pub expected: String,
+    /// Checksum of the replayed response
+    pub actual: String,
+}
+
+/// Outcome of replaying a report run
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ReplayReport {
+    /// The run as re-executed, with fresh checksums
+    pub run: ReportRun,
+    /// Queries whose data changed
+    pub drifted: Vec<Drift>,
+}
+
+impl ReplayReport {
+    /// Whether every query returned the same data as the original run
+    pub fn is_reproducible(&self) -> bool {
+        self.drifted.is_empty()
+    }
+}
+
+impl ReportRun {
+    /// Start recording a run against the client's API version
+    pub fn new<S: Into<String>>(client: &Client, name: S) -> Self {
+        Self {
+            name: name.into(),
+            api_version: api_version(client),
+            recorded_at: client.clock().utc_now(),
+            queries: Vec::new(),
+        }
+    }
+
+    /// Make a GET request and pin its parameters and response checksum
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Client to send the request with
+    /// * `path` - Path relative to the API base URL
+    /// * `query` - Query parameters, as for [`Client::get_with_query`]; `&()` for none
+    pub async fn fetch<T, Q>(&mut self, client: &Client, path: &str, query: &Q) -> Result<T>
+    where
+        T: DeserializeOwned,
+        Q: Serialize + ?Sized,
+    {
+        self.check_version(client)?;
+        let params = query::to_pairs(query)?;
+        let (value, _) = self.fetch_pinned(client, path, params).await?;
+        serde_json::from_value(value).map_err(|e| Error::Parse(e.to_string()))
+    }
+
+    /// Make a GET request with query pairs and pin it, returning the response and its
+    /// checksum
+    async fn fetch_pinned(
+        &mut self,
+        client: &Client,
+        path: &str,
+        params: Vec<(String, String)>,
+    ) -> Result<(serde_json::Value, String)> {
+        let value: serde_json::Value = client.get(&with_query(path, &params)).await?;
+        let checksum = checksum(&value);
+        self.queries.push(PinnedQuery {
+            path: path.to_string(),
+            params,
+            checksum: checksum.clone(),
+        });
+
+        Ok((value, checksum))
+    }
+
+    /// Re-execute every pinned query and report which responses changed
+    ///
+    /// Queries are sent with the `X-API-Version` the run was recorded with, whatever
+    /// the client's own, and skip the response cache so every checksum reflects the
+    /// API's current data. Fails with [`Error::Configuration`] if the client's base URL
+    /// targets a different API version path than the run.
+    pub async fn replay(&self, client: &Client) -> Result<ReplayReport> {
+        let client = self.pinned_client(client)?;
+        let mut run = ReportRun::new(&client, self.name.clone());
+
+        let mut drifted = Vec::new();
+        for pinned in &self.queries {
+            let (_, actual) = run
+                .fetch_pinned(&client, &pinned.path, pinned.params.clone())
+                .await?;
+
+            if actual != pinned.checksum {
+                drifted.push(Drift {
+                    path: pinned.path.clone(),
+                    params: pinned.params.clone(),
+                    expected: pinned.checksum.clone(),
+                    actual,
+                });
+            }
+        }
+
+        Ok(ReplayReport { run, drifted })
+    }
+
+    /// `client` without its response cache, requesting the API version of the run
+    fn pinned_client(&self, client: &Client) -> Result<Client> {
+        let client = client.without_cache();
+        if api_version(&client) == self.api_version {
+            return Ok(client);
+        }
+
+        let pinned = match self.api_version.split_once('+') {
+            Some((_, version)) => client.with_api_version(version.parse()?),
+            None => client,
+        };
+        self.check_version(&pinned)?;
+        Ok(pinned)
+    }
+
+    fn check_version(&self, client: &Client) -> Result<()> {
+        let current = api_version(client);
+        if current != self.api_version {
+            return Err(Error::Configuration(format!(
+                "Report '{}' is pinned to API version '{}' but the client uses '{}'",
+                self.name, self.api_version, current
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// API version from the client's base URL path, e.g. `v1`, with the requested
+/// `X-API-Version` if one is set, e.g. `v1+v2`
+fn api_version(client: &Client) -> String {
+    let path = Url::parse(client.base_url())
+        .map(|url| url.path().trim_matches('/').to_string())
+        .unwrap_or_default();
+    match client.api_version() {
+        Some(version) => format!("{}+{}", path, version),
+        None => path,
+    }
+}
+
+/// `path` with `params` appended as its query string
+fn with_query(path: &str, params: &[(String, String)]) -> String {
+    if params.is_empty() {
+        return path.to_string();
+    }
+    let query = form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(params)
+        .finish();
+    format!("{}?{}", path, query)
+}
+
+/// SHA-256 of the JSON value with object keys in sorted order
+fn checksum(value: &serde_json::Value) -> String {
+    let canonical = serde_json::to_string(&sort_keys(value)).unwrap_or_default();
+    hex::encode(Sha256::digest(canonical.as_bytes()))
+}
+
+fn sort_keys(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let sorted: BTreeMap<&String, serde_json::Value> =
+                map.iter().map(|(k, v)| (k, sort_keys(v))).collect();
+            serde_json::Value::Object(sorted.into_iter().map(|(k, v)| (k.clone(), v)).collect())
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.iter().map(sort_keys).collect())
+        }
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ApiVersion;
+    use serde_json::json;
+    use wiremock::{
+        matchers::{header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    #[tokio::test]
+    async fn replays_with_the_recorded_api_version() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/analytics/projects/proj_1"))
+            .and(header("X-API-Version", "2024-06-01"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "raised": "100" })))
+            .expect(2)
+            .mount(&server)
+            .await;
+        let client = Client::builder()
+            .api_key("t")
+            .base_url(format!("{}/v1/", server.uri()))
+            .max_retries(0)
+            .build()
+            .unwrap();
+
+        let pinned = client.with_api_version(ApiVersion::Other("2024-06-01".to_string()));
+        let mut run = ReportRun::new(&pinned, "report");
+        let _: serde_json::Value = run
+            .fetch(&pinned, "/analytics/projects/proj_1", &())
+            .await
+            .unwrap();
+        assert_eq!(run.api_version, "v1+2024-06-01");
+
+        let replay = run.replay(&client).await.unwrap();
+        assert!(replay.is_reproducible());
+        assert_eq!(replay.run.api_version, run.api_version);
+        server.verify().await;
+    }
+}
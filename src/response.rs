@@ -0,0 +1,218 @@
+//! Response metadata
+//!
+//! The plain request methods return just the deserialized body. The `*_with_meta`
+//! variants on [`Client`](crate::Client) also return the response's status, request ID,
+//! rate limit headers, and timing, e.g. to quote the request ID in a support ticket:
+//!
+//! ```rust
+//! use xrplsale::{types::projects::Project, ApiResponse, Client};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let client = Client::builder().api_key("test").build()?;
+//! let response: ApiResponse<Project> = client.get_with_meta("/projects/proj_abc123").await?;
+//!
+//! println!(
+//!     "{} in {:?} (request {})",
+//!     response.meta.status,
+//!     response.meta.elapsed,
+//!     response.meta.request_id.as_deref().unwrap_or("-"),
+//! );
+//! let project = response.data;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    error::{Error, Result},
+    rate_limit,
+};
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures::{Stream, StreamExt};
+use reqwest::{header::HeaderMap, StatusCode};
+use std::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Header carrying the API's ID for a request
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Header selecting, and in responses reporting, the API version
+pub const API_VERSION_HEADER: &str = "x-api-version";
+
+/// A deserialized response body with its metadata
+#[derive(Debug, Clone)]
+pub struct ApiResponse<T> {
+    /// The deserialized body
+    pub data: T,
+    /// Status, headers, and timing of the response
+    pub meta: ResponseMeta,
+}
+
+impl<T> ApiResponse<T> {
+    /// Discard the metadata
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+
+    /// Transform the body, keeping the metadata
+    pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> ApiResponse<U> {
+        ApiResponse {
+            data: f(self.data),
+            meta: self.meta,
+        }
+    }
+}
+
+/// Metadata of a response
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    /// HTTP status
+    pub status: StatusCode,
+    /// Value of the `X-Request-Id` header, to quote when contacting support
+    pub request_id: Option<String>,
+    /// API version that served the request, from the `X-API-Version` header
+    pub api_version: Option<String>,
+    /// Requests left in the current rate limit window, if reported
+    pub rate_limit_remaining: Option<u64>,
+    /// Time until the rate limit window resets, if reported
+    pub rate_limit_reset: Option<Duration>,
+    /// Time from sending the request, including retries, until the body was read
+    pub elapsed: Duration,
+    /// All response headers
+    pub headers: HeaderMap,
+}
+
+impl ResponseMeta {
+    pub(crate) fn new(
+        status: StatusCode,
+        headers: HeaderMap,
+        elapsed: Duration,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            status,
+            request_id: header(&headers, REQUEST_ID_HEADER),
+            api_version: header(&headers, API_VERSION_HEADER),
+            rate_limit_remaining: rate_limit::remaining(&headers),
+            rate_limit_reset: rate_limit::reset_after(&headers, now),
+            elapsed,
+            headers,
+        }
+    }
+}
+
+fn header(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+type BodyStream = futures::stream::BoxStream<'static, Result<Bytes>>;
+#[cfg(target_arch = "wasm32")]
+type BodyStream = futures::stream::LocalBoxStream<'static, Result<Bytes>>;
+
+/// A response body read incrementally instead of buffered in memory
+///
+/// Returned by [`Client::get_bytes_stream`](crate::Client::get_bytes_stream) for large
+/// downloads such as documents and exports. Yields the body in chunks as they arrive.
+///
+/// # Example
+///
+/// ```rust
+/// use xrplsale::Client;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = Client::builder().api_key("test").build()?;
+/// let download = client.get_bytes_stream("/exports/exp_123/download").await?;
+/// println!("{:?}, {:?} bytes", download.content_type, download.content_length);
+///
+/// let mut file = tokio::fs::File::create("export.csv").await?;
+/// download.write_to(&mut file).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ByteStream {
+    /// Status and headers of the response; `elapsed` covers the time until the headers arrived
+    pub meta: ResponseMeta,
+    /// Value of the `Content-Type` header
+    pub content_type: Option<String>,
+    /// Size of the body in bytes, if the server reported it and it was not compressed
+    pub content_length: Option<u64>,
+    body: BodyStream,
+}
+
+impl ByteStream {
+    pub(crate) fn new(response: reqwest::Response, elapsed: Duration, now: DateTime<Utc>) -> Self {
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let content_length = response.content_length();
+        let meta = ResponseMeta::new(response.status(), response.headers().clone(), elapsed, now);
+        let body = response
+            .bytes_stream()
+            .map(|chunk| chunk.map_err(|e| Error::HttpClient(e.to_string())));
+        // Browser response bodies can't be sent across threads
+        #[cfg(not(target_arch = "wasm32"))]
+        let body = body.boxed();
+        #[cfg(target_arch = "wasm32")]
+        let body = body.boxed_local();
+
+        Self {
+            meta,
+            content_type,
+            content_length,
+            body,
+        }
+    }
+
+    /// Copy the whole body into a writer, returning the number of bytes written
+    pub async fn write_to<W>(mut self, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let mut written = 0;
+        while let Some(chunk) = self.body.next().await {
+            let chunk = chunk?;
+            writer
+                .write_all(&chunk)
+                .await
+                .map_err(|e| Error::HttpClient(format!("Failed to write body: {}", e)))?;
+            written += chunk.len() as u64;
+        }
+        writer
+            .flush()
+            .await
+            .map_err(|e| Error::HttpClient(format!("Failed to write body: {}", e)))?;
+
+        Ok(written)
+    }
+}
+
+impl Stream for ByteStream {
+    type Item = Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.body.poll_next_unpin(cx)
+    }
+}
+
+impl fmt::Debug for ByteStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByteStream")
+            .field("meta", &self.meta)
+            .field("content_type", &self.content_type)
+            .field("content_length", &self.content_length)
+            .finish_non_exhaustive()
+    }
+}
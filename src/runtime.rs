@@ -0,0 +1,251 @@
+//! Timers and clocks that work both natively and in the browser
+//!
+//! Native builds use tokio's timer and `std::time`. On `wasm32`, where neither is
+//! available, the `wasm` feature switches to browser timers and `performance.now()`.
+//! Everything in the SDK that waits or measures time goes through this module, so the
+//! same [`Client`](crate::Client) runs under tokio and in a Yew or Leptos frontend.
+//!
+//! A client reads the time and sleeps through its [`Clock`]. Tests can replace the
+//! default [`SystemClock`] with a [`MockClock`] to run retries, backoff, rate limit
+//! holds, and token expiry without waiting:
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use xrplsale::{runtime::MockClock, Client};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> xrplsale::Result<()> {
+//! let clock = MockClock::new().auto_advance();
+//! let client = Client::builder()
+//!     .api_key("test-key")
+//!     .base_url("http://127.0.0.1:1") // nothing listens here
+//!     .max_retries(3)
+//!     .retry_delay(Duration::from_secs(1))
+//!     .clock(clock.clone())
+//!     .build()?;
+//!
+//! assert!(client.get::<serde_json::Value>("/projects").await.is_err());
+//! // The backoff was recorded, not waited out
+//! assert_eq!(clock.sleeps(), [1, 2, 4].map(Duration::from_secs));
+//! assert_eq!(clock.elapsed(), Duration::from_secs(7));
+//! # Ok(())
+//! # }
+//! ```
+
+use chrono::{DateTime, Utc};
+use std::{
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Poll, Waker},
+    time::Duration,
+};
+
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!("building for wasm32 requires the `wasm` feature");
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::{Instant, SystemTime, UNIX_EPOCH};
+#[cfg(target_arch = "wasm32")]
+pub use web_time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Future returned by [`Clock::sleep`]
+#[cfg(not(target_arch = "wasm32"))]
+pub type Sleep = futures::future::BoxFuture<'static, ()>;
+/// Future returned by [`Clock::sleep`]
+#[cfg(target_arch = "wasm32")]
+pub type Sleep = futures::future::LocalBoxFuture<'static, ()>;
+
+/// Source of time for a client
+///
+/// Used for retry backoff, rate limit holds, request deadlines, circuit breaker
+/// cooldowns, cache freshness, OAuth token expiry, and polling intervals.
+pub trait Clock: Send + Sync + std::fmt::Debug + 'static {
+    /// The current monotonic time
+    fn now(&self) -> Instant;
+
+    /// The current wall-clock time
+    fn utc_now(&self) -> DateTime<Utc>;
+
+    /// Wait for `duration`
+    fn sleep(&self, duration: Duration) -> Sleep;
+}
+
+/// The real clock: tokio's timer natively, browser timers on `wasm32`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn utc_now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> Sleep {
+        Box::pin(sleep(duration))
+    }
+}
+
+/// The clock clients use unless [`ClientBuilder::clock`](crate::ClientBuilder::clock)
+/// sets another
+pub(crate) fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+/// A clock that only moves when told to, for tests
+///
+/// Time starts at the moment the clock is created and moves forward with
+/// [`advance`](Self::advance), which also completes the sleeps that are due. With
+/// [`auto_advance`](Self::auto_advance), every sleep instead moves the clock forward by
+/// its duration and completes at once, which suits tests that just need retries and
+/// backoff to finish.
+///
+/// Clones share the same time.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    shared: Arc<MockShared>,
+}
+
+#[derive(Debug)]
+struct MockShared {
+    start: Instant,
+    start_utc: DateTime<Utc>,
+    auto_advance: bool,
+    state: Mutex<MockState>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    elapsed: Duration,
+    sleeps: Vec<Duration>,
+    waiters: Vec<(Duration, Waker)>,
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockClock {
+    /// A clock starting at the current time
+    pub fn new() -> Self {
+        Self::starting_at(Utc::now())
+    }
+
+    /// A clock whose wall-clock time starts at `start`
+    pub fn starting_at(start: DateTime<Utc>) -> Self {
+        Self::build(start, false)
+    }
+
+    /// Complete sleeps immediately, moving the clock forward by their duration
+    pub fn auto_advance(self) -> Self {
+        let start_utc = self.shared.start_utc;
+        Self::build(start_utc, true)
+    }
+
+    fn build(start_utc: DateTime<Utc>, auto_advance: bool) -> Self {
+        Self {
+            shared: Arc::new(MockShared {
+                start: Instant::now(),
+                start_utc,
+                auto_advance,
+                state: Mutex::new(MockState::default()),
+            }),
+        }
+    }
+
+    /// Move the clock forward, completing every sleep that is now due
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.lock();
+        state.elapsed += duration;
+        let elapsed = state.elapsed;
+        let (due, waiting) = std::mem::take(&mut state.waiters)
+            .into_iter()
+            .partition::<Vec<_>, _>(|(until, _)| *until <= elapsed);
+        state.waiters = waiting;
+        drop(state);
+
+        for (_, waker) in due {
+            waker.wake();
+        }
+    }
+
+    /// Time the clock has moved forward since it was created
+    pub fn elapsed(&self) -> Duration {
+        self.lock().elapsed
+    }
+
+    /// Durations of every sleep started on this clock, in order
+    pub fn sleeps(&self) -> Vec<Duration> {
+        self.lock().sleeps.clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        self.shared
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.shared.start + self.elapsed()
+    }
+
+    fn utc_now(&self) -> DateTime<Utc> {
+        self.shared.start_utc
+            + chrono::Duration::from_std(self.elapsed()).unwrap_or(chrono::Duration::MAX)
+    }
+
+    fn sleep(&self, duration: Duration) -> Sleep {
+        let until = {
+            let mut state = self.lock();
+            state.sleeps.push(duration);
+            state.elapsed + duration
+        };
+        if self.shared.auto_advance {
+            let mut state = self.lock();
+            state.elapsed = state.elapsed.max(until);
+            return Box::pin(std::future::ready(()));
+        }
+
+        let clock = self.clone();
+        Box::pin(futures::future::poll_fn(move |cx| {
+            let mut state = clock.lock();
+            if state.elapsed >= until {
+                Poll::Ready(())
+            } else {
+                state.waiters.push((until, cx.waker().clone()));
+                Poll::Pending
+            }
+        }))
+    }
+}
+
+/// Wait for `duration` without blocking the thread
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
+
+/// Run `future`, failing with `None` if it takes longer than `duration`
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn timeout<F: std::future::Future>(
+    duration: Duration,
+    future: F,
+) -> Option<F::Output> {
+    use futures::future::{select, Either};
+
+    futures::pin_mut!(future);
+    let timer = sleep(duration);
+    futures::pin_mut!(timer);
+    match select(future, timer).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
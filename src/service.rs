@@ -0,0 +1,439 @@
+//! The client as a [`tower::Service`]
+//!
+//! [`Client`] implements `Service<ApiRequest>`, so any tower middleware can wrap the
+//! request path: timeouts, concurrency limits, buffering, load shedding, or your own
+//! layers. Requests go through the same authentication, middleware, rate limiting, and
+//! retries as the typed methods, and answer with the JSON body and response metadata:
+//!
+//! ```rust,no_run
+//! use tower::{Service, ServiceExt};
+//! use xrplsale::{service::ApiRequest, Client};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut client = Client::builder().api_key("your-api-key").build()?;
+//!
+//! let request = ApiRequest::get("/projects").query("status", "active");
+//! let response = client.ready().await?.call(request).await?;
+//! println!("{} in {:?}", response.data, response.meta.elapsed);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Compose it with layers from your own `tower` dependency, e.g. with the `timeout` and
+//! `limit` features enabled:
+//!
+//! ```rust,ignore
+//! use std::time::Duration;
+//! use tower::ServiceBuilder;
+//!
+//! let service = ServiceBuilder::new()
+//!     .timeout(Duration::from_secs(5))
+//!     .concurrency_limit(8)
+//!     .service(client.clone());
+//! ```
+//!
+//! The client's own retry, rate-limit, and circuit-breaker handling is also available
+//! as layers, for stacks that put other layers in between or wrap a service other
+//! than the client:
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use tower::ServiceBuilder;
+//! use xrplsale::{
+//!     circuit_breaker::CircuitBreakerConfig,
+//!     service::{CircuitBreakerLayer, RateLimitLayer, RetryLayer},
+//!     Client,
+//! };
+//!
+//! # fn main() -> xrplsale::Result<()> {
+//! let client = Client::builder().api_key("your-api-key").max_retries(0).build()?;
+//! let service = ServiceBuilder::new()
+//!     .layer(RetryLayer::new(3, Duration::from_millis(500)))
+//!     .layer(CircuitBreakerLayer::new(CircuitBreakerConfig::new()))
+//!     .layer(RateLimitLayer::new(client.rate_limits()))
+//!     .service(client);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! When adding a retry layer, build the client with `max_retries(0)` so requests are
+//! not retried twice. Like [`Client::request`], the service bypasses the response
+//! cache.
+
+use crate::{
+    circuit_breaker::{CircuitBreaker, CircuitBreakerConfig},
+    client::{endpoint_template, Client},
+    error::Error,
+    priority::Priority,
+    rate_limit::{RateLimitGuard, RateLimiter},
+    response::ApiResponse,
+    runtime,
+};
+use reqwest::Method;
+use serde_json::Value;
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tower::{Layer, Service, ServiceExt};
+
+#[cfg(not(target_arch = "wasm32"))]
+type ResponseFuture = futures::future::BoxFuture<'static, Result<ApiResponse<Value>, Error>>;
+#[cfg(target_arch = "wasm32")]
+type ResponseFuture = futures::future::LocalBoxFuture<'static, Result<ApiResponse<Value>, Error>>;
+
+/// A request to the API, independent of any client
+///
+/// Cheap to clone, so retry layers can resend it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiRequest {
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+    body: Option<Value>,
+}
+
+impl ApiRequest {
+    /// A request with any method to a path relative to the base URL, e.g. `/projects`
+    pub fn new<S: Into<String>>(method: Method, path: S) -> Self {
+        Self {
+            method,
+            path: path.into(),
+            query: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// A `GET` request
+    pub fn get<S: Into<String>>(path: S) -> Self {
+        Self::new(Method::GET, path)
+    }
+
+    /// A `POST` request
+    pub fn post<S: Into<String>>(path: S) -> Self {
+        Self::new(Method::POST, path)
+    }
+
+    /// A `PUT` request
+    pub fn put<S: Into<String>>(path: S) -> Self {
+        Self::new(Method::PUT, path)
+    }
+
+    /// A `PATCH` request
+    pub fn patch<S: Into<String>>(path: S) -> Self {
+        Self::new(Method::PATCH, path)
+    }
+
+    /// A `DELETE` request
+    pub fn delete<S: Into<String>>(path: S) -> Self {
+        Self::new(Method::DELETE, path)
+    }
+
+    /// Add a query parameter
+    pub fn query<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.query.push((key.into(), value.into()));
+        self
+    }
+
+    /// Send a JSON body
+    pub fn json(mut self, body: Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// The HTTP method
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// The path, relative to the base URL
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Query parameters, in the order they were added
+    pub fn query_pairs(&self) -> &[(String, String)] {
+        &self.query
+    }
+
+    /// The JSON body, if any
+    pub fn body(&self) -> Option<&Value> {
+        self.body.as_ref()
+    }
+
+    /// Whether sending the request twice has the same effect as sending it once
+    ///
+    /// Useful in retry policies.
+    pub fn is_idempotent(&self) -> bool {
+        matches!(
+            self.method,
+            Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+        )
+    }
+}
+
+impl Service<ApiRequest> for Client {
+    type Response = ApiResponse<Value>;
+    type Error = Error;
+    type Future = ResponseFuture;
+
+    /// Always ready; limit concurrency with the client's
+    /// [`max_concurrent_requests`](crate::ClientBuilder::max_concurrent_requests) or a
+    /// tower layer
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: ApiRequest) -> Self::Future {
+        let client = self.clone();
+        Box::pin(async move { client.send_api_request(request).await })
+    }
+}
+
+/// Retries idempotent requests that fail with a retryable error
+///
+/// Waits `delay`, doubling after each attempt, or as long as a `429` response's
+/// `Retry-After` asks if that is longer, like the client's own retries.
+#[derive(Debug, Clone)]
+pub struct RetryLayer {
+    max_retries: usize,
+    delay: Duration,
+}
+
+impl RetryLayer {
+    /// Retry up to `max_retries` times, starting with `delay` between attempts
+    pub fn new(max_retries: usize, delay: Duration) -> Self {
+        Self { max_retries, delay }
+    }
+}
+
+impl<S> Layer<S> for RetryLayer {
+    type Service = RetryService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RetryService {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+/// Service built by [`RetryLayer`]
+#[derive(Debug, Clone)]
+pub struct RetryService<S> {
+    inner: S,
+    layer: RetryLayer,
+}
+
+impl<S> Service<ApiRequest> for RetryService<S>
+where
+    S: Service<ApiRequest, Response = ApiResponse<Value>, Error = Error> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = ApiResponse<Value>;
+    type Error = Error;
+    type Future = ResponseFuture;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: ApiRequest) -> Self::Future {
+        let RetryLayer { max_retries, delay } = self.layer;
+        let mut inner = ready_inner(&mut self.inner);
+
+        Box::pin(async move {
+            let mut attempt = 0;
+            loop {
+                let error = match inner.call(request.clone()).await {
+                    Err(e)
+                        if attempt < max_retries && request.is_idempotent() && e.is_retryable() =>
+                    {
+                        e
+                    }
+                    result => return result,
+                };
+
+                let mut wait = delay * 2_u32.pow(attempt as u32);
+                if let Error::RateLimit {
+                    retry_after: Some(seconds),
+                    ..
+                } = error
+                {
+                    wait = wait.max(Duration::from_secs(seconds));
+                }
+                log::debug!("Request failed, retrying in {:?}: {}", wait, error);
+                runtime::sleep(wait).await;
+
+                attempt += 1;
+                inner.ready().await?;
+            }
+        })
+    }
+}
+
+/// Holds requests to endpoints that are rate limited until their window resets
+///
+/// Reads the limits a client has observed, so pass it that client's
+/// [`rate_limits`](Client::rate_limits). With [`with_limiter`](Self::with_limiter),
+/// requests also wait for a client-side [`RateLimiter`].
+#[derive(Debug, Clone)]
+pub struct RateLimitLayer {
+    guard: RateLimitGuard,
+    limiter: Option<Arc<dyn RateLimiter>>,
+}
+
+impl RateLimitLayer {
+    /// Hold requests according to the limits tracked by `guard`
+    pub fn new(guard: RateLimitGuard) -> Self {
+        Self {
+            guard,
+            limiter: None,
+        }
+    }
+
+    /// Also spend every request from `limiter`'s budget, at the default priority
+    pub fn with_limiter<L: RateLimiter>(mut self, limiter: L) -> Self {
+        self.limiter = Some(Arc::new(limiter));
+        self
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitService {
+            inner,
+            guard: self.guard.clone(),
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+/// Service built by [`RateLimitLayer`]
+#[derive(Debug, Clone)]
+pub struct RateLimitService<S> {
+    inner: S,
+    guard: RateLimitGuard,
+    limiter: Option<Arc<dyn RateLimiter>>,
+}
+
+impl<S> Service<ApiRequest> for RateLimitService<S>
+where
+    S: Service<ApiRequest, Response = ApiResponse<Value>, Error = Error> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = ApiResponse<Value>;
+    type Error = Error;
+    type Future = ResponseFuture;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: ApiRequest) -> Self::Future {
+        let guard = self.guard.clone();
+        let limiter = self.limiter.clone();
+        let mut inner = ready_inner(&mut self.inner);
+
+        Box::pin(async move {
+            while let Some(wait) = guard.retry_in(request.method(), request.path()) {
+                log::debug!(
+                    "Holding request to {} {} for {:?} (rate limited)",
+                    request.method(),
+                    request.path(),
+                    wait
+                );
+                runtime::sleep(wait).await;
+            }
+            if let Some(limiter) = limiter {
+                while let Some(wait) = limiter.try_acquire(Priority::default()).await? {
+                    runtime::sleep(wait).await;
+                }
+            }
+            inner.call(request).await
+        })
+    }
+}
+
+/// Fails requests fast while their endpoint keeps failing
+///
+/// Circuits open and close as described in [`circuit_breaker`](crate::circuit_breaker),
+/// and a refused request fails with the same [`Error::CircuitOpen`]. Circuit state is
+/// shared by every service the layer builds.
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerLayer {
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl CircuitBreakerLayer {
+    /// Break circuits according to `config`
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            breaker: Arc::new(CircuitBreaker::new(config, runtime::system_clock())),
+        }
+    }
+}
+
+impl<S> Layer<S> for CircuitBreakerLayer {
+    type Service = CircuitBreakerService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CircuitBreakerService {
+            inner,
+            breaker: self.breaker.clone(),
+        }
+    }
+}
+
+/// Service built by [`CircuitBreakerLayer`]
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerService<S> {
+    inner: S,
+    breaker: Arc<CircuitBreaker>,
+}
+
+impl<S> Service<ApiRequest> for CircuitBreakerService<S>
+where
+    S: Service<ApiRequest, Response = ApiResponse<Value>, Error = Error> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = ApiResponse<Value>;
+    type Error = Error;
+    type Future = ResponseFuture;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: ApiRequest) -> Self::Future {
+        let breaker = self.breaker.clone();
+        let mut inner = ready_inner(&mut self.inner);
+
+        Box::pin(async move {
+            let endpoint = format!(
+                "{} {}",
+                request.method(),
+                endpoint_template(request.path(), "")
+            );
+            let permit = breaker.acquire(&endpoint)?;
+            let result = inner.call(request).await;
+            // Like the client's breaker, only server errors and transport failures count
+            let healthy = !matches!(
+                &result,
+                Err(Error::HttpClient(_)) | Err(Error::Api { status: 500.., .. })
+            );
+            permit.record(healthy);
+            result
+        })
+    }
+}
+
+/// Take the service that was polled ready, leaving a clone in its place
+fn ready_inner<S: Clone>(inner: &mut S) -> S {
+    let clone = inner.clone();
+    std::mem::replace(inner, clone)
+}
@@ -0,0 +1,149 @@
+//! Airdrops service for bonus token campaigns
+
+use crate::{
+    client::Client,
+    error::Result,
+    ids::ProjectId,
+    operation::Operation,
+    types::airdrops::{Airdrop, AirdropDelivery, AirdropRecipient, CreateAirdropRequest},
+};
+use serde::Serialize;
+
+/// Service for creating and running airdrop campaigns
+///
+/// A campaign starts as a draft: preview its recipients, adjust them if needed (for
+/// example by importing a reviewed CSV), then execute it and track each delivery.
+///
+/// # Example
+///
+/// ```rust
+/// # use xrplsale::{
+/// #     types::{airdrops::{AmountRule, CreateAirdropRequest, SnapshotCriteria}, common::AsOf},
+/// #     Client, ProjectId,
+/// # };
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = Client::builder().api_key("test").build()?;
+/// let airdrop = client
+///     .airdrops()
+///     .create(CreateAirdropRequest {
+///         project_id: ProjectId::new("proj_abc123"),
+///         name: "Early investor bonus".to_string(),
+///         criteria: SnapshotCriteria {
+///             as_of: AsOf::Time(chrono::Utc::now()),
+///             min_investment_xrp: Some("100".to_string()),
+///             tiers: vec![1],
+///             invested_before: None,
+///         },
+///         amount_rule: AmountRule::ProRata {
+///             total_amount: "1000000".to_string(),
+///         },
+///     })
+///     .await?;
+///
+/// std::fs::write("recipients.csv", client.airdrops().export_csv(&airdrop.id).await?)?;
+/// client.airdrops().execute(&airdrop.id).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AirdropsService {
+    client: Client,
+}
+
+impl AirdropsService {
+    /// Create a new airdrops service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Create a draft campaign
+    pub async fn create(&self, request: CreateAirdropRequest) -> Result<Airdrop> {
+        self.client.post("/airdrops", Some(&request)).await
+    }
+
+    /// Get a campaign and its distribution progress
+    pub async fn get(&self, airdrop_id: &str) -> Result<Airdrop> {
+        self.client.get(&format!("/airdrops/{}", airdrop_id)).await
+    }
+
+    /// List a project's campaigns
+    pub async fn list(&self, project_id: impl Into<ProjectId>) -> Result<Vec<Airdrop>> {
+        let project_id: ProjectId = project_id.into();
+        let query = AirdropQuery {
+            project_id: &project_id,
+        };
+        self.client.get_list_with_query("/airdrops", &query).await
+    }
+
+    /// Preview the recipients and amounts of a campaign
+    pub async fn preview(&self, airdrop_id: &str) -> Result<Vec<AirdropRecipient>> {
+        self.client
+            .get_list(&format!("/airdrops/{}/recipients", airdrop_id))
+            .await
+    }
+
+    /// Replace a draft campaign's recipients
+    pub async fn set_recipients(
+        &self,
+        airdrop_id: &str,
+        recipients: Vec<AirdropRecipient>,
+    ) -> Result<Airdrop> {
+        self.client
+            .put(
+                &format!("/airdrops/{}/recipients", airdrop_id),
+                Some(&serde_json::json!({ "recipients": recipients })),
+            )
+            .await
+    }
+
+    /// Replace a draft campaign's recipients with the rows of an `account,amount` CSV
+    ///
+    /// The CSV is checked with [`AirdropRecipient::from_csv`] before anything is sent.
+    pub async fn import_csv(&self, airdrop_id: &str, csv: &str) -> Result<Airdrop> {
+        let recipients = AirdropRecipient::from_csv(csv)?;
+        self.set_recipients(airdrop_id, recipients).await
+    }
+
+    /// Export a campaign's recipients as an `account,amount` CSV
+    pub async fn export_csv(&self, airdrop_id: &str) -> Result<String> {
+        let recipients = self.preview(airdrop_id).await?;
+        Ok(AirdropRecipient::to_csv(&recipients))
+    }
+
+    /// Start distributing a draft campaign
+    pub async fn execute(&self, airdrop_id: &str) -> Result<Airdrop> {
+        self.client
+            .post(&format!("/airdrops/{}/execute", airdrop_id), None::<&()>)
+            .await
+    }
+
+    /// Start distributing a draft campaign and track it until every delivery is made
+    pub async fn start_execution(&self, airdrop_id: &str) -> Result<Operation<Airdrop>> {
+        let response = self
+            .client
+            .post_with_meta(&format!("/airdrops/{}/execute", airdrop_id), None::<&()>)
+            .await?;
+        Operation::from_response(self.client.clone(), response)
+    }
+
+    /// Cancel a campaign; deliveries already made are not reversed
+    pub async fn cancel(&self, airdrop_id: &str) -> Result<Airdrop> {
+        self.client
+            .post(&format!("/airdrops/{}/cancel", airdrop_id), None::<&()>)
+            .await
+    }
+
+    /// Get the delivery status of each recipient
+    pub async fn deliveries(&self, airdrop_id: &str) -> Result<Vec<AirdropDelivery>> {
+        self.client
+            .get_list(&format!("/airdrops/{}/deliveries", airdrop_id))
+            .await
+    }
+}
+
+/// Query parameters of the campaign listing
+#[derive(Serialize)]
+struct AirdropQuery<'a> {
+    project_id: &'a ProjectId,
+}
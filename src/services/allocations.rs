@@ -0,0 +1,104 @@
+//! Allocations service for token distribution readiness
+
+use crate::{
+    client::Client,
+    error::Result,
+    ids::ProjectId,
+    types::allocations::{AccountAllocation, TrustlineStatus},
+};
+
+/// Service for checking investors' readiness to receive allocated tokens
+#[derive(Debug, Clone)]
+pub struct AllocationsService {
+    client: Client,
+}
+
+impl AllocationsService {
+    /// Create a new allocations service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Get the tokens allocated to a wallet in a project
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The investor's XRPL address
+    /// * `project_id` - The project ID
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use xrplsale::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let allocation = client
+    ///     .allocations()
+    ///     .get("rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH", "proj_abc123")
+    ///     .await?;
+    /// println!(
+    ///     "{} allocated, {} distributed, {} vesting",
+    ///     allocation.allocated_tokens, allocation.distributed_tokens, allocation.vesting_remaining
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(
+        &self,
+        account: &str,
+        project_id: impl Into<ProjectId>,
+    ) -> Result<AccountAllocation> {
+        let project_id: ProjectId = project_id.into();
+        self.client
+            .get(&format!(
+                "/projects/{}/allocations/{}",
+                project_id.path_segment(),
+                account
+            ))
+            .await
+    }
+
+    /// Check whether a wallet has a trustline able to hold its allocation
+    ///
+    /// Wallets that are not [`ready`](TrustlineStatus::ready) must submit a `TrustSet`
+    /// transaction before tokens can be distributed to them; with the `xrpl` feature,
+    /// `trustlines::build_trust_set` builds one.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The investor's XRPL address
+    /// * `project_id` - The project ID
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use xrplsale::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let status = client
+    ///     .allocations()
+    ///     .check_trustline("rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH", "proj_abc123")
+    ///     .await?;
+    /// if !status.ready {
+    ///     println!("Set a trustline for {} issued by {}", status.currency, status.issuer);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn check_trustline(
+        &self,
+        account: &str,
+        project_id: impl Into<ProjectId>,
+    ) -> Result<TrustlineStatus> {
+        let project_id: ProjectId = project_id.into();
+        self.client
+            .get(&format!(
+                "/projects/{}/allocations/{}/trustline",
+                project_id.path_segment(),
+                account
+            ))
+            .await
+    }
+}
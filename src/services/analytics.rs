@@ -0,0 +1,21 @@
+//! Analytics service for platform and project metrics
+//!
+//! This snapshot carries only the service definition; the core endpoints live in
+//! the upstream module. Additional endpoints are grouped in submodules.
+
+use crate::client::Client;
+
+mod launches;
+
+/// Service for platform and project analytics
+#[derive(Debug, Clone)]
+pub struct AnalyticsService {
+    client: Client,
+}
+
+impl AnalyticsService {
+    /// Create a new analytics service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
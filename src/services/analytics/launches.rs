@@ -0,0 +1,121 @@
+//! Launch post-mortems comparing sales and their investors
+
+use super::AnalyticsService;
+use crate::{
+    error::Result,
+    ids::ProjectId,
+    types::{
+        launch_analytics::{ComparisonMetric, ComparisonRange, InvestorCohorts, ProjectComparison},
+        market::Granularity,
+    },
+};
+use serde::Serialize;
+
+impl AnalyticsService {
+    /// Compare metrics of several projects over the same period of their sales
+    ///
+    /// Each project's series starts at its own sale start, so the points of every
+    /// series line up by offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_ids` - The projects to compare
+    /// * `metrics` - The metrics to return for each point
+    /// * `range` - Period after each sale start, and the length of each point
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use xrplsale::{
+    /// #     types::launch_analytics::{ComparisonMetric, ComparisonRange},
+    /// #     Client,
+    /// # };
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let comparison = client
+    ///     .analytics()
+    ///     .compare(
+    ///         ["proj_abc123", "proj_def456"],
+    ///         &[ComparisonMetric::AmountRaised, ComparisonMetric::Investors],
+    ///         ComparisonRange::first_days(14),
+    ///     )
+    ///     .await?;
+    ///
+    /// for series in &comparison.series {
+    ///     let raised: Vec<_> = series.values(ComparisonMetric::AmountRaised).collect();
+    ///     println!("{}: {:?}", series.project_id, raised);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn compare<I, S>(
+        &self,
+        project_ids: I,
+        metrics: &[ComparisonMetric],
+        range: ComparisonRange,
+    ) -> Result<ProjectComparison>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<ProjectId>,
+    {
+        let project_ids: Vec<String> = project_ids
+            .into_iter()
+            .map(|id| id.into().to_string())
+            .collect();
+        let metrics: Vec<&str> = metrics.iter().map(ComparisonMetric::as_str).collect();
+        let query = CompareQuery {
+            project_ids: project_ids.join(","),
+            metrics: metrics.join(","),
+            days: range.days,
+            interval: range.interval,
+        };
+
+        self.client
+            .get_with_query("/analytics/projects/compare", &query)
+            .await
+    }
+
+    /// Split a project's investors into first-time and returning cohorts
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use xrplsale::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let cohorts = client.analytics().cohorts("proj_abc123").await?;
+    /// println!(
+    ///     "{} first-time and {} returning investors",
+    ///     cohorts.first_time.investors, cohorts.returning.investors
+    /// );
+    /// if let Some(retention) = cohorts.first_time.retention() {
+    ///     println!("{:.0}% of newcomers invested again", retention * 100.0);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn cohorts(&self, project_id: impl Into<ProjectId>) -> Result<InvestorCohorts> {
+        let project_id: ProjectId = project_id.into();
+        self.client
+            .get(&format!(
+                "/analytics/projects/{}/cohorts",
+                project_id.path_segment()
+            ))
+            .await
+    }
+}
+
+/// Query parameters of a comparison
+#[derive(Serialize)]
+struct CompareQuery {
+    project_ids: String,
+    metrics: String,
+    days: u32,
+    interval: Granularity,
+}
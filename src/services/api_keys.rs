@@ -0,0 +1,76 @@
+//! API keys service for scoped key management
+
+use crate::{
+    client::Client,
+    error::Result,
+    types::api_keys::{ApiKey, ApiKeySecret, CreateApiKeyRequest, RotateApiKeyRequest},
+};
+
+/// Service for creating, listing, rotating, and revoking API keys
+///
+/// Use this to automate key rotation from a secrets manager. Secrets are only returned
+/// when a key is created or rotated.
+#[derive(Debug, Clone)]
+pub struct ApiKeysService {
+    client: Client,
+}
+
+impl ApiKeysService {
+    /// Create a new API keys service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// List the account's API keys
+    pub async fn list(&self) -> Result<Vec<ApiKey>> {
+        self.client.get_list("/api-keys").await
+    }
+
+    /// Get a single API key
+    pub async fn get(&self, key_id: &str) -> Result<ApiKey> {
+        self.client.get(&format!("/api-keys/{}", key_id)).await
+    }
+
+    /// Create an API key
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use xrplsale::{types::api_keys::CreateApiKeyRequest, Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let created = client
+    ///     .auth()
+    ///     .api_keys()
+    ///     .create(CreateApiKeyRequest {
+    ///         name: "reporting".to_string(),
+    ///         scopes: vec!["analytics:read".to_string()],
+    ///         expires_at: Some(chrono::Utc::now() + chrono::Duration::days(90)),
+    ///     })
+    ///     .await?;
+    /// println!("store this secret: {}", created.secret);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(&self, request: CreateApiKeyRequest) -> Result<ApiKeySecret> {
+        self.client.post("/api-keys", Some(&request)).await
+    }
+
+    /// Rotate an API key, returning its new secret
+    ///
+    /// # Arguments
+    ///
+    /// * `key_id` - The key to rotate
+    /// * `request` - Grace period for the old secret and expiry for the new one
+    pub async fn rotate(&self, key_id: &str, request: RotateApiKeyRequest) -> Result<ApiKeySecret> {
+        self.client
+            .post(&format!("/api-keys/{}/rotate", key_id), Some(&request))
+            .await
+    }
+
+    /// Revoke an API key immediately
+    pub async fn revoke(&self, key_id: &str) -> Result<ApiKey> {
+        self.client.delete(&format!("/api-keys/{}", key_id)).await
+    }
+}
@@ -0,0 +1,24 @@
+//! Authentication service for tokens and credentials
+//!
+//! This snapshot carries only the service definition; the core endpoints live in
+//! the upstream module. Additional endpoints are grouped in submodules.
+
+use crate::client::Client;
+
+mod api_keys;
+mod session;
+#[cfg(feature = "xumm")]
+mod xumm;
+
+/// Service for authentication
+#[derive(Debug, Clone)]
+pub struct AuthService {
+    client: Client,
+}
+
+impl AuthService {
+    /// Create a new auth service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
@@ -0,0 +1,11 @@
+//! Access to the account's API keys
+
+use super::AuthService;
+use crate::services::ApiKeysService;
+
+impl AuthService {
+    /// Manage the account's scoped API keys
+    pub fn api_keys(&self) -> ApiKeysService {
+        ApiKeysService::new(self.client.clone())
+    }
+}
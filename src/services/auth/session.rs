@@ -0,0 +1,48 @@
+//! The authenticated account and its session
+
+use super::AuthService;
+use crate::{
+    error::Result,
+    types::session::{Account, TokenIntrospection},
+};
+use serde_json::json;
+
+impl AuthService {
+    /// Get the account the client is authenticated as
+    pub async fn me(&self) -> Result<Account> {
+        self.client.get("/auth/me").await
+    }
+
+    /// Invalidate the current session token
+    ///
+    /// The client's auth token is cleared as well, so later requests fall back to the
+    /// configured API key or client credentials.
+    pub async fn logout(&self) -> Result<()> {
+        self.client.post::<(), ()>("/auth/logout", None).await?;
+        self.client.set_auth_token(None::<String>).await;
+        Ok(())
+    }
+
+    /// Check a token's validity, expiry, and scopes
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use xrplsale::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// # let token = "session-token";
+    /// let info = client.auth().introspect(token).await?;
+    /// if info.expires_within(chrono::Duration::minutes(5)) {
+    ///     // re-authenticate before requests start failing
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn introspect(&self, token: &str) -> Result<TokenIntrospection> {
+        self.client
+            .post("/auth/introspect", Some(&json!({ "token": token })))
+            .await
+    }
+}
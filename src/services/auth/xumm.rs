@@ -0,0 +1,111 @@
+//! Xaman (formerly Xumm) wallet sign-in
+
+use super::AuthService;
+use crate::{
+    error::{Error, Result},
+    types::xumm::{XummSession, XummSignIn, XummSignInState, XummSignInStatus},
+};
+use std::time::Duration;
+
+impl AuthService {
+    /// Create a Xaman sign-in request
+    ///
+    /// The platform creates the sign-in payload with Xaman, so no Xaman API credentials
+    /// are needed. Show the returned QR code or deep link to the user.
+    pub async fn create_xumm_sign_in(&self) -> Result<XummSignIn> {
+        self.client.post("/auth/xumm/sign-in", None::<&()>).await
+    }
+
+    /// Get the current state of a Xaman sign-in request
+    pub async fn xumm_sign_in_status(&self, uuid: &str) -> Result<XummSignInStatus> {
+        self.client
+            .get(&format!("/auth/xumm/sign-in/{}", uuid))
+            .await
+    }
+
+    /// Exchange a signed Xaman request for a platform session token
+    ///
+    /// The token is also set as the client's auth token.
+    pub async fn exchange_xumm_sign_in(&self, uuid: &str) -> Result<XummSession> {
+        let session: XummSession = self
+            .client
+            .post(&format!("/auth/xumm/sign-in/{}/session", uuid), None::<&()>)
+            .await?;
+        self.client
+            .set_auth_token(Some(session.token.clone()))
+            .await;
+        Ok(session)
+    }
+
+    /// Poll a Xaman sign-in request until it is signed, then exchange it for a session
+    ///
+    /// Fails with [`Error::Unauthorized`] if the user rejects the request or it expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `sign_in` - The request returned by [`create_xumm_sign_in`](Self::create_xumm_sign_in)
+    /// * `poll_interval` - How often to check the request's state
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::time::Duration;
+    /// # use xrplsale::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let auth = client.auth();
+    /// let sign_in = auth.create_xumm_sign_in().await?;
+    /// println!("Scan {} or open {}", sign_in.qr_png, sign_in.deeplink);
+    ///
+    /// let session = auth
+    ///     .wait_for_xumm_sign_in(&sign_in, Duration::from_secs(2))
+    ///     .await?;
+    /// println!("Signed in as {}", session.account);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_xumm_sign_in(
+        &self,
+        sign_in: &XummSignIn,
+        poll_interval: Duration,
+    ) -> Result<XummSession> {
+        loop {
+            match self.xumm_sign_in_status(&sign_in.uuid).await?.state {
+                XummSignInState::Signed => return self.exchange_xumm_sign_in(&sign_in.uuid).await,
+                XummSignInState::Rejected => {
+                    return Err(Error::Unauthorized(
+                        "Xaman sign-in was rejected".to_string(),
+                    ))
+                }
+                XummSignInState::Expired => {
+                    return Err(Error::Unauthorized("Xaman sign-in expired".to_string()))
+                }
+                XummSignInState::Pending | XummSignInState::Opened => {}
+            }
+
+            if self.client.clock().utc_now() >= sign_in.expires_at {
+                return Err(Error::Unauthorized("Xaman sign-in expired".to_string()));
+            }
+            self.client.clock().sleep(poll_interval).await;
+        }
+    }
+
+    /// Sign in with the Xaman wallet app
+    ///
+    /// Creates a sign-in request and waits for it to be signed. `on_created` receives
+    /// the request so the QR code or deep link can be shown before polling starts. The
+    /// resulting session token is installed on the client.
+    pub async fn login_with_xumm<F>(
+        &self,
+        poll_interval: Duration,
+        on_created: F,
+    ) -> Result<XummSession>
+    where
+        F: FnOnce(&XummSignIn),
+    {
+        let sign_in = self.create_xumm_sign_in().await?;
+        on_created(&sign_in);
+        self.wait_for_xumm_sign_in(&sign_in, poll_interval).await
+    }
+}
@@ -0,0 +1,94 @@
+//! Eligibility service for sale tier access
+
+use crate::{
+    client::Client,
+    error::Result,
+    ids::ProjectId,
+    types::eligibility::{EligibilityReport, EligibilityRules},
+};
+
+/// Service for checking which sale tiers a wallet qualifies for
+///
+/// # Example
+///
+/// ```rust
+/// # use xrplsale::Client;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = Client::builder().api_key("test").build()?;
+/// let report = client
+///     .eligibility()
+///     .check("rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH", "proj_abc123")
+///     .await?;
+///
+/// for tier in &report.tiers {
+///     for check in tier.requirements.iter().filter(|check| !check.met) {
+///         println!("Tier {}: {}", tier.tier, check.description);
+///     }
+/// }
+///
+/// // Preview the effect of a lower stake requirement before changing the tiers
+/// let mut rules = client.eligibility().rules("proj_abc123").await?;
+/// rules.tiers[1].min_stake = Some("500".to_string());
+/// let preview = rules.evaluate(&report.standing)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct EligibilityService {
+    client: Client,
+}
+
+impl EligibilityService {
+    /// Create a new eligibility service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Check which of a project's tiers an account qualifies for
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The investor's XRPL address
+    /// * `project_id` - The project ID
+    pub async fn check(
+        &self,
+        account: &str,
+        project_id: impl Into<ProjectId>,
+    ) -> Result<EligibilityReport> {
+        let project_id: ProjectId = project_id.into();
+        self.client
+            .get(&format!(
+                "/projects/{}/eligibility/{}",
+                project_id.path_segment(),
+                account
+            ))
+            .await
+    }
+
+    /// Get the requirements of a project's tiers
+    pub async fn rules(&self, project_id: impl Into<ProjectId>) -> Result<EligibilityRules> {
+        let project_id: ProjectId = project_id.into();
+        self.client
+            .get(&format!(
+                "/projects/{}/tier-rules",
+                project_id.path_segment()
+            ))
+            .await
+    }
+
+    /// Replace the requirements of a project's tiers
+    pub async fn update_rules(
+        &self,
+        project_id: impl Into<ProjectId>,
+        rules: &EligibilityRules,
+    ) -> Result<EligibilityRules> {
+        let project_id: ProjectId = project_id.into();
+        self.client
+            .put(
+                &format!("/projects/{}/tier-rules", project_id.path_segment()),
+                Some(rules),
+            )
+            .await
+    }
+}
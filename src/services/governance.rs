@@ -0,0 +1,117 @@
+//! Governance service for token holder votes
+
+use crate::{
+    client::Client,
+    error::Result,
+    ids::ProjectId,
+    types::governance::{CastVoteRequest, Proposal, ProposalStatus, Vote, VoteChoice, VoteTally},
+    wallet::WalletSigner,
+};
+use serde::Serialize;
+
+/// Service for proposals and votes on milestone releases
+///
+/// Votes are authorized by the investor's wallet through a [`WalletSigner`]. Proposal
+/// and vote webhook events can be read with
+/// [`GovernanceEvent`](crate::types::webhooks::GovernanceEvent).
+#[derive(Debug, Clone)]
+pub struct GovernanceService {
+    client: Client,
+}
+
+impl GovernanceService {
+    /// Create a new governance service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// List a project's proposals
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `status` - Only return proposals in this state
+    pub async fn proposals(
+        &self,
+        project_id: impl Into<ProjectId>,
+        status: Option<ProposalStatus>,
+    ) -> Result<Vec<Proposal>> {
+        let project_id: ProjectId = project_id.into();
+        let query = ProposalQuery { status };
+
+        self.client
+            .get_list_with_query(
+                &format!("/projects/{}/proposals", project_id.path_segment()),
+                &query,
+            )
+            .await
+    }
+
+    /// Get a single proposal
+    pub async fn proposal(&self, proposal_id: &str) -> Result<Proposal> {
+        self.client
+            .get(&format!("/proposals/{}", proposal_id))
+            .await
+    }
+
+    /// Get the current vote totals of a proposal
+    pub async fn tally(&self, proposal_id: &str) -> Result<VoteTally> {
+        self.client
+            .get(&format!("/proposals/{}/tally", proposal_id))
+            .await
+    }
+
+    /// Cast a vote, signed by the voting wallet
+    ///
+    /// The wallet signs [`CastVoteRequest::message`] for the current time; the vote
+    /// counts for the tokens the wallet holds when voting closes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use xrplsale::{types::governance::VoteChoice, wallet::WalletSigner, Client};
+    /// # async fn vote(client: &Client, wallet: &dyn WalletSigner) -> xrplsale::Result<()> {
+    /// let vote = client
+    ///     .governance()
+    ///     .vote("prop_123", VoteChoice::For, wallet)
+    ///     .await?;
+    /// println!("Vote counted with weight {}", vote.weight);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn vote(
+        &self,
+        proposal_id: &str,
+        choice: VoteChoice,
+        signer: &dyn WalletSigner,
+    ) -> Result<Vote> {
+        let account = signer.account();
+        let timestamp = self.client.clock().utc_now().timestamp();
+        let message = CastVoteRequest::message(proposal_id, choice, &account, timestamp);
+        let signature = signer.sign(message.as_bytes()).await?;
+
+        let request = CastVoteRequest {
+            account,
+            choice,
+            timestamp,
+            public_key: signer.public_key(),
+            signature,
+        };
+        self.client
+            .post(&format!("/proposals/{}/votes", proposal_id), Some(&request))
+            .await
+    }
+
+    /// List the votes cast on a proposal
+    pub async fn votes(&self, proposal_id: &str) -> Result<Vec<Vote>> {
+        self.client
+            .get_list(&format!("/proposals/{}/votes", proposal_id))
+            .await
+    }
+}
+
+/// Query parameters of the proposal listing
+#[derive(Serialize)]
+struct ProposalQuery {
+    status: Option<ProposalStatus>,
+}
@@ -0,0 +1,150 @@
+//! GraphQL service for querying several resources in one round trip
+
+use crate::{
+    client::Client,
+    error::{Error, Result},
+    ids::ProjectId,
+    types::graphql::{
+        GraphqlRequest, GraphqlResponse, ProjectOverview, PROJECT_OVERVIEWS_QUERY,
+        PROJECT_OVERVIEW_FRAGMENT, PROJECT_OVERVIEW_QUERY,
+    },
+};
+use serde::{de::DeserializeOwned, Deserialize};
+
+/// Service for the platform's GraphQL endpoint
+///
+/// Dashboards that show a project together with its tiers and stats would otherwise
+/// make one REST call per resource; a GraphQL query fetches them together.
+///
+/// # Example
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use xrplsale::Client;
+///
+/// #[derive(Deserialize)]
+/// struct Names {
+///     projects: Vec<Name>,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Name {
+///     name: String,
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = Client::builder().api_key("test").build()?;
+/// let names: Names = client
+///     .graphql()
+///     .query(
+///         "query($status: String) { projects(status: $status) { name } }",
+///         Some(serde_json::json!({ "status": "active" })),
+///     )
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct GraphqlService {
+    client: Client,
+}
+
+impl GraphqlService {
+    /// Create a new GraphQL service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Run a query and deserialize its `data`
+    ///
+    /// Fails with [`Error::BadRequest`] listing the messages if the response contains
+    /// any errors. Use [`execute`](Self::execute) to keep partial results.
+    ///
+    /// # Arguments
+    ///
+    /// * `document` - GraphQL query or mutation
+    /// * `variables` - Values for the document's variables, as a JSON object
+    pub async fn query<T: DeserializeOwned>(
+        &self,
+        document: &str,
+        variables: Option<serde_json::Value>,
+    ) -> Result<T> {
+        let mut request = GraphqlRequest::new(document);
+        request.variables = variables;
+
+        let response = self.execute::<T>(&request).await?;
+        if !response.errors.is_empty() {
+            let messages: Vec<&str> = response
+                .errors
+                .iter()
+                .map(|error| error.message.as_str())
+                .collect();
+            return Err(Error::BadRequest(format!(
+                "GraphQL errors: {}",
+                messages.join("; ")
+            )));
+        }
+
+        response
+            .data
+            .ok_or_else(|| Error::Parse("GraphQL response has no data".to_string()))
+    }
+
+    /// Run an operation, returning its data and errors as sent by the API
+    pub async fn execute<T: DeserializeOwned>(
+        &self,
+        request: &GraphqlRequest,
+    ) -> Result<GraphqlResponse<T>> {
+        self.client.post("/graphql", Some(request)).await
+    }
+
+    /// Get a project with its tiers and stats
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    pub async fn project_overview(
+        &self,
+        project_id: impl Into<ProjectId>,
+    ) -> Result<ProjectOverview> {
+        #[derive(Deserialize)]
+        struct Data {
+            project: ProjectOverview,
+        }
+
+        let project_id: ProjectId = project_id.into();
+        let data: Data = self
+            .query(
+                &format!("{}{}", PROJECT_OVERVIEW_QUERY, PROJECT_OVERVIEW_FRAGMENT),
+                Some(serde_json::json!({ "id": project_id })),
+            )
+            .await?;
+        Ok(data.project)
+    }
+
+    /// Get several projects with their tiers and stats
+    ///
+    /// # Arguments
+    ///
+    /// * `project_ids` - The project IDs
+    pub async fn project_overviews<I, S>(&self, project_ids: I) -> Result<Vec<ProjectOverview>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<ProjectId>,
+    {
+        #[derive(Deserialize)]
+        struct Data {
+            projects: Vec<ProjectOverview>,
+        }
+
+        let project_ids: Vec<ProjectId> = project_ids.into_iter().map(Into::into).collect();
+        let data: Data = self
+            .query(
+                &format!("{}{}", PROJECT_OVERVIEWS_QUERY, PROJECT_OVERVIEW_FRAGMENT),
+                Some(serde_json::json!({ "ids": project_ids })),
+            )
+            .await?;
+        Ok(data.projects)
+    }
+}
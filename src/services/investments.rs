@@ -0,0 +1,23 @@
+//! Investments service for recording and querying investments
+//!
+//! This snapshot carries only the service definition; the core endpoints live in
+//! the upstream module. Additional endpoints are grouped in submodules.
+
+use crate::client::Client;
+
+mod batch;
+mod portfolio;
+mod sync;
+
+/// Service for managing investments
+#[derive(Debug, Clone)]
+pub struct InvestmentsService {
+    client: Client,
+}
+
+impl InvestmentsService {
+    /// Create a new investments service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
@@ -0,0 +1,124 @@
+//! Lookups and imports of many investments at once
+
+use super::InvestmentsService;
+use crate::{
+    error::Result,
+    ids::{InvestmentId, ProjectId},
+    types::{
+        common::BatchResponse,
+        investments::{ImportedInvestment, Investment},
+    },
+};
+use std::collections::HashMap;
+
+impl InvestmentsService {
+    /// Get several investments by ID concurrently
+    ///
+    /// Requests are issued with at most `concurrency` in flight. Results are keyed by
+    /// investment ID, and a failure for one ID does not affect the others.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The investment IDs to fetch
+    /// * `concurrency` - Maximum number of concurrent requests
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use xrplsale::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let investments = client
+    ///     .investments()
+    ///     .get_many(["inv_abc123", "inv_def456"], 8)
+    ///     .await;
+    ///
+    /// for (id, result) in investments {
+    ///     match result {
+    ///         Ok(investment) => println!("{}: {} XRP", id, investment.amount_xrp),
+    ///         Err(e) => eprintln!("{}: {}", id, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_many<I, S>(
+        &self,
+        ids: I,
+        concurrency: usize,
+    ) -> HashMap<InvestmentId, Result<Investment>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<InvestmentId>,
+    {
+        use futures::stream::{self, StreamExt};
+
+        stream::iter(ids.into_iter().map(Into::into))
+            .map(|id: InvestmentId| async move {
+                let result = self
+                    .client
+                    .get(&format!("/investments/{}", id.path_segment()))
+                    .await;
+                (id, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Import historical investments into a project in one request
+    ///
+    /// Each investment is validated and recorded independently; check the per-item
+    /// results for failures.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `investments` - The investments to import
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use xrplsale::{types::investments::ImportedInvestment, Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let results = client
+    ///     .investments()
+    ///     .import(
+    ///         "proj_abc123",
+    ///         vec![ImportedInvestment {
+    ///             investor_account: "rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH".to_string(),
+    ///             amount_xrp: "250".to_string(),
+    ///             token_amount: "250000".to_string(),
+    ///             invested_at: "2024-03-01T12:00:00Z".parse()?,
+    ///             external_id: Some("legacy-1042".to_string()),
+    ///             ..Default::default()
+    ///         }],
+    ///     )
+    ///     .await?;
+    ///
+    /// for failure in results.failed() {
+    ///     if let Some(error) = &failure.error {
+    ///         eprintln!("Row {}: {}", failure.index, error.message);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn import(
+        &self,
+        project_id: impl Into<ProjectId>,
+        investments: Vec<ImportedInvestment>,
+    ) -> Result<BatchResponse<Investment>> {
+        let project_id: ProjectId = project_id.into();
+        let body = serde_json::json!({ "investments": investments });
+        self.client
+            .post(
+                &format!("/projects/{}/investments/import", project_id.path_segment()),
+                Some(&body),
+            )
+            .await
+    }
+}
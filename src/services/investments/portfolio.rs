@@ -0,0 +1,121 @@
+//! An investor's positions across projects
+
+use super::InvestmentsService;
+use crate::{
+    error::{Error, Result},
+    ids::ProjectId,
+    types::{
+        allocations::AccountAllocation,
+        investments::{is_unpaid, Investment, Portfolio, PortfolioPosition},
+        projects::{format_amount, parse_amount},
+    },
+};
+use std::collections::BTreeMap;
+
+/// Projects whose allocations are fetched at once
+const ALLOCATION_CONCURRENCY: usize = 4;
+
+impl InvestmentsService {
+    /// Get an investor's positions across every project they invested in
+    ///
+    /// Investments and allocations live behind separate endpoints; this lists the
+    /// account's investments, fetches its allocation in each project, and totals the
+    /// amounts. Failed and cancelled investments are listed in their position but not
+    /// counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The investor's XRPL address
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use xrplsale::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let portfolio = client
+    ///     .investments()
+    ///     .portfolio("rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH")
+    ///     .await?;
+    /// println!("{} XRP invested", portfolio.total_invested_xrp);
+    /// for position in &portfolio.positions {
+    ///     println!(
+    ///         "{}: {} tokens, {} vesting, {} distributed",
+    ///         position.project_id,
+    ///         position.tokens_allocated,
+    ///         position.vesting_remaining,
+    ///         position.tokens_distributed
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn portfolio(&self, account: &str) -> Result<Portfolio> {
+        use futures::stream::{self, StreamExt, TryStreamExt};
+
+        let investments: Vec<Investment> = self
+            .client
+            .get_list(&format!("/investors/{}/investments", account))
+            .await?;
+
+        let mut by_project: BTreeMap<ProjectId, Vec<Investment>> = BTreeMap::new();
+        for investment in investments {
+            by_project
+                .entry(ProjectId::from(investment.project_id.clone()))
+                .or_default()
+                .push(investment);
+        }
+
+        let allocations = self.client.allocations();
+        let allocations: Vec<AccountAllocation> = stream::iter(by_project.keys())
+            .map(|project_id| allocations.get(account, project_id))
+            .buffered(ALLOCATION_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let mut total_invested = 0u128;
+        let mut positions = Vec::with_capacity(by_project.len());
+        for ((project_id, investments), allocation) in by_project.into_iter().zip(allocations) {
+            let mut invested = 0u128;
+            for investment in investments.iter().filter(|i| !is_unpaid(i)) {
+                invested = add(invested, &investment.amount_xrp)?;
+            }
+            total_invested = total_invested
+                .checked_add(invested)
+                .ok_or_else(|| Error::Parse("Portfolio total overflows".to_string()))?;
+
+            positions.push(PortfolioPosition {
+                project_id,
+                invested_xrp: format_amount(invested),
+                tokens_allocated: normalize(&allocation.allocated_tokens)?,
+                vesting_remaining: normalize(&allocation.vesting_remaining)?,
+                tokens_distributed: normalize(&allocation.distributed_tokens)?,
+                investments,
+            });
+        }
+
+        Ok(Portfolio {
+            account: account.to_string(),
+            total_invested_xrp: format_amount(total_invested),
+            positions,
+        })
+    }
+}
+
+/// Add a decimal amount from the API to a running total
+fn add(total: u128, amount: &str) -> Result<u128> {
+    let value = parse_amount(amount)
+        .ok_or_else(|| Error::Parse(format!("Invalid amount '{}'", amount)))?;
+    total
+        .checked_add(value)
+        .ok_or_else(|| Error::Parse("Portfolio total overflows".to_string()))
+}
+
+/// An amount from the API in canonical form, with an empty amount as zero
+fn normalize(amount: &str) -> Result<String> {
+    if amount.is_empty() {
+        return Ok("0".to_string());
+    }
+    add(0, amount).map(format_amount)
+}
@@ -0,0 +1,155 @@
+//! Listings for loading investments into other systems
+
+use super::InvestmentsService;
+use crate::{
+    error::Result,
+    ids::ProjectId,
+    types::{
+        common::{ChangeSet, SyncCursor},
+        investments::{Investment, InvestmentCheckpoint, InvestmentOrder, OrderedInvestment},
+    },
+};
+use serde::{Deserialize, Serialize};
+
+impl InvestmentsService {
+    /// Stream a project's investments in a stable order, resuming after a checkpoint
+    ///
+    /// Each item carries the checkpoint that resumes right after it. Investments
+    /// recorded later are appended in the same order, so a job that stores each
+    /// checkpoint with the loaded row sees every investment once, even across
+    /// restarts. The stream ends at the latest investment; stream again from the last
+    /// checkpoint to pick up new ones. It stops at the first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `order` - Order to stream in
+    /// * `from_checkpoint` - Checkpoint to resume after, or `None` to start at the
+    ///   beginning
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use xrplsale::{types::investments::{InvestmentCheckpoint, InvestmentOrder}, Client};
+    /// # use futures::TryStreamExt;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// # let load_checkpoint = || None::<String>;
+    /// let investments = client.investments();
+    /// let from = load_checkpoint().map(InvestmentCheckpoint::new);
+    /// let mut stream = Box::pin(investments.stream_ordered("proj_abc123", InvestmentOrder::LedgerIndexAsc, from));
+    ///
+    /// while let Some(item) = stream.try_next().await? {
+    ///     // Write the row and item.checkpoint in one transaction
+    ///     println!("{} at {}", item.investment.id, item.checkpoint);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_ordered(
+        &self,
+        project_id: impl Into<ProjectId>,
+        order: InvestmentOrder,
+        from_checkpoint: Option<InvestmentCheckpoint>,
+    ) -> impl futures::Stream<Item = Result<OrderedInvestment>> + '_ {
+        use futures::stream::{self, StreamExt};
+
+        const PAGE_SIZE: u32 = 100;
+        let project_id: ProjectId = project_id.into();
+        let path = format!(
+            "/projects/{}/investments/ordered",
+            project_id.path_segment()
+        );
+
+        stream::unfold(Some(from_checkpoint), move |after| {
+            let path = path.clone();
+            async move {
+                let after = after?;
+                let query = OrderedQuery {
+                    order,
+                    after: after.as_ref(),
+                    limit: PAGE_SIZE,
+                };
+
+                match self
+                    .client
+                    .get_with_query::<OrderedPage, _>(&path, &query)
+                    .await
+                {
+                    Ok(page) => {
+                        let last = page.data.last().map(|item| item.checkpoint.clone());
+                        let next = match last {
+                            Some(last) if page.has_more => Some(Some(last)),
+                            _ => None,
+                        };
+                        let items: Vec<Result<OrderedInvestment>> =
+                            page.data.into_iter().map(Ok).collect();
+                        Some((stream::iter(items), next))
+                    }
+                    // Stop on error; the last checkpoint seen is where to resume
+                    Err(e) => Some((stream::iter(vec![Err(e)]), None)),
+                }
+            }
+        })
+        .flat_map(|s| s)
+    }
+
+    /// Get the investments created, updated, or deleted since a cursor
+    ///
+    /// Works like [`ProjectsService::changes_since`](crate::services::ProjectsService::changes_since)
+    /// across all of the account's projects: start from a timestamp, then pass the
+    /// returned cursor on each following sync, fetching again right away while
+    /// `has_more` is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use xrplsale::{types::common::{ChangeKind, SyncCursor}, Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let mut cursor = SyncCursor::from(chrono::Utc::now() - chrono::Duration::days(1));
+    /// loop {
+    ///     let changes = client.investments().changes_since(cursor).await?;
+    ///     for change in &changes.changes {
+    ///         match (change.kind, &change.data) {
+    ///             (ChangeKind::Deleted, _) => println!("delete {}", change.id),
+    ///             (_, Some(investment)) => println!("upsert {}", investment.id),
+    ///             (_, None) => {}
+    ///         }
+    ///     }
+    ///     cursor = changes.next_cursor();
+    ///     if !changes.has_more {
+    ///         break;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn changes_since(
+        &self,
+        cursor: impl Into<SyncCursor>,
+    ) -> Result<ChangeSet<Investment>> {
+        self.client
+            .get_with_query("/investments/changes", &cursor.into())
+            .await
+    }
+}
+
+/// Query parameters of the ordered investment listing
+#[derive(Serialize)]
+struct OrderedQuery<'a> {
+    order: InvestmentOrder,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<&'a InvestmentCheckpoint>,
+    limit: u32,
+}
+
+/// A page of the ordered investment listing
+#[derive(Deserialize)]
+struct OrderedPage {
+    data: Vec<OrderedInvestment>,
+    #[serde(default)]
+    has_more: bool,
+}
@@ -0,0 +1,109 @@
+//! Liquidity service for post-sale AMM pools
+
+use crate::{
+    client::Client,
+    error::{Error, Result},
+    ids::ProjectId,
+    types::liquidity::{AmmPool, CreateLiquidityPlanRequest, LiquidityPlan, MAX_TRADING_FEE},
+};
+
+/// Service for seeding XRPL AMM pools with sale proceeds
+///
+/// A liquidity plan describes the pool to create once the sale settles; the platform
+/// executes it and locks the resulting LP tokens. Pool creation and failures are also
+/// delivered as webhook events, readable with
+/// [`LiquidityEvent`](crate::types::webhooks::LiquidityEvent).
+///
+/// # Example
+///
+/// ```rust
+/// # use xrplsale::{types::liquidity::{Asset, CreateLiquidityPlanRequest}, Client, ProjectId};
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = Client::builder().api_key("test").build()?;
+/// let plan = client
+///     .liquidity()
+///     .create_plan(CreateLiquidityPlanRequest {
+///         project_id: ProjectId::new("proj_abc123"),
+///         asset: Asset::issued("MDP", "rIssuerAddress"),
+///         quote: Asset::xrp(),
+///         asset_amount: "5000000".to_string(),
+///         quote_amount: "25000".to_string(),
+///         trading_fee: 500,
+///         lock_duration_days: 365,
+///     })
+///     .await?;
+///
+/// let plan = client.liquidity().plan(&plan.id).await?;
+/// if plan.status.is_final() {
+///     let pool = client.liquidity().pool("proj_abc123").await?;
+///     println!("AMM {} holds {} MDP", pool.amm_account, pool.asset_amount);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LiquidityService {
+    client: Client,
+}
+
+impl LiquidityService {
+    /// Create a new liquidity service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Create a liquidity provisioning plan
+    ///
+    /// Fails with [`Error::Configuration`] without calling the API if the trading fee is
+    /// above [`MAX_TRADING_FEE`].
+    pub async fn create_plan(&self, request: CreateLiquidityPlanRequest) -> Result<LiquidityPlan> {
+        if request.trading_fee > MAX_TRADING_FEE {
+            return Err(Error::Configuration(format!(
+                "AMM trading fee {} exceeds the maximum of {}",
+                request.trading_fee, MAX_TRADING_FEE
+            )));
+        }
+
+        self.client
+            .post(
+                &format!(
+                    "/projects/{}/liquidity/plans",
+                    request.project_id.path_segment()
+                ),
+                Some(&request),
+            )
+            .await
+    }
+
+    /// Get a plan and its execution status
+    pub async fn plan(&self, plan_id: &str) -> Result<LiquidityPlan> {
+        self.client
+            .get(&format!("/liquidity/plans/{}", plan_id))
+            .await
+    }
+
+    /// List a project's liquidity plans
+    pub async fn plans(&self, project_id: impl Into<ProjectId>) -> Result<Vec<LiquidityPlan>> {
+        let project_id: ProjectId = project_id.into();
+        self.client
+            .get_list(&format!(
+                "/projects/{}/liquidity/plans",
+                project_id.path_segment()
+            ))
+            .await
+    }
+
+    /// Get the AMM pool seeded for a project
+    ///
+    /// Fails with [`Error::NotFound`] until a plan has executed.
+    pub async fn pool(&self, project_id: impl Into<ProjectId>) -> Result<AmmPool> {
+        let project_id: ProjectId = project_id.into();
+        self.client
+            .get(&format!(
+                "/projects/{}/liquidity/pool",
+                project_id.path_segment()
+            ))
+            .await
+    }
+}
@@ -0,0 +1,75 @@
+//! Market service for secondary-market token prices
+
+use crate::{
+    client::Client,
+    error::Result,
+    ids::ProjectId,
+    types::market::{Granularity, PriceRange, TokenPrice},
+};
+use serde::Serialize;
+
+/// Service for price data of tokens trading after their sale
+///
+/// Prices are derived from XRPL DEX trades, so post-sale dashboards don't need a
+/// separate market-data integration.
+#[derive(Debug, Clone)]
+pub struct MarketService {
+    client: Client,
+}
+
+impl MarketService {
+    /// Create a new market service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Get OHLCV candles and the current mid-price of a project's token
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `range` - Period of history to return
+    /// * `granularity` - Length of each candle
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use xrplsale::{types::market::{Granularity, PriceRange}, Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let price = client
+    ///     .market()
+    ///     .token_price("proj_abc123", PriceRange::Week, Granularity::Hour)
+    ///     .await?;
+    /// println!("Mid price: {:?} XRP", price.mid_price);
+    /// for candle in &price.candles {
+    ///     println!("{} close {} volume {}", candle.open_time, candle.close, candle.volume);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn token_price(
+        &self,
+        project_id: impl Into<ProjectId>,
+        range: PriceRange,
+        granularity: Granularity,
+    ) -> Result<TokenPrice> {
+        let project_id: ProjectId = project_id.into();
+        let query = PriceQuery { range, granularity };
+
+        self.client
+            .get_with_query(
+                &format!("/analytics/projects/{}/price", project_id.path_segment()),
+                &query,
+            )
+            .await
+    }
+}
+
+/// Query parameters of the price history
+#[derive(Serialize)]
+struct PriceQuery {
+    range: PriceRange,
+    granularity: Granularity,
+}
@@ -0,0 +1,143 @@
+//! Milestones service for project roadmaps
+
+use crate::{
+    client::Client,
+    error::Result,
+    ids::ProjectId,
+    types::projects::{
+        validate_release_percentage, CompleteMilestoneRequest, CreateMilestoneRequest, Milestone,
+        UpdateMilestoneRequest,
+    },
+};
+
+/// Service for managing the milestones of a single project
+///
+/// Obtained from [`ProjectsService::milestones`](crate::services::ProjectsService::milestones).
+/// Completed milestones unlock the escrowed funds they release; see
+/// [`ProjectsService::request_release`](crate::services::ProjectsService::request_release).
+///
+/// # Example
+///
+/// ```rust
+/// # use xrplsale::{types::projects::CreateMilestoneRequest, Client};
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = Client::builder().api_key("test").build()?;
+/// let milestones = client.projects().milestones("proj_abc123");
+///
+/// let beta = milestones
+///     .create(CreateMilestoneRequest {
+///         title: "Public beta".to_string(),
+///         description: "Beta release on mainnet".to_string(),
+///         target_date: chrono::Utc::now() + chrono::Duration::days(90),
+///         release_percentage: "25".to_string(),
+///     })
+///     .await?;
+///
+/// milestones
+///     .complete(&beta.id, vec!["https://example.com/beta-report".to_string()])
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MilestonesService {
+    client: Client,
+    project_id: ProjectId,
+}
+
+impl MilestonesService {
+    /// Create a new milestones service for a project
+    pub fn new(client: Client, project_id: impl Into<ProjectId>) -> Self {
+        Self {
+            client,
+            project_id: project_id.into(),
+        }
+    }
+
+    /// List the project's milestones with their status
+    pub async fn list(&self) -> Result<Vec<Milestone>> {
+        self.client.get_list(&self.path("")).await
+    }
+
+    /// Get a single milestone
+    pub async fn get(&self, milestone_id: &str) -> Result<Milestone> {
+        self.client
+            .get(&self.path(&format!("/{}", milestone_id)))
+            .await
+    }
+
+    /// Create a milestone
+    ///
+    /// Fails with [`Error::Configuration`](crate::Error::Configuration) without calling
+    /// the API if the release percentage is not above 0 and at most 100.
+    pub async fn create(&self, request: CreateMilestoneRequest) -> Result<Milestone> {
+        validate_release_percentage(&request.release_percentage)?;
+        self.client.post(&self.path(""), Some(&request)).await
+    }
+
+    /// Update a milestone
+    ///
+    /// # Arguments
+    ///
+    /// * `milestone_id` - The milestone to update
+    /// * `request` - Fields to change
+    pub async fn update(
+        &self,
+        milestone_id: &str,
+        request: UpdateMilestoneRequest,
+    ) -> Result<Milestone> {
+        if let Some(percentage) = &request.release_percentage {
+            validate_release_percentage(percentage)?;
+        }
+        self.client
+            .patch(&self.path(&format!("/{}", milestone_id)), Some(&request))
+            .await
+    }
+
+    /// Delete a milestone that has not been completed
+    pub async fn delete(&self, milestone_id: &str) -> Result<()> {
+        self.client
+            .delete(&self.path(&format!("/{}", milestone_id)))
+            .await
+    }
+
+    /// Mark a milestone complete, submitting evidence for approval
+    ///
+    /// # Arguments
+    ///
+    /// * `milestone_id` - The milestone to complete
+    /// * `evidence` - Links to evidence of completion
+    pub async fn complete(&self, milestone_id: &str, evidence: Vec<String>) -> Result<Milestone> {
+        self.complete_with(
+            milestone_id,
+            CompleteMilestoneRequest {
+                evidence,
+                note: None,
+            },
+        )
+        .await
+    }
+
+    /// Mark a milestone complete with evidence and a note
+    pub async fn complete_with(
+        &self,
+        milestone_id: &str,
+        request: CompleteMilestoneRequest,
+    ) -> Result<Milestone> {
+        self.client
+            .post(
+                &self.path(&format!("/{}/complete", milestone_id)),
+                Some(&request),
+            )
+            .await
+    }
+
+    fn path(&self, suffix: &str) -> String {
+        format!(
+            "/projects/{}/milestones{}",
+            self.project_id.path_segment(),
+            suffix
+        )
+    }
+}
@@ -0,0 +1,49 @@
+//! API services grouped by domain
+
+pub mod airdrops;
+pub mod allocations;
+pub mod analytics;
+pub mod api_keys;
+pub mod auth;
+pub mod eligibility;
+pub mod governance;
+#[cfg(feature = "graphql")]
+pub mod graphql;
+pub mod investments;
+pub mod liquidity;
+pub mod market;
+pub mod milestones;
+pub mod notifications;
+pub mod project_members;
+pub mod projects;
+pub mod realtime;
+#[cfg(feature = "xrpl")]
+pub mod reconcile;
+pub mod sandbox;
+pub mod staking;
+pub mod usage;
+pub mod webhooks;
+
+pub use airdrops::AirdropsService;
+pub use allocations::AllocationsService;
+pub use analytics::AnalyticsService;
+pub use api_keys::ApiKeysService;
+pub use auth::AuthService;
+pub use eligibility::EligibilityService;
+pub use governance::GovernanceService;
+#[cfg(feature = "graphql")]
+pub use graphql::GraphqlService;
+pub use investments::InvestmentsService;
+pub use liquidity::LiquidityService;
+pub use market::MarketService;
+pub use milestones::MilestonesService;
+pub use notifications::NotificationsService;
+pub use project_members::ProjectMembersService;
+pub use projects::ProjectsService;
+pub use realtime::RealtimeService;
+#[cfg(feature = "xrpl")]
+pub use reconcile::ReconcileService;
+pub use sandbox::SandboxService;
+pub use staking::StakingService;
+pub use usage::UsageService;
+pub use webhooks::WebhooksService;
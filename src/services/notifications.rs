@@ -0,0 +1,162 @@
+//! Notifications service for preferences and notification history
+
+use crate::{
+    client::Client,
+    error::Result,
+    types::{
+        common::PaginatedResponse,
+        notifications::{
+            Notification, NotificationCategory, NotificationPreferences,
+            UpdateNotificationPreferencesRequest,
+        },
+    },
+};
+use serde::Serialize;
+
+/// Service for an account's notification preferences and history
+///
+/// The history includes every notification regardless of channel, with its read
+/// state, so integrators can build their own notification center.
+///
+/// # Example
+///
+/// ```rust
+/// # use xrplsale::{
+/// #     types::notifications::{
+/// #         ChannelPreference, NotificationCategory, NotificationChannel,
+/// #         UpdateNotificationPreferencesRequest,
+/// #     },
+/// #     Client,
+/// # };
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = Client::builder().api_key("test").build()?;
+/// let account = "rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH";
+///
+/// client
+///     .notifications()
+///     .update_preferences(
+///         account,
+///         UpdateNotificationPreferencesRequest::new()
+///             .email_address("investor@example.com")
+///             .channel(
+///                 ChannelPreference::enabled(NotificationChannel::Email)
+///                     .mute(NotificationCategory::Governance),
+///             ),
+///     )
+///     .await?;
+///
+/// let unread = client
+///     .notifications()
+///     .list(account, true, None, None, Some(1), Some(20))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NotificationsService {
+    client: Client,
+}
+
+impl NotificationsService {
+    /// Create a new notifications service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Get an account's notification preferences
+    pub async fn preferences(&self, account: &str) -> Result<NotificationPreferences> {
+        self.client
+            .get(&format!("/accounts/{}/notification-preferences", account))
+            .await
+    }
+
+    /// Update an account's notification preferences
+    pub async fn update_preferences(
+        &self,
+        account: &str,
+        request: UpdateNotificationPreferencesRequest,
+    ) -> Result<NotificationPreferences> {
+        self.client
+            .patch(
+                &format!("/accounts/{}/notification-preferences", account),
+                Some(&request),
+            )
+            .await
+    }
+
+    /// List an account's notifications, newest first
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The account's XRPL address
+    /// * `unread_only` - Only return notifications that have not been read
+    /// * `category` - Only return notifications in this category
+    /// * `project_id` - Only return notifications about this project
+    /// * `page` - Page number (1-based)
+    /// * `limit` - Number of items per page
+    pub async fn list(
+        &self,
+        account: &str,
+        unread_only: bool,
+        category: Option<NotificationCategory>,
+        project_id: Option<&str>,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<Notification>> {
+        let query = NotificationQuery {
+            read: unread_only.then_some(false),
+            category,
+            project_id,
+            page,
+            limit,
+        };
+        self.client
+            .get_with_query(&format!("/accounts/{}/notifications", account), &query)
+            .await
+    }
+
+    /// Mark a notification as read
+    pub async fn mark_read(&self, account: &str, notification_id: &str) -> Result<Notification> {
+        self.set_read(account, notification_id, true).await
+    }
+
+    /// Mark a notification as unread
+    pub async fn mark_unread(&self, account: &str, notification_id: &str) -> Result<Notification> {
+        self.set_read(account, notification_id, false).await
+    }
+
+    /// Mark all of an account's notifications as read
+    pub async fn mark_all_read(&self, account: &str) -> Result<()> {
+        self.client
+            .post(
+                &format!("/accounts/{}/notifications/read", account),
+                None::<&()>,
+            )
+            .await
+    }
+
+    async fn set_read(
+        &self,
+        account: &str,
+        notification_id: &str,
+        read: bool,
+    ) -> Result<Notification> {
+        self.client
+            .patch(
+                &format!("/accounts/{}/notifications/{}", account, notification_id),
+                Some(&serde_json::json!({ "read": read })),
+            )
+            .await
+    }
+}
+
+/// Query parameters of the notification history
+#[derive(Serialize)]
+struct NotificationQuery<'a> {
+    read: Option<bool>,
+    category: Option<NotificationCategory>,
+    project_id: Option<&'a str>,
+    page: Option<u32>,
+    limit: Option<u32>,
+}
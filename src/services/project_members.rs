@@ -0,0 +1,118 @@
+//! Project members service for team and role management
+
+use crate::{
+    client::Client,
+    error::Result,
+    ids::ProjectId,
+    types::projects::{InviteMemberRequest, ProjectInvite, ProjectMember, ProjectRole},
+};
+
+/// Service for managing the team of a single project
+///
+/// Obtained from [`ProjectsService::members`](crate::services::ProjectsService::members).
+/// Each collaborator gets their own access with a [`ProjectRole`], so teams don't need
+/// to share one API key.
+///
+/// # Example
+///
+/// ```rust
+/// # use xrplsale::{types::projects::ProjectRole, Client};
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = Client::builder().api_key("test").build()?;
+/// let members = client.projects().members("proj_abc123");
+///
+/// let invite = members.invite("alice@example.com", ProjectRole::Admin).await?;
+/// for member in members.list().await? {
+///     println!("{} ({:?})", member.email, member.role);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProjectMembersService {
+    client: Client,
+    project_id: ProjectId,
+}
+
+impl ProjectMembersService {
+    /// Create a new members service for a project
+    pub fn new(client: Client, project_id: impl Into<ProjectId>) -> Self {
+        Self {
+            client,
+            project_id: project_id.into(),
+        }
+    }
+
+    /// List the project's members
+    pub async fn list(&self) -> Result<Vec<ProjectMember>> {
+        self.client.get_list(&self.path("")).await
+    }
+
+    /// Get a single member
+    pub async fn get(&self, member_id: &str) -> Result<ProjectMember> {
+        self.client
+            .get(&self.path(&format!("/{}", member_id)))
+            .await
+    }
+
+    /// Invite a collaborator by email
+    ///
+    /// # Arguments
+    ///
+    /// * `email` - Address to send the invitation to
+    /// * `role` - Role granted once the invitation is accepted
+    pub async fn invite<S: Into<String>>(
+        &self,
+        email: S,
+        role: ProjectRole,
+    ) -> Result<ProjectInvite> {
+        let request = InviteMemberRequest {
+            email: email.into(),
+            role,
+        };
+        self.client
+            .post(&self.path("/invites"), Some(&request))
+            .await
+    }
+
+    /// List invitations that have not been accepted yet
+    pub async fn pending_invites(&self) -> Result<Vec<ProjectInvite>> {
+        self.client.get_list(&self.path("/invites")).await
+    }
+
+    /// Withdraw a pending invitation
+    pub async fn revoke_invite(&self, invite_id: &str) -> Result<()> {
+        self.client
+            .delete(&self.path(&format!("/invites/{}", invite_id)))
+            .await
+    }
+
+    /// Change a member's role
+    ///
+    /// Fails with [`Error::Configuration`](crate::Error::Configuration) if the change
+    /// would leave the project without an owner.
+    pub async fn assign_role(&self, member_id: &str, role: ProjectRole) -> Result<ProjectMember> {
+        self.client
+            .patch(
+                &self.path(&format!("/{}", member_id)),
+                Some(&serde_json::json!({ "role": role })),
+            )
+            .await
+    }
+
+    /// Remove a member from the project
+    pub async fn remove(&self, member_id: &str) -> Result<()> {
+        self.client
+            .delete(&self.path(&format!("/{}", member_id)))
+            .await
+    }
+
+    fn path(&self, suffix: &str) -> String {
+        format!(
+            "/projects/{}/members{}",
+            self.project_id.path_segment(),
+            suffix
+        )
+    }
+}
@@ -2,12 +2,26 @@
 
 use crate::{
     client::Client,
-    error::Result,
+    error::{Error, Result},
+    ids::{self, ProjectId},
     models::{
         CreateProjectRequest, Investment, PaginatedResponse, Project, ProjectStats, ProjectTier,
         UpdateProjectRequest,
     },
+    operation::Operation,
+    services::{MilestonesService, ProjectMembersService},
+    types::{
+        common::{AsOf, BatchResponse, ChangeSet, SortOrder, SyncCursor},
+        projects::{
+            CapTable, Distribution, Escrow, EscrowConfig, InvestorMessage, IssuerChecks,
+            LaunchChecklist, ProjectAlert, ProjectDocument, ProjectQuery, ProjectSortField,
+            ReleaseRequest, SaleToken, ScheduleDistributionRequest, SegmentMember, SegmentQuery,
+            TierProgress, TierUpdate, ValidationReport,
+        },
+    },
 };
+use chrono::{DateTime, Utc};
+use serde::Serialize;
 use std::collections::HashMap;
 
 /// Service for managing token sale projects
@@ -34,12 +48,12 @@ impl ProjectsService {
     /// * `page` - Page number (1-based)
     /// * `limit` - Number of items per page
     /// * `sort_by` - Field to sort by
-    /// * `sort_order` - Sort order (asc or desc)
+    /// * `sort_order` - Sort order
     ///
     /// # Example
     ///
     /// ```rust
-    /// # use xrplsale::Client;
+    /// # use xrplsale::{types::{common::SortOrder, projects::ProjectSortField}, Client};
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::builder().api_key("test").build()?;
@@ -47,8 +61,8 @@ impl ProjectsService {
     ///     Some("active"),
     ///     Some(1),
     ///     Some(10),
-    ///     Some("created_at"),
-    ///     Some("desc")
+    ///     Some(ProjectSortField::CreatedAt),
+    ///     Some(SortOrder::Desc)
     /// ).await?;
     /// # Ok(())
     /// # }
@@ -58,29 +72,41 @@ impl ProjectsService {
         status: Option<&str>,
         page: Option<u32>,
         limit: Option<u32>,
-        sort_by: Option<&str>,
-        sort_order: Option<&str>,
+        sort_by: Option<ProjectSortField>,
+        sort_order: Option<SortOrder>,
     ) -> Result<PaginatedResponse<Project>> {
-        let mut query = HashMap::new();
-
-        if let Some(status) = status {
-            query.insert("status".to_string(), status.to_string());
-        }
-        if let Some(page) = page {
-            query.insert("page".to_string(), page.to_string());
-        }
-        if let Some(limit) = limit {
-            query.insert("limit".to_string(), limit.to_string());
-        }
-        if let Some(sort_by) = sort_by {
-            query.insert("sort_by".to_string(), sort_by.to_string());
-        }
-        if let Some(sort_order) = sort_order {
-            query.insert("sort_order".to_string(), sort_order.to_string());
-        }
+        self.query(ProjectQuery {
+            statuses: status.map(|status| vec![status.to_string()]).unwrap_or_default(),
+            page,
+            limit,
+            sort_by,
+            sort_order,
+        })
+        .await
+    }
 
-        let query = if query.is_empty() { None } else { Some(&query) };
-        self.client.get("/projects", query).await
+    /// List projects matching a query
+    ///
+    /// Unlike [`ProjectsService::list`], several statuses can be requested at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use xrplsale::{types::{common::SortOrder, projects::{ProjectQuery, ProjectSortField}}, Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let query = ProjectQuery::new()
+    ///     .status("active")
+    ///     .status("upcoming")
+    ///     .sort(ProjectSortField::SaleStartDate, SortOrder::Asc)
+    ///     .limit(20);
+    /// let projects = client.projects().query(query).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query(&self, query: ProjectQuery) -> Result<PaginatedResponse<Project>> {
+        self.client.get_with_query("/projects", &query).await
     }
 
     /// Get active projects
@@ -98,6 +124,44 @@ impl ProjectsService {
         self.list(Some("completed"), page, limit, None, None).await
     }
 
+    /// Get the projects created, updated, or deleted since a cursor
+    ///
+    /// Start from a timestamp (the Unix epoch for a full sync), then pass the
+    /// returned cursor on each following sync. While `has_more` is set, more changes
+    /// are waiting and the next page can be fetched right away.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use xrplsale::{types::common::{ChangeKind, SyncCursor}, Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let mut cursor = SyncCursor::from(chrono::Utc::now() - chrono::Duration::days(1));
+    /// loop {
+    ///     let changes = client.projects().changes_since(cursor).await?;
+    ///     for change in &changes.changes {
+    ///         match (change.kind, &change.data) {
+    ///             (ChangeKind::Deleted, _) => println!("delete {}", change.id),
+    ///             (_, Some(project)) => println!("upsert {}", project.name),
+    ///             (_, None) => {}
+    ///         }
+    ///     }
+    ///     cursor = changes.next_cursor();
+    ///     if !changes.has_more {
+    ///         break;
+    ///     }
+    /// }
+    /// // Store `cursor` for the next sync
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn changes_since(&self, cursor: impl Into<SyncCursor>) -> Result<ChangeSet<Project>> {
+        self.client
+            .get_with_query("/projects/changes", &cursor.into())
+            .await
+    }
+
     /// Get a specific project by ID
     ///
     /// # Arguments
@@ -116,8 +180,53 @@ impl ProjectsService {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get(&self, project_id: &str) -> Result<Project> {
-        self.client.get(&format!("/projects/{}", project_id), None).await
+    pub async fn get(&self, project_id: impl Into<ProjectId>) -> Result<Project> {
+        self.client.get(&project_path(project_id.into(), "")).await
+    }
+
+    /// Get several projects by ID concurrently
+    ///
+    /// Requests are issued with at most `concurrency` in flight. Results are keyed by
+    /// project ID, and a failure for one ID does not affect the others.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The project IDs to fetch
+    /// * `concurrency` - Maximum number of concurrent requests
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use xrplsale::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::builder().api_key("test").build()?;
+    /// let projects = client.projects().get_many(["proj_abc123", "proj_def456"], 8).await;
+    ///
+    /// for (id, result) in projects {
+    ///     match result {
+    ///         Ok(project) => println!("{}: {}", id, project.name),
+    ///         Err(e) => eprintln!("{}: {}", id, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_many<I, S>(&self, ids: I, concurrency: usize) -> HashMap<ProjectId, Result<Project>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<ProjectId>,
+    {
+        use futures::stream::{self, StreamExt};
+
+        stream::iter(ids.into_iter().map(Into::into))
+            .map(|id: ProjectId| async move {
+                let result = self.get(&id).await;
+                (id, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
     }
 
     /// Create a new project
@@ -129,7 +238,7 @@ impl ProjectsService {
     /// # Example
     ///
     /// ```rust
-    /// # use xrplsale::{Client, CreateProjectRequest, ProjectTier};
+    /// # use xrplsale::{types::projects::{CreateProjectRequest, ProjectTier}, Client};
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = Client::builder().api_key("test").build()?;
@@ -155,14 +264,53 @@ impl ProjectsService {
         self.client.post("/projects", Some(&request)).await
     }
 
+    /// Validate a project definition without creating it
+    ///
+    /// The offline checks of [`CreateProjectRequest::validate`] run first; if they find
+    /// errors the report is returned without calling the API. Otherwise the platform
+    /// performs its own checks (such as symbol availability) and its report is returned
+    /// together with any local warnings.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The project definition to check
+    pub async fn validate(&self, request: &CreateProjectRequest) -> Result<ValidationReport> {
+        let local = request.validate_at(self.client.clock().utc_now());
+        if !local.is_valid() {
+            return Ok(local);
+        }
+
+        let mut report: ValidationReport =
+            self.client.post("/projects/validate", Some(request)).await?;
+        for warning in local.issues {
+            if !report.issues.contains(&warning) {
+                report.issues.push(warning);
+            }
+        }
+        Ok(report)
+    }
+
+    /// Create several projects in one request
+    ///
+    /// Each project is validated and created independently; check the per-item
+    /// results for failures.
+    ///
+    /// # Arguments
+    ///
+    /// * `requests` - The projects to create
+    pub async fn create_many(&self, requests: Vec<CreateProjectRequest>) -> Result<BatchResponse<Project>> {
+        let body = serde_json::json!({ "projects": requests });
+        self.client.post("/projects/batch", Some(&body)).await
+    }
+
     /// Update an existing project
     ///
     /// # Arguments
     ///
     /// * `project_id` - The project ID
     /// * `request` - Project update data
-    pub async fn update(&self, project_id: &str, request: UpdateProjectRequest) -> Result<Project> {
-        self.client.patch(&format!("/projects/{}", project_id), Some(&request)).await
+    pub async fn update(&self, project_id: impl Into<ProjectId>, request: UpdateProjectRequest) -> Result<Project> {
+        self.client.patch(&project_path(project_id.into(), ""), Some(&request)).await
     }
 
     /// Launch a project (make it active)
@@ -170,8 +318,8 @@ impl ProjectsService {
     /// # Arguments
     ///
     /// * `project_id` - The project ID
-    pub async fn launch(&self, project_id: &str) -> Result<Project> {
-        self.client.post(&format!("/projects/{}/launch", project_id), None::<&()>).await
+    pub async fn launch(&self, project_id: impl Into<ProjectId>) -> Result<Project> {
+        self.client.post(&project_path(project_id.into(), "/launch"), None::<&()>).await
     }
 
     /// Pause a project
@@ -179,8 +327,8 @@ impl ProjectsService {
     /// # Arguments
     ///
     /// * `project_id` - The project ID
-    pub async fn pause(&self, project_id: &str) -> Result<Project> {
-        self.client.post(&format!("/projects/{}/pause", project_id), None::<&()>).await
+    pub async fn pause(&self, project_id: impl Into<ProjectId>) -> Result<Project> {
+        self.client.post(&project_path(project_id.into(), "/pause"), None::<&()>).await
     }
 
     /// Resume a paused project
@@ -188,8 +336,8 @@ impl ProjectsService {
     /// # Arguments
     ///
     /// * `project_id` - The project ID
-    pub async fn resume(&self, project_id: &str) -> Result<Project> {
-        self.client.post(&format!("/projects/{}/resume", project_id), None::<&()>).await
+    pub async fn resume(&self, project_id: impl Into<ProjectId>) -> Result<Project> {
+        self.client.post(&project_path(project_id.into(), "/resume"), None::<&()>).await
     }
 
     /// Cancel a project
@@ -197,8 +345,94 @@ impl ProjectsService {
     /// # Arguments
     ///
     /// * `project_id` - The project ID
-    pub async fn cancel(&self, project_id: &str) -> Result<Project> {
-        self.client.post(&format!("/projects/{}/cancel", project_id), None::<&()>).await
+    pub async fn cancel(&self, project_id: impl Into<ProjectId>) -> Result<Project> {
+        self.client.post(&project_path(project_id.into(), "/cancel"), None::<&()>).await
+    }
+
+    /// Schedule a project to launch automatically at a given time
+    ///
+    /// Fails with [`Error::Configuration`] without calling the API if `launch_at` is not
+    /// in the future.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `launch_at` - When the sale should go live
+    pub async fn schedule(&self, project_id: impl Into<ProjectId>, launch_at: DateTime<Utc>) -> Result<Project> {
+        if launch_at <= self.client.clock().utc_now() {
+            return Err(Error::Configuration(format!(
+                "Launch time {} is not in the future",
+                launch_at.to_rfc3339()
+            )));
+        }
+
+        let body = serde_json::json!({ "launch_at": launch_at });
+        self.client.post(&project_path(project_id.into(), "/schedule"), Some(&body)).await
+    }
+
+    /// Create a draft project from an existing project's configuration
+    ///
+    /// Tiers, escrow settings, and documents are copied; investments and statistics
+    /// are not. Fields set in `overrides` replace the copied values.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project to copy
+    /// * `overrides` - Fields to change on the copy
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use xrplsale::{prelude::UpdateProjectRequest, Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let draft = client
+    ///     .projects()
+    ///     .clone_project("proj_abc123", UpdateProjectRequest::default())
+    ///     .await?;
+    /// println!("New draft: {}", draft.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn clone_project(&self, project_id: impl Into<ProjectId>, overrides: UpdateProjectRequest) -> Result<Project> {
+        self.client.post(&project_path(project_id.into(), "/clone"), Some(&overrides)).await
+    }
+
+    /// Archive a finished project
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    pub async fn archive(&self, project_id: impl Into<ProjectId>) -> Result<Project> {
+        self.client.post(&project_path(project_id.into(), "/archive"), None::<&()>).await
+    }
+
+    /// Settle a completed sale, releasing raised funds to the project
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    pub async fn settle(&self, project_id: impl Into<ProjectId>) -> Result<Project> {
+        self.client.post(&project_path(project_id.into(), "/settlement"), None::<&()>).await
+    }
+
+    /// Manage the project's team members, roles, and invitations
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    pub fn members(&self, project_id: impl Into<ProjectId>) -> ProjectMembersService {
+        ProjectMembersService::new(self.client.clone(), project_id)
+    }
+
+    /// Manage the project's milestones and roadmap
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    pub fn milestones(&self, project_id: impl Into<ProjectId>) -> MilestonesService {
+        MilestonesService::new(self.client.clone(), project_id)
     }
 
     /// Get project statistics
@@ -206,8 +440,92 @@ impl ProjectsService {
     /// # Arguments
     ///
     /// * `project_id` - The project ID
-    pub async fn stats(&self, project_id: &str) -> Result<ProjectStats> {
-        self.client.get(&format!("/projects/{}/stats", project_id), None).await
+    pub async fn stats(&self, project_id: impl Into<ProjectId>) -> Result<ProjectStats> {
+        self.client.get(&project_path(project_id.into(), "/stats")).await
+    }
+
+    /// Freeze the project's statistics into a final snapshot
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    pub async fn snapshot_stats(&self, project_id: impl Into<ProjectId>) -> Result<ProjectStats> {
+        self.client.post(&project_path(project_id.into(), "/stats/snapshot"), None::<&()>).await
+    }
+
+    /// Generate the project's cap table
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `as_of` - Snapshot to compute the cap table at; the latest state if `None`
+    pub async fn generate_cap_table(&self, project_id: impl Into<ProjectId>, as_of: Option<AsOf>) -> Result<CapTable> {
+        let body = serde_json::json!({ "as_of": as_of });
+        self.client.post(&project_path(project_id.into(), "/cap-table"), Some(&body)).await
+    }
+
+    /// Schedule the token distribution to investors
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `request` - Distribution schedule
+    pub async fn schedule_distribution(
+        &self,
+        project_id: impl Into<ProjectId>,
+        request: ScheduleDistributionRequest,
+    ) -> Result<Distribution> {
+        self.client
+            .post(&project_path(project_id.into(), "/distributions"), Some(&request))
+            .await
+    }
+
+    /// Get a scheduled distribution
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `distribution_id` - The distribution ID
+    pub async fn get_distribution(
+        &self,
+        project_id: impl Into<ProjectId>,
+        distribution_id: &str,
+    ) -> Result<Distribution> {
+        let suffix = format!("/distributions/{}", ids::path_segment(distribution_id));
+        self.client.get(&project_path(project_id.into(), &suffix)).await
+    }
+
+    /// Schedule the token distribution and track it until it finishes
+    ///
+    /// Unlike [`schedule_distribution`](Self::schedule_distribution), which returns as
+    /// soon as the distribution is scheduled, this returns an [`Operation`] to wait on.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `request` - Distribution schedule
+    pub async fn start_distribution(
+        &self,
+        project_id: impl Into<ProjectId>,
+        request: ScheduleDistributionRequest,
+    ) -> Result<Operation<Distribution>> {
+        let response = self
+            .client
+            .post_with_meta(&project_path(project_id.into(), "/distributions"), Some(&request))
+            .await?;
+        Operation::from_response(self.client.clone(), response)
+    }
+
+    /// Send a message to every investor in the project
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `message` - The message to send
+    pub async fn notify_investors(&self, project_id: impl Into<ProjectId>, message: InvestorMessage) -> Result<()> {
+        self.client
+            .post(&project_path(project_id.into(), "/communications"), Some(&message))
+            .await
     }
 
     /// Get project investors
@@ -219,21 +537,98 @@ impl ProjectsService {
     /// * `limit` - Number of items per page
     pub async fn investors(
         &self,
-        project_id: &str,
+        project_id: impl Into<ProjectId>,
         page: Option<u32>,
         limit: Option<u32>,
     ) -> Result<PaginatedResponse<Investment>> {
-        let mut query = HashMap::new();
+        let query = PageQuery { page, limit };
+        self.client.get_with_query(&project_path(project_id.into(), "/investors"), &query).await
+    }
 
-        if let Some(page) = page {
-            query.insert("page".to_string(), page.to_string());
-        }
-        if let Some(limit) = limit {
-            query.insert("limit".to_string(), limit.to_string());
-        }
+    /// Find the investors matching a segment query
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `query` - Segment filters and pagination
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use xrplsale::{types::projects::SegmentQuery, Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// // Investors with at least 5,000 XRP in tier 1 who have not claimed yet
+    /// let query = SegmentQuery::new()
+    ///     .min_invested_xrp("5000")
+    ///     .tier(1)
+    ///     .claimed(false);
+    /// let wallets = client.projects().segments("proj_abc123", query).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn segments(
+        &self,
+        project_id: impl Into<ProjectId>,
+        query: SegmentQuery,
+    ) -> Result<PaginatedResponse<SegmentMember>> {
+        self.client.post(&project_path(project_id.into(), "/segments"), Some(&query)).await
+    }
 
-        let query = if query.is_empty() { None } else { Some(&query) };
-        self.client.get(&format!("/projects/{}/investors", project_id), query).await
+    /// Get the project's escrow configuration
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    pub async fn escrow(&self, project_id: impl Into<ProjectId>) -> Result<EscrowConfig> {
+        self.client.get(&project_path(project_id.into(), "/escrow")).await
+    }
+
+    /// Get the issued token the project sells
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    pub async fn token(&self, project_id: impl Into<ProjectId>) -> Result<SaleToken> {
+        self.client.get(&project_path(project_id.into(), "/token")).await
+    }
+
+    /// List the on-ledger escrows holding the project's funds
+    ///
+    /// Each escrow carries its ledger sequence and transaction hashes, so it can be
+    /// reconciled against the XRPL directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    pub async fn escrows(&self, project_id: impl Into<ProjectId>) -> Result<Vec<Escrow>> {
+        self.client.get_list(&project_path(project_id.into(), "/escrows")).await
+    }
+
+    /// Request release of the escrows unlocked by a milestone
+    ///
+    /// The release happens once the request is approved; watch for the
+    /// `escrow.released` webhook event (see [`EscrowEvent`](crate::types::webhooks::EscrowEvent)).
+    ///
+    /// # Arguments
+    ///
+    /// * `milestone_id` - The completed milestone
+    pub async fn request_release(&self, milestone_id: &str) -> Result<ReleaseRequest> {
+        self.client.post(&format!("/milestones/{}/release", milestone_id), None::<&()>).await
+    }
+
+    /// Replace the project's escrow configuration
+    ///
+    /// The configuration is validated with [`EscrowConfig::validate`] before it is sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `config` - The new escrow configuration
+    pub async fn update_escrow(&self, project_id: impl Into<ProjectId>, config: EscrowConfig) -> Result<EscrowConfig> {
+        config.validate()?;
+        self.client.put(&project_path(project_id.into(), "/escrow"), Some(&config)).await
     }
 
     /// Get project tiers
@@ -241,8 +636,20 @@ impl ProjectsService {
     /// # Arguments
     ///
     /// * `project_id` - The project ID
-    pub async fn tiers(&self, project_id: &str) -> Result<Vec<ProjectTier>> {
-        self.client.get(&format!("/projects/{}/tiers", project_id), None).await
+    pub async fn tiers(&self, project_id: impl Into<ProjectId>) -> Result<Vec<ProjectTier>> {
+        self.client.get_list(&project_path(project_id.into(), "/tiers")).await
+    }
+
+    /// Get the sale progress of each tier
+    ///
+    /// Each entry reports tokens sold and remaining, and its pricing curve so the
+    /// current price can be computed with [`TierProgress::current_price`].
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    pub async fn tier_progress(&self, project_id: impl Into<ProjectId>) -> Result<Vec<TierProgress>> {
+        self.client.get_list(&project_path(project_id.into(), "/tiers/progress")).await
     }
 
     /// Update project tiers
@@ -251,9 +658,82 @@ impl ProjectsService {
     ///
     /// * `project_id` - The project ID
     /// * `tiers` - New tier configuration
-    pub async fn update_tiers(&self, project_id: &str, tiers: Vec<ProjectTier>) -> Result<Vec<ProjectTier>> {
+    pub async fn update_tiers(&self, project_id: impl Into<ProjectId>, tiers: Vec<ProjectTier>) -> Result<Vec<ProjectTier>> {
         let body = serde_json::json!({ "tiers": tiers });
-        self.client.put(&format!("/projects/{}/tiers", project_id), Some(&body)).await
+        self.client.put(&project_path(project_id.into(), "/tiers"), Some(&body)).await
+    }
+
+    /// Replace the tiers of several projects in one request
+    ///
+    /// # Arguments
+    ///
+    /// * `updates` - New tiers per project
+    pub async fn update_tiers_bulk(&self, updates: Vec<TierUpdate>) -> Result<BatchResponse<Vec<ProjectTier>>> {
+        let body = serde_json::json!({ "updates": updates });
+        self.client.put("/projects/tiers/batch", Some(&body)).await
+    }
+
+    /// Attach a document to a project
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `document` - The document to attach
+    pub async fn add_document(&self, project_id: impl Into<ProjectId>, document: ProjectDocument) -> Result<ProjectDocument> {
+        self.client.post(&project_path(project_id.into(), "/documents"), Some(&document)).await
+    }
+
+    /// Get project documents
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    pub async fn documents(&self, project_id: impl Into<ProjectId>) -> Result<Vec<ProjectDocument>> {
+        self.client.get_list(&project_path(project_id.into(), "/documents")).await
+    }
+
+    /// Create an alert on a project metric
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `alert` - The alert rule
+    pub async fn create_alert(&self, project_id: impl Into<ProjectId>, alert: ProjectAlert) -> Result<ProjectAlert> {
+        self.client.post(&project_path(project_id.into(), "/alerts"), Some(&alert)).await
+    }
+
+    /// Run the platform's pre-launch checks
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    pub async fn launch_checklist(&self, project_id: impl Into<ProjectId>) -> Result<LaunchChecklist> {
+        self.client.get(&project_path(project_id.into(), "/launch-checklist")).await
+    }
+
+    /// Check the on-ledger configuration of the project's issuing account
+    ///
+    /// Reports the account's `DefaultRipple` and `RequireAuth` flags, whether it is
+    /// blackholed, its tick size, and its transfer fee, with a pass/fail item for each.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use xrplsale::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// // Fails the CI job if any check does not pass
+    /// let checks = client.projects().issuer_checks("proj_abc123").await?.into_result()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn issuer_checks(&self, project_id: impl Into<ProjectId>) -> Result<IssuerChecks> {
+        self.client.get(&project_path(project_id.into(), "/issuer-checks")).await
     }
 
     /// Search projects
@@ -271,20 +751,8 @@ impl ProjectsService {
         page: Option<u32>,
         limit: Option<u32>,
     ) -> Result<PaginatedResponse<Project>> {
-        let mut params = HashMap::new();
-        params.insert("q".to_string(), query.to_string());
-
-        if let Some(status) = status {
-            params.insert("status".to_string(), status.to_string());
-        }
-        if let Some(page) = page {
-            params.insert("page".to_string(), page.to_string());
-        }
-        if let Some(limit) = limit {
-            params.insert("limit".to_string(), limit.to_string());
-        }
-
-        self.client.get("/projects/search", Some(&params)).await
+        let query = SearchQuery { q: query, status, page, limit };
+        self.client.get_with_query("/projects/search", &query).await
     }
 
     /// Get featured projects
@@ -293,15 +761,8 @@ impl ProjectsService {
     ///
     /// * `limit` - Maximum number of projects to return
     pub async fn featured(&self, limit: Option<u32>) -> Result<Vec<Project>> {
-        let mut query = HashMap::new();
-
-        if let Some(limit) = limit {
-            query.insert("limit".to_string(), limit.to_string());
-        }
-
-        let query = if query.is_empty() { None } else { Some(&query) };
-        let response: PaginatedResponse<Project> = self.client.get("/projects/featured", query).await?;
-        Ok(response.data.unwrap_or_default())
+        let query = HighlightQuery { period: None, limit };
+        self.client.get_list_with_query("/projects/featured", &query).await
     }
 
     /// Get trending projects
@@ -311,18 +772,8 @@ impl ProjectsService {
     /// * `period` - Time period (24h, 7d, 30d)
     /// * `limit` - Maximum number of projects to return
     pub async fn trending(&self, period: Option<&str>, limit: Option<u32>) -> Result<Vec<Project>> {
-        let mut query = HashMap::new();
-
-        if let Some(period) = period {
-            query.insert("period".to_string(), period.to_string());
-        }
-        if let Some(limit) = limit {
-            query.insert("limit".to_string(), limit.to_string());
-        }
-
-        let query = if query.is_empty() { None } else { Some(&query) };
-        let response: PaginatedResponse<Project> = self.client.get("/projects/trending", query).await?;
-        Ok(response.data.unwrap_or_default())
+        let query = HighlightQuery { period, limit };
+        self.client.get_list_with_query("/projects/trending", &query).await
     }
 
     /// Get all projects with automatic pagination
@@ -354,37 +805,63 @@ impl ProjectsService {
     /// # }
     /// ```
     pub fn stream_all(&self, status: Option<&str>) -> impl futures::Stream<Item = Result<Project>> + '_ {
-        use futures::stream::{self, StreamExt, TryStreamExt};
+        use futures::stream::{self, StreamExt};
 
+        const PAGE_SIZE: u32 = 50;
         let status = status.map(|s| s.to_string());
-        
-        stream::unfold((1u32, false), move |mut state| async move {
-            let (page, done) = state;
-            
-            if done {
-                return None;
-            }
 
-            let result = self.list(status.as_deref(), Some(page), Some(50), None, None).await;
-            
-            match result {
-                Ok(response) => {
-                    let has_more = response.pagination.as_ref()
-                        .map(|p| p.page < p.total_pages)
-                        .unwrap_or(false);
-                    
-                    state.0 = page + 1;
-                    state.1 = !has_more;
-                    
-                    let projects = response.data.unwrap_or_default();
-                    Some((stream::iter(projects.into_iter().map(Ok)), state))
-                }
-                Err(e) => {
-                    state.1 = true; // Stop on error
-                    Some((stream::iter(vec![Err(e)]), state))
+        stream::unfold(Some(1u32), move |page| {
+            let status = status.clone();
+            async move {
+                let page = page?;
+
+                match self.list(status.as_deref(), Some(page), Some(PAGE_SIZE), None, None).await {
+                    Ok(response) => {
+                        let next_page = response.next_page();
+                        let paginated = response.pagination.is_some();
+                        let projects = response.data.unwrap_or_default();
+                        // Without pagination metadata, keep going while pages come back full
+                        let next = if paginated {
+                            next_page
+                        } else {
+                            (projects.len() as u32 >= PAGE_SIZE).then_some(page + 1)
+                        };
+
+                        let items: Vec<Result<Project>> = projects.into_iter().map(Ok).collect();
+                        Some((stream::iter(items), next))
+                    }
+                    // Stop on error
+                    Err(e) => Some((stream::iter(vec![Err(e)]), None)),
                 }
             }
         })
         .flat_map(|s| s)
     }
-}
\ No newline at end of file
+}
+
+fn project_path(project_id: ProjectId, suffix: &str) -> String {
+    format!("/projects/{}{}", project_id.path_segment(), suffix)
+}
+
+/// Query parameters of paged listings
+#[derive(Serialize)]
+struct PageQuery {
+    page: Option<u32>,
+    limit: Option<u32>,
+}
+
+/// Query parameters of `/projects/search`
+#[derive(Serialize)]
+struct SearchQuery<'a> {
+    q: &'a str,
+    status: Option<&'a str>,
+    page: Option<u32>,
+    limit: Option<u32>,
+}
+
+/// Query parameters of the featured and trending listings
+#[derive(Serialize)]
+struct HighlightQuery<'a> {
+    period: Option<&'a str>,
+    limit: Option<u32>,
+}
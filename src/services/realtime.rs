@@ -0,0 +1,477 @@
+//! Realtime service for live updates
+
+use crate::{
+    client::Client,
+    error::{Error, Result},
+    response::ByteStream,
+    sse::{SseFrame, SseParser},
+    types::realtime::RealtimeEvent,
+};
+use futures::{stream, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{collections::VecDeque, time::Duration};
+
+/// Reconnection delay when the server has not asked for another
+const DEFAULT_RETRY: Duration = Duration::from_secs(3);
+
+/// How long one event stream connection is kept before it is recycled
+///
+/// The request timeout covers the whole body, so a long-lived stream needs its own.
+const CONNECTION_LIFETIME: Duration = Duration::from_secs(300);
+
+/// How long the API holds a poll open waiting for events
+const POLL_WAIT: Duration = Duration::from_secs(30);
+
+/// Time allowed for a poll beyond [`POLL_WAIT`] before it is abandoned
+const POLL_GRACE: Duration = Duration::from_secs(10);
+
+/// Service for subscribing to live updates
+#[derive(Debug, Clone)]
+pub struct RealtimeService {
+    client: Client,
+}
+
+impl RealtimeService {
+    /// Create a new realtime service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Subscribe to topics, falling back to long polling where streaming is blocked
+    ///
+    /// Starts on server-sent events like [`subscribe_sse`](Self::subscribe_sse). If the
+    /// event stream can't be opened before its first event arrives, e.g. because a
+    /// proxy rejects it or answers with something other than an event stream, the
+    /// subscription moves to long polling, as with
+    /// [`subscribe_long_poll`](Self::subscribe_long_poll), and stays there. Either way
+    /// the stream yields the same events and errors.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// # use xrplsale::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let mut events = Box::pin(client.realtime().subscribe(["investments"]));
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     let event = event?;
+    ///     println!("{}: {}", event.topic, event.data);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscribe<I, S>(&self, topics: I) -> impl Stream<Item = Result<RealtimeEvent>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let sse = SseSubscription::new(&self.client, topic_list(topics));
+        events(Subscription {
+            transport: Transport::Sse(Box::new(sse)),
+            fallback: true,
+        })
+    }
+
+    /// Subscribe to topics over server-sent events
+    ///
+    /// For networks where WebSockets don't make it through proxies. When the
+    /// connection drops, the stream reconnects after the delay the server asked for
+    /// and sends `Last-Event-ID`, so the server resumes after the last event received.
+    /// Errors from reconnecting and events with malformed data are yielded without
+    /// ending the stream; errors that retrying can't fix, such as an invalid API key,
+    /// end it. Pin the stream (e.g. with `Box::pin`) before polling it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// # use xrplsale::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let mut events = Box::pin(
+    ///     client
+    ///         .realtime()
+    ///         .subscribe_sse(["projects.proj_abc123.stats", "investments"]),
+    /// );
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     let event = event?;
+    ///     println!("{}: {}", event.topic, event.data);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscribe_sse<I, S>(&self, topics: I) -> impl Stream<Item = Result<RealtimeEvent>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let sse = SseSubscription::new(&self.client, topic_list(topics));
+        events(Subscription {
+            transport: Transport::Sse(Box::new(sse)),
+            fallback: false,
+        })
+    }
+
+    /// Subscribe to topics by long polling
+    ///
+    /// For networks that block both WebSockets and event streams. Each poll is an
+    /// ordinary request that the API holds open until events arrive or it times out,
+    /// and asks for the events after the last one received. Errors are handled as in
+    /// [`subscribe_sse`](Self::subscribe_sse): a failed poll is yielded and retried
+    /// after a delay, and errors that retrying can't fix end the stream.
+    pub fn subscribe_long_poll<I, S>(&self, topics: I) -> impl Stream<Item = Result<RealtimeEvent>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let poll = LongPollSubscription::new(&self.client, topic_list(topics), None);
+        events(Subscription {
+            transport: Transport::LongPoll(Box::new(poll)),
+            fallback: false,
+        })
+    }
+}
+
+/// Topics as the endpoints expect them, comma-separated
+fn topic_list<I, S>(topics: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let topics: Vec<String> = topics.into_iter().map(Into::into).collect();
+    topics.join(",")
+}
+
+/// Stream a subscription's events until an error retrying can't fix
+fn events(subscription: Subscription) -> impl Stream<Item = Result<RealtimeEvent>> {
+    stream::unfold(Some(subscription), |subscription| async move {
+        let mut subscription = subscription?;
+        match subscription.next_event().await {
+            Ok(event) => Some((Ok(event), Some(subscription))),
+            // A malformed event is skipped; the connection is still good
+            Err(e @ Error::Parse(_)) => Some((Err(e), Some(subscription))),
+            Err(e) if e.is_retryable() => Some((Err(e), Some(subscription))),
+            Err(e) => Some((Err(e), None)),
+        }
+    })
+}
+
+/// A subscription on whichever transport it is using
+struct Subscription {
+    transport: Transport,
+    /// Whether to move to long polling if the event stream can't be opened
+    fallback: bool,
+}
+
+enum Transport {
+    Sse(Box<SseSubscription>),
+    LongPoll(Box<LongPollSubscription>),
+}
+
+impl Subscription {
+    async fn next_event(&mut self) -> Result<RealtimeEvent> {
+        loop {
+            let sse = match &mut self.transport {
+                Transport::LongPoll(poll) => return poll.next_event().await,
+                Transport::Sse(sse) => sse,
+            };
+
+            match sse.next_event().await {
+                // Any event, even a malformed one, shows the stream gets through, so it
+                // is kept through later outages
+                Ok(event) => {
+                    self.fallback = false;
+                    return Ok(event);
+                }
+                Err(e @ Error::Parse(_)) => {
+                    self.fallback = false;
+                    return Err(e);
+                }
+                // Credentials that fail here fail on every transport
+                Err(e) if self.fallback && !e.is_auth_error() => {
+                    log::info!(
+                        "Event stream unavailable, falling back to long polling: {}",
+                        e
+                    );
+                    let poll = LongPollSubscription::new(
+                        &sse.client,
+                        sse.query.topics.clone(),
+                        sse.parser.last_event_id().map(str::to_string),
+                    );
+                    self.transport = Transport::LongPoll(Box::new(poll));
+                    self.fallback = false;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// State of an event stream across reconnects
+struct SseSubscription {
+    client: Client,
+    query: SseQuery,
+    parser: SseParser,
+    body: Option<ByteStream>,
+    reconnecting: bool,
+}
+
+impl SseSubscription {
+    fn new(client: &Client, topics: String) -> Self {
+        Self {
+            client: client.with_timeout(CONNECTION_LIFETIME),
+            query: SseQuery { topics },
+            parser: SseParser::default(),
+            body: None,
+            reconnecting: false,
+        }
+    }
+
+    /// Read until the next event, reconnecting when the connection ends
+    async fn next_event(&mut self) -> Result<RealtimeEvent> {
+        loop {
+            if let Some(frame) = self.parser.next_frame() {
+                return event(frame);
+            }
+
+            let Some(body) = &mut self.body else {
+                self.connect().await?;
+                continue;
+            };
+            match body.next().await {
+                Some(Ok(chunk)) => self.parser.feed(&chunk),
+                Some(Err(e)) => {
+                    log::debug!("Event stream interrupted, reconnecting: {}", e);
+                    self.disconnect();
+                }
+                None => self.disconnect(),
+            }
+        }
+    }
+
+    async fn connect(&mut self) -> Result<()> {
+        if self.reconnecting {
+            let delay = self.parser.retry().unwrap_or(DEFAULT_RETRY);
+            self.client.clock().sleep(delay).await;
+        }
+
+        // Whatever happens, a later attempt waits before connecting again
+        self.reconnecting = true;
+        let body = self
+            .client
+            .get_event_stream("/realtime/sse", &self.query, self.parser.last_event_id())
+            .await?;
+        if !is_event_stream(body.content_type.as_deref()) {
+            return Err(Error::HttpClient(format!(
+                "Expected an event stream, got {}",
+                body.content_type.as_deref().unwrap_or("no content type")
+            )));
+        }
+        self.body = Some(body);
+        self.reconnecting = false;
+        Ok(())
+    }
+
+    fn disconnect(&mut self) {
+        self.body = None;
+        self.parser.reset();
+        self.reconnecting = true;
+    }
+}
+
+/// Whether a response is an event stream rather than, say, a proxy's error page
+fn is_event_stream(content_type: Option<&str>) -> bool {
+    content_type
+        .and_then(|content_type| content_type.split(';').next())
+        .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("text/event-stream"))
+}
+
+fn event(frame: SseFrame) -> Result<RealtimeEvent> {
+    let data = serde_json::from_str(&frame.data).map_err(|e| {
+        Error::Parse(format!(
+            "Invalid data in '{}' event {:?}: {}",
+            frame.event, frame.id, e
+        ))
+    })?;
+
+    Ok(RealtimeEvent {
+        id: frame.id,
+        topic: frame.event,
+        data,
+    })
+}
+
+/// Query parameters of the event stream
+#[derive(Serialize)]
+struct SseQuery {
+    /// Comma-separated, as the endpoint expects
+    topics: String,
+}
+
+/// State of a long-polling subscription across polls
+struct LongPollSubscription {
+    client: Client,
+    query: PollQuery,
+    pending: VecDeque<RealtimeEvent>,
+    retrying: bool,
+}
+
+impl LongPollSubscription {
+    fn new(client: &Client, topics: String, after: Option<String>) -> Self {
+        Self {
+            // Each poll must reach the API, and may take as long as it holds it open
+            client: client.without_cache().with_timeout(POLL_WAIT + POLL_GRACE),
+            query: PollQuery {
+                topics,
+                after,
+                wait: POLL_WAIT.as_secs(),
+            },
+            pending: VecDeque::new(),
+            retrying: false,
+        }
+    }
+
+    /// Poll until the next event
+    async fn next_event(&mut self) -> Result<RealtimeEvent> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                if let Some(id) = &event.id {
+                    self.query.after = Some(id.clone());
+                }
+                return Ok(event);
+            }
+
+            if self.retrying {
+                self.client.clock().sleep(DEFAULT_RETRY).await;
+            }
+            self.retrying = true;
+            let page: PollPage = self
+                .client
+                .get_with_query("/realtime/poll", &self.query)
+                .await?;
+            self.retrying = false;
+            self.pending.extend(page.events);
+        }
+    }
+}
+
+/// Query parameters of a poll
+#[derive(Serialize)]
+struct PollQuery {
+    /// Comma-separated, as the endpoint expects
+    topics: String,
+    /// ID of the last event received
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<String>,
+    /// Seconds to wait for events before answering with none
+    wait: u64,
+}
+
+/// Events returned by a poll, empty if none arrived in time
+#[derive(Deserialize)]
+struct PollPage {
+    events: Vec<RealtimeEvent>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, Request, ResponseTemplate,
+    };
+
+    fn client(server: &MockServer) -> Client {
+        Client::builder()
+            .api_key("t")
+            .base_url(format!("{}/v1/", server.uri()))
+            .max_retries(0)
+            .build()
+            .unwrap()
+    }
+
+    fn poll_after(after: Option<&'static str>) -> impl Fn(&Request) -> bool {
+        move |request: &Request| {
+            let query = request.url.query().unwrap_or_default();
+            query.contains("topics=investments")
+                && match after {
+                    Some(id) => query.contains(&format!("after={}", id)),
+                    None => !query.contains("after="),
+                }
+        }
+    }
+
+    fn events(ids: &[&str]) -> ResponseTemplate {
+        let events: Vec<_> = ids
+            .iter()
+            .map(|id| json!({ "id": id, "topic": "investments", "data": { "n": id } }))
+            .collect();
+        ResponseTemplate::new(200).set_body_json(json!({ "events": events }))
+    }
+
+    #[tokio::test]
+    async fn subscribe_falls_back_to_long_polling() {
+        let server = MockServer::start().await;
+        // A proxy answering the event stream with its own page
+        Mock::given(method("GET"))
+            .and(path("/v1/realtime/sse"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw("<html>Blocked</html>", "text/html"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/realtime/poll"))
+            .and(poll_after(None))
+            .respond_with(events(&["1"]))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/realtime/poll"))
+            .and(poll_after(Some("1")))
+            .respond_with(events(&["2", "3"]))
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let stream = client.realtime().subscribe(["investments"]);
+        let received: Vec<_> = Box::pin(stream)
+            .take(3)
+            .map(|event| event.unwrap().id.unwrap())
+            .collect()
+            .await;
+        assert_eq!(received, ["1", "2", "3"]);
+    }
+
+    #[tokio::test]
+    async fn subscribe_sse_does_not_fall_back() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/realtime/sse"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw("<html>Blocked</html>", "text/html"),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/v1/realtime/poll"))
+            .respond_with(events(&["1"]))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let mut stream = Box::pin(client.realtime().subscribe_sse(["investments"]));
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(Error::HttpClient(_)))
+        ));
+    }
+}
@@ -0,0 +1,276 @@
+//! Reconciliation service for checking platform records against the XRPL
+
+use crate::{
+    client::Client,
+    error::Result,
+    ids::ProjectId,
+    types::{
+        investments::{is_unpaid, Investment},
+        reconcile::{LedgerPayment, Mismatch, MismatchField, ReconcileRange, ReconciliationReport},
+    },
+    xrpl_rpc::{self, RpcError, XrplRpc},
+};
+use chrono::{DateTime, Duration, Utc};
+use serde_json::{json, Value};
+
+/// Page size of platform listings and `account_tx` requests
+const PAGE_SIZE: u32 = 200;
+
+/// Seconds of history compared on both sides of the window, so an investment
+/// recorded just inside it still finds a payment validated just outside
+const SLACK_SECS: i64 = 300;
+
+/// Service for proving platform records match the XRP Ledger
+///
+/// Payments are read from a public XRPL JSON-RPC endpoint chosen by the client's
+/// environment; set [`rpc_url`](Self::rpc_url) to use your own node, which is
+/// required for [`Environment::Custom`].
+#[derive(Debug, Clone)]
+pub struct ReconcileService {
+    client: Client,
+    rpc_url: Option<String>,
+    account: Option<String>,
+}
+
+impl ReconcileService {
+    /// Create a new reconciliation service
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            rpc_url: None,
+            account: None,
+        }
+    }
+
+    /// Use this XRPL JSON-RPC endpoint, e.g. `http://localhost:5005`
+    pub fn rpc_url<S: Into<String>>(mut self, rpc_url: S) -> Self {
+        self.rpc_url = Some(rpc_url.into());
+        self
+    }
+
+    /// Look payments up on this account instead of the project's issuer
+    pub fn account<S: Into<String>>(mut self, account: S) -> Self {
+        self.account = Some(account.into());
+        self
+    }
+
+    /// Compare a project's investments with the XRP payments its account received
+    ///
+    /// Investments are matched to payments by sender and amount; an investment left
+    /// over is paired with a remaining payment from the same sender and reported as
+    /// mismatched. Investments with status `failed` or `cancelled` are skipped. Only successful payments count,
+    /// using the amount actually delivered.
+    ///
+    /// # Arguments
+    ///
+    /// * `project_id` - The project ID
+    /// * `range` - Window of investment and payment times to compare
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use xrplsale::Client;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let to = chrono::Utc::now();
+    /// let report = client
+    ///     .reconcile()
+    ///     .investments("proj_abc123", to - chrono::Duration::days(7)..to)
+    ///     .await?;
+    ///
+    /// for payment in &report.missing_on_platform {
+    ///     println!("No investment for {} ({} XRP)", payment.hash, payment.delivered_xrp());
+    /// }
+    /// println!(
+    ///     "{} matched, {} missing on the ledger, {} mismatched",
+    ///     report.matched,
+    ///     report.missing_on_ledger.len(),
+    ///     report.mismatched.len()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn investments(
+        &self,
+        project_id: impl Into<ProjectId>,
+        range: impl Into<ReconcileRange>,
+    ) -> Result<ReconciliationReport> {
+        let project_id = project_id.into();
+        let range = range.into();
+        let account = match &self.account {
+            Some(account) => account.clone(),
+            None => {
+                self.client
+                    .projects()
+                    .token(project_id.clone())
+                    .await?
+                    .issuer
+            }
+        };
+
+        let investments = self.platform_investments(&project_id, &range).await?;
+        let mut payments = self.ledger_payments(&account, &range).await?;
+
+        let mut report = ReconciliationReport {
+            project_id,
+            account,
+            range,
+            matched: 0,
+            missing_on_ledger: Vec::new(),
+            missing_on_platform: Vec::new(),
+            mismatched: Vec::new(),
+        };
+
+        // Exact matches first, so a differing amount can't claim another's payment
+        let mut unmatched = Vec::new();
+        for (investment, record) in investments {
+            let found = payments.iter().position(|payment| {
+                payment.account == record.account && Some(payment.delivered_drops) == record.drops
+            });
+            match found {
+                Some(index) => {
+                    payments.swap_remove(index);
+                    // Investments just outside the window only claim their payment
+                    report.matched += usize::from(range.contains(&record.created_at));
+                }
+                None => unmatched.push((investment, record)),
+            }
+        }
+
+        // A leftover payment from the investor is taken to be theirs, for another amount
+        for (investment, record) in unmatched {
+            let in_range = range.contains(&record.created_at);
+            match payments
+                .iter()
+                .position(|payment| payment.account == record.account)
+            {
+                Some(index) => report.mismatched.push(Mismatch {
+                    investment,
+                    payment: payments.swap_remove(index),
+                    fields: vec![MismatchField::Amount],
+                }),
+                None if in_range => report.missing_on_ledger.push(investment),
+                None => {}
+            }
+        }
+
+        report.missing_on_platform = payments
+            .into_iter()
+            .filter(|payment| report.range.contains(&payment.executed_at))
+            .collect();
+        report
+            .missing_on_platform
+            .sort_by_key(|payment| payment.ledger_index);
+        Ok(report)
+    }
+
+    /// The project's investments around the window that should have a payment
+    async fn platform_investments(
+        &self,
+        project_id: &ProjectId,
+        range: &ReconcileRange,
+    ) -> Result<Vec<(Investment, InvestmentRecord)>> {
+        let (from, to) = widened(range);
+        let mut investments = Vec::new();
+        let mut page = Some(1);
+
+        while let Some(current) = page {
+            let response = self
+                .client
+                .investments()
+                .get_by_project(project_id.as_str(), Some(current), Some(PAGE_SIZE))
+                .await?;
+            page = response.next_page();
+
+            for investment in response.data.unwrap_or_default() {
+                let record = InvestmentRecord::read(&investment);
+                let near_range = from <= record.created_at && record.created_at < to;
+                if !is_unpaid(&investment) && near_range {
+                    investments.push((investment, record));
+                }
+            }
+        }
+
+        Ok(investments)
+    }
+
+    /// Successful XRP payments to `account` validated around the window
+    async fn ledger_payments(
+        &self,
+        account: &str,
+        range: &ReconcileRange,
+    ) -> Result<Vec<LedgerPayment>> {
+        const SETTING: &str = "ReconcileService::rpc_url";
+        let rpc = XrplRpc::new(&self.client, self.rpc_url.as_deref())
+            .map_err(|e| e.into_error(account, SETTING))?;
+        let (from, to) = widened(range);
+        let mut payments = Vec::new();
+        let mut marker: Option<Value> = None;
+
+        loop {
+            let mut params = json!({
+                "account": account,
+                "ledger_index_min": -1,
+                "ledger_index_max": -1,
+                "limit": PAGE_SIZE,
+                "forward": false,
+            });
+            if let Some(marker) = marker.take() {
+                params["marker"] = marker;
+            }
+            let result = match rpc.call("account_tx", params).await {
+                Ok(result) => result,
+                // An account that was never funded has no history
+                Err(RpcError::Ledger(code)) if code == "actNotFound" => return Ok(payments),
+                Err(e) => return Err(e.into_error(account, SETTING)),
+            };
+
+            let mut reached_start = false;
+            for entry in result["transactions"].as_array().into_iter().flatten() {
+                let Some(payment) = xrpl_rpc::payment(entry, account) else {
+                    continue;
+                };
+                if payment.executed_at < from {
+                    reached_start = true;
+                    break;
+                }
+                if payment.executed_at < to {
+                    payments.push(payment);
+                }
+            }
+
+            marker = result
+                .get("marker")
+                .filter(|marker| !marker.is_null())
+                .cloned();
+            if reached_start || marker.is_none() {
+                return Ok(payments);
+            }
+        }
+    }
+}
+
+/// The window with the slack added on both sides
+fn widened(range: &ReconcileRange) -> (DateTime<Utc>, DateTime<Utc>) {
+    let slack = Duration::seconds(SLACK_SECS);
+    (range.from - slack, range.to + slack)
+}
+
+/// The fields of an investment that reconciliation compares
+struct InvestmentRecord {
+    account: String,
+    /// `None` if the amount isn't a valid XRP amount, so it matches no payment
+    drops: Option<u64>,
+    created_at: DateTime<Utc>,
+}
+
+impl InvestmentRecord {
+    fn read(investment: &Investment) -> Self {
+        Self {
+            account: investment.investor_account.to_string(),
+            drops: xrpl_rpc::xrp_to_drops(&investment.amount_xrp),
+            created_at: investment.created_at,
+        }
+    }
+}
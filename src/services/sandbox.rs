@@ -0,0 +1,165 @@
+//! Sandbox service for simulating sale activity on Testnet
+
+use crate::{
+    client::Client,
+    error::{Error, Result},
+    ids::ProjectId,
+    models::Investment,
+    types::{
+        projects::TierProgress,
+        sandbox::{SaleClock, SimulateInvestmentRequest, SyntheticEventRequest},
+    },
+    webhook::WebhookEvent,
+    Environment,
+};
+use chrono::{DateTime, Duration, Utc};
+
+/// Service for driving a project through its lifecycle in integration tests
+///
+/// Only available on [`Environment::Testnet`]; see [`Client::sandbox`]. Sandbox calls
+/// change the project as real activity would, so the normal services and webhooks see
+/// the results.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use xrplsale::{types::sandbox::{SimulateInvestmentRequest, SyntheticEventRequest}, Client, Environment};
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::builder()
+///     .api_key("test-key")
+///     .environment(Environment::Testnet)
+///     .build()?;
+/// let sandbox = client.sandbox()?;
+///
+/// // Open the sale, sell out tier 1, and invest in tier 2
+/// sandbox.advance_clock("proj_abc123", chrono::Duration::days(7)).await?;
+/// sandbox.sell_out_tier("proj_abc123", 1).await?;
+/// let investment = sandbox
+///     .simulate_investment(SimulateInvestmentRequest::new("proj_abc123", "250").tier(2))
+///     .await?;
+///
+/// sandbox
+///     .emit_event(SyntheticEventRequest::new("project.completed").project("proj_abc123"))
+///     .await?;
+///
+/// sandbox.reset_clock("proj_abc123").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SandboxService {
+    client: Client,
+}
+
+impl SandboxService {
+    /// Create a new sandbox service
+    ///
+    /// Fails with [`Error::Configuration`] unless the client targets Testnet.
+    pub fn new(client: Client) -> Result<Self> {
+        if *client.environment() != Environment::Testnet {
+            return Err(Error::Configuration(format!(
+                "The sandbox is only available on testnet, not {}",
+                client.environment()
+            )));
+        }
+        Ok(Self { client })
+    }
+
+    /// Move a project's sale clock forward
+    ///
+    /// Sale start and end dates, tier schedules, and vesting are evaluated against the
+    /// moved clock.
+    pub async fn advance_clock(
+        &self,
+        project_id: impl Into<ProjectId>,
+        by: Duration,
+    ) -> Result<SaleClock> {
+        if by <= Duration::zero() {
+            return Err(Error::Configuration(
+                "Invalid clock change: the clock can only move forward".to_string(),
+            ));
+        }
+        self.client
+            .post(
+                &clock_path(&project_id.into()),
+                Some(&serde_json::json!({ "advance_seconds": by.num_seconds() })),
+            )
+            .await
+    }
+
+    /// Set a project's sale clock to a point in time
+    ///
+    /// Fails if `to` is before the project's current simulated time.
+    pub async fn set_clock(
+        &self,
+        project_id: impl Into<ProjectId>,
+        to: DateTime<Utc>,
+    ) -> Result<SaleClock> {
+        self.client
+            .post(
+                &clock_path(&project_id.into()),
+                Some(&serde_json::json!({ "at": to })),
+            )
+            .await
+    }
+
+    /// Return a project's sale clock to real time
+    pub async fn reset_clock(&self, project_id: impl Into<ProjectId>) -> Result<()> {
+        self.client.delete(&clock_path(&project_id.into())).await
+    }
+
+    /// Invest in a project from a faucet-funded Testnet wallet
+    ///
+    /// Returns the investment once it is confirmed on the ledger. Fails with
+    /// [`Error::Configuration`] without calling the API if the amount is not positive or
+    /// the tier is 0.
+    pub async fn simulate_investment(
+        &self,
+        request: SimulateInvestmentRequest,
+    ) -> Result<Investment> {
+        request.validate()?;
+        self.client
+            .post("/sandbox/investments", Some(&request))
+            .await
+    }
+
+    /// Sell the remaining tokens of a tier, moving the sale on to the next tier
+    ///
+    /// Returns the project's tier progress after the sell-out.
+    pub async fn sell_out_tier(
+        &self,
+        project_id: impl Into<ProjectId>,
+        tier: u32,
+    ) -> Result<Vec<TierProgress>> {
+        if tier == 0 {
+            return Err(Error::Configuration(
+                "Invalid tier: tiers are numbered from 1".to_string(),
+            ));
+        }
+        let project_id: ProjectId = project_id.into();
+        self.client
+            .post(
+                &format!(
+                    "/sandbox/projects/{}/tiers/{}/sell-out",
+                    project_id.path_segment(),
+                    tier
+                ),
+                None::<&()>,
+            )
+            .await
+    }
+
+    /// Deliver a synthetic webhook event to the registered endpoints
+    ///
+    /// The event is signed like a real delivery. Returns the event as sent.
+    pub async fn emit_event(&self, request: SyntheticEventRequest) -> Result<WebhookEvent> {
+        self.client
+            .post("/sandbox/webhooks/events", Some(&request))
+            .await
+    }
+}
+
+fn clock_path(project_id: &ProjectId) -> String {
+    format!("/sandbox/projects/{}/clock", project_id.path_segment())
+}
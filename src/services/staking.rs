@@ -0,0 +1,167 @@
+//! Staking service for token locking programs
+
+use crate::{
+    client::Client,
+    error::Result,
+    ids::ProjectId,
+    types::staking::{
+        PreparedTransaction, SignedTransaction, StakePosition, StakingProgram,
+        SubmittedTransaction, TierProjection,
+    },
+    wallet::WalletSigner,
+};
+use serde::Serialize;
+
+/// Service for staking launched tokens to earn sale tier access
+///
+/// Staking and unstaking are XRPL transactions sent from the investor's wallet. The
+/// API prepares each transaction, the wallet signs it through a [`WalletSigner`], and
+/// the signed transaction is submitted back. [`stake`](Self::stake) and
+/// [`unstake`](Self::unstake) run all three steps.
+///
+/// # Example
+///
+/// ```rust
+/// # use xrplsale::{wallet::WalletSigner, Client};
+/// # async fn stake(client: &Client, wallet: &dyn WalletSigner) -> xrplsale::Result<()> {
+/// let projection = client
+///     .staking()
+///     .projected_tier(&wallet.account(), "proj_abc123", Some("5000"))
+///     .await?;
+///
+/// if projection.projected_tier > projection.current_tier {
+///     let submitted = client.staking().stake("prog_123", "5000", wallet).await?;
+///     println!("Staked in {}", submitted.tx_hash);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StakingService {
+    client: Client,
+}
+
+impl StakingService {
+    /// Create a new staking service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// List staking programs, optionally for one project
+    pub async fn programs(&self, project_id: Option<&str>) -> Result<Vec<StakingProgram>> {
+        let query = ProgramQuery { project_id };
+        self.client
+            .get_list_with_query("/staking/programs", &query)
+            .await
+    }
+
+    /// Get a staking program
+    pub async fn program(&self, program_id: &str) -> Result<StakingProgram> {
+        self.client
+            .get(&format!("/staking/programs/{}", program_id))
+            .await
+    }
+
+    /// List an account's stake positions across all programs
+    pub async fn positions(&self, account: &str) -> Result<Vec<StakePosition>> {
+        self.client
+            .get_list(&format!("/staking/accounts/{}/positions", account))
+            .await
+    }
+
+    /// Project the sale tier an account would reach with more stake
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - The investor's XRPL address
+    /// * `project_id` - The project whose tiers to check
+    /// * `additional_amount` - Tokens the account is considering staking
+    pub async fn projected_tier(
+        &self,
+        account: &str,
+        project_id: impl Into<ProjectId>,
+        additional_amount: Option<&str>,
+    ) -> Result<TierProjection> {
+        let project_id: ProjectId = project_id.into();
+        let query = ProjectionQuery {
+            account,
+            project_id: &project_id,
+            additional_amount,
+        };
+        self.client
+            .get_with_query("/staking/projections", &query)
+            .await
+    }
+
+    /// Prepare a transaction staking `amount` tokens from `account`
+    pub async fn prepare_stake(
+        &self,
+        program_id: &str,
+        account: &str,
+        amount: &str,
+    ) -> Result<PreparedTransaction> {
+        self.client
+            .post(
+                &format!("/staking/programs/{}/stake", program_id),
+                Some(&serde_json::json!({ "account": account, "amount": amount })),
+            )
+            .await
+    }
+
+    /// Prepare a transaction withdrawing an unlocked position
+    pub async fn prepare_unstake(&self, position_id: &str) -> Result<PreparedTransaction> {
+        self.client
+            .post(
+                &format!("/staking/positions/{}/unstake", position_id),
+                None::<&()>,
+            )
+            .await
+    }
+
+    /// Submit a signed transaction to the ledger
+    pub async fn submit(&self, signed: &SignedTransaction) -> Result<SubmittedTransaction> {
+        self.client
+            .post(
+                &format!("/staking/transactions/{}/submit", signed.prepared_id),
+                Some(signed),
+            )
+            .await
+    }
+
+    /// Prepare, sign, and submit a stake from the signer's account
+    pub async fn stake(
+        &self,
+        program_id: &str,
+        amount: &str,
+        signer: &dyn WalletSigner,
+    ) -> Result<SubmittedTransaction> {
+        let prepared = self
+            .prepare_stake(program_id, &signer.account(), amount)
+            .await?;
+        self.submit(&prepared.sign(signer).await?).await
+    }
+
+    /// Prepare, sign, and submit the withdrawal of a position
+    pub async fn unstake(
+        &self,
+        position_id: &str,
+        signer: &dyn WalletSigner,
+    ) -> Result<SubmittedTransaction> {
+        let prepared = self.prepare_unstake(position_id).await?;
+        self.submit(&prepared.sign(signer).await?).await
+    }
+}
+
+/// Query parameters of the program listing
+#[derive(Serialize)]
+struct ProgramQuery<'a> {
+    project_id: Option<&'a str>,
+}
+
+/// Query parameters of a tier projection
+#[derive(Serialize)]
+struct ProjectionQuery<'a> {
+    account: &'a str,
+    project_id: &'a ProjectId,
+    additional_amount: Option<&'a str>,
+}
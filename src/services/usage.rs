@@ -0,0 +1,39 @@
+//! Usage service for API quota consumption
+
+use crate::{client::Client, error::Result, types::usage::Usage};
+
+/// Service for reading the account's API quota consumption
+///
+/// Use this to alert before plan limits are reached instead of discovering them
+/// through rate limit errors.
+#[derive(Debug, Clone)]
+pub struct UsageService {
+    client: Client,
+}
+
+impl UsageService {
+    /// Create a new usage service
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Get the current quota consumption
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use xrplsale::{types::usage::UsageThresholds, Client};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = Client::builder().api_key("test").build()?;
+    /// let usage = client.usage().get().await?;
+    /// for (quota, level) in usage.alerts(&UsageThresholds::default()) {
+    ///     println!("{} is at {:?}", quota, level);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self) -> Result<Usage> {
+        self.client.get("/usage").await
+    }
+}
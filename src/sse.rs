@@ -0,0 +1,118 @@
+//! Server-sent events parsing
+//!
+//! [`SseParser`] turns a `text/event-stream` body, fed in arbitrary chunks, into the
+//! events it carries. Lines end in `\n` or `\r\n`; comment lines (starting with `:`)
+//! and unknown fields are ignored.
+
+use std::{collections::VecDeque, time::Duration};
+
+/// One dispatched event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SseFrame {
+    /// The last event ID seen when the event was dispatched
+    pub id: Option<String>,
+    /// Value of the `event` field, `message` if absent
+    pub event: String,
+    /// The `data` lines, joined with `\n`
+    pub data: String,
+}
+
+/// Incremental `text/event-stream` parser
+#[derive(Debug, Default)]
+pub(crate) struct SseParser {
+    buffer: Vec<u8>,
+    event: Option<String>,
+    data: Option<String>,
+    /// ID of the event being received, committed when it is dispatched
+    id: Option<String>,
+    last_event_id: Option<String>,
+    retry: Option<Duration>,
+    frames: VecDeque<SseFrame>,
+}
+
+impl SseParser {
+    /// Parse a chunk of the body, queuing the events it completes
+    pub fn feed(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+
+        while let Some(end) = self.buffer.iter().position(|&byte| byte == b'\n') {
+            let mut line: Vec<u8> = self.buffer.drain(..=end).collect();
+            line.pop();
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+            self.line(&String::from_utf8_lossy(&line));
+        }
+    }
+
+    /// Take the next complete event
+    pub fn next_frame(&mut self) -> Option<SseFrame> {
+        self.frames.pop_front()
+    }
+
+    /// Drop a partially received event, e.g. after the connection dropped
+    ///
+    /// The last event ID and reconnection delay are kept.
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.event = None;
+        self.data = None;
+        self.id = self.last_event_id.clone();
+    }
+
+    /// ID of the last dispatched event, sent as `Last-Event-ID` when reconnecting
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// Reconnection delay requested by the server with a `retry` field
+    pub fn retry(&self) -> Option<Duration> {
+        self.retry
+    }
+
+    fn line(&mut self, line: &str) {
+        if line.is_empty() {
+            self.dispatch();
+            return;
+        }
+        if line.starts_with(':') {
+            return;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field {
+            "event" => self.event = Some(value.to_string()),
+            "data" => match &mut self.data {
+                Some(data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => self.data = Some(value.to_string()),
+            },
+            "id" if !value.contains('\0') => {
+                self.id = (!value.is_empty()).then(|| value.to_string());
+            }
+            "retry" => {
+                if let Ok(millis) = value.parse() {
+                    self.retry = Some(Duration::from_millis(millis));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn dispatch(&mut self) {
+        self.last_event_id = self.id.clone();
+        let event = self.event.take();
+        if let Some(data) = self.data.take() {
+            self.frames.push_back(SseFrame {
+                id: self.last_event_id.clone(),
+                event: event.unwrap_or_else(|| "message".to_string()),
+                data,
+            });
+        }
+    }
+}
@@ -0,0 +1,402 @@
+//! A local mirror of projects and investments
+//!
+//! [`SyncedStore`] keeps an in-memory copy of the account's projects and their
+//! investments, so latency-sensitive code such as trading bots can read them
+//! synchronously instead of calling the API:
+//!
+//! ```rust,no_run
+//! use xrplsale::{synced_store::SyncedStore, Client, ProjectId};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let client = Client::builder().api_key("test").build()?;
+//! let store = SyncedStore::new(client);
+//! store.load().await?;
+//!
+//! // Keep the mirror fresh in the background
+//! let follower = store.clone();
+//! tokio::spawn(async move { follower.follow().await });
+//!
+//! // Reads never touch the network
+//! let project_id = ProjectId::from("proj_abc123");
+//! if let Some(project) = store.project(&project_id) {
+//!     let investments = store.investments_for(&project_id);
+//!     println!("{}: {} investments", project.name, investments.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`load`](SyncedStore::load) fills the store from the list endpoints, then replays the
+//! changes made while it was listing. After that it is kept fresh by events, mapped with the default [`CdcMapper`]: `project.*` and
+//! `investment.*` events upsert the row from the event data, and `*.deleted` events
+//! remove it. Feed webhook events in with [`apply_webhook`](SyncedStore::apply_webhook),
+//! or let [`follow`](SyncedStore::follow) subscribe to them over server-sent events.
+//! Event data only needs the changed fields; it is merged into the stored row.
+//! [`refresh`](SyncedStore::refresh) catches up on project and investment changes
+//! missed while no events were received.
+
+use crate::{
+    cdc::{CdcMapper, ChangeOperation, ChangeRecord},
+    client::Client,
+    error::{Error, Result},
+    ids::{InvestmentId, ProjectId},
+    types::{
+        common::{ChangeKind, ChangeSet, SyncCursor},
+        investments::Investment,
+        projects::Project,
+        realtime::RealtimeEvent,
+    },
+    webhook::WebhookEvent,
+};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+/// Realtime topics carrying the events the store applies
+const TOPICS: [&str; 2] = ["project.*", "investment.*"];
+
+/// Page size of the initial load
+const PAGE_SIZE: u32 = 100;
+
+/// In-memory mirror of projects and investments, kept fresh by events
+///
+/// Cheap to clone; clones share the same data.
+#[derive(Debug, Clone)]
+pub struct SyncedStore {
+    client: Client,
+    mapper: CdcMapper,
+    state: Arc<RwLock<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    projects: Table<Project>,
+    investments: Table<Investment>,
+    project_cursor: Option<SyncCursor>,
+    investment_cursor: Option<SyncCursor>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl SyncedStore {
+    /// Create an empty store; call [`load`](Self::load) to fill it
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            mapper: CdcMapper::default(),
+            state: Arc::new(RwLock::new(State::default())),
+        }
+    }
+
+    /// Replace the contents with every project and its investments
+    ///
+    /// Changes made while the lists are read, including events applied meanwhile, are
+    /// replayed from the change feeds once the new contents are in place.
+    pub async fn load(&self) -> Result<()> {
+        // Taken before listing, so the replay covers everything the lists may miss
+        let started = self.client.clock().utc_now();
+        let mut projects = Table::default();
+        let mut investments = Table::default();
+
+        let service = self.client.projects();
+        let mut listing = Box::pin(service.stream_all(None));
+        while let Some(project) = listing.next().await {
+            projects.insert(project?)?;
+        }
+
+        let project_ids: Vec<String> = projects.rows.keys().cloned().collect();
+        for project_id in project_ids {
+            let mut page = Some(1);
+            while let Some(current) = page {
+                let response = self
+                    .client
+                    .investments()
+                    .get_by_project(project_id.as_str(), Some(current), Some(PAGE_SIZE))
+                    .await?;
+                page = response.next_page();
+                for investment in response.data.unwrap_or_default() {
+                    investments.insert(investment)?;
+                }
+            }
+        }
+
+        {
+            let mut state = self.write();
+            state.projects = projects;
+            state.investments = investments;
+            state.project_cursor = Some(SyncCursor::Since(started));
+            state.investment_cursor = Some(SyncCursor::Since(started));
+            state.updated_at = Some(self.client.clock().utc_now());
+        }
+
+        self.refresh().await?;
+        Ok(())
+    }
+
+    /// Apply the project and investment changes made since the last load or refresh
+    ///
+    /// Returns the number of changes applied.
+    pub async fn refresh(&self) -> Result<usize> {
+        let projects = self.client.projects();
+        let projects = &projects;
+        let investments = self.client.investments();
+        let investments = &investments;
+
+        let applied = self
+            .catch_up(
+                move |cursor| projects.changes_since(cursor),
+                |state| (&mut state.projects, &mut state.project_cursor),
+            )
+            .await?;
+        Ok(applied
+            + self
+                .catch_up(
+                    move |cursor| investments.changes_since(cursor),
+                    |state| (&mut state.investments, &mut state.investment_cursor),
+                )
+                .await?)
+    }
+
+    /// Apply a webhook event, returning whether the store changed
+    ///
+    /// Events for other resources are ignored. Fails with [`Error::Parse`] if the
+    /// event data doesn't fit the stored row.
+    pub fn apply_webhook(&self, event: &WebhookEvent) -> Result<bool> {
+        self.apply(self.mapper.map(event)?)
+    }
+
+    /// Apply a realtime event, matching its topic like a webhook event type
+    pub fn apply_realtime(&self, event: &RealtimeEvent) -> Result<bool> {
+        self.apply(self.mapper.map_parts(&event.topic, &event.data)?)
+    }
+
+    /// Apply project and investment events from the realtime service until the
+    /// subscription ends
+    ///
+    /// Events that fail to apply are logged and skipped, and dropped connections are
+    /// resumed. Returns the error that ended the subscription, such as an invalid
+    /// API key.
+    pub async fn follow(&self) -> Result<()> {
+        let mut events = Box::pin(self.client.realtime().subscribe_sse(TOPICS));
+        while let Some(event) = events.next().await {
+            match event {
+                Ok(event) => {
+                    if let Err(e) = self.apply_realtime(&event) {
+                        log::warn!("SyncedStore skipped a '{}' event: {}", event.topic, e);
+                    }
+                }
+                // The subscription carries on after these
+                Err(e @ Error::Parse(_)) => log::warn!("SyncedStore skipped an event: {}", e),
+                Err(e) if e.is_retryable() => log::warn!("SyncedStore reconnecting: {}", e),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Get a project by ID
+    pub fn project(&self, project_id: &ProjectId) -> Option<Project> {
+        self.read().projects.get(project_id.as_str())
+    }
+
+    /// All projects, in no particular order
+    pub fn projects(&self) -> Vec<Project> {
+        self.find_projects(|_| true)
+    }
+
+    /// Projects matching a predicate
+    pub fn find_projects<F: Fn(&Project) -> bool>(&self, predicate: F) -> Vec<Project> {
+        self.read().projects.filter(|row| predicate(&row.value))
+    }
+
+    /// Get an investment by ID
+    pub fn investment(&self, investment_id: &InvestmentId) -> Option<Investment> {
+        self.read().investments.get(investment_id.as_str())
+    }
+
+    /// Investments in a project
+    pub fn investments_for(&self, project_id: &ProjectId) -> Vec<Investment> {
+        self.read()
+            .investments
+            .filter(|row| row.field("project_id") == Some(project_id.as_str()))
+    }
+
+    /// Investments matching a predicate
+    pub fn find_investments<F: Fn(&Investment) -> bool>(&self, predicate: F) -> Vec<Investment> {
+        self.read().investments.filter(|row| predicate(&row.value))
+    }
+
+    /// When the store last loaded, refreshed, or applied an event
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.read().updated_at
+    }
+
+    /// Fetch and apply the changes of one table until none are waiting
+    async fn catch_up<T, F, Fut>(
+        &self,
+        fetch: F,
+        table: fn(&mut State) -> (&mut Table<T>, &mut Option<SyncCursor>),
+    ) -> Result<usize>
+    where
+        T: Clone + Serialize + DeserializeOwned,
+        F: Fn(SyncCursor) -> Fut,
+        Fut: Future<Output = Result<ChangeSet<T>>>,
+    {
+        let Some(mut cursor) = table(&mut self.write()).1.clone() else {
+            return Err(Error::Configuration(
+                "SyncedStore::refresh called before load".to_string(),
+            ));
+        };
+
+        let mut applied = 0;
+        loop {
+            let changes = fetch(cursor).await?;
+            cursor = changes.next_cursor();
+            let mut state = self.write();
+            let (rows, stored) = table(&mut state);
+            for change in changes.changes {
+                match (change.kind, change.data) {
+                    (ChangeKind::Deleted, _) => {
+                        rows.remove(&change.id);
+                    }
+                    (_, Some(value)) => rows.insert(value)?,
+                    (_, None) => continue,
+                }
+                applied += 1;
+            }
+            *stored = Some(cursor.clone());
+            state.updated_at = Some(self.client.clock().utc_now());
+            if !changes.has_more {
+                return Ok(applied);
+            }
+        }
+    }
+
+    fn apply(&self, change: Option<ChangeRecord>) -> Result<bool> {
+        let Some(change) = change else {
+            return Ok(false);
+        };
+        let Some(id) = change.key.get("id").and_then(key_string) else {
+            return Ok(false);
+        };
+
+        let mut state = self.write();
+        let changed = match (change.table.as_str(), change.operation) {
+            ("projects", ChangeOperation::Upsert) => {
+                state.projects.merge(&id, change.payload)?;
+                true
+            }
+            ("projects", ChangeOperation::Delete) => state.projects.remove(&id),
+            ("investments", ChangeOperation::Upsert) => {
+                state.investments.merge(&id, change.payload)?;
+                true
+            }
+            ("investments", ChangeOperation::Delete) => state.investments.remove(&id),
+            _ => false,
+        };
+        if changed {
+            state.updated_at = Some(self.client.clock().utc_now());
+        }
+        Ok(changed)
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, State> {
+        self.state
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, State> {
+        self.state
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Rows of one resource, keyed by ID
+#[derive(Debug)]
+struct Table<T> {
+    rows: HashMap<String, Row<T>>,
+}
+
+impl<T> Default for Table<T> {
+    fn default() -> Self {
+        Self {
+            rows: HashMap::new(),
+        }
+    }
+}
+
+/// A stored row, with the JSON it was built from so partial updates can be merged
+#[derive(Debug)]
+struct Row<T> {
+    json: Value,
+    value: T,
+}
+
+impl<T> Row<T> {
+    fn field(&self, name: &str) -> Option<&str> {
+        self.json.get(name).and_then(Value::as_str)
+    }
+}
+
+impl<T: Clone + Serialize + DeserializeOwned> Table<T> {
+    fn get(&self, id: &str) -> Option<T> {
+        self.rows.get(id).map(|row| row.value.clone())
+    }
+
+    fn filter<F: Fn(&Row<T>) -> bool>(&self, predicate: F) -> Vec<T> {
+        self.rows
+            .values()
+            .filter(|row| predicate(row))
+            .map(|row| row.value.clone())
+            .collect()
+    }
+
+    fn insert(&mut self, value: T) -> Result<()> {
+        let json = serde_json::to_value(&value).map_err(|e| Error::Parse(e.to_string()))?;
+        let id = json
+            .get("id")
+            .and_then(key_string)
+            .ok_or_else(|| Error::Parse("Synced row has no 'id'".to_string()))?;
+        self.rows.insert(id, Row { json, value });
+        Ok(())
+    }
+
+    /// Merge the fields of `update` into the row, creating it if needed
+    fn merge(&mut self, id: &str, update: Value) -> Result<()> {
+        let json = match (self.rows.get(id), update) {
+            (Some(row), Value::Object(fields)) => {
+                let mut json = row.json.clone();
+                if let Value::Object(existing) = &mut json {
+                    existing.extend(fields);
+                }
+                json
+            }
+            (_, update) => update,
+        };
+        let value = serde_json::from_value(json.clone())
+            .map_err(|e| Error::Parse(format!("Invalid event data for '{}': {}", id, e)))?;
+        self.rows.insert(id.to_string(), Row { json, value });
+        Ok(())
+    }
+
+    fn remove(&mut self, id: &str) -> bool {
+        self.rows.remove(id).is_some()
+    }
+}
+
+/// A key value as a string, whether the API sent it as a string or a number
+fn key_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(key) => Some(key.clone()),
+        Value::Number(key) => Some(key.to_string()),
+        _ => None,
+    }
+}
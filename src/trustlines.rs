@@ -0,0 +1,131 @@
+//! Trustline transactions for sale tokens
+//!
+//! Investors need a trustline to a project's issuer before its tokens can be
+//! distributed to them. [`build_trust_set`] produces the `TrustSet` transaction as
+//! JSON in the XRPL's field format, ready to be autofilled and signed by any XRPL
+//! library or wallet.
+//!
+//! ```rust
+//! use xrplsale::{trustlines::build_trust_set, Client};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let client = Client::builder().api_key("test").build()?;
+//! let token = client.projects().token("proj_abc123").await?;
+//! let tx = build_trust_set(&token, "1000000")?
+//!     .account("rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH");
+//!
+//! let tx_json = serde_json::to_string(&tx)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    error::{Error, Result},
+    multisig::{SigningMode, UnsignedTransaction},
+    types::projects::SaleToken,
+};
+use serde::{Deserialize, Serialize};
+
+/// `tfSetNoRipple`: stop rippling through the holder's trustline
+pub const TF_SET_NO_RIPPLE: u32 = 0x0002_0000;
+
+/// An amount of an issued currency
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IssuedAmount {
+    /// XRPL currency code
+    pub currency: String,
+    /// Issuing account
+    pub issuer: String,
+    /// Decimal amount
+    pub value: String,
+}
+
+/// An unsigned `TrustSet` transaction
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TrustSet {
+    /// Always `TrustSet`
+    pub transaction_type: String,
+    /// Account creating the trustline; left for the signer to fill in if unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    /// Token and maximum balance to trust
+    pub limit_amount: IssuedAmount,
+    /// Transaction flags
+    pub flags: u32,
+}
+
+impl TrustSet {
+    /// Set the account creating the trustline
+    pub fn account<S: Into<String>>(mut self, account: S) -> Self {
+        self.account = Some(account.into());
+        self
+    }
+
+    /// The transaction with instructions for signing it, e.g. by a multi-signed account
+    ///
+    /// Fails with [`Error::Configuration`] if the account is not set.
+    pub fn unsigned(&self, mode: SigningMode) -> Result<UnsignedTransaction> {
+        let tx_json = serde_json::to_value(self).map_err(|e| Error::Parse(e.to_string()))?;
+        UnsignedTransaction::new(tx_json, mode)
+    }
+}
+
+/// Build a `TrustSet` transaction trusting a sale token up to `limit`
+///
+/// The transaction sets `tfSetNoRipple`, as recommended for token holders. Fails with
+/// [`Error::Configuration`] if `limit` is not a positive decimal amount or the token's
+/// currency code is invalid.
+pub fn build_trust_set(token: &SaleToken, limit: &str) -> Result<TrustSet> {
+    if !is_positive_decimal(limit) {
+        return Err(Error::Configuration(format!(
+            "Trustline limit '{}' must be a positive decimal amount",
+            limit
+        )));
+    }
+
+    let currency = if token.currency.is_empty() {
+        currency_code(&token.symbol)?
+    } else {
+        token.currency.clone()
+    };
+
+    Ok(TrustSet {
+        transaction_type: "TrustSet".to_string(),
+        account: None,
+        limit_amount: IssuedAmount {
+            currency,
+            issuer: token.issuer.clone(),
+            value: limit.to_string(),
+        },
+        flags: TF_SET_NO_RIPPLE,
+    })
+}
+
+/// XRPL currency code for a token symbol
+///
+/// Three-character symbols are used as-is; longer symbols (up to 20 bytes) are
+/// hex-encoded and zero-padded to 40 characters.
+pub fn currency_code(symbol: &str) -> Result<String> {
+    let invalid = || Error::Configuration(format!("'{}' is not a valid XRPL currency", symbol));
+
+    if symbol.eq_ignore_ascii_case("XRP") || !symbol.is_ascii() {
+        return Err(invalid());
+    }
+    match symbol.len() {
+        3 => Ok(symbol.to_string()),
+        4..=20 => Ok(format!("{:0<40}", hex::encode_upper(symbol))),
+        _ => Err(invalid()),
+    }
+}
+
+fn is_positive_decimal(value: &str) -> bool {
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    !(whole.is_empty() && fraction.is_empty())
+        && whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+        && value.chars().any(|c| matches!(c, '1'..='9'))
+}
@@ -0,0 +1,537 @@
+//! Live launch dashboard for terminal UIs
+//!
+//! [`DashboardState`] follows one project's sale, refreshed periodically from the API
+//! and updated between refreshes by delivered webhook events. The [ratatui] widgets
+//! in this module render it:
+//!
+//! * [`RaiseTicker`] - XRP raised, investors, progress, and a sparkline of the raise
+//! * [`TierBars`] - how full each tier is
+//! * [`RecentInvestments`] - the latest investments, newest first
+//! * [`Dashboard`] - all three under a title bar, with a status line
+//!
+//! ```rust,no_run
+//! use futures::StreamExt;
+//! use std::time::Duration;
+//! use xrplsale::{
+//!     tui::{Dashboard, DashboardState},
+//!     webhook_tail::WebhookTail,
+//!     Client,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::from_env()?;
+//! let mut state = DashboardState::new("proj_abc123");
+//! let tail = WebhookTail::new(client.clone()).project("proj_abc123");
+//! let mut events = Box::pin(tail.stream().fuse());
+//! let mut refresh = tokio::time::interval(Duration::from_secs(10));
+//!
+//! let mut terminal = ratatui::init();
+//! loop {
+//!     tokio::select! {
+//!         _ = refresh.tick() => {
+//!             // Failures are shown in the dashboard's status line
+//!             let _ = state.refresh(&client).await;
+//!         }
+//!         Some(Ok(event)) = events.next() => {
+//!             state.apply_event(&event);
+//!         }
+//!     }
+//!     terminal.draw(|frame| frame.render_widget(Dashboard::new(&state), frame.area()))?;
+//! }
+//! # }
+//! ```
+//!
+//! See `examples/launch_dashboard.rs` for a complete program with keyboard handling.
+
+use crate::{
+    client::Client,
+    display::{self, AmountFormat},
+    error::Result,
+    ids::ProjectId,
+    types::{
+        investments::Investment,
+        projects::{
+            format_amount, parse_amount, Project, ProjectStats, TierProgress, AMOUNT_SCALE,
+        },
+    },
+    webhook::WebhookEvent,
+};
+use chrono::{DateTime, Utc};
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style, Stylize},
+    symbols,
+    text::{Line, Span},
+    widgets::{Block, LineGauge, Paragraph, Row, Sparkline, Table, Widget},
+};
+use std::collections::VecDeque;
+
+/// Investments kept for [`RecentInvestments`] by default
+const DEFAULT_RECENT: usize = 20;
+
+/// Samples of the amount raised kept for the sparkline
+const HISTORY_LEN: usize = 240;
+
+/// Webhook events carrying an investment
+const INVESTMENT_EVENTS: &[&str] = &["investment.created", "investment.confirmed"];
+
+/// What the dashboard knows about one project's sale
+#[derive(Debug, Clone)]
+pub struct DashboardState {
+    project_id: ProjectId,
+    project: Option<Project>,
+    stats: Option<ProjectStats>,
+    tiers: Vec<TierProgress>,
+    recent: VecDeque<Investment>,
+    recent_limit: usize,
+    raised_history: VecDeque<u64>,
+    raised_change: Option<String>,
+    updated_at: Option<DateTime<Utc>>,
+    error: Option<String>,
+}
+
+impl DashboardState {
+    /// Follow a project; nothing is shown until the first [`refresh`](Self::refresh)
+    pub fn new(project_id: impl Into<ProjectId>) -> Self {
+        Self {
+            project_id: project_id.into(),
+            project: None,
+            stats: None,
+            tiers: Vec::new(),
+            recent: VecDeque::new(),
+            recent_limit: DEFAULT_RECENT,
+            raised_history: VecDeque::new(),
+            raised_change: None,
+            updated_at: None,
+            error: None,
+        }
+    }
+
+    /// Keep this many recent investments (20 by default)
+    pub fn recent_limit(mut self, limit: usize) -> Self {
+        self.recent_limit = limit.max(1);
+        self
+    }
+
+    /// Fetch the project, its stats, tier progress, and latest investments
+    ///
+    /// On failure the previous data is kept and the error is shown in the
+    /// [`Dashboard`] status line until the next successful refresh.
+    pub async fn refresh(&mut self, client: &Client) -> Result<()> {
+        let projects = client.projects();
+        let investments = client.investments();
+        let limit = u32::try_from(self.recent_limit).unwrap_or(u32::MAX);
+
+        let fetched = futures::try_join!(
+            projects.get(&self.project_id),
+            projects.stats(&self.project_id),
+            projects.tier_progress(&self.project_id),
+            investments.get_by_project(self.project_id.as_str(), Some(1), Some(limit)),
+        );
+
+        match fetched {
+            Ok((project, stats, tiers, latest)) => {
+                self.record_raised(&stats);
+                self.project = Some(project);
+                self.stats = Some(stats);
+                self.tiers = tiers;
+                for investment in latest.data.unwrap_or_default().into_iter().rev() {
+                    self.upsert(investment);
+                }
+                self.updated_at = Some(Utc::now());
+                self.error = None;
+                Ok(())
+            }
+            Err(e) => {
+                self.error = Some(e.to_string());
+                Err(e)
+            }
+        }
+    }
+
+    /// Add the investment in an `investment.created` or `investment.confirmed` event
+    ///
+    /// Returns whether the event changed the state. Events for other projects and of
+    /// other types are ignored; stats and tiers catch up on the next refresh.
+    pub fn apply_event(&mut self, event: &WebhookEvent) -> bool {
+        if !INVESTMENT_EVENTS.contains(&event.event_type.as_str()) {
+            return false;
+        }
+        match serde_json::from_value::<Investment>(event.data.clone()) {
+            Ok(investment) if self.project_id == investment.project_id.as_str() => {
+                self.upsert(investment);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The project, once fetched
+    pub fn project(&self) -> Option<&Project> {
+        self.project.as_ref()
+    }
+
+    /// The latest sale stats, once fetched
+    pub fn stats(&self) -> Option<&ProjectStats> {
+        self.stats.as_ref()
+    }
+
+    /// Progress of each tier, in order
+    pub fn tiers(&self) -> &[TierProgress] {
+        &self.tiers
+    }
+
+    /// Recent investments, newest first
+    pub fn recent(&self) -> impl Iterator<Item = &Investment> {
+        self.recent.iter()
+    }
+
+    /// When the last successful refresh finished
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
+    /// The error from the last refresh, if it failed
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Replace an investment already listed, or list it first
+    fn upsert(&mut self, investment: Investment) {
+        if let Some(existing) = self.recent.iter_mut().find(|i| i.id == investment.id) {
+            *existing = investment;
+            return;
+        }
+        self.recent.push_front(investment);
+        self.recent.truncate(self.recent_limit);
+    }
+
+    fn record_raised(&mut self, stats: &ProjectStats) {
+        let Some(raised) = parse_amount(&stats.total_raised_xrp) else {
+            return;
+        };
+        self.raised_change = self
+            .stats
+            .as_ref()
+            .and_then(|previous| parse_amount(&previous.total_raised_xrp))
+            .and_then(|previous| raised.checked_sub(previous))
+            .filter(|change| *change > 0)
+            .map(format_amount);
+
+        let whole_xrp = raised / 10u128.pow(AMOUNT_SCALE);
+        self.raised_history
+            .push_back(u64::try_from(whole_xrp).unwrap_or(u64::MAX));
+        if self.raised_history.len() > HISTORY_LEN {
+            self.raised_history.pop_front();
+        }
+    }
+}
+
+/// XRP raised, investor count, funding progress, and a sparkline of the raise
+#[derive(Debug, Clone, Copy)]
+pub struct RaiseTicker<'a> {
+    state: &'a DashboardState,
+    format: AmountFormat,
+}
+
+impl<'a> RaiseTicker<'a> {
+    /// Render a dashboard's raise
+    pub fn new(state: &'a DashboardState) -> Self {
+        Self {
+            state,
+            format: AmountFormat::new().precision(2),
+        }
+    }
+
+    /// Format amounts with `format` (two decimal places by default)
+    pub fn format(mut self, format: AmountFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+impl Widget for RaiseTicker<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = Block::bordered().title(" Raise ");
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let Some(stats) = self.state.stats() else {
+            Paragraph::new("Loading…").dim().render(inner, buf);
+            return;
+        };
+        let amount = |value: &str| {
+            self.format
+                .format(value)
+                .unwrap_or_else(|| value.to_string())
+        };
+
+        let mut raised = vec![
+            Span::styled(
+                format!("{} XRP", amount(&stats.total_raised_xrp)),
+                Style::new().fg(Color::Green).add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" raised"),
+        ];
+        if let Some(change) = &self.state.raised_change {
+            raised.push(Span::styled(
+                format!("  +{} XRP", amount(change)),
+                Style::new().fg(Color::Green),
+            ));
+        }
+
+        let mut progress = vec![format!("{} investors", stats.total_investors)];
+        if let Some(average) = stats.average_investment() {
+            progress.push(format!("avg {} XRP", amount(&average)));
+        }
+        if let Some(percent) = self
+            .state
+            .project()
+            .and_then(|project| project.percent_funded(stats))
+        {
+            progress.push(format!("{}% funded", percent));
+        }
+
+        let mut lines = vec![Line::from(raised), Line::raw(progress.join(" · "))];
+        if let Some(project) = self.state.project() {
+            lines.push(Line::raw(display::sale_countdown(
+                project.sale_start_date,
+                project.sale_end_date,
+                Utc::now(),
+            )));
+        }
+
+        let [text, chart] =
+            Layout::horizontal([Constraint::Min(36), Constraint::Percentage(40)]).areas(inner);
+        Paragraph::new(lines).render(text, buf);
+        let history: Vec<u64> = self.state.raised_history.iter().copied().collect();
+        Sparkline::default()
+            .data(&history)
+            .style(Style::new().fg(Color::Green))
+            .render(chart, buf);
+    }
+}
+
+/// A fill bar per tier, with the tier currently selling highlighted
+#[derive(Debug, Clone, Copy)]
+pub struct TierBars<'a> {
+    state: &'a DashboardState,
+    format: AmountFormat,
+}
+
+impl<'a> TierBars<'a> {
+    /// Render a dashboard's tiers
+    pub fn new(state: &'a DashboardState) -> Self {
+        Self {
+            state,
+            format: AmountFormat::new().precision(0),
+        }
+    }
+
+    /// Format token amounts with `format` (whole tokens by default)
+    pub fn format(mut self, format: AmountFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Rows needed to show every tier, including the border
+    pub fn height(&self) -> u16 {
+        u16::try_from(self.state.tiers().len().max(1) + 2).unwrap_or(u16::MAX)
+    }
+}
+
+impl Widget for TierBars<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let block = Block::bordered().title(" Tiers ");
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let tiers = self.state.tiers();
+        if tiers.is_empty() {
+            Paragraph::new("No tiers").dim().render(inner, buf);
+            return;
+        }
+        let current = tiers
+            .iter()
+            .position(|tier| parse_amount(&tier.tokens_remaining).is_some_and(|left| left > 0));
+        let amount = |value: &str| {
+            self.format
+                .format(value)
+                .unwrap_or_else(|| value.to_string())
+        };
+
+        let rows = Layout::vertical(vec![Constraint::Length(1); tiers.len()]).split(inner);
+        for (index, (tier, row)) in tiers.iter().zip(rows.iter()).enumerate() {
+            let ratio = tier.percent_sold.parse::<f64>().unwrap_or(0.0) / 100.0;
+            let color = match current {
+                Some(current) if index == current => Color::Yellow,
+                Some(current) if index > current => Color::DarkGray,
+                _ => Color::Green,
+            };
+            LineGauge::default()
+                .ratio(ratio.clamp(0.0, 1.0))
+                .label(format!(
+                    "Tier {} {:>6}% {:>12} / {:<12}",
+                    tier.tier,
+                    tier.percent_sold,
+                    amount(&tier.tokens_sold),
+                    amount(&tier.total_tokens)
+                ))
+                .line_set(symbols::line::THICK)
+                .filled_style(Style::new().fg(color))
+                .unfilled_style(Style::new().fg(Color::DarkGray))
+                .render(*row, buf);
+        }
+    }
+}
+
+/// The latest investments, newest first
+#[derive(Debug, Clone, Copy)]
+pub struct RecentInvestments<'a> {
+    state: &'a DashboardState,
+    format: AmountFormat,
+}
+
+impl<'a> RecentInvestments<'a> {
+    /// Render a dashboard's recent investments
+    pub fn new(state: &'a DashboardState) -> Self {
+        Self {
+            state,
+            format: AmountFormat::new().precision(2),
+        }
+    }
+
+    /// Format amounts with `format` (two decimal places by default)
+    pub fn format(mut self, format: AmountFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+impl Widget for RecentInvestments<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let now = Utc::now();
+        let amount = |value: &str| {
+            self.format
+                .format(value)
+                .unwrap_or_else(|| value.to_string())
+        };
+
+        let rows = self.state.recent().map(|investment| {
+            let status = display::label(&investment.status);
+            let style = match status.as_str() {
+                "confirmed" => Style::new().fg(Color::Green),
+                "pending" => Style::new().fg(Color::Yellow),
+                _ => Style::new().fg(Color::Red),
+            };
+            Row::new(vec![
+                Line::raw(display::humanize(now - investment.created_at)).right_aligned(),
+                Line::raw(short_account(&investment.investor_account.to_string())),
+                Line::raw(amount(&investment.amount_xrp)).right_aligned(),
+                Line::raw(amount(&investment.token_amount)).right_aligned(),
+                Line::styled(status, style),
+            ])
+        });
+
+        Table::new(
+            rows,
+            [
+                Constraint::Length(7),
+                Constraint::Length(14),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Length(10),
+            ],
+        )
+        .header(
+            Row::new(["Ago", "Investor", "XRP", "Tokens", "Status"]).add_modifier(Modifier::BOLD),
+        )
+        .block(Block::bordered().title(" Recent investments "))
+        .render(area, buf);
+    }
+}
+
+/// The full dashboard: title bar, raise ticker, tier bars, recent investments, and
+/// a status line with the refresh time or error
+#[derive(Debug, Clone, Copy)]
+pub struct Dashboard<'a> {
+    state: &'a DashboardState,
+    format: Option<AmountFormat>,
+}
+
+impl<'a> Dashboard<'a> {
+    /// Render a dashboard
+    pub fn new(state: &'a DashboardState) -> Self {
+        Self {
+            state,
+            format: None,
+        }
+    }
+
+    /// Format every amount with `format` instead of each widget's default
+    pub fn format(mut self, format: AmountFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+}
+
+impl Widget for Dashboard<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut ticker = RaiseTicker::new(self.state);
+        let mut tiers = TierBars::new(self.state);
+        let mut recent = RecentInvestments::new(self.state);
+        if let Some(format) = self.format {
+            ticker = ticker.format(format);
+            tiers = tiers.format(format);
+            recent = recent.format(format);
+        }
+
+        let [title_area, ticker_area, tiers_area, recent_area, status_area] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(5),
+            Constraint::Length(tiers.height()),
+            Constraint::Min(4),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+        let title = match self.state.project() {
+            Some(project) => format!(
+                " {} ({}) · {} ",
+                project.name,
+                project.token_symbol,
+                display::label(&project.status)
+            ),
+            None => format!(" {} ", self.state.project_id),
+        };
+        Line::from(title).bold().reversed().render(title_area, buf);
+
+        ticker.render(ticker_area, buf);
+        tiers.render(tiers_area, buf);
+        recent.render(recent_area, buf);
+
+        let status = match (self.state.error(), self.state.updated_at()) {
+            (Some(error), _) => Line::styled(format!("Refresh failed: {}", error), Color::Red),
+            (None, Some(updated_at)) => Line::raw(format!(
+                "Updated {} ago",
+                display::humanize(Utc::now() - updated_at)
+            ))
+            .dim(),
+            (None, None) => Line::raw("Loading…").dim(),
+        };
+        status.render(status_area, buf);
+    }
+}
+
+/// Shorten an XRPL account to its start and end, e.g. `rHb9CJ…Gyth`
+fn short_account(account: &str) -> String {
+    let chars: Vec<char> = account.chars().collect();
+    if chars.len() <= 12 {
+        return account.to_string();
+    }
+    let start: String = chars[..6].iter().collect();
+    let end: String = chars[chars.len() - 4..].iter().collect();
+    format!("{}…{}", start, end)
+}
@@ -0,0 +1,236 @@
+//! Airdrop campaign types
+
+use crate::{
+    error::{Error, Result},
+    ids::ProjectId,
+    types::common::AsOf,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Which investors a campaign targets, evaluated against a snapshot
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SnapshotCriteria {
+    /// Point in time the investor snapshot is taken at
+    pub as_of: AsOf,
+    /// Only investors who invested at least this much XRP
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_investment_xrp: Option<String>,
+    /// Only investors who bought into these tiers; all tiers if empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tiers: Vec<u32>,
+    /// Only investors whose first investment was before this time
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::types::dates::option"
+    )]
+    pub invested_before: Option<DateTime<Utc>>,
+}
+
+/// How many tokens each recipient receives
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AmountRule {
+    /// The same amount for every recipient
+    Fixed {
+        /// Tokens per recipient
+        amount: String,
+    },
+    /// Split a total in proportion to each recipient's investment
+    ProRata {
+        /// Tokens shared across all recipients
+        total_amount: String,
+    },
+    /// A number of tokens per XRP invested
+    PerXrp {
+        /// Tokens per XRP invested
+        tokens_per_xrp: String,
+    },
+}
+
+/// Request to create an airdrop campaign
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CreateAirdropRequest {
+    /// Project whose token is airdropped
+    pub project_id: ProjectId,
+    /// Campaign name
+    pub name: String,
+    /// Investors to include
+    pub criteria: SnapshotCriteria,
+    /// Amount each recipient receives
+    pub amount_rule: AmountRule,
+}
+
+/// An airdrop campaign
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Airdrop {
+    /// Campaign ID
+    pub id: String,
+    /// Project whose token is airdropped
+    pub project_id: ProjectId,
+    /// Campaign name
+    pub name: String,
+    /// Current state
+    pub status: AirdropStatus,
+    /// Investors included
+    pub criteria: SnapshotCriteria,
+    /// Amount each recipient receives
+    pub amount_rule: AmountRule,
+    /// Number of recipients
+    pub recipient_count: u64,
+    /// Tokens to distribute in total
+    pub total_amount: String,
+    /// Tokens delivered so far
+    pub distributed_amount: String,
+    /// Deliveries that failed
+    pub failed_count: u64,
+    /// When the campaign was created
+    #[serde(with = "crate::types::dates")]
+    pub created_at: DateTime<Utc>,
+    /// When distribution started
+    #[serde(default, with = "crate::types::dates::option")]
+    pub executed_at: Option<DateTime<Utc>>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// State of an airdrop campaign
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum AirdropStatus {
+    /// Recipients can still be previewed and changed
+    Draft,
+    /// Tokens are being sent
+    Distributing,
+    /// Every delivery has been attempted
+    Completed,
+    /// Cancelled before completion
+    Cancelled,
+    /// A status added to the API after this SDK version
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A recipient and the tokens they receive
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct AirdropRecipient {
+    /// XRPL address of the recipient
+    pub account: String,
+    /// Tokens to send
+    pub amount: String,
+}
+
+impl AirdropRecipient {
+    /// Parse recipients from CSV with `account,amount` columns
+    ///
+    /// A header row starting with `account` is skipped, as are blank lines. Fails with
+    /// [`Error::Configuration`] naming the line of the first malformed row.
+    ///
+    /// ```rust
+    /// use xrplsale::types::airdrops::AirdropRecipient;
+    ///
+    /// let csv = "account,amount\nrN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH,250\n";
+    /// let recipients = AirdropRecipient::from_csv(csv)?;
+    /// assert_eq!(recipients[0].amount, "250");
+    /// # Ok::<(), xrplsale::Error>(())
+    /// ```
+    pub fn from_csv(csv: &str) -> Result<Vec<Self>> {
+        let mut recipients = Vec::new();
+        for (index, line) in csv.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || (index == 0 && line.to_lowercase().starts_with("account")) {
+                continue;
+            }
+
+            let invalid = |message: &str| {
+                Error::Configuration(format!("Recipient CSV line {}: {}", index + 1, message))
+            };
+            let mut fields = line.split(',').map(str::trim);
+            let (Some(account), Some(amount), None) = (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(invalid("expected two columns, account and amount"));
+            };
+            if !looks_like_address(account) {
+                return Err(invalid(&format!("'{}' is not an XRPL address", account)));
+            }
+            if !is_positive_decimal(amount) {
+                return Err(invalid(&format!("'{}' is not a positive amount", amount)));
+            }
+
+            recipients.push(AirdropRecipient {
+                account: account.to_string(),
+                amount: amount.to_string(),
+            });
+        }
+        Ok(recipients)
+    }
+
+    /// Write recipients as CSV with an `account,amount` header
+    pub fn to_csv(recipients: &[Self]) -> String {
+        let mut csv = String::from("account,amount\n");
+        for recipient in recipients {
+            csv.push_str(&recipient.account);
+            csv.push(',');
+            csv.push_str(&recipient.amount);
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+/// Delivery of an airdrop to one recipient
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct AirdropDelivery {
+    /// XRPL address of the recipient
+    pub account: String,
+    /// Tokens sent
+    pub amount: String,
+    /// Delivery state
+    pub status: DeliveryStatus,
+    /// Hash of the payment transaction, once submitted
+    pub tx_hash: Option<String>,
+    /// Why the delivery failed (e.g. no trustline)
+    pub error: Option<String>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// State of a single airdrop delivery
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DeliveryStatus {
+    /// Not sent yet
+    Pending,
+    /// Payment validated on-ledger
+    Delivered,
+    /// Payment failed
+    Failed,
+    /// A status added to the API after this SDK version
+    #[serde(untagged)]
+    Other(String),
+}
+
+fn looks_like_address(account: &str) -> bool {
+    account.starts_with('r')
+        && (25..=35).contains(&account.len())
+        && account.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn is_positive_decimal(value: &str) -> bool {
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    !(whole.is_empty() && fraction.is_empty())
+        && whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+        && value.chars().any(|c| matches!(c, '1'..='9'))
+}
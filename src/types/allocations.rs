@@ -0,0 +1,52 @@
+//! Token allocation types
+
+use crate::ids::ProjectId;
+use serde::{Deserialize, Serialize};
+
+/// Whether a wallet can receive a project's tokens
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TrustlineStatus {
+    /// The wallet checked
+    pub account: String,
+    /// The project ID
+    pub project_id: ProjectId,
+    /// XRPL currency code of the sale token
+    pub currency: String,
+    /// Issuing account of the sale token
+    pub issuer: String,
+    /// Whether the wallet has a trustline to the issuer for the token
+    pub has_trustline: bool,
+    /// Limit of the trustline, if it exists
+    pub limit: Option<String>,
+    /// Tokens allocated to the wallet and awaiting distribution
+    pub allocated_tokens: String,
+    /// Whether the trustline exists and its limit covers the allocation
+    pub ready: bool,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// Tokens allocated to one wallet in a project, and how much has reached it
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct AccountAllocation {
+    /// The wallet
+    pub account: String,
+    /// The project ID
+    pub project_id: ProjectId,
+    /// Tokens allocated to the wallet in total
+    pub allocated_tokens: String,
+    /// Tokens already delivered to the wallet
+    #[serde(default)]
+    pub distributed_tokens: String,
+    /// Tokens still locked by the project's vesting schedule
+    #[serde(default)]
+    pub vesting_remaining: String,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
@@ -0,0 +1,99 @@
+//! API key management types
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// An API key as listed by the platform (the secret is never returned again)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ApiKey {
+    /// Key ID
+    pub id: String,
+    /// Display name
+    pub name: String,
+    /// First characters of the key, for identifying it in logs and dashboards
+    pub prefix: String,
+    /// Scopes granted to the key (e.g. "projects:read")
+    pub scopes: Vec<String>,
+    /// When the key was created
+    #[serde(with = "crate::types::dates")]
+    pub created_at: DateTime<Utc>,
+    /// When the key stops working, if it expires
+    #[serde(default, with = "crate::types::dates::option")]
+    pub expires_at: Option<DateTime<Utc>>,
+    /// When the key was last used
+    #[serde(default, with = "crate::types::dates::option")]
+    pub last_used_at: Option<DateTime<Utc>>,
+    /// When the key was revoked
+    #[serde(default, with = "crate::types::dates::option")]
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+impl ApiKey {
+    /// Whether the key can still be used
+    pub fn is_active(&self) -> bool {
+        self.is_active_at(Utc::now())
+    }
+
+    /// Whether the key can still be used at `now`
+    pub fn is_active_at(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at.map_or(true, |at| at > now)
+    }
+}
+
+/// Request to create an API key
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CreateApiKeyRequest {
+    /// Display name
+    pub name: String,
+    /// Scopes to grant; an empty list grants none
+    pub scopes: Vec<String>,
+    /// When the key should stop working
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::types::dates::option"
+    )]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// Request to rotate an API key
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RotateApiKeyRequest {
+    /// How long the old secret keeps working after rotation, in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grace_period_secs: Option<u64>,
+    /// Expiry for the new secret; defaults to the old key's expiry
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::types::dates::option"
+    )]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// A newly created or rotated API key, including its secret
+///
+/// The secret is only returned once; store it before dropping this value.
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ApiKeySecret {
+    /// The key's metadata, including any fields this SDK version does not model
+    #[serde(flatten)]
+    pub key: ApiKey,
+    /// The full API key to send as `X-API-Key`
+    pub secret: String,
+}
+
+impl std::fmt::Debug for ApiKeySecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiKeySecret")
+            .field("key", &self.key)
+            .field("secret", &crate::logging::REDACTED)
+            .finish()
+    }
+}
@@ -0,0 +1,487 @@
+//! Types shared across API domains
+
+pub use crate::models::PaginatedResponse;
+
+use crate::error_code::ErrorCode;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Response fields not modelled by this version of the SDK
+///
+/// Response models are `#[non_exhaustive]` and ignore fields they do not know, so
+/// payloads from newer API versions still parse. Status enums map values added later
+/// to an `Other` variant that keeps the raw value. With the `unknown-fields` feature, the ignored fields are kept
+/// in each model's `extra` map instead of being dropped:
+///
+/// ```rust
+/// use xrplsale::types::governance::{Proposal, ProposalStatus};
+///
+/// // A proposal from a newer API version, with a new field and a new status
+/// let proposal: Proposal = serde_json::from_value(serde_json::json!({
+///     "id": "prop_123",
+///     "project_id": "proj_abc123",
+///     "milestone_id": null,
+///     "title": "Release milestone 2",
+///     "description": "Release the second tranche",
+///     "status": "vetoed",
+///     "opens_at": "2026-01-01T00:00:00Z",
+///     "closes_at": "2026-01-08T00:00:00Z",
+///     "quorum_percentage": "10",
+///     "veto_reason": "Audit pending"
+/// }))?;
+///
+/// assert_eq!(proposal.title, "Release milestone 2");
+/// assert_eq!(proposal.status, ProposalStatus::Other("vetoed".to_string()));
+/// assert_eq!(serde_json::to_value(&proposal)?["status"], "vetoed");
+/// #[cfg(feature = "unknown-fields")]
+/// assert_eq!(proposal.extra["veto_reason"], "Audit pending");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub type UnknownFields = serde_json::Map<String, serde_json::Value>;
+
+impl<T> PaginatedResponse<T> {
+    /// Whether the listing continues on a later page
+    ///
+    /// False when the response carries no pagination metadata; see
+    /// [`PaginatedResponse::next_page`].
+    pub fn has_next(&self) -> bool {
+        self.pagination
+            .as_ref()
+            .is_some_and(|pagination| pagination.page < pagination.total_pages)
+    }
+
+    /// The page to request next, or `None` on the last page
+    pub fn next_page(&self) -> Option<u32> {
+        self.pagination
+            .as_ref()
+            .filter(|_| self.has_next())
+            .map(|pagination| pagination.page + 1)
+    }
+}
+
+/// Per-item results of a batch request
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BatchResponse<T> {
+    /// One result per submitted item, in submission order
+    pub results: Vec<BatchItemResult<T>>,
+}
+
+impl<T> BatchResponse<T> {
+    /// Items that were applied successfully
+    pub fn succeeded(&self) -> impl Iterator<Item = &T> {
+        self.results
+            .iter()
+            .filter_map(|result| result.data.as_ref())
+    }
+
+    /// Items that were rejected, with their errors
+    pub fn failed(&self) -> impl Iterator<Item = &BatchItemResult<T>> {
+        self.results.iter().filter(|result| result.error.is_some())
+    }
+
+    /// Whether every item was applied
+    pub fn all_succeeded(&self) -> bool {
+        self.results.iter().all(BatchItemResult::is_success)
+    }
+}
+
+/// Result of a single item in a batch request
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BatchItemResult<T> {
+    /// Position of the item in the submitted batch
+    pub index: usize,
+    /// The created or updated resource, if the item succeeded
+    pub data: Option<T>,
+    /// Why the item was rejected, if it failed
+    pub error: Option<BatchItemError>,
+}
+
+impl<T> BatchItemResult<T> {
+    /// Whether the item was applied
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Error reported for a rejected batch item
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BatchItemError {
+    /// Machine-readable error code
+    pub code: ErrorCode,
+    /// Human-readable message
+    pub message: String,
+}
+
+/// Where a delta sync resumes
+///
+/// Sent as the `since` or `cursor` query parameter of a changes endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncCursor {
+    /// Changes made after a point in time, for the first sync
+    Since(#[serde(with = "crate::types::dates")] DateTime<Utc>),
+    /// Changes made after the [`ChangeSet::cursor`] of the previous sync
+    Cursor(String),
+}
+
+impl From<DateTime<Utc>> for SyncCursor {
+    fn from(since: DateTime<Utc>) -> Self {
+        SyncCursor::Since(since)
+    }
+}
+
+impl From<String> for SyncCursor {
+    fn from(cursor: String) -> Self {
+        SyncCursor::Cursor(cursor)
+    }
+}
+
+impl From<&str> for SyncCursor {
+    fn from(cursor: &str) -> Self {
+        SyncCursor::Cursor(cursor.to_string())
+    }
+}
+
+/// Kind of change in a delta sync
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// The resource was created
+    Created,
+    /// The resource was updated
+    Updated,
+    /// The resource was deleted
+    Deleted,
+}
+
+/// A change to one resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Change<T> {
+    /// Whether the resource was created, updated, or deleted
+    pub kind: ChangeKind,
+    /// ID of the resource
+    pub id: String,
+    /// The resource after the change; `None` for deletions
+    pub data: Option<T>,
+    /// When the change was made
+    #[serde(with = "crate::types::dates")]
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Changes since a [`SyncCursor`], oldest first
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ChangeSet<T> {
+    /// The changes, in the order they were made
+    pub changes: Vec<Change<T>>,
+    /// Cursor to resume from on the next sync; store it with the synced data
+    pub cursor: String,
+    /// Whether more changes are waiting; fetch them right away with `cursor`
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+impl<T> ChangeSet<T> {
+    /// The cursor to resume from, as a [`SyncCursor`]
+    pub fn next_cursor(&self) -> SyncCursor {
+        SyncCursor::Cursor(self.cursor.clone())
+    }
+}
+
+/// Point in time a report reflects
+///
+/// Reports generated during an active sale are computed against this snapshot, so
+/// every row is consistent with the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AsOf {
+    /// State at a wall-clock time
+    Time(#[serde(with = "crate::types::dates")] DateTime<Utc>),
+    /// State as of a validated XRPL ledger index
+    Ledger(u32),
+}
+
+/// Direction of a sorted listing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    /// Smallest or oldest first
+    Asc,
+    /// Largest or newest first
+    Desc,
+}
+
+impl SortOrder {
+    /// Value sent in the `sort_order` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        }
+    }
+}
+
+impl std::fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{
+        airdrops::{Airdrop, AirdropDelivery, AirdropStatus, DeliveryStatus},
+        api_keys::{ApiKey, ApiKeySecret},
+        eligibility::{AccountStanding, KycStatus, Requirement, RequirementCheck},
+        governance::{Proposal, ProposalStatus},
+        liquidity::{LiquidityPlan, LiquidityPlanStatus},
+        notifications::{Notification, NotificationCategory},
+        projects::{
+            Escrow, EscrowStatus, IssuerCheck, IssuerCheckItem, Milestone, MilestoneStatus,
+        },
+        staking::{StakePosition, StakeStatus},
+    };
+    use serde::{de::DeserializeOwned, Serialize};
+    use serde_json::{json, Value};
+
+    const NEW_VALUE: &str = "added_later";
+
+    /// Parse `payload` as a newer API version would send it, with an extra field and,
+    /// if given, an unknown value in `enum_field`, then check what serializes back
+    ///
+    /// Nested value types don't keep unknown fields, so `keeps_extra` is false for
+    /// them.
+    fn parse_newer<T: DeserializeOwned + Serialize>(
+        mut payload: Value,
+        enum_field: Option<&str>,
+        keeps_extra: bool,
+    ) -> T {
+        payload["field_added_later"] = json!("new");
+        if let Some(field) = enum_field {
+            payload[field] = json!(NEW_VALUE);
+        }
+
+        let model: T = serde_json::from_value(payload).expect("newer payload should parse");
+        let written = serde_json::to_value(&model).unwrap();
+
+        if let Some(field) = enum_field {
+            assert_eq!(written[field], NEW_VALUE);
+        }
+        let kept = written.get("field_added_later").is_some();
+        assert_eq!(kept, keeps_extra && cfg!(feature = "unknown-fields"));
+        model
+    }
+
+    fn other<T>(wrap: impl Fn(String) -> T) -> T {
+        wrap(NEW_VALUE.to_string())
+    }
+
+    #[test]
+    fn airdrop() {
+        let airdrop: Airdrop = parse_newer(
+            json!({
+                "id": "air_1",
+                "project_id": "proj_abc123",
+                "name": "Early backers",
+                "status": "draft",
+                "criteria": { "as_of": { "ledger": 90000000 } },
+                "amount_rule": { "type": "fixed", "amount": "100" },
+                "recipient_count": 12,
+                "total_amount": "1200",
+                "distributed_amount": "0",
+                "failed_count": 0,
+                "created_at": "2026-01-01T00:00:00Z"
+            }),
+            Some("status"),
+            true,
+        );
+        assert_eq!(airdrop.status, other(AirdropStatus::Other));
+    }
+
+    #[test]
+    fn airdrop_delivery() {
+        let delivery: AirdropDelivery = parse_newer(
+            json!({
+                "account": "rInvestor",
+                "amount": "100",
+                "status": "pending",
+                "tx_hash": null,
+                "error": null
+            }),
+            Some("status"),
+            true,
+        );
+        assert_eq!(delivery.status, other(DeliveryStatus::Other));
+    }
+
+    #[test]
+    fn api_key() {
+        let key = json!({
+            "id": "key_1",
+            "name": "CI",
+            "prefix": "xs_live_ab",
+            "scopes": ["projects:read"],
+            "created_at": "2026-01-01T00:00:00Z"
+        });
+        let parsed: ApiKey = parse_newer(key.clone(), None, true);
+        assert_eq!(parsed.name, "CI");
+
+        let mut with_secret = key;
+        with_secret["secret"] = json!("xs_live_abcdef");
+        let parsed: ApiKeySecret = parse_newer(with_secret, None, true);
+        assert_eq!(parsed.secret, "xs_live_abcdef");
+        #[cfg(feature = "unknown-fields")]
+        assert_eq!(parsed.key.extra.len(), 1);
+    }
+
+    #[test]
+    fn account_standing() {
+        let standing: AccountStanding = parse_newer(
+            json!({ "staked_amount": "500", "whitelisted": true, "kyc_status": "verified" }),
+            Some("kyc_status"),
+            false,
+        );
+        assert_eq!(standing.kyc_status, other(KycStatus::Other));
+    }
+
+    #[test]
+    fn requirement_check() {
+        let check: RequirementCheck = parse_newer(
+            json!({ "requirement": "kyc", "met": false, "description": "Complete KYC" }),
+            Some("requirement"),
+            false,
+        );
+        assert_eq!(check.requirement, other(Requirement::Other));
+    }
+
+    #[test]
+    fn proposal() {
+        let proposal: Proposal = parse_newer(
+            json!({
+                "id": "prop_123",
+                "project_id": "proj_abc123",
+                "milestone_id": null,
+                "title": "Release milestone 2",
+                "description": "Release the second tranche",
+                "status": "open",
+                "opens_at": "2026-01-01T00:00:00Z",
+                "closes_at": "2026-01-08T00:00:00Z",
+                "quorum_percentage": "10"
+            }),
+            Some("status"),
+            true,
+        );
+        assert_eq!(proposal.status, other(ProposalStatus::Other));
+    }
+
+    #[test]
+    fn liquidity_plan() {
+        let plan: LiquidityPlan = parse_newer(
+            json!({
+                "id": "liq_1",
+                "project_id": "proj_abc123",
+                "asset": { "currency": "ABC", "issuer": "rIssuer" },
+                "quote": { "currency": "XRP" },
+                "asset_amount": "100000",
+                "quote_amount": "5000",
+                "trading_fee": 500,
+                "lock_duration_days": 180,
+                "status": "planned",
+                "amm_account": null,
+                "failure_reason": null,
+                "created_at": "2026-01-01T00:00:00Z"
+            }),
+            Some("status"),
+            true,
+        );
+        assert_eq!(plan.status, other(LiquidityPlanStatus::Other));
+    }
+
+    #[test]
+    fn notification() {
+        let notification: Notification = parse_newer(
+            json!({
+                "id": "ntf_1",
+                "account": "rInvestor",
+                "category": "security",
+                "title": "New sign-in",
+                "body": "A new device signed in",
+                "project_id": null,
+                "channels": ["email", "in_app"],
+                "read": false,
+                "created_at": "2026-01-01T00:00:00Z"
+            }),
+            Some("category"),
+            true,
+        );
+        assert_eq!(notification.category, other(NotificationCategory::Other));
+        assert_eq!(notification.category.as_str(), NEW_VALUE);
+    }
+
+    #[test]
+    fn issuer_check() {
+        let check: IssuerCheckItem = parse_newer(
+            json!({ "check": "tick_size", "description": "Tick size is 5", "passed": true }),
+            Some("check"),
+            false,
+        );
+        assert_eq!(check.check, other(IssuerCheck::Other));
+    }
+
+    #[test]
+    fn escrow() {
+        let escrow: Escrow = parse_newer(
+            json!({
+                "id": "esc_1",
+                "project_id": "proj_abc123",
+                "owner_account": "rOwner",
+                "destination_account": "rProject",
+                "sequence": 42,
+                "amount_xrp": "10000",
+                "release_condition": "milestone",
+                "milestone_id": "ms_1",
+                "status": "locked",
+                "create_tx_hash": "ABC123",
+                "settle_tx_hash": null
+            }),
+            Some("status"),
+            true,
+        );
+        assert_eq!(escrow.status, other(EscrowStatus::Other));
+    }
+
+    #[test]
+    fn milestone() {
+        let milestone: Milestone = parse_newer(
+            json!({
+                "id": "ms_1",
+                "project_id": "proj_abc123",
+                "title": "Beta launch",
+                "description": "Ship the beta",
+                "target_date": "2026-06-01T00:00:00Z",
+                "release_percentage": "25",
+                "status": "pending"
+            }),
+            Some("status"),
+            true,
+        );
+        assert_eq!(milestone.status, other(MilestoneStatus::Other));
+    }
+
+    #[test]
+    fn stake_position() {
+        let position: StakePosition = parse_newer(
+            json!({
+                "id": "stk_1",
+                "program_id": "prog_123",
+                "account": "rInvestor",
+                "amount": "5000",
+                "status": "locked",
+                "staked_at": "2026-01-01T00:00:00Z",
+                "unlocks_at": "2026-04-01T00:00:00Z"
+            }),
+            Some("status"),
+            true,
+        );
+        assert_eq!(position.status, other(StakeStatus::Other));
+    }
+}
@@ -0,0 +1,278 @@
+//! Tolerant deserialization of API timestamps
+//!
+//! The API sends timestamps as RFC 3339 strings, but some endpoints return Unix
+//! timestamps in seconds or milliseconds instead. Model fields use this module with
+//! `#[serde(with = "...")]` so every form parses to a [`DateTime<Utc>`]:
+//!
+//! ```rust
+//! use chrono::{DateTime, Utc};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "xrplsale::types::dates")]
+//!     at: DateTime<Utc>,
+//!     #[serde(default, with = "xrplsale::types::dates::option")]
+//!     until: Option<DateTime<Utc>>,
+//! }
+//!
+//! for at in [
+//!     r#""2026-03-01T12:00:00Z""#,
+//!     r#""2026-03-01T14:00:00+02:00""#,
+//!     r#""2026-03-01 12:00:00""#,
+//!     "1772366400",
+//!     "1772366400000",
+//!     r#""1772366400""#,
+//! ] {
+//!     let event: Event = serde_json::from_str(&format!(r#"{{"at": {}}}"#, at))?;
+//!     assert_eq!(event.at.to_rfc3339(), "2026-03-01T12:00:00+00:00");
+//!     assert_eq!(event.until, None);
+//!
+//!     // Serialized back as RFC 3339, which parses to the same instant
+//!     let json = serde_json::to_string(&event)?;
+//!     assert_eq!(json, r#"{"at":"2026-03-01T12:00:00Z","until":null}"#);
+//!     let again: Event = serde_json::from_str(&json)?;
+//!     assert_eq!(again.at, event.at);
+//! }
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Accepted forms:
+//!
+//! * RFC 3339 strings with any offset, converted to UTC
+//! * strings without an offset (`2026-03-01T12:00:00`, `2026-03-01 12:00:00`), read as
+//!   UTC
+//! * Unix timestamps in seconds or milliseconds, as numbers or numeric strings;
+//!   values of 100 billion or more are read as milliseconds
+//!
+//! Timestamps are always serialized as RFC 3339 in UTC, exactly as chrono does.
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use serde::{de, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Timestamps at or above this are in milliseconds (in seconds it is the year 5138)
+const MILLIS_THRESHOLD: i64 = 100_000_000_000;
+
+/// Formats accepted for timestamps without an offset
+const NAIVE_FORMATS: &[&str] = &["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"];
+
+/// Serialize a timestamp as RFC 3339
+pub fn serialize<S: Serializer>(date: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+    date.serialize(serializer)
+}
+
+/// Deserialize a timestamp from any of the accepted forms
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+    deserializer.deserialize_any(DateVisitor)
+}
+
+/// Read a timestamp string in any of the accepted forms
+///
+/// # Example
+///
+/// ```rust
+/// use xrplsale::types::dates;
+///
+/// assert_eq!(dates::parse("2026-03-01T14:00:00+02:00"), dates::parse("1772366400"));
+/// assert_eq!(dates::parse("March 1st"), None);
+/// ```
+pub fn parse(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Some(date.with_timezone(&Utc));
+    }
+    if let Ok(timestamp) = value.parse::<i64>() {
+        return from_timestamp(timestamp);
+    }
+    NAIVE_FORMATS.iter().find_map(|format| {
+        NaiveDateTime::parse_from_str(value, format)
+            .ok()
+            .map(|naive| Utc.from_utc_datetime(&naive))
+    })
+}
+
+/// Read a Unix timestamp in seconds or milliseconds
+pub fn from_timestamp(timestamp: i64) -> Option<DateTime<Utc>> {
+    if timestamp.abs() >= MILLIS_THRESHOLD {
+        DateTime::from_timestamp_millis(timestamp)
+    } else {
+        DateTime::from_timestamp(timestamp, 0)
+    }
+}
+
+struct DateVisitor;
+
+impl<'de> de::Visitor<'de> for DateVisitor {
+    type Value = DateTime<Utc>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an RFC 3339 timestamp or Unix time in seconds or milliseconds")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        parse(value).ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        from_timestamp(value).ok_or_else(|| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        i64::try_from(value)
+            .ok()
+            .and_then(from_timestamp)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        let invalid = || E::invalid_value(de::Unexpected::Float(value), &self);
+        if !value.is_finite() {
+            return Err(invalid());
+        }
+        let millis = if value.abs() >= MILLIS_THRESHOLD as f64 {
+            value
+        } else {
+            value * 1000.0
+        };
+        DateTime::from_timestamp_millis(millis.round() as i64).ok_or_else(invalid)
+    }
+}
+
+/// The same forms for optional timestamps, with `null` read as `None`
+///
+/// Combine with `#[serde(default)]` so a missing field is also `None`.
+pub mod option {
+    use super::DateVisitor;
+    use chrono::{DateTime, Utc};
+    use serde::{de, Deserializer, Serialize, Serializer};
+    use std::fmt;
+
+    /// Serialize an optional timestamp as RFC 3339 or `null`
+    pub fn serialize<S: Serializer>(
+        date: &Option<DateTime<Utc>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        date.serialize(serializer)
+    }
+
+    /// Deserialize an optional timestamp from `null` or any of the accepted forms
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<DateTime<Utc>>, D::Error> {
+        deserializer.deserialize_option(OptionVisitor)
+    }
+
+    struct OptionVisitor;
+
+    impl<'de> de::Visitor<'de> for OptionVisitor {
+        type Value = Option<DateTime<Utc>>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("null, an RFC 3339 timestamp, or Unix time")
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_any(DateVisitor).map(Some)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize)]
+    struct At {
+        #[serde(with = "super")]
+        at: DateTime<Utc>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Until {
+        #[serde(default, with = "super::option")]
+        until: Option<DateTime<Utc>>,
+    }
+
+    fn at(value: serde_json::Value) -> Result<DateTime<Utc>, serde_json::Error> {
+        serde_json::from_value::<At>(json!({ "at": value })).map(|parsed| parsed.at)
+    }
+
+    fn noon() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn seconds_and_milliseconds() {
+        assert_eq!(at(json!(1772366400)).unwrap(), noon());
+        assert_eq!(at(json!(1772366400000_i64)).unwrap(), noon());
+        assert_eq!(at(json!("1772366400")).unwrap(), noon());
+        assert_eq!(at(json!("1772366400000")).unwrap(), noon());
+        assert_eq!(
+            at(json!(1772366400.25)).unwrap(),
+            noon() + chrono::Duration::milliseconds(250)
+        );
+        assert_eq!(
+            at(json!(1772366400250_i64)).unwrap(),
+            noon() + chrono::Duration::milliseconds(250)
+        );
+        // Just under the threshold is still seconds, far in the future
+        assert_eq!(
+            super::from_timestamp(99_999_999_999).unwrap().timestamp(),
+            99_999_999_999
+        );
+    }
+
+    #[test]
+    fn offsets_convert_to_utc() {
+        assert_eq!(at(json!("2026-03-01T14:00:00+02:00")).unwrap(), noon());
+        assert_eq!(at(json!("2026-03-01T07:00:00-05:00")).unwrap(), noon());
+        assert_eq!(at(json!("2026-03-01T12:00:00Z")).unwrap(), noon());
+        // No offset is read as UTC
+        assert_eq!(at(json!("2026-03-01T12:00:00")).unwrap(), noon());
+        assert_eq!(at(json!("2026-03-01 12:00:00.000")).unwrap(), noon());
+    }
+
+    #[test]
+    fn option_null_or_missing() {
+        let parse = |value| serde_json::from_value::<Until>(value).unwrap().until;
+        assert_eq!(parse(json!({})), None);
+        assert_eq!(parse(json!({ "until": null })), None);
+        assert_eq!(parse(json!({ "until": 1772366400 })), Some(noon()));
+        assert_eq!(
+            parse(json!({ "until": "2026-03-01T14:00:00+02:00" })),
+            Some(noon())
+        );
+        assert!(serde_json::from_value::<Until>(json!({ "until": "soon" })).is_err());
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        for value in [
+            json!("March 1st"),
+            json!(""),
+            json!("2026-13-01T00:00:00Z"),
+            json!(true),
+            json!([1772366400]),
+            json!({ "seconds": 1772366400 }),
+            json!(null),
+            json!(u64::MAX),
+        ] {
+            assert!(at(value.clone()).is_err(), "accepted {}", value);
+        }
+        assert_eq!(super::parse("not a date"), None);
+    }
+}
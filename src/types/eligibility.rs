@@ -0,0 +1,267 @@
+//! Sale tier eligibility types
+
+use crate::{
+    error::{Error, Result},
+    ids::ProjectId,
+    types::projects::parse_amount,
+};
+use serde::{Deserialize, Serialize};
+
+/// Which sale tiers an account qualifies for, and why
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct EligibilityReport {
+    /// The account checked
+    pub account: String,
+    /// The project ID
+    pub project_id: ProjectId,
+    /// The facts eligibility was decided on
+    #[serde(flatten)]
+    pub standing: AccountStanding,
+    /// Outcome for each tier, lowest tier first
+    pub tiers: Vec<TierEligibility>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+impl EligibilityReport {
+    /// Tiers the account qualifies for
+    pub fn eligible_tiers(&self) -> impl Iterator<Item = u32> + '_ {
+        self.tiers
+            .iter()
+            .filter(|tier| tier.eligible)
+            .map(|tier| tier.tier)
+    }
+
+    /// Whether the account qualifies for a tier
+    pub fn is_eligible(&self, tier: u32) -> bool {
+        self.eligible_tiers().any(|eligible| eligible == tier)
+    }
+}
+
+/// An account's stake, whitelist, and KYC state for a project
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AccountStanding {
+    /// Tokens staked toward the project's tiers
+    pub staked_amount: String,
+    /// Whether the account is on the project's whitelist
+    pub whitelisted: bool,
+    /// KYC state of the investor behind the account
+    pub kyc_status: KycStatus,
+}
+
+/// KYC state of an investor
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum KycStatus {
+    /// KYC not started
+    NotStarted,
+    /// Documents submitted and under review
+    Pending,
+    /// Identity verified
+    Verified,
+    /// Verification rejected
+    Rejected,
+    /// A status added to the API after this SDK version
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl KycStatus {
+    /// API value of the status
+    pub fn as_str(&self) -> &str {
+        match self {
+            KycStatus::NotStarted => "not_started",
+            KycStatus::Pending => "pending",
+            KycStatus::Verified => "verified",
+            KycStatus::Rejected => "rejected",
+            KycStatus::Other(value) => value,
+        }
+    }
+}
+
+/// Outcome for a single tier
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TierEligibility {
+    /// Tier number
+    pub tier: u32,
+    /// Whether every requirement is met
+    pub eligible: bool,
+    /// Each requirement of the tier and whether it is met
+    pub requirements: Vec<RequirementCheck>,
+}
+
+/// A tier requirement and whether the account meets it
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RequirementCheck {
+    /// The requirement
+    pub requirement: Requirement,
+    /// Whether it is met
+    pub met: bool,
+    /// Human-readable explanation, e.g. "staked 800 of 1000 required"
+    pub description: String,
+}
+
+/// Kinds of tier requirement
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Requirement {
+    /// A minimum staked amount
+    Stake,
+    /// A place on the project's whitelist
+    Whitelist,
+    /// Verified KYC
+    Kyc,
+    /// A requirement this version of the SDK does not know about
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Requirements of a project's sale tiers
+///
+/// Rules can be fetched with
+/// [`EligibilityService::rules`](crate::services::EligibilityService::rules), edited,
+/// and evaluated locally to preview who would qualify before the tiers are updated.
+///
+/// ```rust
+/// use xrplsale::types::eligibility::{AccountStanding, EligibilityRules, KycStatus, TierRule};
+///
+/// let rules = EligibilityRules {
+///     tiers: vec![
+///         TierRule::new(1).kyc_required(),
+///         TierRule::new(2).min_stake("1000").kyc_required(),
+///     ],
+/// };
+/// let standing = AccountStanding {
+///     staked_amount: "800".to_string(),
+///     whitelisted: false,
+///     kyc_status: KycStatus::Verified,
+/// };
+///
+/// let tiers = rules.evaluate(&standing)?;
+/// assert!(tiers[0].eligible);
+/// assert!(!tiers[1].eligible);
+/// # Ok::<(), xrplsale::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EligibilityRules {
+    /// Rules for each tier
+    pub tiers: Vec<TierRule>,
+}
+
+impl EligibilityRules {
+    /// Decide which tiers an account with the given standing qualifies for
+    ///
+    /// Results are ordered by tier. Fails with [`Error::Configuration`] if a stake amount
+    /// is not a valid decimal.
+    pub fn evaluate(&self, standing: &AccountStanding) -> Result<Vec<TierEligibility>> {
+        let staked = parse_amount(&standing.staked_amount).ok_or_else(|| {
+            Error::Configuration(format!("Invalid staked amount: {}", standing.staked_amount))
+        })?;
+
+        let mut tiers = self
+            .tiers
+            .iter()
+            .map(|rule| rule.evaluate(staked, standing))
+            .collect::<Result<Vec<_>>>()?;
+        tiers.sort_by_key(|tier| tier.tier);
+        Ok(tiers)
+    }
+}
+
+/// Requirements of one sale tier
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TierRule {
+    /// Tier number
+    pub tier: u32,
+    /// Minimum staked amount, if staking is required
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_stake: Option<String>,
+    /// Whether the account must be whitelisted
+    #[serde(default)]
+    pub whitelist_required: bool,
+    /// Whether the investor must have verified KYC
+    #[serde(default)]
+    pub kyc_required: bool,
+}
+
+impl TierRule {
+    /// Create a tier with no requirements
+    pub fn new(tier: u32) -> Self {
+        Self {
+            tier,
+            min_stake: None,
+            whitelist_required: false,
+            kyc_required: false,
+        }
+    }
+
+    /// Require a minimum staked amount
+    pub fn min_stake<S: Into<String>>(mut self, amount: S) -> Self {
+        self.min_stake = Some(amount.into());
+        self
+    }
+
+    /// Require a place on the whitelist
+    pub fn whitelist_required(mut self) -> Self {
+        self.whitelist_required = true;
+        self
+    }
+
+    /// Require verified KYC
+    pub fn kyc_required(mut self) -> Self {
+        self.kyc_required = true;
+        self
+    }
+
+    fn evaluate(&self, staked: u128, standing: &AccountStanding) -> Result<TierEligibility> {
+        let mut requirements = Vec::new();
+
+        if let Some(min_stake) = &self.min_stake {
+            let required = parse_amount(min_stake).ok_or_else(|| {
+                Error::Configuration(format!(
+                    "Invalid minimum stake for tier {}: {}",
+                    self.tier, min_stake
+                ))
+            })?;
+            requirements.push(RequirementCheck {
+                requirement: Requirement::Stake,
+                met: staked >= required,
+                description: format!(
+                    "staked {} of {} required",
+                    standing.staked_amount.trim(),
+                    min_stake.trim()
+                ),
+            });
+        }
+
+        if self.whitelist_required {
+            requirements.push(RequirementCheck {
+                requirement: Requirement::Whitelist,
+                met: standing.whitelisted,
+                description: if standing.whitelisted {
+                    "on the whitelist".to_string()
+                } else {
+                    "not on the whitelist".to_string()
+                },
+            });
+        }
+
+        if self.kyc_required {
+            requirements.push(RequirementCheck {
+                requirement: Requirement::Kyc,
+                met: standing.kyc_status == KycStatus::Verified,
+                description: format!("KYC {}", standing.kyc_status.as_str()),
+            });
+        }
+
+        Ok(TierEligibility {
+            tier: self.tier,
+            eligible: requirements.iter().all(|check| check.met),
+            requirements,
+        })
+    }
+}
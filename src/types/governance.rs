@@ -0,0 +1,167 @@
+//! Governance types for token holder votes
+
+use crate::ids::ProjectId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A proposal put to a project's token holders
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Proposal {
+    /// Proposal ID
+    pub id: String,
+    /// The project ID
+    pub project_id: ProjectId,
+    /// Milestone whose release is being voted on, if any
+    pub milestone_id: Option<String>,
+    /// Short title
+    pub title: String,
+    /// What a vote in favour approves
+    pub description: String,
+    /// Current state
+    pub status: ProposalStatus,
+    /// When voting opens
+    #[serde(with = "crate::types::dates")]
+    pub opens_at: DateTime<Utc>,
+    /// When voting closes
+    #[serde(with = "crate::types::dates")]
+    pub closes_at: DateTime<Utc>,
+    /// Share of the token supply that must vote for the result to count, as a
+    /// decimal percentage
+    pub quorum_percentage: String,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// State of a proposal
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ProposalStatus {
+    /// Voting has not opened yet
+    Pending,
+    /// Voting is open
+    Open,
+    /// Voting closed and the proposal was approved
+    Passed,
+    /// Voting closed and the proposal was rejected or missed quorum
+    Rejected,
+    /// Withdrawn before voting closed
+    Cancelled,
+    /// A status added to the API after this SDK version
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl ProposalStatus {
+    /// Value sent in the `status` query parameter
+    pub fn as_str(&self) -> &str {
+        match self {
+            ProposalStatus::Pending => "pending",
+            ProposalStatus::Open => "open",
+            ProposalStatus::Passed => "passed",
+            ProposalStatus::Rejected => "rejected",
+            ProposalStatus::Cancelled => "cancelled",
+            ProposalStatus::Other(value) => value,
+        }
+    }
+}
+
+/// A token holder's choice on a proposal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VoteChoice {
+    /// In favour
+    For,
+    /// Against
+    Against,
+    /// Counted towards quorum without taking a side
+    Abstain,
+}
+
+impl VoteChoice {
+    /// Value sent to the API and included in the signed vote message
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VoteChoice::For => "for",
+            VoteChoice::Against => "against",
+            VoteChoice::Abstain => "abstain",
+        }
+    }
+}
+
+/// Running or final totals of a proposal, weighted by tokens held
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct VoteTally {
+    /// The proposal ID
+    pub proposal_id: String,
+    /// Tokens voting in favour
+    pub votes_for: String,
+    /// Tokens voting against
+    pub votes_against: String,
+    /// Tokens abstaining
+    pub votes_abstain: String,
+    /// Number of wallets that have voted
+    pub voters: u64,
+    /// Whether enough of the supply has voted for the result to count
+    pub quorum_reached: bool,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// A recorded vote
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Vote {
+    /// Vote ID
+    pub id: String,
+    /// The proposal ID
+    pub proposal_id: String,
+    /// Wallet that voted
+    pub account: String,
+    /// The choice made
+    pub choice: VoteChoice,
+    /// Tokens the vote counts for
+    pub weight: String,
+    /// When the vote was cast
+    #[serde(with = "crate::types::dates")]
+    pub cast_at: DateTime<Utc>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// A vote signed by the voting wallet
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CastVoteRequest {
+    /// Wallet casting the vote
+    pub account: String,
+    /// The choice made
+    pub choice: VoteChoice,
+    /// Unix time in seconds included in the signed message
+    pub timestamp: i64,
+    /// Hex-encoded public key of the wallet
+    pub public_key: String,
+    /// Hex-encoded signature of the vote message
+    pub signature: String,
+}
+
+impl CastVoteRequest {
+    /// The message a wallet signs to cast a vote:
+    /// `xrplsale-vote:{proposal_id}:{choice}:{account}:{timestamp}`
+    pub fn message(proposal_id: &str, choice: VoteChoice, account: &str, timestamp: i64) -> String {
+        format!(
+            "xrplsale-vote:{}:{}:{}:{}",
+            proposal_id,
+            choice.as_str(),
+            account,
+            timestamp
+        )
+    }
+}
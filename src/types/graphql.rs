@@ -0,0 +1,139 @@
+//! GraphQL request and response types
+
+use crate::models::{Project, ProjectStats, ProjectTier};
+use serde::{Deserialize, Serialize};
+
+/// Project with its tiers and stats, fetched in one round trip
+pub const PROJECT_OVERVIEW_QUERY: &str = r#"query ProjectOverview($id: ID!) {
+  project(id: $id) {
+    ...ProjectOverviewFields
+  }
+}
+"#;
+
+/// Several projects with their tiers and stats, fetched in one round trip
+pub const PROJECT_OVERVIEWS_QUERY: &str = r#"query ProjectOverviews($ids: [ID!]!) {
+  projects(ids: $ids) {
+    ...ProjectOverviewFields
+  }
+}
+"#;
+
+/// Fields selected by the overview queries
+///
+/// Field names match the REST API, so results deserialize into the same models.
+pub const PROJECT_OVERVIEW_FRAGMENT: &str = r#"fragment ProjectOverviewFields on Project {
+  id
+  name
+  description
+  status
+  token_symbol
+  total_supply
+  sale_start_date
+  sale_end_date
+  created_at
+  updated_at
+  tiers {
+    tier
+    price_per_token
+    total_tokens
+  }
+  stats {
+    total_raised_xrp
+    total_investors
+    tokens_sold
+  }
+}
+"#;
+
+/// A GraphQL operation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphqlRequest {
+    /// Query or mutation document
+    pub query: String,
+    /// Values for the document's variables
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<serde_json::Value>,
+    /// Operation to run, if the document defines several
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_name: Option<String>,
+}
+
+impl GraphqlRequest {
+    /// Create a request for a document without variables
+    pub fn new<S: Into<String>>(query: S) -> Self {
+        Self {
+            query: query.into(),
+            variables: None,
+            operation_name: None,
+        }
+    }
+
+    /// Set the variables
+    pub fn variables(mut self, variables: serde_json::Value) -> Self {
+        self.variables = Some(variables);
+        self
+    }
+
+    /// Select the operation to run
+    pub fn operation_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.operation_name = Some(name.into());
+        self
+    }
+}
+
+/// Response to a GraphQL operation
+///
+/// `data` and `errors` can both be present when part of a query failed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphqlResponse<T> {
+    /// Result of the operation, if it ran
+    pub data: Option<T>,
+    /// Errors raised while validating or running the operation
+    #[serde(default)]
+    pub errors: Vec<GraphqlError>,
+}
+
+/// An error reported by the GraphQL endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GraphqlError {
+    /// Description of the error
+    pub message: String,
+    /// Path to the response field that failed, e.g. `["project", "stats"]`
+    #[serde(default)]
+    pub path: Vec<serde_json::Value>,
+    /// Positions in the document the error refers to
+    #[serde(default)]
+    pub locations: Vec<GraphqlLocation>,
+    /// Additional details, such as an error code
+    #[serde(default)]
+    pub extensions: Option<serde_json::Value>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// A position in a GraphQL document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GraphqlLocation {
+    /// 1-based line
+    pub line: u32,
+    /// 1-based column
+    pub column: u32,
+}
+
+/// A project with its tiers and sale stats
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ProjectOverview {
+    /// The project
+    #[serde(flatten)]
+    pub project: Project,
+    /// Sale tiers
+    pub tiers: Vec<ProjectTier>,
+    /// Sale statistics
+    pub stats: ProjectStats,
+}
@@ -0,0 +1,158 @@
+//! Investment types
+
+pub use crate::models::{CreateInvestmentRequest, Investment};
+
+use crate::ids::ProjectId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Statuses of investments whose payment never settled
+const UNPAID_STATUSES: [&str; 2] = ["failed", "cancelled"];
+
+/// Whether an investment failed or was cancelled, so its amounts don't count
+pub(crate) fn is_unpaid(investment: &Investment) -> bool {
+    match serde_json::to_value(&investment.status) {
+        Ok(serde_json::Value::String(status)) => UNPAID_STATUSES.contains(&status.as_str()),
+        _ => false,
+    }
+}
+
+/// Field to sort investment listings by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvestmentSortField {
+    /// Creation time
+    CreatedAt,
+    /// Amount invested in XRP
+    AmountXrp,
+    /// Tokens allocated
+    TokenAmount,
+}
+
+impl InvestmentSortField {
+    /// Value sent in the `sort_by` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InvestmentSortField::CreatedAt => "created_at",
+            InvestmentSortField::AmountXrp => "amount_xrp",
+            InvestmentSortField::TokenAmount => "token_amount",
+        }
+    }
+}
+
+impl std::fmt::Display for InvestmentSortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Order of an ordered investment stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvestmentOrder {
+    /// By the ledger index of the investment's payment, oldest first
+    LedgerIndexAsc,
+    /// By creation time, oldest first
+    CreatedAtAsc,
+}
+
+impl InvestmentOrder {
+    /// Value sent in the `order` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InvestmentOrder::LedgerIndexAsc => "ledger_index_asc",
+            InvestmentOrder::CreatedAtAsc => "created_at_asc",
+        }
+    }
+}
+
+/// Position in an ordered investment stream
+///
+/// An opaque token issued by the platform. Store it in the same transaction as the
+/// investment it follows, then pass it to
+/// [`InvestmentsService::stream_ordered`](crate::services::InvestmentsService::stream_ordered)
+/// after a restart to continue with the next investment.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct InvestmentCheckpoint(String);
+
+impl InvestmentCheckpoint {
+    /// Wrap a stored checkpoint token
+    pub fn new<S: Into<String>>(token: S) -> Self {
+        Self(token.into())
+    }
+
+    /// The token, for storing
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for InvestmentCheckpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// An investment from an ordered stream, with the checkpoint that resumes after it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct OrderedInvestment {
+    /// The investment
+    pub investment: Investment,
+    /// Resumes the stream with the investment after this one
+    pub checkpoint: InvestmentCheckpoint,
+}
+
+/// An investor's positions across every project they invested in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Portfolio {
+    /// The investor's XRPL address
+    pub account: String,
+    /// XRP invested across all projects, excluding failed and cancelled investments
+    pub total_invested_xrp: String,
+    /// One position per project, ordered by project ID
+    pub positions: Vec<PortfolioPosition>,
+}
+
+/// An investor's position in one project
+///
+/// Token amounts are in the project's own token, so they are totalled per project
+/// rather than across the portfolio.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PortfolioPosition {
+    /// The project ID
+    pub project_id: ProjectId,
+    /// XRP invested, excluding failed and cancelled investments
+    pub invested_xrp: String,
+    /// Tokens allocated to the investor
+    pub tokens_allocated: String,
+    /// Allocated tokens still locked by vesting
+    pub vesting_remaining: String,
+    /// Tokens already distributed to the investor's wallet
+    pub tokens_distributed: String,
+    /// The investor's investments in the project, including failed and cancelled ones
+    pub investments: Vec<Investment>,
+}
+
+/// A historical investment to import, e.g. when migrating a sale from another platform
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ImportedInvestment {
+    /// The investor's XRPL address
+    pub investor_account: String,
+    /// Amount invested in XRP
+    pub amount_xrp: String,
+    /// Tokens allocated for the investment
+    pub token_amount: String,
+    /// When the investment was made
+    #[serde(with = "crate::types::dates")]
+    pub invested_at: DateTime<Utc>,
+    /// Hash of the payment on the XRP Ledger, if it was paid on-ledger
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_hash: Option<String>,
+    /// ID of the investment in the system it is imported from, to spot duplicates
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+}
@@ -0,0 +1,199 @@
+//! Cross-project comparisons and investor cohorts for launch post-mortems
+
+use crate::{ids::ProjectId, types::market::Granularity};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A measure compared across projects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComparisonMetric {
+    /// XRP raised, cumulative
+    AmountRaised,
+    /// Tokens sold, cumulative
+    TokensSold,
+    /// Investments made in the interval
+    Investments,
+    /// Distinct investors, cumulative
+    Investors,
+}
+
+impl ComparisonMetric {
+    /// Value sent in the `metrics` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ComparisonMetric::AmountRaised => "amount_raised",
+            ComparisonMetric::TokensSold => "tokens_sold",
+            ComparisonMetric::Investments => "investments",
+            ComparisonMetric::Investors => "investors",
+        }
+    }
+}
+
+impl std::fmt::Display for ComparisonMetric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Period of a comparison, measured from each project's sale start
+///
+/// Measuring from the sale start lines up launches that ran at different times, so
+/// day 3 of one sale is compared with day 3 of another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ComparisonRange {
+    /// Days after the sale start to cover
+    pub days: u32,
+    /// Length of each point
+    pub interval: Granularity,
+}
+
+impl ComparisonRange {
+    /// The first `days` days of each sale, one point per day
+    pub fn first_days(days: u32) -> Self {
+        Self {
+            days,
+            interval: Granularity::Day,
+        }
+    }
+
+    /// Set the length of each point
+    pub fn interval(mut self, interval: Granularity) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+/// Time series of several projects over the same period of their sales
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ProjectComparison {
+    /// Length of each point
+    pub interval: Granularity,
+    /// One series per project, in the order requested
+    pub series: Vec<ProjectSeries>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+impl ProjectComparison {
+    /// The series of a project, if it was compared
+    pub fn project(&self, project_id: &ProjectId) -> Option<&ProjectSeries> {
+        self.series
+            .iter()
+            .find(|series| &series.project_id == project_id)
+    }
+
+    /// Every point of every series as a flat row, e.g. for
+    /// [`arrow::to_record_batch`](crate::arrow::to_record_batch)
+    pub fn stats_points(&self) -> impl Iterator<Item = StatsPoint> + '_ {
+        self.series.iter().flat_map(|series| {
+            series.points.iter().map(move |point| {
+                let value = |metric| point.values.get(&metric).cloned();
+                StatsPoint {
+                    project_id: series.project_id.clone(),
+                    offset: point.offset,
+                    time: point.time,
+                    amount_raised: value(ComparisonMetric::AmountRaised),
+                    tokens_sold: value(ComparisonMetric::TokensSold),
+                    investments: value(ComparisonMetric::Investments),
+                    investors: value(ComparisonMetric::Investors),
+                }
+            })
+        })
+    }
+}
+
+/// One project's values over the compared period
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ProjectSeries {
+    /// The project ID
+    pub project_id: ProjectId,
+    /// When the project's sale started
+    #[serde(with = "crate::types::dates")]
+    pub sale_start: DateTime<Utc>,
+    /// Points in order; every series has a point at each offset, including periods
+    /// after the sale ended
+    pub points: Vec<SeriesPoint>,
+}
+
+impl ProjectSeries {
+    /// The values of one metric, in order of offset
+    pub fn values(&self, metric: ComparisonMetric) -> impl Iterator<Item = Option<&str>> + '_ {
+        self.points
+            .iter()
+            .map(move |point| point.values.get(&metric).map(String::as_str))
+    }
+}
+
+/// Values of the compared metrics over one interval
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SeriesPoint {
+    /// Intervals since the sale start
+    pub offset: u32,
+    /// Start of the interval
+    #[serde(with = "crate::types::dates")]
+    pub time: DateTime<Utc>,
+    /// Value of each requested metric, as a decimal string
+    pub values: BTreeMap<ComparisonMetric, String>,
+}
+
+/// One point of a project's series as a flat row
+///
+/// Metrics that were not requested are `None`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StatsPoint {
+    /// The project ID
+    pub project_id: ProjectId,
+    /// Intervals since the sale start
+    pub offset: u32,
+    /// Start of the interval
+    #[serde(with = "crate::types::dates")]
+    pub time: DateTime<Utc>,
+    /// XRP raised, cumulative
+    pub amount_raised: Option<String>,
+    /// Tokens sold, cumulative
+    pub tokens_sold: Option<String>,
+    /// Investments made in the interval
+    pub investments: Option<String>,
+    /// Distinct investors, cumulative
+    pub investors: Option<String>,
+}
+
+/// A project's investors split by whether they had invested on the platform before
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct InvestorCohorts {
+    /// The project ID
+    pub project_id: ProjectId,
+    /// Investors whose first platform investment was in this project
+    pub first_time: Cohort,
+    /// Investors who had invested in an earlier project
+    pub returning: Cohort,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// Size, contribution, and retention of one group of investors
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Cohort {
+    /// Investors in the cohort
+    pub investors: u64,
+    /// XRP the cohort invested in the project
+    pub amount_xrp: String,
+    /// Investors of the cohort who went on to invest in a later project
+    pub reinvested: u64,
+}
+
+impl Cohort {
+    /// Share of the cohort that invested again, from 0 to 1; `None` for an empty
+    /// cohort
+    pub fn retention(&self) -> Option<f64> {
+        (self.investors > 0).then(|| self.reinvested as f64 / self.investors as f64)
+    }
+}
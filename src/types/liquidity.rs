@@ -0,0 +1,153 @@
+//! Post-sale liquidity provisioning types
+
+use crate::ids::ProjectId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Highest AMM trading fee the XRPL accepts, in units of 1/100,000 (1%)
+pub const MAX_TRADING_FEE: u16 = 1000;
+
+/// An asset on the XRPL: XRP or an issued currency
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Asset {
+    /// Currency code, `XRP` for XRP
+    pub currency: String,
+    /// Issuing account; absent for XRP
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+}
+
+impl Asset {
+    /// XRP
+    pub fn xrp() -> Self {
+        Self {
+            currency: "XRP".to_string(),
+            issuer: None,
+        }
+    }
+
+    /// An issued currency
+    pub fn issued<C: Into<String>, I: Into<String>>(currency: C, issuer: I) -> Self {
+        Self {
+            currency: currency.into(),
+            issuer: Some(issuer.into()),
+        }
+    }
+}
+
+/// Request to plan the seeding of an AMM pool after a sale
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CreateLiquidityPlanRequest {
+    /// The project ID
+    pub project_id: ProjectId,
+    /// The sale token side of the pool
+    pub asset: Asset,
+    /// The other side of the pool, usually XRP
+    pub quote: Asset,
+    /// Sale tokens to deposit
+    pub asset_amount: String,
+    /// Quote asset to deposit; with `asset_amount` this sets the pool's starting price
+    pub quote_amount: String,
+    /// AMM trading fee in units of 1/100,000 (e.g. `500` for 0.5%), at most
+    /// [`MAX_TRADING_FEE`]
+    pub trading_fee: u16,
+    /// How long the LP tokens stay locked after the pool is created, in days
+    pub lock_duration_days: u32,
+}
+
+/// A planned or executed liquidity provisioning
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct LiquidityPlan {
+    /// Plan ID
+    pub id: String,
+    /// The project ID
+    pub project_id: ProjectId,
+    /// The sale token side of the pool
+    pub asset: Asset,
+    /// The other side of the pool
+    pub quote: Asset,
+    /// Sale tokens to deposit
+    pub asset_amount: String,
+    /// Quote asset to deposit
+    pub quote_amount: String,
+    /// AMM trading fee in units of 1/100,000
+    pub trading_fee: u16,
+    /// How long the LP tokens stay locked, in days
+    pub lock_duration_days: u32,
+    /// Execution state
+    pub status: LiquidityPlanStatus,
+    /// Account of the created AMM, once executed
+    pub amm_account: Option<String>,
+    /// Why execution failed, if it did
+    pub failure_reason: Option<String>,
+    /// When the plan was created
+    #[serde(with = "crate::types::dates")]
+    pub created_at: DateTime<Utc>,
+    /// When the pool was created
+    #[serde(default, with = "crate::types::dates::option")]
+    pub executed_at: Option<DateTime<Utc>>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// Execution state of a liquidity plan
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum LiquidityPlanStatus {
+    /// Waiting for the sale to settle
+    Pending,
+    /// `AMMCreate` and deposit transactions are being submitted
+    Executing,
+    /// The pool exists and the LP tokens are locked
+    Completed,
+    /// Execution failed; see the plan's failure reason
+    Failed,
+    /// A status added to the API after this SDK version
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl LiquidityPlanStatus {
+    /// Whether the plan has finished executing, successfully or not
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            LiquidityPlanStatus::Completed | LiquidityPlanStatus::Failed
+        )
+    }
+}
+
+/// An XRPL AMM pool seeded for a project
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct AmmPool {
+    /// The project ID
+    pub project_id: ProjectId,
+    /// Account of the AMM
+    pub amm_account: String,
+    /// The sale token side of the pool
+    pub asset: Asset,
+    /// The other side of the pool
+    pub quote: Asset,
+    /// Sale tokens in the pool
+    pub asset_amount: String,
+    /// Quote asset in the pool
+    pub quote_amount: String,
+    /// LP token issued by the AMM
+    pub lp_token: Asset,
+    /// Outstanding LP tokens
+    pub lp_token_supply: String,
+    /// AMM trading fee in units of 1/100,000
+    pub trading_fee: u16,
+    /// When the project's LP tokens unlock
+    #[serde(default, with = "crate::types::dates::option")]
+    pub locked_until: Option<DateTime<Utc>>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
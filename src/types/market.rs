@@ -0,0 +1,127 @@
+//! Secondary-market data for launched tokens
+
+use crate::ids::ProjectId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Period of price history to return
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum PriceRange {
+    /// The last 24 hours
+    #[serde(rename = "24h")]
+    Day,
+    /// The last 7 days
+    #[serde(rename = "7d")]
+    Week,
+    /// The last 30 days
+    #[serde(rename = "30d")]
+    Month,
+    /// The last year
+    #[serde(rename = "1y")]
+    Year,
+    /// Since the token was first traded
+    #[serde(rename = "all")]
+    All,
+}
+
+impl PriceRange {
+    /// Value sent in the `range` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PriceRange::Day => "24h",
+            PriceRange::Week => "7d",
+            PriceRange::Month => "30d",
+            PriceRange::Year => "1y",
+            PriceRange::All => "all",
+        }
+    }
+}
+
+/// Length of each candle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Granularity {
+    /// One minute
+    #[serde(rename = "1m")]
+    Minute,
+    /// Five minutes
+    #[serde(rename = "5m")]
+    FiveMinutes,
+    /// Fifteen minutes
+    #[serde(rename = "15m")]
+    FifteenMinutes,
+    /// One hour
+    #[serde(rename = "1h")]
+    Hour,
+    /// Four hours
+    #[serde(rename = "4h")]
+    FourHours,
+    /// One day
+    #[serde(rename = "1d")]
+    Day,
+}
+
+impl Granularity {
+    /// Value sent in the `granularity` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Granularity::Minute => "1m",
+            Granularity::FiveMinutes => "5m",
+            Granularity::FifteenMinutes => "15m",
+            Granularity::Hour => "1h",
+            Granularity::FourHours => "4h",
+            Granularity::Day => "1d",
+        }
+    }
+}
+
+/// Price history and current price of a launched token, derived from XRPL DEX trades
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TokenPrice {
+    /// The project ID
+    pub project_id: ProjectId,
+    /// XRPL currency code of the token
+    pub currency: String,
+    /// Issuing account of the token
+    pub issuer: String,
+    /// Midpoint of the best bid and ask, in XRP; absent when one side of the book is
+    /// empty
+    pub mid_price: Option<String>,
+    /// Best bid on the order book, in XRP
+    pub best_bid: Option<String>,
+    /// Best ask on the order book, in XRP
+    pub best_ask: Option<String>,
+    /// Candles over the requested range, oldest first
+    pub candles: Vec<Candle>,
+    /// When the data was last updated from the ledger
+    #[serde(with = "crate::types::dates")]
+    pub updated_at: DateTime<Utc>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// Open, high, low, close, and volume over one interval
+///
+/// Prices are in XRP per token.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Candle {
+    /// Start of the interval
+    #[serde(with = "crate::types::dates")]
+    pub open_time: DateTime<Utc>,
+    /// Price of the first trade
+    pub open: String,
+    /// Highest trade price
+    pub high: String,
+    /// Lowest trade price
+    pub low: String,
+    /// Price of the last trade
+    pub close: String,
+    /// Tokens traded
+    pub volume: String,
+    /// XRP traded
+    pub quote_volume: String,
+    /// Number of trades
+    pub trades: u64,
+}
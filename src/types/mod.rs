@@ -0,0 +1,238 @@
+//! API data types grouped by domain
+//!
+//! Prefer importing from these modules (or from [`crate::prelude`]) over the crate root,
+//! where model types are only re-exported for backwards compatibility.
+//!
+//! ## Comparison and serialization guarantees
+//!
+//! Types defined in these modules derive `PartialEq`, `Eq`, and `Hash` over all of
+//! their fields, so they can be stored in sets and diffed across syncs. Amounts are
+//! decimal strings and are compared textually, so `"100"` and `"100.0"` are not
+//! equal. For the same reason, types holding amounts, such as
+//! [`projects::CapTableEntry`], don't implement `Ord`: sort them by a parsed amount
+//! instead.
+//!
+//! Serializing a deserialized value reproduces every field the SDK knows about:
+//! optional fields that were absent stay absent or `null`, no field is renamed or
+//! reformatted on the way out, and status values this SDK version does not know are
+//! kept in an `Other` variant and written back as they were received. The one exception is timestamps: the API occasionally
+//! sends Unix time instead of RFC 3339, and [`dates`] normalizes every form to
+//! `DateTime<Utc>`, which serializes as RFC 3339.
+
+pub mod airdrops;
+pub mod allocations;
+pub mod api_keys;
+pub mod common;
+pub mod dates;
+pub mod eligibility;
+pub mod governance;
+#[cfg(feature = "graphql")]
+pub mod graphql;
+pub mod investments;
+pub mod launch_analytics;
+pub mod liquidity;
+pub mod market;
+pub mod notifications;
+pub mod projects;
+pub mod realtime;
+#[cfg(feature = "xrpl")]
+pub mod reconcile;
+pub mod sandbox;
+pub mod session;
+pub mod staking;
+pub mod usage;
+pub mod webhooks;
+#[cfg(feature = "xumm")]
+pub mod xumm;
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        governance::Proposal,
+        projects::{CapTableEntry, Escrow, Milestone, SegmentMember},
+        staking::StakePosition,
+        webhooks::{WebhookPayloads, WEBHOOK_PAYLOAD_VERSION},
+    };
+    use crate::Error;
+    use serde::{de::DeserializeOwned, Serialize};
+    use serde_json::{json, Value};
+    use std::{collections::HashSet, fmt::Debug, hash::Hash};
+
+    /// Parse `payload`, serialize it back, and check nothing the SDK models changed
+    fn round_trip<T>(payload: Value) -> T
+    where
+        T: DeserializeOwned + Serialize + Eq + Hash + Debug,
+    {
+        let model: T = serde_json::from_value(payload.clone()).unwrap();
+        let written = serde_json::to_value(&model).unwrap();
+
+        let (payload, written_fields) =
+            (payload.as_object().unwrap(), written.as_object().unwrap());
+        for (field, value) in payload {
+            assert_eq!(&written_fields[field], value, "field {} changed", field);
+        }
+        for (field, value) in written_fields {
+            assert!(
+                payload.contains_key(field) || value.is_null(),
+                "absent field {} written as {}",
+                field,
+                value
+            );
+        }
+
+        let again: T = serde_json::from_value(written).unwrap();
+        assert_eq!(again, model);
+        let set: HashSet<&T> = [&model, &again].into_iter().collect();
+        assert_eq!(set.len(), 1);
+        model
+    }
+
+    fn proposal() -> Value {
+        json!({
+            "id": "prop_123",
+            "project_id": "proj_abc123",
+            "milestone_id": null,
+            "title": "Release milestone 2",
+            "description": "Release the second tranche",
+            "status": "open",
+            "opens_at": "2026-01-01T00:00:00Z",
+            "closes_at": "2026-01-08T00:00:00Z",
+            "quorum_percentage": "10"
+        })
+    }
+
+    #[test]
+    fn proposal_round_trips() {
+        round_trip::<Proposal>(proposal());
+    }
+
+    #[test]
+    fn absent_optional_fields_stay_absent_or_null() {
+        round_trip::<Escrow>(json!({
+            "id": "esc_1",
+            "project_id": "proj_abc123",
+            "owner_account": "rOwner",
+            "destination_account": "rProject",
+            "sequence": 42,
+            "amount_xrp": "10000.50",
+            "release_condition": "milestone",
+            "milestone_id": "ms_1",
+            "status": "locked",
+            "create_tx_hash": "ABC123",
+            "settle_tx_hash": null
+        }));
+        let milestone = round_trip::<Milestone>(json!({
+            "id": "ms_1",
+            "project_id": "proj_abc123",
+            "title": "Beta launch",
+            "description": "Ship the beta",
+            "target_date": "2026-06-01T00:00:00Z",
+            "release_percentage": "25.0",
+            "status": "completed",
+            "evidence": ["https://example.com/beta"],
+            "completed_at": "2026-05-30T09:15:00Z"
+        }));
+        assert_eq!(milestone.release_percentage, "25.0");
+    }
+
+    #[test]
+    fn timestamps_are_normalized_to_rfc3339() {
+        let position: StakePosition = serde_json::from_value(json!({
+            "id": "stk_1",
+            "program_id": "prog_123",
+            "account": "rInvestor",
+            "amount": "5000",
+            "status": "locked",
+            "staked_at": 1772366400,
+            "unlocks_at": "2026-06-01T02:00:00+02:00"
+        }))
+        .unwrap();
+        let written = serde_json::to_value(&position).unwrap();
+        assert_eq!(written["staked_at"], "2026-03-01T12:00:00Z");
+        assert_eq!(written["unlocks_at"], "2026-06-01T00:00:00Z");
+        assert_eq!(written["amount"], "5000");
+    }
+
+    #[test]
+    fn amounts_compare_textually() {
+        let mut other = proposal();
+        other["quorum_percentage"] = json!("10.0");
+        let (a, b): (Proposal, Proposal) = (
+            serde_json::from_value(proposal()).unwrap(),
+            serde_json::from_value(other).unwrap(),
+        );
+        assert_ne!(a, b);
+        assert_eq!(HashSet::from([a.clone(), b, a]).len(), 2);
+    }
+
+    #[test]
+    fn value_types_compare_by_value() {
+        let entry = |account: &str, amount: &str| CapTableEntry {
+            investor_account: account.to_string(),
+            amount_xrp: amount.to_string(),
+            token_amount: "1".to_string(),
+        };
+        let entries = HashSet::from([entry("rA", "9"), entry("rA", "10"), entry("rA", "9")]);
+        assert_eq!(entries.len(), 2);
+
+        let member = SegmentMember {
+            investor_account: "rA".to_string(),
+            amount_xrp: "100".to_string(),
+            token_amount: "1000".to_string(),
+            tiers: vec![1],
+            claimed: true,
+        };
+        round_trip::<SegmentMember>(serde_json::to_value(member).unwrap());
+    }
+
+    #[test]
+    fn webhook_payloads_upgrade_to_the_current_version() {
+        let payloads = WebhookPayloads::default();
+        let current = json!({
+            "id": "evt_1",
+            "event_type": "vote.cast",
+            "data": { "choice": "for" }
+        });
+
+        let upgraded = payloads
+            .upgrade(json!({
+                "version": 1,
+                "id": "evt_1",
+                "type": "vote.cast",
+                "payload": { "choice": "for" }
+            }))
+            .unwrap();
+        let mut expected = current.clone();
+        expected["version"] = json!(WEBHOOK_PAYLOAD_VERSION);
+        assert_eq!(upgraded, expected);
+
+        let event = payloads.parse(&current.to_string()).unwrap();
+        assert_eq!(event.event_type, "vote.cast");
+        assert_eq!(event.data, json!({ "choice": "for" }));
+    }
+
+    #[test]
+    fn webhook_payloads_reject_unknown_versions() {
+        let newer = json!({ "version": WEBHOOK_PAYLOAD_VERSION + 1, "id": "evt_1" });
+        assert!(matches!(
+            WebhookPayloads::default().upgrade(newer),
+            Err(Error::UnsupportedWebhookVersion { version }) if version == WEBHOOK_PAYLOAD_VERSION + 1
+        ));
+
+        let unknown = json!({ "version": 0, "id": "evt_1" });
+        assert!(matches!(
+            WebhookPayloads::default().upgrade(unknown.clone()),
+            Err(Error::UnsupportedWebhookVersion { version: 0 })
+        ));
+        let upgraded = WebhookPayloads::default()
+            .with_upgrade(0, Ok)
+            .upgrade(unknown)
+            .unwrap();
+        assert_eq!(upgraded["version"], json!(WEBHOOK_PAYLOAD_VERSION));
+
+        assert!(matches!(
+            WebhookPayloads::default().upgrade(json!({ "version": "two" })),
+            Err(Error::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,199 @@
+//! Notification types
+
+use crate::ids::ProjectId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Where notifications are delivered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationChannel {
+    /// Email to the account's address
+    Email,
+    /// POST to the account's webhook URL
+    Webhook,
+    /// Listed in the account's notification feed
+    InApp,
+}
+
+/// What a notification is about
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationCategory {
+    /// An investment was confirmed on-ledger
+    InvestmentConfirmed,
+    /// A followed project's sale opened or closed
+    SaleStatus,
+    /// Tokens were distributed to the account
+    TokensDistributed,
+    /// A milestone release or escrow changed
+    Milestone,
+    /// A governance proposal opened or closed
+    Governance,
+    /// Sign-ins, API keys, and other security events
+    Security,
+    /// A category this version of the SDK does not know about
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl NotificationCategory {
+    /// API value of the category
+    pub fn as_str(&self) -> &str {
+        match self {
+            NotificationCategory::InvestmentConfirmed => "investment_confirmed",
+            NotificationCategory::SaleStatus => "sale_status",
+            NotificationCategory::TokensDistributed => "tokens_distributed",
+            NotificationCategory::Milestone => "milestone",
+            NotificationCategory::Governance => "governance",
+            NotificationCategory::Security => "security",
+            NotificationCategory::Other(value) => value,
+        }
+    }
+}
+
+/// An account's notification settings
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct NotificationPreferences {
+    /// The account
+    pub account: String,
+    /// Address email notifications are sent to
+    pub email_address: Option<String>,
+    /// URL webhook notifications are sent to
+    pub webhook_url: Option<String>,
+    /// Settings for each channel
+    pub channels: Vec<ChannelPreference>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+impl NotificationPreferences {
+    /// Settings for a channel, if the account has any
+    pub fn channel(&self, channel: NotificationChannel) -> Option<&ChannelPreference> {
+        self.channels.iter().find(|pref| pref.channel == channel)
+    }
+
+    /// Whether a category is delivered on a channel
+    pub fn is_enabled(&self, channel: NotificationChannel, category: NotificationCategory) -> bool {
+        self.channel(channel)
+            .is_some_and(|pref| pref.enabled && !pref.muted.contains(&category))
+    }
+}
+
+/// Settings for one delivery channel
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ChannelPreference {
+    /// The channel
+    pub channel: NotificationChannel,
+    /// Whether anything is delivered on the channel
+    pub enabled: bool,
+    /// Categories not delivered on the channel
+    #[serde(default)]
+    pub muted: Vec<NotificationCategory>,
+}
+
+impl ChannelPreference {
+    /// Enable a channel for every category
+    pub fn enabled(channel: NotificationChannel) -> Self {
+        Self {
+            channel,
+            enabled: true,
+            muted: Vec::new(),
+        }
+    }
+
+    /// Disable a channel
+    pub fn disabled(channel: NotificationChannel) -> Self {
+        Self {
+            channel,
+            enabled: false,
+            muted: Vec::new(),
+        }
+    }
+
+    /// Stop delivering a category on this channel
+    pub fn mute(mut self, category: NotificationCategory) -> Self {
+        if !self.muted.contains(&category) {
+            self.muted.push(category);
+        }
+        self
+    }
+}
+
+/// Changes to an account's notification settings
+///
+/// Only the fields that are set are changed; channels not listed keep their settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UpdateNotificationPreferencesRequest {
+    /// New email address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+    /// New webhook URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    /// Replacement settings for the listed channels
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub channels: Vec<ChannelPreference>,
+}
+
+impl UpdateNotificationPreferencesRequest {
+    /// Create an empty update
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the email address
+    pub fn email_address<S: Into<String>>(mut self, email: S) -> Self {
+        self.email_address = Some(email.into());
+        self
+    }
+
+    /// Set the webhook URL
+    pub fn webhook_url<S: Into<String>>(mut self, url: S) -> Self {
+        self.webhook_url = Some(url.into());
+        self
+    }
+
+    /// Replace the settings of a channel
+    pub fn channel(mut self, preference: ChannelPreference) -> Self {
+        self.channels
+            .retain(|pref| pref.channel != preference.channel);
+        self.channels.push(preference);
+        self
+    }
+}
+
+/// A notification sent to an account
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Notification {
+    /// Notification ID
+    pub id: String,
+    /// The account notified
+    pub account: String,
+    /// What the notification is about
+    pub category: NotificationCategory,
+    /// Short title
+    pub title: String,
+    /// Full text
+    pub body: String,
+    /// Project the notification relates to, if any
+    pub project_id: Option<ProjectId>,
+    /// Channels the notification was delivered on
+    pub channels: Vec<NotificationChannel>,
+    /// Whether the notification has been read
+    pub read: bool,
+    /// When the notification was created
+    #[serde(with = "crate::types::dates")]
+    pub created_at: DateTime<Utc>,
+    /// When the notification was marked read
+    #[serde(default, with = "crate::types::dates::option")]
+    pub read_at: Option<DateTime<Utc>>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
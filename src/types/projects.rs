@@ -0,0 +1,1469 @@
+//! Project types
+
+pub use crate::models::{
+    CreateProjectRequest, Project, ProjectStats, ProjectTier, UpdateProjectRequest,
+};
+
+use crate::{ids::ProjectId, types::common::SortOrder};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A document attached to a project, such as a whitepaper or audit report
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ProjectDocument {
+    /// Display name
+    pub name: String,
+    /// Document kind (e.g. "whitepaper", "audit", "terms")
+    pub document_type: String,
+    /// Publicly reachable URL of the document
+    pub url: String,
+}
+
+/// An alert raised when a project metric crosses a threshold
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ProjectAlert {
+    /// Metric to watch (e.g. "total_raised_xrp", "tier_percent_sold")
+    pub metric: String,
+    /// Threshold that triggers the alert
+    pub threshold: String,
+    /// Delivery channel ("email" or "webhook")
+    pub channel: String,
+    /// Email address or URL to notify
+    pub target: String,
+}
+
+/// Result of the platform's pre-launch checks for a project
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct LaunchChecklist {
+    /// Whether every blocking item passed
+    pub ready: bool,
+    /// Individual checks
+    pub items: Vec<LaunchChecklistItem>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// A single pre-launch check
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LaunchChecklistItem {
+    /// Stable identifier of the check
+    pub key: String,
+    /// Human-readable description
+    pub description: String,
+    /// Whether the check passed
+    pub passed: bool,
+    /// Whether a failure prevents launching
+    #[serde(default)]
+    pub blocking: bool,
+}
+
+/// On-ledger configuration of a project's issuing account, checked against the
+/// platform's requirements
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct IssuerChecks {
+    /// The issuing account
+    pub issuer: String,
+    /// Whether `DefaultRipple` is set, letting tokens move between holders
+    pub default_ripple: bool,
+    /// Whether `RequireAuth` is set, requiring the issuer to approve each trustline
+    pub require_auth: bool,
+    /// Whether the account's keys are disabled, so no more tokens can be issued
+    pub blackholed: bool,
+    /// Significant digits used for exchange rates on the DEX, if set
+    pub tick_size: Option<u8>,
+    /// Fee charged on transfers between holders, as a decimal percentage
+    pub transfer_fee: String,
+    /// Individual checks
+    pub items: Vec<IssuerCheckItem>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+impl IssuerChecks {
+    /// Whether every check passed
+    pub fn passed(&self) -> bool {
+        self.items.iter().all(|item| item.passed)
+    }
+
+    /// Checks that failed
+    pub fn failures(&self) -> impl Iterator<Item = &IssuerCheckItem> {
+        self.items.iter().filter(|item| !item.passed)
+    }
+
+    /// Convert failed checks into
+    /// [`Error::Configuration`](crate::Error::Configuration), e.g. to fail a CI job
+    pub fn into_result(self) -> crate::Result<Self> {
+        if self.passed() {
+            return Ok(self);
+        }
+
+        let messages: Vec<String> = self
+            .failures()
+            .map(|item| format!("{}: {}", item.check.as_str(), item.description))
+            .collect();
+        Err(crate::Error::Configuration(format!(
+            "Issuer {} failed checks: {}",
+            self.issuer,
+            messages.join("; ")
+        )))
+    }
+}
+
+/// A single issuer check
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IssuerCheckItem {
+    /// What was checked
+    pub check: IssuerCheck,
+    /// Human-readable outcome
+    pub description: String,
+    /// Whether the check passed
+    pub passed: bool,
+}
+
+/// Issuer settings verified by [`IssuerChecks`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssuerCheck {
+    /// `DefaultRipple` is enabled
+    DefaultRipple,
+    /// `RequireAuth` matches the sale's investor approval settings
+    RequireAuth,
+    /// The account is blackholed, fixing the token supply
+    Blackholed,
+    /// The tick size is within the platform's supported range
+    TickSize,
+    /// The transfer fee is within the platform's limit
+    TransferFee,
+    /// A check added to the platform after this SDK version
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl IssuerCheck {
+    /// Identifier used by the API
+    pub fn as_str(&self) -> &str {
+        match self {
+            IssuerCheck::DefaultRipple => "default_ripple",
+            IssuerCheck::RequireAuth => "require_auth",
+            IssuerCheck::Blackholed => "blackholed",
+            IssuerCheck::TickSize => "tick_size",
+            IssuerCheck::TransferFee => "transfer_fee",
+            IssuerCheck::Other(value) => value,
+        }
+    }
+}
+
+/// Replacement tiers for one project in a bulk tier update
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TierUpdate {
+    /// The project to update
+    pub project_id: ProjectId,
+    /// The project's new tiers
+    pub tiers: Vec<ProjectTier>,
+}
+
+/// Token holdings of every investor in a project
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CapTable {
+    /// The project ID
+    pub project_id: ProjectId,
+    /// One entry per investor
+    pub entries: Vec<CapTableEntry>,
+    /// When the cap table was generated
+    #[serde(with = "crate::types::dates")]
+    pub generated_at: DateTime<Utc>,
+    /// Snapshot time the cap table reflects
+    #[serde(default, with = "crate::types::dates::option")]
+    pub as_of: Option<DateTime<Utc>>,
+    /// Validated ledger index the cap table reflects
+    #[serde(default)]
+    pub ledger_index: Option<u32>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// A single investor's holdings in a cap table
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CapTableEntry {
+    /// Investor's XRPL account
+    pub investor_account: String,
+    /// Total XRP invested
+    pub amount_xrp: String,
+    /// Tokens allocated to the investor
+    pub token_amount: String,
+}
+
+/// Request to schedule a token distribution
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ScheduleDistributionRequest {
+    /// When to distribute; immediately if unset
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::types::dates::option"
+    )]
+    pub scheduled_for: Option<DateTime<Utc>>,
+}
+
+/// A scheduled or running token distribution
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Distribution {
+    /// Distribution ID
+    pub id: String,
+    /// The project ID
+    pub project_id: ProjectId,
+    /// Current status (e.g. "scheduled", "running", "completed")
+    pub status: String,
+    /// When the distribution is scheduled to run
+    #[serde(default, with = "crate::types::dates::option")]
+    pub scheduled_for: Option<DateTime<Utc>>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// A message sent to all investors in a project
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct InvestorMessage {
+    /// Message subject
+    pub subject: String,
+    /// Message body
+    pub body: String,
+}
+
+/// Filters selecting a segment of a project's investors
+///
+/// All set filters must match (they are combined with AND).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SegmentQuery {
+    /// Minimum total XRP invested (inclusive)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_invested_xrp: Option<String>,
+    /// Maximum total XRP invested (inclusive)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_invested_xrp: Option<String>,
+    /// Tiers the investor bought into; any tier matches
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tiers: Vec<u32>,
+    /// Whether the investor has claimed their tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claimed: Option<bool>,
+    /// Whether the investor has completed KYC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kyc_verified: Option<bool>,
+    /// Only investors whose first investment is at or after this time
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::types::dates::option"
+    )]
+    pub invested_after: Option<DateTime<Utc>>,
+    /// Only investors whose first investment is before this time
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::types::dates::option"
+    )]
+    pub invested_before: Option<DateTime<Utc>>,
+    /// Page number (1-based)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    /// Number of items per page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl SegmentQuery {
+    /// Create a query matching every investor
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require at least this much XRP invested
+    pub fn min_invested_xrp<S: Into<String>>(mut self, amount: S) -> Self {
+        self.min_invested_xrp = Some(amount.into());
+        self
+    }
+
+    /// Require at most this much XRP invested
+    pub fn max_invested_xrp<S: Into<String>>(mut self, amount: S) -> Self {
+        self.max_invested_xrp = Some(amount.into());
+        self
+    }
+
+    /// Match investors in this tier (may be called several times)
+    pub fn tier(mut self, tier: u32) -> Self {
+        self.tiers.push(tier);
+        self
+    }
+
+    /// Match investors by claim status
+    pub fn claimed(mut self, claimed: bool) -> Self {
+        self.claimed = Some(claimed);
+        self
+    }
+
+    /// Match investors by KYC status
+    pub fn kyc_verified(mut self, verified: bool) -> Self {
+        self.kyc_verified = Some(verified);
+        self
+    }
+
+    /// Match investors who first invested at or after `at`
+    pub fn invested_after(mut self, at: DateTime<Utc>) -> Self {
+        self.invested_after = Some(at);
+        self
+    }
+
+    /// Match investors who first invested before `at`
+    pub fn invested_before(mut self, at: DateTime<Utc>) -> Self {
+        self.invested_before = Some(at);
+        self
+    }
+
+    /// Set the page number (1-based)
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Set the number of items per page
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// An investor wallet matched by a segment query
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SegmentMember {
+    /// Investor's XRPL account
+    pub investor_account: String,
+    /// Total XRP invested in the project
+    pub amount_xrp: String,
+    /// Tokens allocated to the investor
+    pub token_amount: String,
+    /// Tiers the investor bought into
+    pub tiers: Vec<u32>,
+    /// Whether the investor has claimed their tokens
+    pub claimed: bool,
+}
+
+/// How a project's raised funds are held and released
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EscrowConfig {
+    /// What triggers the release of each tranche
+    pub release_condition: ReleaseCondition,
+    /// Release tranches; percentages must sum to 100
+    pub tranches: Vec<EscrowTranche>,
+    /// XRPL account allowed to approve or dispute releases
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arbiter_account: Option<String>,
+}
+
+/// What triggers an escrow release
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReleaseCondition {
+    /// Release when the sale ends successfully
+    SaleEnd,
+    /// Release each tranche when its milestone is approved
+    Milestone,
+    /// Release each tranche at its scheduled time
+    Schedule,
+}
+
+/// A portion of the escrowed funds released together
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EscrowTranche {
+    /// Milestone that unlocks the tranche (required for milestone releases)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<String>,
+    /// Share of the funds as a decimal percentage, e.g. `"25"` or `"33.5"`
+    pub percentage: String,
+    /// Earliest release time (required for scheduled releases)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::types::dates::option"
+    )]
+    pub release_at: Option<DateTime<Utc>>,
+    /// Whether the tranche has been released
+    #[serde(default)]
+    pub released: bool,
+}
+
+/// The issued token a project sells
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SaleToken {
+    /// The project ID
+    pub project_id: ProjectId,
+    /// Token symbol, e.g. `MDP`
+    pub symbol: String,
+    /// XRPL currency code: the symbol itself for three-character codes, otherwise the
+    /// 40-character hex form
+    pub currency: String,
+    /// Issuing account
+    pub issuer: String,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// An XRPL escrow holding part of a project's raised funds
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Escrow {
+    /// Escrow ID on the platform
+    pub id: String,
+    /// The project ID
+    pub project_id: ProjectId,
+    /// Account that created the escrow
+    pub owner_account: String,
+    /// Account that receives the funds on release
+    pub destination_account: String,
+    /// Sequence number of the `EscrowCreate` transaction, identifying the escrow on-ledger
+    pub sequence: u32,
+    /// Escrowed amount in XRP
+    pub amount_xrp: String,
+    /// What triggers the release
+    pub release_condition: ReleaseCondition,
+    /// Milestone that unlocks the escrow, for milestone releases
+    pub milestone_id: Option<String>,
+    /// Earliest time the escrow can be released (`FinishAfter`)
+    #[serde(default, with = "crate::types::dates::option")]
+    pub finish_after: Option<DateTime<Utc>>,
+    /// Time after which the escrow can be cancelled and returned (`CancelAfter`)
+    #[serde(default, with = "crate::types::dates::option")]
+    pub cancel_after: Option<DateTime<Utc>>,
+    /// Current state
+    pub status: EscrowStatus,
+    /// Hash of the `EscrowCreate` transaction
+    pub create_tx_hash: String,
+    /// Hash of the `EscrowFinish` or `EscrowCancel` transaction, once settled
+    pub settle_tx_hash: Option<String>,
+    /// When the escrow was released or clawed back
+    #[serde(default, with = "crate::types::dates::option")]
+    pub settled_at: Option<DateTime<Utc>>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// State of an escrow
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum EscrowStatus {
+    /// Funds are held on-ledger
+    Held,
+    /// A release has been requested and awaits approval
+    ReleaseRequested,
+    /// Funds were released to the destination account
+    Released,
+    /// Funds were returned to investors
+    ClawedBack,
+    /// A status added to the API after this SDK version
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A request to release the escrows tied to a milestone
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ReleaseRequest {
+    /// Release request ID
+    pub id: String,
+    /// The project ID
+    pub project_id: ProjectId,
+    /// The milestone whose escrows are to be released
+    pub milestone_id: String,
+    /// Escrows covered by the request
+    pub escrow_ids: Vec<String>,
+    /// When the release was requested
+    #[serde(with = "crate::types::dates")]
+    pub requested_at: DateTime<Utc>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+impl EscrowConfig {
+    /// Check the configuration before sending it to the platform
+    ///
+    /// Tranche percentages must be positive and sum to exactly 100, milestone and
+    /// scheduled releases need a milestone or release time on every tranche, and the
+    /// arbiter must look like a classic XRPL address.
+    pub fn validate(&self) -> crate::Result<()> {
+        let invalid = |message: String| {
+            crate::Error::Configuration(format!("Invalid escrow config: {}", message))
+        };
+
+        if self.tranches.is_empty() {
+            return Err(invalid("at least one tranche is required".to_string()));
+        }
+
+        let mut total = 0u64;
+        for (index, tranche) in self.tranches.iter().enumerate() {
+            let share = parse_percentage(&tranche.percentage).ok_or_else(|| {
+                invalid(format!(
+                    "tranche {} has invalid percentage '{}'",
+                    index, tranche.percentage
+                ))
+            })?;
+            if share == 0 {
+                return Err(invalid(format!("tranche {} has a zero percentage", index)));
+            }
+            total += share;
+
+            match self.release_condition {
+                ReleaseCondition::Milestone
+                    if tranche
+                        .milestone
+                        .as_deref()
+                        .map_or(true, |m| m.trim().is_empty()) =>
+                {
+                    return Err(invalid(format!("tranche {} needs a milestone", index)));
+                }
+                ReleaseCondition::Schedule if tranche.release_at.is_none() => {
+                    return Err(invalid(format!("tranche {} needs a release time", index)));
+                }
+                _ => {}
+            }
+        }
+
+        if total != 100 * PERCENT_SCALE {
+            return Err(invalid(format!(
+                "tranche percentages sum to {}, expected 100",
+                format_percentage(total)
+            )));
+        }
+
+        if let Some(arbiter) = &self.arbiter_account {
+            let looks_valid = arbiter.starts_with('r')
+                && (25..=35).contains(&arbiter.len())
+                && arbiter.chars().all(|c| c.is_ascii_alphanumeric());
+            if !looks_valid {
+                return Err(invalid(format!("'{}' is not an XRPL address", arbiter)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Outcome of validating a project definition
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ValidationReport {
+    /// Problems found, in the order they were checked
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Whether the definition has no errors (warnings are allowed)
+    pub fn is_valid(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    /// Issues that would make the platform reject the definition
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == ValidationSeverity::Error)
+    }
+
+    /// Issues worth reviewing that do not block submission
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues
+            .iter()
+            .filter(|issue| issue.severity == ValidationSeverity::Warning)
+    }
+
+    /// Convert the errors into [`Error::Configuration`](crate::Error::Configuration)
+    pub fn into_result(self) -> crate::Result<()> {
+        if self.is_valid() {
+            return Ok(());
+        }
+
+        let messages: Vec<String> = self
+            .errors()
+            .map(|issue| format!("{}: {}", issue.field, issue.message))
+            .collect();
+        Err(crate::Error::Configuration(format!(
+            "Invalid project: {}",
+            messages.join("; ")
+        )))
+    }
+
+    fn error(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.push(field, ValidationSeverity::Error, message);
+    }
+
+    fn warning(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.push(field, ValidationSeverity::Warning, message);
+    }
+
+    fn push(
+        &mut self,
+        field: impl Into<String>,
+        severity: ValidationSeverity,
+        message: impl Into<String>,
+    ) {
+        self.issues.push(ValidationIssue {
+            field: field.into(),
+            severity,
+            message: message.into(),
+        });
+    }
+}
+
+/// A single problem found while validating a project definition
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    /// Path of the offending field, e.g. `tiers[1].price_per_token`
+    pub field: String,
+    /// Whether the issue blocks submission
+    pub severity: ValidationSeverity,
+    /// Human-readable description
+    pub message: String,
+}
+
+/// Severity of a [`ValidationIssue`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationSeverity {
+    /// The platform would reject the definition
+    Error,
+    /// Allowed, but probably a mistake
+    Warning,
+}
+
+impl CreateProjectRequest {
+    /// Check the definition against the platform's documented rules without calling the API
+    ///
+    /// * `name` must not be blank
+    /// * `token_symbol` must be 3 to 12 uppercase letters or digits, and not `XRP`
+    /// * `total_supply` must be a positive amount
+    /// * tiers must be numbered 1, 2, 3, ... in order, each with a positive price and
+    ///   token count, and together may not offer more tokens than `total_supply`
+    /// * the sale must start before it ends; a start date in the past is a warning
+    pub fn validate(&self) -> ValidationReport {
+        self.validate_at(Utc::now())
+    }
+
+    /// Like [`validate`](Self::validate), judging whether the sale starts in the past
+    /// against `now`
+    pub fn validate_at(&self, now: DateTime<Utc>) -> ValidationReport {
+        let mut report = ValidationReport::default();
+
+        if self.name.trim().is_empty() {
+            report.error("name", "is required");
+        }
+
+        let symbol = &self.token_symbol;
+        if symbol.trim().is_empty() {
+            report.error("token_symbol", "is required");
+        } else if !(3..=12).contains(&symbol.len())
+            || !symbol
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        {
+            report.error(
+                "token_symbol",
+                format!("'{}' must be 3 to 12 uppercase letters or digits", symbol),
+            );
+        } else if symbol == "XRP" {
+            report.error("token_symbol", "XRP is reserved");
+        }
+
+        let total_supply = parse_amount(&self.total_supply);
+        match total_supply {
+            Some(0) => report.error("total_supply", "must be greater than zero"),
+            None => report.error(
+                "total_supply",
+                format!("'{}' is not a valid amount", self.total_supply),
+            ),
+            Some(_) => {}
+        }
+
+        if self.tiers.is_empty() {
+            report.error("tiers", "at least one tier is required");
+        }
+        let mut offered: Option<u128> = Some(0);
+        for (index, tier) in self.tiers.iter().enumerate() {
+            let field = |name: &str| format!("tiers[{}].{}", index, name);
+
+            if tier.tier as usize != index + 1 {
+                report.error(
+                    field("tier"),
+                    format!("expected tier {}, found {}", index + 1, tier.tier),
+                );
+            }
+            match parse_amount(&tier.price_per_token) {
+                Some(0) => report.error(field("price_per_token"), "must be greater than zero"),
+                None => report.error(
+                    field("price_per_token"),
+                    format!("'{}' is not a valid amount", tier.price_per_token),
+                ),
+                Some(_) => {}
+            }
+            match parse_amount(&tier.total_tokens) {
+                Some(0) => report.error(field("total_tokens"), "must be greater than zero"),
+                None => {
+                    report.error(
+                        field("total_tokens"),
+                        format!("'{}' is not a valid amount", tier.total_tokens),
+                    );
+                    offered = None;
+                }
+                Some(tokens) => offered = offered.and_then(|total| total.checked_add(tokens)),
+            }
+        }
+        if let (Some(offered), Some(supply)) = (offered, total_supply) {
+            if offered > supply {
+                report.error(
+                    "tiers",
+                    format!(
+                        "tiers offer {} tokens, more than the total supply of {}",
+                        format_amount(offered),
+                        self.total_supply
+                    ),
+                );
+            }
+        }
+
+        if self.sale_start_date >= self.sale_end_date {
+            report.error("sale_end_date", "must be after sale_start_date");
+        }
+        if self.sale_start_date < now {
+            report.warning("sale_start_date", "is in the past");
+        }
+
+        report
+    }
+}
+
+/// Typestate marker for a required builder field that has not been set
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Missing;
+
+/// Typestate marker for a required builder field that has been set
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Set;
+
+/// Builder for [`CreateProjectRequest`] that only compiles `build()` once every
+/// required field is set
+///
+/// Obtain one with [`CreateProjectRequest::builder`]. The type parameters track which
+/// of the name, token symbol, total supply, tiers, and sale window have been provided.
+///
+/// ```rust
+/// use xrplsale::types::projects::{CreateProjectRequest, ProjectTier};
+///
+/// # fn main() -> xrplsale::Result<()> {
+/// let start = chrono::Utc::now() + chrono::Duration::days(30);
+/// let request = CreateProjectRequest::builder()
+///     .name("My DeFi Protocol")
+///     .token_symbol("MDP")
+///     .total_supply("100000000")
+///     .tier("0.001", "20000000")
+///     .sale_window(start, start + chrono::Duration::days(30))
+///     .description("Revolutionary DeFi protocol on XRPL")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Leaving out a required field is a compile error:
+///
+/// ```rust,compile_fail
+/// use xrplsale::types::projects::CreateProjectRequest;
+///
+/// let request = CreateProjectRequest::builder().name("No tiers").build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct CreateProjectBuilder<
+    Name = Missing,
+    Symbol = Missing,
+    Supply = Missing,
+    Tiers = Missing,
+    Window = Missing,
+> {
+    request: CreateProjectRequest,
+    state: std::marker::PhantomData<(Name, Symbol, Supply, Tiers, Window)>,
+}
+
+impl CreateProjectRequest {
+    /// Start a builder that checks required fields at compile time
+    pub fn builder() -> CreateProjectBuilder {
+        CreateProjectBuilder {
+            request: CreateProjectRequest::default(),
+            state: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<Name, Symbol, Supply, Tiers, Window>
+    CreateProjectBuilder<Name, Symbol, Supply, Tiers, Window>
+{
+    fn transition<N2, S2, Su2, T2, W2>(self) -> CreateProjectBuilder<N2, S2, Su2, T2, W2> {
+        CreateProjectBuilder {
+            request: self.request,
+            state: std::marker::PhantomData,
+        }
+    }
+
+    /// Set the project name
+    pub fn name<S: Into<String>>(
+        mut self,
+        name: S,
+    ) -> CreateProjectBuilder<Set, Symbol, Supply, Tiers, Window> {
+        self.request.name = name.into();
+        self.transition()
+    }
+
+    /// Set the token symbol
+    pub fn token_symbol<S: Into<String>>(
+        mut self,
+        symbol: S,
+    ) -> CreateProjectBuilder<Name, Set, Supply, Tiers, Window> {
+        self.request.token_symbol = symbol.into();
+        self.transition()
+    }
+
+    /// Set the total token supply
+    pub fn total_supply<S: Into<String>>(
+        mut self,
+        total_supply: S,
+    ) -> CreateProjectBuilder<Name, Symbol, Set, Tiers, Window> {
+        self.request.total_supply = total_supply.into();
+        self.transition()
+    }
+
+    /// Add the next tier, numbered after the tiers already added
+    ///
+    /// # Arguments
+    ///
+    /// * `price_per_token` - Price per token in XRP
+    /// * `total_tokens` - Tokens offered in this tier
+    pub fn tier<P: Into<String>, T: Into<String>>(
+        mut self,
+        price_per_token: P,
+        total_tokens: T,
+    ) -> CreateProjectBuilder<Name, Symbol, Supply, Set, Window> {
+        let tier = self.request.tiers.len() as u32 + 1;
+        self.request.tiers.push(ProjectTier {
+            tier,
+            price_per_token: price_per_token.into(),
+            total_tokens: total_tokens.into(),
+            ..Default::default()
+        });
+        self.transition()
+    }
+
+    /// Add a fully specified tier
+    pub fn tier_with(
+        mut self,
+        tier: ProjectTier,
+    ) -> CreateProjectBuilder<Name, Symbol, Supply, Set, Window> {
+        self.request.tiers.push(tier);
+        self.transition()
+    }
+
+    /// Set when the sale opens and closes
+    pub fn sale_window(
+        mut self,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> CreateProjectBuilder<Name, Symbol, Supply, Tiers, Set> {
+        self.request.sale_start_date = start;
+        self.request.sale_end_date = end;
+        self.transition()
+    }
+
+    /// Set the project description
+    pub fn description<S: Into<String>>(mut self, description: S) -> Self {
+        self.request.description = description.into();
+        self
+    }
+}
+
+impl CreateProjectBuilder<Set, Set, Set, Set, Set> {
+    /// Build the request, checking it with [`CreateProjectRequest::validate`]
+    ///
+    /// Fails with [`Error::Configuration`](crate::Error::Configuration) if validation
+    /// finds errors; warnings are ignored.
+    pub fn build(self) -> crate::Result<CreateProjectRequest> {
+        self.request.validate().into_result()?;
+        Ok(self.request)
+    }
+}
+
+impl Project {
+    /// Share of the tokens offered in the tiers that has been sold, as a decimal
+    /// percentage, e.g. `"42.5"`
+    ///
+    /// Computed locally with exact decimal arithmetic. Returns `None` if the project
+    /// offers no tokens or any of the amounts involved is malformed.
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - The project's sale stats
+    pub fn percent_funded(&self, stats: &ProjectStats) -> Option<String> {
+        let offered = self.tokens_offered()?;
+        let sold = parse_amount(&stats.tokens_sold)?;
+        percentage_of(sold, offered)
+    }
+
+    /// Time left until the sale ends, or `None` once it has ended
+    pub fn time_remaining(&self) -> Option<chrono::Duration> {
+        self.time_remaining_at(Utc::now())
+    }
+
+    /// Time left from `now` until the sale ends, or `None` once it has ended
+    pub fn time_remaining_at(&self, now: DateTime<Utc>) -> Option<chrono::Duration> {
+        let remaining = self.sale_end_date - now;
+        (remaining > chrono::Duration::zero()).then_some(remaining)
+    }
+
+    /// Whether the project is active and the current time is inside its sale window
+    pub fn is_live_now(&self) -> bool {
+        self.is_live_at(Utc::now())
+    }
+
+    /// Whether the project is active and `now` is inside its sale window
+    pub fn is_live_at(&self, now: DateTime<Utc>) -> bool {
+        let active = serde_json::to_value(&self.status)
+            .map(|status| status == "active")
+            .unwrap_or(false);
+        active && self.sale_start_date <= now && now < self.sale_end_date
+    }
+
+    /// The tier currently selling, given the tokens sold so far
+    ///
+    /// Tiers sell out in order, so this is the first tier whose tokens, added to those
+    /// of the tiers before it, exceed the tokens sold. Returns `None` once every tier
+    /// has sold out, or if any of the amounts involved is malformed.
+    ///
+    /// # Arguments
+    ///
+    /// * `stats` - The project's sale stats
+    pub fn current_tier(&self, stats: &ProjectStats) -> Option<&ProjectTier> {
+        let sold = parse_amount(&stats.tokens_sold)?;
+        let mut offered: u128 = 0;
+        for tier in &self.tiers {
+            offered = offered.checked_add(parse_amount(&tier.total_tokens)?)?;
+            if sold < offered {
+                return Some(tier);
+            }
+        }
+        None
+    }
+
+    fn tokens_offered(&self) -> Option<u128> {
+        self.tiers.iter().try_fold(0u128, |total, tier| {
+            total.checked_add(parse_amount(&tier.total_tokens)?)
+        })
+    }
+}
+
+impl ProjectStats {
+    /// Average amount invested per investor, in XRP
+    ///
+    /// Computed locally with exact decimal arithmetic, rounded down to fifteen decimal
+    /// places. Returns `None` if there are no investors yet or the total raised is
+    /// malformed.
+    pub fn average_investment(&self) -> Option<String> {
+        let raised = parse_amount(&self.total_raised_xrp)?;
+        let investors: u128 = self.total_investors.to_string().parse().ok()?;
+        if investors == 0 {
+            return None;
+        }
+        Some(format_amount(raised / investors))
+    }
+}
+
+/// Sale progress of a single tier
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TierProgress {
+    /// Tier number
+    pub tier: u32,
+    /// Price per token when the tier opened, in XRP
+    pub price_per_token: String,
+    /// Tokens offered in the tier
+    pub total_tokens: String,
+    /// Tokens sold so far
+    pub tokens_sold: String,
+    /// Tokens still available
+    pub tokens_remaining: String,
+    /// Share of the tier sold, as a decimal percentage
+    pub percent_sold: String,
+    /// How the price moves as the tier sells; a fixed price if absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pricing: Option<PriceCurve>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+impl TierProgress {
+    /// Whether every token in the tier has been sold
+    pub fn is_sold_out(&self) -> bool {
+        parse_amount(&self.tokens_remaining) == Some(0)
+    }
+
+    /// Price of the next token sold, in XRP
+    ///
+    /// Computed locally from the tier's pricing curve and tokens sold. Returns `None`
+    /// if any of the amounts involved is malformed.
+    pub fn current_price(&self) -> Option<String> {
+        self.pricing.clone().unwrap_or(PriceCurve::Fixed).price_at(
+            &self.price_per_token,
+            &self.tokens_sold,
+            &self.total_tokens,
+        )
+    }
+}
+
+/// Dynamic pricing of a tier
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PriceCurve {
+    /// The price stays at the tier's price per token
+    Fixed,
+    /// Bonding curve: the price rises linearly to `end_price` as the tier sells out
+    Linear {
+        /// Price per token of the last token in the tier, in XRP
+        end_price: String,
+    },
+    /// The price rises by `increment` every `step_size` tokens sold
+    Stepped {
+        /// Tokens sold between price increases
+        step_size: String,
+        /// Price increase per step, in XRP
+        increment: String,
+    },
+}
+
+impl PriceCurve {
+    /// Price per token after `sold` of `total` tokens have been sold, in XRP
+    ///
+    /// # Arguments
+    ///
+    /// * `base_price` - The tier's price per token
+    /// * `sold` - Tokens sold so far
+    /// * `total` - Tokens offered in the tier
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use xrplsale::types::projects::PriceCurve;
+    ///
+    /// let curve = PriceCurve::Linear {
+    ///     end_price: "0.002".to_string(),
+    /// };
+    /// assert_eq!(
+    ///     curve.price_at("0.001", "500000", "1000000").as_deref(),
+    ///     Some("0.0015")
+    /// );
+    /// ```
+    pub fn price_at(&self, base_price: &str, sold: &str, total: &str) -> Option<String> {
+        let base = parse_amount(base_price)?;
+        let sold = parse_amount(sold)?;
+        let total = parse_amount(total)?;
+
+        let price = match self {
+            PriceCurve::Fixed => base,
+            PriceCurve::Linear { end_price } => {
+                let end = parse_amount(end_price)?;
+                if total == 0 {
+                    return Some(format_amount(base));
+                }
+                // Share sold with nine decimal places keeps the products within u128
+                let share = sold.min(total).checked_mul(CURVE_SCALE)? / total;
+                if end >= base {
+                    base.checked_add((end - base).checked_mul(share)? / CURVE_SCALE)?
+                } else {
+                    base - (base - end).checked_mul(share)? / CURVE_SCALE
+                }
+            }
+            PriceCurve::Stepped {
+                step_size,
+                increment,
+            } => {
+                let step_size = parse_amount(step_size)?;
+                if step_size == 0 {
+                    return None;
+                }
+                let steps = sold.min(total) / step_size;
+                base.checked_add(parse_amount(increment)?.checked_mul(steps)?)?
+            }
+        };
+
+        Some(format_amount(price))
+    }
+}
+
+/// Precision of the sold share used when interpolating bonding curves
+const CURVE_SCALE: u128 = 1_000_000_000;
+
+/// Token amounts are compared as integers with fifteen decimal places
+pub(crate) const AMOUNT_SCALE: u32 = 15;
+
+pub(crate) fn parse_amount(value: &str) -> Option<u128> {
+    let (whole, fraction) = value.trim().split_once('.').unwrap_or((value.trim(), ""));
+    if whole.is_empty()
+        || fraction.len() > AMOUNT_SCALE as usize
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let whole: u128 = whole.parse().ok()?;
+    let fraction: u128 = format!("{:0<width$}", fraction, width = AMOUNT_SCALE as usize)
+        .parse()
+        .ok()?;
+    whole
+        .checked_mul(10u128.pow(AMOUNT_SCALE))?
+        .checked_add(fraction)
+}
+
+pub(crate) fn format_amount(value: u128) -> String {
+    let scale = 10u128.pow(AMOUNT_SCALE);
+    let fraction = format!("{:0width$}", value % scale, width = AMOUNT_SCALE as usize);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        (value / scale).to_string()
+    } else {
+        format!("{}.{}", value / scale, fraction)
+    }
+}
+
+/// Percentages are compared as integers with six decimal places
+const PERCENT_SCALE: u64 = 1_000_000;
+
+fn parse_percentage(value: &str) -> Option<u64> {
+    let (whole, fraction) = value.trim().split_once('.').unwrap_or((value.trim(), ""));
+    if whole.is_empty() && fraction.is_empty()
+        || fraction.len() > 6
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let whole: u64 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().ok()?
+    };
+    let fraction: u64 = format!("{:0<6}", fraction).parse().ok()?;
+    whole.checked_mul(PERCENT_SCALE)?.checked_add(fraction)
+}
+
+/// `part` as a decimal percentage of `whole`, both in amount scale
+fn percentage_of(part: u128, whole: u128) -> Option<String> {
+    if whole == 0 {
+        return None;
+    }
+    let hundred = 100 * u128::from(PERCENT_SCALE);
+    let scaled = match part.checked_mul(hundred) {
+        Some(product) => product / whole,
+        // Amounts this large lose nothing meaningful when the divisor is scaled instead
+        None => part / (whole / hundred).max(1),
+    };
+    u64::try_from(scaled).ok().map(format_percentage)
+}
+
+fn format_percentage(value: u64) -> String {
+    let fraction = format!("{:06}", value % PERCENT_SCALE);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        (value / PERCENT_SCALE).to_string()
+    } else {
+        format!("{}.{}", value / PERCENT_SCALE, fraction)
+    }
+}
+
+/// Filters, sorting, and pagination for project listings
+///
+/// Several statuses can be given; projects in any of them match.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ProjectQuery {
+    /// Statuses to include, e.g. `active`; all statuses if empty
+    #[serde(rename = "status", default, skip_serializing_if = "Vec::is_empty")]
+    pub statuses: Vec<String>,
+    /// Page number (1-based)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    /// Number of items per page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Field to sort by
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<ProjectSortField>,
+    /// Sort direction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<SortOrder>,
+}
+
+impl ProjectQuery {
+    /// Create a query matching every project
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Include projects with this status (may be called several times)
+    pub fn status<S: Into<String>>(mut self, status: S) -> Self {
+        self.statuses.push(status.into());
+        self
+    }
+
+    /// Request a page (1-based)
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Set the number of items per page
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sort by a field
+    pub fn sort(mut self, field: ProjectSortField, order: SortOrder) -> Self {
+        self.sort_by = Some(field);
+        self.sort_order = Some(order);
+        self
+    }
+}
+
+/// Field to sort project listings by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectSortField {
+    /// Creation time
+    CreatedAt,
+    /// Last update time
+    UpdatedAt,
+    /// Project name
+    Name,
+    /// Sale start date
+    SaleStartDate,
+    /// Sale end date
+    SaleEndDate,
+    /// Total XRP raised
+    TotalRaisedXrp,
+}
+
+impl ProjectSortField {
+    /// Value sent in the `sort_by` query parameter
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProjectSortField::CreatedAt => "created_at",
+            ProjectSortField::UpdatedAt => "updated_at",
+            ProjectSortField::Name => "name",
+            ProjectSortField::SaleStartDate => "sale_start_date",
+            ProjectSortField::SaleEndDate => "sale_end_date",
+            ProjectSortField::TotalRaisedXrp => "total_raised_xrp",
+        }
+    }
+}
+
+impl std::fmt::Display for ProjectSortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A collaborator's role on a project
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectRole {
+    /// Full control, including managing members and deleting the project
+    Owner,
+    /// Can edit, launch, and manage the sale, but not change owners
+    Admin,
+    /// Read-only access to the project and its analytics
+    Viewer,
+}
+
+/// A member of a project team
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ProjectMember {
+    /// Member ID
+    pub id: String,
+    /// Member's email address
+    pub email: String,
+    /// Display name, if set
+    pub name: Option<String>,
+    /// Role on the project
+    pub role: ProjectRole,
+    /// When the member joined the project
+    #[serde(with = "crate::types::dates")]
+    pub joined_at: DateTime<Utc>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// An invitation to join a project team that has not been accepted yet
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ProjectInvite {
+    /// Invite ID
+    pub id: String,
+    /// Address the invitation was sent to
+    pub email: String,
+    /// Role granted on acceptance
+    pub role: ProjectRole,
+    /// ID of the member who sent the invitation
+    pub invited_by: String,
+    /// When the invitation was sent
+    #[serde(with = "crate::types::dates")]
+    pub created_at: DateTime<Utc>,
+    /// When the invitation stops being valid
+    #[serde(with = "crate::types::dates")]
+    pub expires_at: DateTime<Utc>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// Request to invite a collaborator to a project
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct InviteMemberRequest {
+    /// Address to send the invitation to
+    pub email: String,
+    /// Role granted on acceptance
+    pub role: ProjectRole,
+}
+
+/// A project milestone governing the release of escrowed funds
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Milestone {
+    /// Milestone ID
+    pub id: String,
+    /// The project ID
+    pub project_id: ProjectId,
+    /// Short title shown on the roadmap
+    pub title: String,
+    /// What completing the milestone involves
+    pub description: String,
+    /// When the milestone is due
+    #[serde(with = "crate::types::dates")]
+    pub target_date: DateTime<Utc>,
+    /// Share of the escrowed funds released on completion, as a decimal percentage
+    pub release_percentage: String,
+    /// Current state
+    pub status: MilestoneStatus,
+    /// Links to evidence supplied when the milestone was marked complete
+    #[serde(default)]
+    pub evidence: Vec<String>,
+    /// When the milestone was marked complete
+    #[serde(default, with = "crate::types::dates::option")]
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// State of a milestone
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum MilestoneStatus {
+    /// Not yet complete
+    Pending,
+    /// Marked complete and awaiting approval of the release
+    Submitted,
+    /// Approved; its escrowed funds can be released
+    Completed,
+    /// Past its target date without being completed
+    Overdue,
+    /// A status added to the API after this SDK version
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Request to create a milestone
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CreateMilestoneRequest {
+    /// Short title shown on the roadmap
+    pub title: String,
+    /// What completing the milestone involves
+    pub description: String,
+    /// When the milestone is due
+    #[serde(with = "crate::types::dates")]
+    pub target_date: DateTime<Utc>,
+    /// Share of the escrowed funds released on completion, e.g. `"25"`
+    pub release_percentage: String,
+}
+
+/// Request to update a milestone; unset fields are left unchanged
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UpdateMilestoneRequest {
+    /// New title
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// New description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// New target date
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::types::dates::option"
+    )]
+    pub target_date: Option<DateTime<Utc>>,
+    /// New release percentage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_percentage: Option<String>,
+}
+
+/// Request to mark a milestone complete
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CompleteMilestoneRequest {
+    /// Links to evidence of completion (reports, releases, audits)
+    pub evidence: Vec<String>,
+    /// Note for investors and approvers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Check that a milestone release percentage is above 0 and at most 100
+pub(crate) fn validate_release_percentage(value: &str) -> crate::Result<()> {
+    match parse_percentage(value) {
+        Some(share) if share > 0 && share <= 100 * PERCENT_SCALE => Ok(()),
+        _ => Err(crate::Error::Configuration(format!(
+            "Invalid milestone: release percentage '{}' must be above 0 and at most 100",
+            value
+        ))),
+    }
+}
@@ -0,0 +1,25 @@
+//! Real-time update types
+
+use crate::error::{Error, Result};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+/// An update pushed on a realtime subscription
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RealtimeEvent {
+    /// Event ID; a subscription resumes after the last one it received
+    pub id: Option<String>,
+    /// Topic the event was published on, e.g. `projects.proj_abc123.stats`
+    pub topic: String,
+    /// Event payload
+    pub data: Value,
+}
+
+impl RealtimeEvent {
+    /// Deserialize the payload into a typed value
+    pub fn data_as<T: DeserializeOwned>(&self) -> Result<T> {
+        serde_json::from_value(self.data.clone())
+            .map_err(|e| Error::Parse(format!("Invalid payload of '{}' event: {}", self.topic, e)))
+    }
+}
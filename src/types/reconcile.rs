@@ -0,0 +1,119 @@
+//! Ledger reconciliation types
+
+use crate::{ids::ProjectId, types::investments::Investment};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// Time window to reconcile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ReconcileRange {
+    /// Start of the window, inclusive
+    #[serde(with = "crate::types::dates")]
+    pub from: DateTime<Utc>,
+    /// End of the window, exclusive
+    #[serde(with = "crate::types::dates")]
+    pub to: DateTime<Utc>,
+}
+
+impl ReconcileRange {
+    /// Create a window from `from` up to, but not including, `to`
+    pub fn new(from: DateTime<Utc>, to: DateTime<Utc>) -> Self {
+        Self { from, to }
+    }
+
+    /// Whether a point in time falls inside the window
+    pub fn contains(&self, time: &DateTime<Utc>) -> bool {
+        self.from <= *time && *time < self.to
+    }
+}
+
+impl From<Range<DateTime<Utc>>> for ReconcileRange {
+    fn from(range: Range<DateTime<Utc>>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+/// A validated XRP payment found on the ledger
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LedgerPayment {
+    /// Transaction hash
+    pub hash: String,
+    /// Sending account
+    pub account: String,
+    /// Receiving account
+    pub destination: String,
+    /// Destination tag, if set
+    pub destination_tag: Option<u32>,
+    /// XRP actually delivered, in drops
+    pub delivered_drops: u64,
+    /// Ledger the transaction was validated in
+    pub ledger_index: u32,
+    /// Close time of that ledger
+    #[serde(with = "crate::types::dates")]
+    pub executed_at: DateTime<Utc>,
+}
+
+impl LedgerPayment {
+    /// The delivered amount as a decimal XRP string, e.g. `"12.5"`
+    pub fn delivered_xrp(&self) -> String {
+        let whole = self.delivered_drops / 1_000_000;
+        let fraction = self.delivered_drops % 1_000_000;
+        if fraction == 0 {
+            whole.to_string()
+        } else {
+            format!("{}.{:06}", whole, fraction)
+                .trim_end_matches('0')
+                .to_string()
+        }
+    }
+}
+
+/// A field that differs between an investment and its payment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum MismatchField {
+    /// The delivered amount differs from the invested amount
+    Amount,
+}
+
+/// An investment whose payment was found but doesn't agree with it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Mismatch {
+    /// The platform record
+    pub investment: Investment,
+    /// The payment it was paired with
+    pub payment: LedgerPayment,
+    /// The fields that differ
+    pub fields: Vec<MismatchField>,
+}
+
+/// Differences between a project's investments and the payments on the ledger
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ReconciliationReport {
+    /// The reconciled project
+    pub project_id: ProjectId,
+    /// Account the payments were looked up on
+    pub account: String,
+    /// The reconciled window
+    pub range: ReconcileRange,
+    /// Investments that agree with their payment
+    pub matched: usize,
+    /// Investments without a successful payment on the ledger
+    pub missing_on_ledger: Vec<Investment>,
+    /// Payments to the account without an investment on the platform
+    pub missing_on_platform: Vec<LedgerPayment>,
+    /// Investments that disagree with their payment
+    pub mismatched: Vec<Mismatch>,
+}
+
+impl ReconciliationReport {
+    /// Whether the platform and the ledger agree
+    pub fn is_clean(&self) -> bool {
+        self.missing_on_ledger.is_empty()
+            && self.missing_on_platform.is_empty()
+            && self.mismatched.is_empty()
+    }
+}
@@ -0,0 +1,113 @@
+//! Testnet sandbox types
+
+use crate::{
+    error::{Error, Result},
+    ids::ProjectId,
+    types::projects::parse_amount,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A project's simulated sale clock
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SaleClock {
+    /// Project the clock belongs to
+    pub project_id: ProjectId,
+    /// The current time as the project's sale sees it
+    #[serde(with = "crate::types::dates")]
+    pub now: DateTime<Utc>,
+    /// Seconds the clock runs ahead of real time
+    pub offset_seconds: i64,
+}
+
+/// An investment to simulate from a faucet-funded wallet
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SimulateInvestmentRequest {
+    /// Project to invest in
+    pub project_id: ProjectId,
+    /// XRP to invest
+    pub amount_xrp: String,
+    /// Tier to buy from; the sale's current tier if unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tier: Option<u32>,
+    /// Testnet account to invest from; a new faucet wallet if unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub investor_account: Option<String>,
+}
+
+impl SimulateInvestmentRequest {
+    /// Invest `amount_xrp` in a project from a new faucet wallet
+    pub fn new<S: Into<String>>(project_id: impl Into<ProjectId>, amount_xrp: S) -> Self {
+        Self {
+            project_id: project_id.into(),
+            amount_xrp: amount_xrp.into(),
+            tier: None,
+            investor_account: None,
+        }
+    }
+
+    /// Buy from a specific tier
+    pub fn tier(mut self, tier: u32) -> Self {
+        self.tier = Some(tier);
+        self
+    }
+
+    /// Invest from an existing Testnet account, e.g. to test per-investor limits
+    pub fn investor_account<S: Into<String>>(mut self, account: S) -> Self {
+        self.investor_account = Some(account.into());
+        self
+    }
+
+    /// Check the amount and tier before sending
+    pub(crate) fn validate(&self) -> Result<()> {
+        if !matches!(parse_amount(&self.amount_xrp), Some(amount) if amount > 0) {
+            return Err(Error::Configuration(format!(
+                "Invalid simulated investment: amount_xrp '{}' must be a positive amount",
+                self.amount_xrp
+            )));
+        }
+        if self.tier == Some(0) {
+            return Err(Error::Configuration(
+                "Invalid simulated investment: tiers are numbered from 1".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A synthetic webhook event to deliver to the registered endpoints
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyntheticEventRequest {
+    /// Event type, e.g. `investment.created`
+    pub event_type: String,
+    /// Project the event refers to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<ProjectId>,
+    /// Event data; the platform fills in a sample payload for the type if unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl SyntheticEventRequest {
+    /// An event of the given type with a sample payload
+    pub fn new<S: Into<String>>(event_type: S) -> Self {
+        Self {
+            event_type: event_type.into(),
+            project_id: None,
+            data: None,
+        }
+    }
+
+    /// Attach the event to a project
+    pub fn project(mut self, project_id: impl Into<ProjectId>) -> Self {
+        self.project_id = Some(project_id.into());
+        self
+    }
+
+    /// Send this data instead of the sample payload
+    pub fn data(mut self, data: Value) -> Self {
+        self.data = Some(data);
+        self
+    }
+}
@@ -0,0 +1,71 @@
+//! Session and token types
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The authenticated account
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Account {
+    /// Account ID
+    pub id: String,
+    /// Linked XRPL wallet address, if any
+    pub wallet_address: Option<String>,
+    /// Display name
+    pub name: Option<String>,
+    /// Contact email
+    pub email: Option<String>,
+    /// Roles granted to the account (e.g. "issuer", "investor")
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// When the account was created
+    #[serde(with = "crate::types::dates")]
+    pub created_at: DateTime<Utc>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// Result of introspecting an access token
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TokenIntrospection {
+    /// Whether the token is currently valid
+    pub active: bool,
+    /// Scopes granted to the token
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// Account the token belongs to
+    pub subject: Option<String>,
+    /// When the token was issued
+    #[serde(default, with = "crate::types::dates::option")]
+    pub issued_at: Option<DateTime<Utc>>,
+    /// When the token expires
+    #[serde(default, with = "crate::types::dates::option")]
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+impl TokenIntrospection {
+    /// Whether the token is inactive or expires within `window`
+    ///
+    /// Long-running services can use this to re-authenticate before requests start
+    /// failing.
+    pub fn expires_within(&self, window: chrono::Duration) -> bool {
+        self.expires_within_at(window, Utc::now())
+    }
+
+    /// Whether the token is inactive or expires within `window` of `now`
+    pub fn expires_within_at(&self, window: chrono::Duration, now: DateTime<Utc>) -> bool {
+        !self.active || self.expires_at.is_some_and(|at| at <= now + window)
+    }
+
+    /// Whether the token grants a scope
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|s| s == scope)
+    }
+}
@@ -0,0 +1,210 @@
+//! Staking program types
+
+use crate::{
+    error::{Error, Result},
+    ids::ProjectId,
+    wallet::WalletSigner,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "xrpl")]
+use crate::multisig::{SigningMode, UnsignedTransaction};
+
+/// A program for staking a launched token to earn tier access
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StakingProgram {
+    /// Program ID
+    pub id: String,
+    /// Project whose token is staked
+    pub project_id: ProjectId,
+    /// Program name
+    pub name: String,
+    /// XRPL currency code of the staked token
+    pub currency: String,
+    /// Issuing account of the staked token
+    pub issuer: String,
+    /// Smallest stake accepted
+    pub min_stake: String,
+    /// Days a stake stays locked before it can be withdrawn
+    pub lock_days: u32,
+    /// Stake needed for each tier, lowest tier first
+    pub tier_thresholds: Vec<TierThreshold>,
+    /// Whether new stakes are accepted
+    pub active: bool,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// Stake needed to reach a sale tier
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TierThreshold {
+    /// Tier number
+    pub tier: u32,
+    /// Minimum staked amount
+    pub min_stake: String,
+}
+
+/// An account's stake in a program
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StakePosition {
+    /// Position ID
+    pub id: String,
+    /// Program staked in
+    pub program_id: String,
+    /// Staking account
+    pub account: String,
+    /// Tokens staked
+    pub amount: String,
+    /// Current state
+    pub status: StakeStatus,
+    /// When the stake was locked
+    #[serde(with = "crate::types::dates")]
+    pub staked_at: DateTime<Utc>,
+    /// When the stake can be withdrawn
+    #[serde(with = "crate::types::dates")]
+    pub unlocks_at: DateTime<Utc>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// State of a stake position
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum StakeStatus {
+    /// Locked and counting toward tier access
+    Locked,
+    /// Lock period over; can be withdrawn
+    Unlocked,
+    /// Withdrawal submitted
+    Unstaking,
+    /// Tokens returned to the account
+    Withdrawn,
+    /// A status added to the API after this SDK version
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The tier an account qualifies for now, and would with a larger stake
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct TierProjection {
+    /// The account checked
+    pub account: String,
+    /// The project ID
+    pub project_id: ProjectId,
+    /// Tokens currently staked
+    pub staked_amount: String,
+    /// Tier reached by the current stake, if any
+    pub current_tier: Option<u32>,
+    /// Tier reached after adding the requested amount, if any
+    pub projected_tier: Option<u32>,
+    /// Further stake needed to reach the next tier above the projected one
+    pub amount_to_next_tier: Option<String>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// An unsigned XRPL transaction prepared by the API
+///
+/// The API autofills the sequence, fee, and last ledger, and returns the bytes the
+/// account must sign. Sign with [`PreparedTransaction::sign`] and submit the result
+/// before [`expires_at`](Self::expires_at).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PreparedTransaction {
+    /// Prepared transaction ID
+    pub id: String,
+    /// Account that must sign
+    pub account: String,
+    /// The transaction in the XRPL's JSON format
+    pub tx_json: serde_json::Value,
+    /// Hex-encoded signing payload of the transaction
+    pub signing_payload: String,
+    /// When the transaction's last ledger is expected to close
+    #[serde(with = "crate::types::dates")]
+    pub expires_at: DateTime<Utc>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+impl PreparedTransaction {
+    /// Sign the transaction with the account's wallet
+    ///
+    /// Fails with [`Error::Configuration`] if the wallet is not the account the
+    /// transaction was prepared for.
+    pub async fn sign(&self, signer: &dyn WalletSigner) -> Result<SignedTransaction> {
+        let account = signer.account();
+        if account != self.account {
+            return Err(Error::Configuration(format!(
+                "Transaction must be signed by {}, not {}",
+                self.account, account
+            )));
+        }
+
+        let payload = hex::decode(&self.signing_payload)
+            .map_err(|e| Error::Parse(format!("Invalid signing payload: {}", e)))?;
+        let signature = signer.sign(&payload).await?;
+
+        Ok(SignedTransaction {
+            prepared_id: self.id.clone(),
+            public_key: signer.public_key(),
+            signature,
+        })
+    }
+
+    /// The transaction with instructions for signing it outside the SDK, e.g. by a
+    /// multi-signed account
+    ///
+    /// The signing payload only applies to single signing by [`account`](Self::account).
+    #[cfg(feature = "xrpl")]
+    pub fn unsigned(&self, mode: SigningMode) -> Result<UnsignedTransaction> {
+        let mut tx_json = self.tx_json.clone();
+        if let serde_json::Value::Object(fields) = &mut tx_json {
+            fields
+                .entry("Account")
+                .or_insert_with(|| self.account.clone().into());
+        }
+        let mut unsigned = UnsignedTransaction::new(tx_json, mode)?;
+        unsigned.instructions.expires_at = Some(self.expires_at);
+        Ok(unsigned)
+    }
+}
+
+/// A prepared transaction's signature, ready to submit
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SignedTransaction {
+    /// ID of the prepared transaction
+    pub prepared_id: String,
+    /// Hex-encoded public key of the signer
+    pub public_key: String,
+    /// Hex-encoded signature over the signing payload
+    pub signature: String,
+}
+
+/// Result of submitting a signed transaction
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SubmittedTransaction {
+    /// Transaction hash
+    pub tx_hash: String,
+    /// Preliminary engine result, e.g. `tesSUCCESS`
+    pub engine_result: String,
+    /// The position created or updated by the transaction
+    pub position: Option<StakePosition>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
@@ -0,0 +1,107 @@
+//! API quota and usage types
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The account's current quota consumption
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Usage {
+    /// Name of the account's plan
+    pub plan: String,
+    /// API calls made today
+    pub api_calls_today: UsageMetric,
+    /// Webhook deliveries in the current billing period
+    pub webhook_deliveries: UsageMetric,
+    /// Rows exported in the current billing period
+    pub export_volume: UsageMetric,
+    /// When the daily counters reset
+    #[serde(with = "crate::types::dates")]
+    pub resets_at: DateTime<Utc>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+impl Usage {
+    /// Quotas at or above the warning threshold, with their level
+    pub fn alerts(&self, thresholds: &UsageThresholds) -> Vec<(&'static str, UsageLevel)> {
+        [
+            ("api_calls_today", &self.api_calls_today),
+            ("webhook_deliveries", &self.webhook_deliveries),
+            ("export_volume", &self.export_volume),
+        ]
+        .into_iter()
+        .map(|(name, metric)| (name, metric.level(thresholds)))
+        .filter(|(_, level)| *level != UsageLevel::Normal)
+        .collect()
+    }
+}
+
+/// Consumption of a single quota
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UsageMetric {
+    /// Amount used so far
+    pub used: u64,
+    /// Plan limit, or `None` if unlimited
+    pub limit: Option<u64>,
+}
+
+impl UsageMetric {
+    /// Fraction of the limit used, or `None` if unlimited
+    pub fn fraction_used(&self) -> Option<f64> {
+        match self.limit {
+            Some(0) => Some(1.0),
+            Some(limit) => Some(self.used as f64 / limit as f64),
+            None => None,
+        }
+    }
+
+    /// Amount left before the limit, or `None` if unlimited
+    pub fn remaining(&self) -> Option<u64> {
+        self.limit.map(|limit| limit.saturating_sub(self.used))
+    }
+
+    /// Classify the consumption against the given thresholds
+    pub fn level(&self, thresholds: &UsageThresholds) -> UsageLevel {
+        match self.fraction_used() {
+            Some(fraction) if fraction >= 1.0 => UsageLevel::Exceeded,
+            Some(fraction) if fraction >= thresholds.critical => UsageLevel::Critical,
+            Some(fraction) if fraction >= thresholds.warning => UsageLevel::Warning,
+            _ => UsageLevel::Normal,
+        }
+    }
+}
+
+/// Fractions of a limit at which consumption is reported
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UsageThresholds {
+    /// Fraction at which usage is a warning (default `0.8`)
+    pub warning: f64,
+    /// Fraction at which usage is critical (default `0.95`)
+    pub critical: f64,
+}
+
+impl Default for UsageThresholds {
+    fn default() -> Self {
+        Self {
+            warning: 0.8,
+            critical: 0.95,
+        }
+    }
+}
+
+/// How close a quota is to its limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageLevel {
+    /// Below the warning threshold
+    Normal,
+    /// At or above the warning threshold
+    Warning,
+    /// At or above the critical threshold
+    Critical,
+    /// At or above the limit
+    Exceeded,
+}
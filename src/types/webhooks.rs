@@ -0,0 +1,270 @@
+//! Webhook types
+
+pub use crate::webhook::{WebhookEvent, WebhookSignatureValidator};
+pub use crate::webhook_forwarding::{ForwardMetadata, ForwardedWebhook};
+
+use crate::{
+    error::{Error, Result},
+    ids::ProjectId,
+    types::{
+        governance::{Proposal, Vote},
+        liquidity::{AmmPool, LiquidityPlan},
+        projects::Escrow,
+    },
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// A webhook endpoint subscription
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct WebhookRegistration {
+    /// URL that receives deliveries
+    pub url: String,
+    /// Event types to deliver (e.g. "investment.created")
+    pub events: Vec<String>,
+    /// Restrict deliveries to a single project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<ProjectId>,
+}
+
+/// Payload version of the webhook deliveries this SDK reads natively
+pub const WEBHOOK_PAYLOAD_VERSION: u32 = 2;
+
+/// Upgrades a payload object from one version to the next
+pub type WebhookUpgrade = fn(Value) -> Result<Value>;
+
+/// Reads webhook payloads of any supported version as a current [`WebhookEvent`]
+///
+/// Each delivery's `version` field names the shape of its payload; a payload without
+/// one is read as the current version. Older payloads are upgraded one version at a
+/// time until they have the current shape, so the typed events
+/// ([`EscrowEvent::from_webhook`] and friends) only ever see that shape.
+///
+/// ```rust
+/// use xrplsale::types::webhooks::WebhookPayloads;
+///
+/// # fn receive(payload: &str) -> xrplsale::Result<()> {
+/// let event = WebhookPayloads::default().parse(payload)?;
+/// println!("{} {}", event.event_type, event.data);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct WebhookPayloads {
+    upgrades: BTreeMap<u32, WebhookUpgrade>,
+}
+
+impl Default for WebhookPayloads {
+    /// Upgrades for every payload version the platform has sent
+    fn default() -> Self {
+        Self {
+            upgrades: BTreeMap::from([(1, upgrade_v1 as WebhookUpgrade)]),
+        }
+    }
+}
+
+impl WebhookPayloads {
+    /// Add or replace the upgrade from `version` to the next version
+    ///
+    /// Useful to read payloads from a staging platform ahead of an SDK release.
+    pub fn with_upgrade(mut self, version: u32, upgrade: WebhookUpgrade) -> Self {
+        self.upgrades.insert(version, upgrade);
+        self
+    }
+
+    /// Parse a raw webhook body into an event of the current version
+    ///
+    /// Fails with [`Error::UnsupportedWebhookVersion`] if the payload is newer than
+    /// this SDK or no upgrade path exists from its version, and [`Error::Parse`] if
+    /// it is not a valid payload of its version.
+    pub fn parse(&self, payload: &str) -> Result<WebhookEvent> {
+        let payload = serde_json::from_str(payload)
+            .map_err(|e| Error::Parse(format!("Webhook payload: {}", e)))?;
+        self.parse_value(payload)
+    }
+
+    /// Read a webhook payload already parsed as JSON into an event of the current version
+    pub fn parse_value(&self, payload: Value) -> Result<WebhookEvent> {
+        let payload = self.upgrade(payload)?;
+        serde_json::from_value(payload).map_err(|e| Error::Parse(format!("Webhook payload: {}", e)))
+    }
+
+    /// Upgrade a webhook payload to the current version's shape
+    pub fn upgrade(&self, mut payload: Value) -> Result<Value> {
+        let mut version = payload_version(&payload)?;
+        if version > WEBHOOK_PAYLOAD_VERSION {
+            return Err(Error::UnsupportedWebhookVersion { version });
+        }
+
+        while version < WEBHOOK_PAYLOAD_VERSION {
+            let upgrade = self
+                .upgrades
+                .get(&version)
+                .ok_or(Error::UnsupportedWebhookVersion { version })?;
+            payload = upgrade(payload)?;
+            version += 1;
+        }
+
+        if let Value::Object(fields) = &mut payload {
+            fields.insert("version".to_string(), WEBHOOK_PAYLOAD_VERSION.into());
+        }
+        Ok(payload)
+    }
+}
+
+impl WebhookEvent {
+    /// Parse a raw webhook body of any supported payload version
+    ///
+    /// Shorthand for [`WebhookPayloads::parse`] with the SDK's upgrades.
+    pub fn parse_versioned(payload: &str) -> Result<Self> {
+        WebhookPayloads::default().parse(payload)
+    }
+}
+
+/// The `version` field of a payload, defaulting to the current version
+fn payload_version(payload: &Value) -> Result<u32> {
+    match payload.get("version") {
+        None | Some(Value::Null) => Ok(WEBHOOK_PAYLOAD_VERSION),
+        Some(version) => version
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| Error::Parse(format!("Invalid webhook payload version {}", version))),
+    }
+}
+
+/// Version 1 named the event type `type` and its data `payload`
+fn upgrade_v1(mut payload: Value) -> Result<Value> {
+    let fields = payload
+        .as_object_mut()
+        .ok_or_else(|| Error::Parse("Webhook payload is not an object".to_string()))?;
+    for (old, new) in [("type", "event_type"), ("payload", "data")] {
+        if let Some(value) = fields.remove(old) {
+            fields.insert(new.to_string(), value);
+        }
+    }
+    Ok(payload)
+}
+
+/// Typed escrow webhook events
+///
+/// ```rust
+/// use xrplsale::types::webhooks::{EscrowEvent, WebhookEvent};
+///
+/// # fn handle(event: &WebhookEvent) -> xrplsale::Result<()> {
+/// match EscrowEvent::from_webhook(event)? {
+///     Some(EscrowEvent::Released(escrow)) => {
+///         println!("{} XRP released in {:?}", escrow.amount_xrp, escrow.settle_tx_hash);
+///     }
+///     Some(EscrowEvent::ClawedBack(escrow)) => {
+///         println!("{} XRP returned to investors", escrow.amount_xrp);
+///     }
+///     None => {}
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EscrowEvent {
+    /// `escrow.released`: funds were released to the project
+    Released(Escrow),
+    /// `escrow.clawed_back`: funds were returned to investors
+    ClawedBack(Escrow),
+}
+
+impl EscrowEvent {
+    /// Event type of released escrows
+    pub const RELEASED: &'static str = "escrow.released";
+    /// Event type of clawed-back escrows
+    pub const CLAWED_BACK: &'static str = "escrow.clawed_back";
+
+    /// Read an escrow event from a webhook event
+    ///
+    /// Returns `Ok(None)` for other event types, and [`Error::Parse`] if the event data
+    /// is not an escrow.
+    pub fn from_webhook(event: &WebhookEvent) -> Result<Option<Self>> {
+        let variant = match event.event_type.as_str() {
+            Self::RELEASED => EscrowEvent::Released,
+            Self::CLAWED_BACK => EscrowEvent::ClawedBack,
+            _ => return Ok(None),
+        };
+        let escrow = serde_json::from_value(event.data.clone())
+            .map_err(|e| Error::Parse(format!("{} event: {}", event.event_type, e)))?;
+        Ok(Some(variant(escrow)))
+    }
+}
+
+/// Typed governance webhook events
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GovernanceEvent {
+    /// `proposal.created`: a proposal was put to token holders
+    ProposalCreated(Proposal),
+    /// `proposal.closed`: voting ended; the proposal's status holds the outcome
+    ProposalClosed(Proposal),
+    /// `vote.cast`: a token holder voted
+    VoteCast(Vote),
+}
+
+impl GovernanceEvent {
+    /// Event types to subscribe to in a [`WebhookRegistration`]
+    pub const EVENT_TYPES: &'static [&'static str] =
+        &["proposal.created", "proposal.closed", "vote.cast"];
+
+    /// Read a governance event from a webhook event
+    ///
+    /// Returns `Ok(None)` for other event types, and [`Error::Parse`] if the event data
+    /// does not match the event type.
+    pub fn from_webhook(event: &WebhookEvent) -> Result<Option<Self>> {
+        let parse =
+            |e: serde_json::Error| Error::Parse(format!("{} event: {}", event.event_type, e));
+        let data = event.data.clone();
+
+        let parsed = match event.event_type.as_str() {
+            "proposal.created" => {
+                GovernanceEvent::ProposalCreated(serde_json::from_value(data).map_err(parse)?)
+            }
+            "proposal.closed" => {
+                GovernanceEvent::ProposalClosed(serde_json::from_value(data).map_err(parse)?)
+            }
+            "vote.cast" => GovernanceEvent::VoteCast(serde_json::from_value(data).map_err(parse)?),
+            _ => return Ok(None),
+        };
+        Ok(Some(parsed))
+    }
+}
+
+/// Typed liquidity webhook events
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LiquidityEvent {
+    /// `liquidity.pool_created`: a plan executed and its AMM pool exists
+    PoolCreated(AmmPool),
+    /// `liquidity.plan_failed`: a plan could not be executed
+    PlanFailed(LiquidityPlan),
+}
+
+impl LiquidityEvent {
+    /// Event types to subscribe to in a [`WebhookRegistration`]
+    pub const EVENT_TYPES: &'static [&'static str] =
+        &["liquidity.pool_created", "liquidity.plan_failed"];
+
+    /// Read a liquidity event from a webhook event
+    ///
+    /// Returns `Ok(None)` for other event types, and [`Error::Parse`] if the event data
+    /// does not match the event type.
+    pub fn from_webhook(event: &WebhookEvent) -> Result<Option<Self>> {
+        let parse =
+            |e: serde_json::Error| Error::Parse(format!("{} event: {}", event.event_type, e));
+        let data = event.data.clone();
+
+        let parsed = match event.event_type.as_str() {
+            "liquidity.pool_created" => {
+                LiquidityEvent::PoolCreated(serde_json::from_value(data).map_err(parse)?)
+            }
+            "liquidity.plan_failed" => {
+                LiquidityEvent::PlanFailed(serde_json::from_value(data).map_err(parse)?)
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(parsed))
+    }
+}
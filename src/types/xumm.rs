@@ -0,0 +1,80 @@
+//! Xaman (formerly Xumm) sign-in types
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A pending Xaman sign-in request to show to the user
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct XummSignIn {
+    /// Payload UUID
+    pub uuid: String,
+    /// URL of a QR code image for scanning with the Xaman app
+    pub qr_png: String,
+    /// Deep link that opens the request in Xaman on mobile
+    pub deeplink: String,
+    /// When the request expires if it is not signed
+    #[serde(with = "crate::types::dates")]
+    pub expires_at: DateTime<Utc>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// State of a Xaman sign-in request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum XummSignInState {
+    /// Waiting for the user to open the request
+    Pending,
+    /// Opened in Xaman but not yet signed
+    Opened,
+    /// Signed by the user
+    Signed,
+    /// Rejected by the user
+    Rejected,
+    /// Expired before it was signed
+    Expired,
+}
+
+/// Current status of a Xaman sign-in request
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct XummSignInStatus {
+    /// Request state
+    pub state: XummSignInState,
+    /// XRPL account that signed, once signed
+    pub account: Option<String>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+/// Platform session obtained from a signed Xaman request
+#[derive(Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct XummSession {
+    /// Session token to send as a bearer token
+    pub token: String,
+    /// XRPL account the session belongs to
+    pub account: String,
+    /// When the session expires
+    #[serde(default, with = "crate::types::dates::option")]
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Fields returned by the API that this version of the SDK does not model
+    #[cfg(feature = "unknown-fields")]
+    #[serde(flatten)]
+    pub extra: crate::types::common::UnknownFields,
+}
+
+impl std::fmt::Debug for XummSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("XummSession")
+            .field("token", &crate::logging::REDACTED)
+            .field("account", &self.account)
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
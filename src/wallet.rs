@@ -0,0 +1,62 @@
+//! Signing with an investor's XRPL wallet
+//!
+//! Some actions, such as casting a governance vote, must be authorized by the wallet
+//! that holds the tokens rather than by the API key. The SDK does not manage private
+//! keys; implement [`WalletSigner`] on top of your wallet library, HSM, or remote
+//! signing service.
+//!
+//! ```rust
+//! use xrplsale::{wallet::WalletSigner, Result};
+//!
+//! struct MyWallet {
+//!     address: String,
+//!     public_key: String,
+//! }
+//!
+//! #[async_trait::async_trait]
+//! impl WalletSigner for MyWallet {
+//!     fn account(&self) -> String {
+//!         self.address.clone()
+//!     }
+//!
+//!     fn public_key(&self) -> String {
+//!         self.public_key.clone()
+//!     }
+//!
+//!     async fn sign(&self, message: &[u8]) -> Result<String> {
+//!         // Sign with the wallet's key and return the hex-encoded signature
+//! #       Ok(hex::encode(message))
+//!     }
+//! }
+//! ```
+
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+
+/// An XRPL wallet able to sign messages
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait WalletSigner: Send + Sync {
+    /// Classic address of the wallet, e.g. `rN7n7otQDd6FczFgLdSqtcsAUxDkw6fzRH`
+    fn account(&self) -> String;
+
+    /// Hex-encoded public key the signature verifies against
+    fn public_key(&self) -> String;
+
+    /// Sign a message, returning the hex-encoded signature
+    async fn sign(&self, message: &[u8]) -> Result<String>;
+
+    /// Sign a transaction as one of the signers of a multi-signed account, returning
+    /// the hex-encoded `TxnSignature`
+    ///
+    /// Encode `tx_json` for multi-signing by this wallet's account with your XRPL
+    /// library and sign the result. Wallets that don't implement this fail with
+    /// [`Error::Configuration`].
+    async fn multisign(&self, tx_json: &serde_json::Value) -> Result<String> {
+        let _ = tx_json;
+        Err(Error::Configuration(format!(
+            "Wallet {} does not support multi-signing",
+            self.account()
+        )))
+    }
+}
@@ -0,0 +1,173 @@
+//! Verifying webhooks signed with the platform's Ed25519 key
+//!
+//! With public-key signatures, receivers hold only the platform's public key, so no
+//! webhook secret lives on the receiving side. The signature header's version prefix
+//! names the scheme: `v2=` carries a hex-encoded Ed25519 signature of the raw body,
+//! anything else is an HMAC signature. While the platform moves a webhook over,
+//! [`Ed25519WebhookValidator::with_hmac`] keeps accepting HMAC deliveries:
+//!
+//! ```rust,no_run
+//! use xrplsale::{Ed25519WebhookValidator, WebhookSignatureValidator};
+//!
+//! # fn receive(payload: &str, signature: &str) -> xrplsale::Result<()> {
+//! let validator = Ed25519WebhookValidator::new("<hex public key>")?
+//!     .with_hmac(WebhookSignatureValidator::new("old-secret".to_string()));
+//!
+//! if validator.verify(payload, signature) {
+//!     // Process the webhook
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    error::{Error, Result},
+    webhook_rotation::{RotatingWebhookValidator, WebhookVerifier},
+};
+use ed25519_dalek::{Signature, VerifyingKey};
+
+/// Version prefix of Ed25519 signatures in the `X-XRPL-Sale-Signature` header
+pub const ED25519_SIGNATURE_PREFIX: &str = "v2=";
+
+/// Verifies webhook signatures against the platform's Ed25519 public keys
+#[derive(Debug, Clone)]
+pub struct Ed25519WebhookValidator {
+    keys: Vec<VerifyingKey>,
+    hmac: Option<RotatingWebhookValidator>,
+}
+
+impl Ed25519WebhookValidator {
+    /// Create a validator for the platform's public key
+    ///
+    /// # Arguments
+    ///
+    /// * `public_key` - Hex-encoded 32-byte Ed25519 public key
+    pub fn new(public_key: &str) -> Result<Self> {
+        Ok(Self {
+            keys: vec![parse_key(public_key)?],
+            hmac: None,
+        })
+    }
+
+    /// Also accept signatures made with an older public key while the key is rotated
+    pub fn with_previous_key(mut self, public_key: &str) -> Result<Self> {
+        self.keys.push(parse_key(public_key)?);
+        Ok(self)
+    }
+
+    /// Also accept HMAC signatures, checked by `validator`
+    ///
+    /// Without this, only `v2=` signatures are accepted.
+    pub fn with_hmac<V: Into<RotatingWebhookValidator>>(mut self, validator: V) -> Self {
+        self.hmac = Some(validator.into());
+        self
+    }
+
+    /// Verify a webhook signature with the scheme named by its version prefix
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - Raw request body
+    /// * `signature` - Value of the `X-XRPL-Sale-Signature` header
+    pub fn verify(&self, payload: &str, signature: &str) -> bool {
+        match signature.strip_prefix(ED25519_SIGNATURE_PREFIX) {
+            Some(encoded) => {
+                let Some(signature) = parse_signature(encoded) else {
+                    return false;
+                };
+                // Check every key, so the time taken doesn't reveal which one matched
+                self.keys.iter().fold(false, |valid, key| {
+                    key.verify_strict(payload.as_bytes(), &signature).is_ok() | valid
+                })
+            }
+            None => self
+                .hmac
+                .as_ref()
+                .is_some_and(|hmac| hmac.verify(payload, signature)),
+        }
+    }
+}
+
+impl WebhookVerifier for Ed25519WebhookValidator {
+    fn verify(&self, payload: &str, signature: &str) -> bool {
+        Ed25519WebhookValidator::verify(self, payload, signature)
+    }
+}
+
+/// A hex-encoded Ed25519 public key
+fn parse_key(public_key: &str) -> Result<VerifyingKey> {
+    let invalid = || Error::Configuration("Invalid Ed25519 webhook public key".to_string());
+    let bytes: [u8; 32] = hex::decode(public_key.trim())
+        .map_err(|_| invalid())?
+        .try_into()
+        .map_err(|_| invalid())?;
+    VerifyingKey::from_bytes(&bytes).map_err(|_| invalid())
+}
+
+/// A hex-encoded Ed25519 signature
+fn parse_signature(encoded: &str) -> Option<Signature> {
+    let bytes: [u8; 64] = hex::decode(encoded.trim()).ok()?.try_into().ok()?;
+    Some(Signature::from_bytes(&bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WebhookSignatureValidator;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    const PAYLOAD: &str = r#"{"id":"evt_1","type":"investment.created"}"#;
+
+    fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from_bytes(&[seed; 32])
+    }
+
+    fn public_key(key: &SigningKey) -> String {
+        hex::encode(key.verifying_key().as_bytes())
+    }
+
+    fn sign(key: &SigningKey, payload: &str) -> String {
+        format!(
+            "{}{}",
+            ED25519_SIGNATURE_PREFIX,
+            hex::encode(key.sign(payload.as_bytes()).to_bytes())
+        )
+    }
+
+    #[test]
+    fn accepts_signatures_from_current_and_previous_keys() {
+        let current = signing_key(1);
+        let previous = signing_key(2);
+        let validator = Ed25519WebhookValidator::new(&public_key(&current))
+            .unwrap()
+            .with_previous_key(&public_key(&previous))
+            .unwrap();
+
+        assert!(validator.verify(PAYLOAD, &sign(&current, PAYLOAD)));
+        assert!(validator.verify(PAYLOAD, &sign(&previous, PAYLOAD)));
+        assert!(!validator.verify(PAYLOAD, &sign(&signing_key(3), PAYLOAD)));
+        assert!(!validator.verify("{}", &sign(&current, PAYLOAD)));
+        assert!(!validator.verify(PAYLOAD, "v2=not-hex"));
+    }
+
+    #[test]
+    fn hmac_signatures_need_an_hmac_validator() {
+        let key = signing_key(1);
+        let validator = Ed25519WebhookValidator::new(&public_key(&key)).unwrap();
+        assert!(!validator.verify(PAYLOAD, "v1=abcdef"));
+
+        let hmac = WebhookSignatureValidator::new("secret".to_string());
+        let expected = hmac.verify(PAYLOAD, "v1=abcdef");
+        let validator = validator.with_hmac(hmac);
+        assert_eq!(validator.verify(PAYLOAD, "v1=abcdef"), expected);
+        assert!(validator.verify(PAYLOAD, &sign(&key, PAYLOAD)));
+    }
+
+    #[test]
+    fn rejects_malformed_public_keys() {
+        assert!(matches!(
+            Ed25519WebhookValidator::new("abcd"),
+            Err(Error::Configuration(_))
+        ));
+    }
+}
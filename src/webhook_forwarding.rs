@@ -0,0 +1,288 @@
+//! Re-signing verified webhooks for internal fan-out
+//!
+//! An edge service verifies incoming webhooks with the platform secret and forwards them
+//! to internal services with [`WebhookForwarder`]. The forwarded request keeps the
+//! original signature and timestamp headers and adds a signature made with an internal
+//! secret, covering the payload and the verification metadata. Downstream services check
+//! it with [`ForwardedWebhookVerifier`] and never need the platform secret.
+//!
+//! ```rust
+//! use xrplsale::{ForwardedWebhookVerifier, RotatingWebhookValidator, WebhookForwarder};
+//!
+//! # fn forward(payload: &str, signature: &str) {
+//! // Edge service
+//! let forwarder = WebhookForwarder::new(
+//!     RotatingWebhookValidator::new("platform-secret").with_previous_secret("old-secret"),
+//!     "internal-secret",
+//! );
+//!
+//! if let Some(forwarded) = forwarder.forward(payload, signature, None) {
+//!     // POST `forwarded.payload` with `forwarded.headers` to internal consumers
+//! }
+//!
+//! // Internal consumer
+//! # let headers = std::collections::HashMap::<String, String>::new();
+//! let verifier = ForwardedWebhookVerifier::new("internal-secret");
+//! let metadata = verifier.verify(payload, |name| headers.get(name).map(String::as_str));
+//! # }
+//! ```
+
+use crate::{
+    runtime::{self, Clock},
+    webhook_rotation::RotatingWebhookValidator,
+};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::{sync::Arc, time::Duration};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Header carrying the platform's original signature
+pub const SIGNATURE_HEADER: &str = "X-XRPL-Sale-Signature";
+/// Header carrying the platform's original delivery timestamp
+pub const TIMESTAMP_HEADER: &str = "X-XRPL-Sale-Timestamp";
+/// Header carrying the internal signature added by the forwarder
+pub const FORWARDED_SIGNATURE_HEADER: &str = "X-XRPL-Sale-Forwarded-Signature";
+/// Header carrying the time the webhook was forwarded (Unix seconds)
+pub const FORWARDED_AT_HEADER: &str = "X-XRPL-Sale-Forwarded-At";
+/// Header recording that the edge verified the original signature
+pub const VERIFIED_HEADER: &str = "X-XRPL-Sale-Verified";
+/// Header identifying the forwarding service
+pub const FORWARDED_BY_HEADER: &str = "X-XRPL-Sale-Forwarded-By";
+
+/// A verified webhook ready to be sent to internal consumers
+#[derive(Debug, Clone)]
+pub struct ForwardedWebhook {
+    /// The original, unmodified payload
+    pub payload: String,
+    /// Headers to attach to the forwarded request
+    pub headers: Vec<(&'static str, String)>,
+}
+
+/// Verification details carried by a forwarded webhook
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForwardMetadata {
+    /// The platform's original signature
+    pub original_signature: String,
+    /// The platform's original timestamp, if it sent one
+    pub original_timestamp: Option<String>,
+    /// When the edge forwarded the webhook
+    pub forwarded_at: DateTime<Utc>,
+    /// Name of the forwarding service, if set
+    pub forwarded_by: Option<String>,
+}
+
+/// Verifies platform webhooks and re-signs them for internal delivery
+#[derive(Debug, Clone)]
+pub struct WebhookForwarder {
+    validator: RotatingWebhookValidator,
+    internal_secret: String,
+    name: Option<String>,
+    clock: Arc<dyn Clock>,
+}
+
+impl WebhookForwarder {
+    /// Create a forwarder
+    ///
+    /// # Arguments
+    ///
+    /// * `validator` - Validator for the platform's webhook secret: a
+    ///   [`WebhookSignatureValidator`](crate::WebhookSignatureValidator), or a
+    ///   [`RotatingWebhookValidator`] while that secret is rotated
+    /// * `internal_secret` - Secret shared with internal consumers
+    pub fn new<V, S>(validator: V, internal_secret: S) -> Self
+    where
+        V: Into<RotatingWebhookValidator>,
+        S: Into<String>,
+    {
+        Self {
+            validator: validator.into(),
+            internal_secret: internal_secret.into(),
+            name: None,
+            clock: runtime::system_clock(),
+        }
+    }
+
+    /// Identify this forwarder in the `X-XRPL-Sale-Forwarded-By` header
+    pub fn with_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the clock that stamps the forwarding time
+    pub fn with_clock<C: Clock>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Verify a platform webhook and prepare it for forwarding
+    ///
+    /// Returns `None` if the platform signature does not match, in which case the
+    /// webhook must not be forwarded.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - Raw request body
+    /// * `signature` - Value of the `X-XRPL-Sale-Signature` header
+    /// * `timestamp` - Value of the `X-XRPL-Sale-Timestamp` header, if present
+    pub fn forward(
+        &self,
+        payload: &str,
+        signature: &str,
+        timestamp: Option<&str>,
+    ) -> Option<ForwardedWebhook> {
+        if !self.validator.verify(payload, signature) {
+            return None;
+        }
+
+        let forwarded_at = self.clock.utc_now().timestamp().to_string();
+        let forwarded_signature = sign(
+            &self.internal_secret,
+            &signing_input(
+                payload,
+                signature,
+                timestamp,
+                &forwarded_at,
+                self.name.as_deref(),
+            ),
+        );
+
+        let mut headers = vec![
+            (SIGNATURE_HEADER, signature.to_string()),
+            (FORWARDED_SIGNATURE_HEADER, forwarded_signature),
+            (FORWARDED_AT_HEADER, forwarded_at),
+            (VERIFIED_HEADER, "true".to_string()),
+        ];
+        if let Some(timestamp) = timestamp {
+            headers.push((TIMESTAMP_HEADER, timestamp.to_string()));
+        }
+        if let Some(name) = &self.name {
+            headers.push((FORWARDED_BY_HEADER, name.clone()));
+        }
+
+        Some(ForwardedWebhook {
+            payload: payload.to_string(),
+            headers,
+        })
+    }
+}
+
+/// Verifies webhooks forwarded by a [`WebhookForwarder`]
+#[derive(Debug, Clone)]
+pub struct ForwardedWebhookVerifier {
+    internal_secrets: Vec<String>,
+    tolerance: Duration,
+    clock: Arc<dyn Clock>,
+}
+
+impl ForwardedWebhookVerifier {
+    /// Create a verifier for the given internal secret
+    ///
+    /// Forwarded webhooks older than five minutes are rejected by default.
+    pub fn new<S: Into<String>>(internal_secret: S) -> Self {
+        Self {
+            internal_secrets: vec![internal_secret.into()],
+            tolerance: Duration::from_secs(300),
+            clock: runtime::system_clock(),
+        }
+    }
+
+    /// Also accept webhooks signed with an older internal secret
+    ///
+    /// While the internal secret is rotated, forwarders may still sign with the
+    /// previous one. Remove it once every forwarder uses the new secret.
+    pub fn with_previous_secret<S: Into<String>>(mut self, secret: S) -> Self {
+        self.internal_secrets.push(secret.into());
+        self
+    }
+
+    /// Set how old a forwarded webhook may be before it is rejected
+    pub fn with_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the clock that the age of forwarded webhooks is measured against
+    pub fn with_clock<C: Clock>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Verify a forwarded webhook
+    ///
+    /// `header` looks up a request header by name. Returns the forwarding metadata if
+    /// the internal signature is valid, the edge marked the webhook as verified, and
+    /// it was forwarded within the tolerance window.
+    pub fn verify<'a, F>(&self, payload: &str, header: F) -> Option<ForwardMetadata>
+    where
+        F: Fn(&str) -> Option<&'a str>,
+    {
+        if header(VERIFIED_HEADER)? != "true" {
+            return None;
+        }
+
+        let signature = header(FORWARDED_SIGNATURE_HEADER)?;
+        let original_signature = header(SIGNATURE_HEADER)?;
+        let original_timestamp = header(TIMESTAMP_HEADER);
+        let forwarded_at_raw = header(FORWARDED_AT_HEADER)?;
+        let forwarded_by = header(FORWARDED_BY_HEADER);
+
+        let expected = hex::decode(signature).ok()?;
+        let input = signing_input(
+            payload,
+            original_signature,
+            original_timestamp,
+            forwarded_at_raw,
+            forwarded_by,
+        );
+        // `verify_slice` compares in constant time
+        let valid = self.internal_secrets.iter().any(|secret| {
+            HmacSha256::new_from_slice(secret.as_bytes()).is_ok_and(|mut mac| {
+                mac.update(input.as_bytes());
+                mac.verify_slice(&expected).is_ok()
+            })
+        });
+        if !valid {
+            return None;
+        }
+
+        let forwarded_at = DateTime::from_timestamp(forwarded_at_raw.parse().ok()?, 0)?;
+        let age = self.clock.utc_now().signed_duration_since(forwarded_at);
+        if age.num_seconds().unsigned_abs() > self.tolerance.as_secs() {
+            return None;
+        }
+
+        Some(ForwardMetadata {
+            original_signature: original_signature.to_string(),
+            original_timestamp: original_timestamp.map(|t| t.to_string()),
+            forwarded_at,
+            forwarded_by: forwarded_by.map(|name| name.to_string()),
+        })
+    }
+}
+
+/// Canonical string covered by the internal signature
+fn signing_input(
+    payload: &str,
+    original_signature: &str,
+    original_timestamp: Option<&str>,
+    forwarded_at: &str,
+    forwarded_by: Option<&str>,
+) -> String {
+    format!(
+        "{}.{}.{}.{}.{}",
+        forwarded_at,
+        forwarded_by.unwrap_or(""),
+        original_timestamp.unwrap_or(""),
+        original_signature,
+        payload
+    )
+}
+
+fn sign(secret: &str, input: &str) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(input.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
@@ -0,0 +1,87 @@
+//! Verifying webhooks while their secret is rotated
+//!
+//! After a webhook secret is rotated, deliveries already in flight or retried are
+//! still signed with the previous secret. [`RotatingWebhookValidator`] accepts a
+//! signature made with any of several secrets, so both are honoured during the
+//! switch-over:
+//!
+//! ```rust
+//! use xrplsale::RotatingWebhookValidator;
+//!
+//! # fn receive(payload: &str, signature: &str) {
+//! let validator = RotatingWebhookValidator::new("new-secret").with_previous_secret("old-secret");
+//!
+//! if validator.verify(payload, signature) {
+//!     // Process the webhook
+//! }
+//! # }
+//! ```
+//!
+//! Remove the previous secret once the rotation window has passed.
+
+use crate::webhook::WebhookSignatureValidator;
+
+/// Verifies webhook signatures against a current secret and any previous ones
+#[derive(Debug, Clone)]
+pub struct RotatingWebhookValidator {
+    validators: Vec<WebhookSignatureValidator>,
+}
+
+impl RotatingWebhookValidator {
+    /// Create a validator for the current secret
+    pub fn new<S: Into<String>>(secret: S) -> Self {
+        WebhookSignatureValidator::new(secret.into()).into()
+    }
+
+    /// Also accept signatures made with an older secret
+    pub fn with_previous_secret<S: Into<String>>(mut self, secret: S) -> Self {
+        self.validators
+            .push(WebhookSignatureValidator::new(secret.into()));
+        self
+    }
+
+    /// Verify a webhook signature, accepting a match with any of the secrets
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - Raw request body
+    /// * `signature` - Value of the `X-XRPL-Sale-Signature` header
+    pub fn verify(&self, payload: &str, signature: &str) -> bool {
+        // Check every secret, so the time taken doesn't reveal which one matched
+        self.validators.iter().fold(false, |valid, validator| {
+            validator.verify(payload, signature) | valid
+        })
+    }
+}
+
+/// Checks webhook signatures, for functions that accept either validator
+pub trait WebhookVerifier {
+    /// Whether `signature` is valid for `payload`
+    fn verify(&self, payload: &str, signature: &str) -> bool;
+}
+
+impl WebhookVerifier for WebhookSignatureValidator {
+    fn verify(&self, payload: &str, signature: &str) -> bool {
+        WebhookSignatureValidator::verify(self, payload, signature)
+    }
+}
+
+impl WebhookVerifier for RotatingWebhookValidator {
+    fn verify(&self, payload: &str, signature: &str) -> bool {
+        RotatingWebhookValidator::verify(self, payload, signature)
+    }
+}
+
+impl From<WebhookSignatureValidator> for RotatingWebhookValidator {
+    fn from(validator: WebhookSignatureValidator) -> Self {
+        Self {
+            validators: vec![validator],
+        }
+    }
+}
+
+impl From<Vec<WebhookSignatureValidator>> for RotatingWebhookValidator {
+    fn from(validators: Vec<WebhookSignatureValidator>) -> Self {
+        Self { validators }
+    }
+}
@@ -0,0 +1,355 @@
+//! Durable, queryable history of received webhook events
+//!
+//! A [`WebhookStore`] keeps every verified event exactly once, keyed by its delivery
+//! ID, so redelivered webhooks are recognised and skipped. Stored events get an
+//! increasing sequence number, which consumers can use as a cursor to replay the
+//! history or build projections from it.
+//!
+//! [`store_verified`] checks the signature, parses the payload, and stores the event in
+//! one step, returning the event only the first time it is delivered:
+//!
+//! ```rust
+//! use xrplsale::{
+//!     webhook_store::{store_verified, EventQuery, InMemoryWebhookStore, WebhookStore},
+//!     WebhookSignatureValidator,
+//! };
+//!
+//! # async fn receive(payload: &str, signature: &str) -> xrplsale::Result<()> {
+//! let store = InMemoryWebhookStore::new();
+//! let validator = WebhookSignatureValidator::new("webhook-secret".to_string());
+//!
+//! if let Some(event) = store_verified(&store, &validator, payload, signature).await? {
+//!     println!("New event {} ({})", event.id, event.event_type);
+//! }
+//!
+//! let investments = store
+//!     .list(&EventQuery::new().event_type("investment.*").project("proj_abc123"))
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Durable implementations are available behind features:
+//!
+//! | Feature | Store |
+//! |---------|-------|
+//! | `webhook-store-postgres` | [`PostgresWebhookStore`] |
+//! | `webhook-store-sqlite` | [`SqliteWebhookStore`] |
+//!
+//! Both create their table with `migrate` and store the whole event as JSON next to
+//! the columns used for filtering.
+
+#[cfg(feature = "webhook-store-postgres")]
+mod postgres;
+#[cfg(feature = "webhook-store-sqlite")]
+mod sqlite;
+
+#[cfg(feature = "webhook-store-postgres")]
+pub use postgres::PostgresWebhookStore;
+#[cfg(feature = "webhook-store-sqlite")]
+pub use sqlite::SqliteWebhookStore;
+
+use crate::{
+    error::{Error, Result},
+    runtime::{self, Clock},
+    webhook::WebhookEvent,
+    webhook_rotation::WebhookVerifier,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// Table used by the database stores unless configured otherwise
+pub const DEFAULT_TABLE: &str = "webhook_events";
+
+/// Default and maximum number of events returned by [`WebhookStore::list`]
+const MAX_LIMIT: u32 = 1000;
+
+/// An event kept in a [`WebhookStore`]
+#[derive(Debug, Clone)]
+pub struct StoredEvent {
+    /// Position in the store's history, increasing in the order events were stored
+    pub sequence: i64,
+    /// When the event was stored
+    pub received_at: DateTime<Utc>,
+    /// The event
+    pub event: WebhookEvent,
+}
+
+/// Storage for verified webhook events
+///
+/// Implementations store each delivery ID at most once, also when the same event is
+/// inserted concurrently.
+///
+/// Sequence numbers are assigned in insertion order, but with concurrent writers to the
+/// PostgreSQL store an insert can commit after one that started later and got a higher
+/// number. A reader resuming with
+/// [`EventQuery::after`] may then already be past it. Resume from a cursor some way
+/// behind the last event seen and skip delivery IDs already processed, or insert from
+/// a single writer. The in-memory and SQLite stores assign numbers in commit order.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait WebhookStore: Send + Sync + 'static {
+    /// Store an event
+    ///
+    /// Returns `false` without changing anything if an event with the same ID is
+    /// already stored.
+    async fn insert(&self, event: &WebhookEvent) -> Result<bool>;
+
+    /// Look up an event by its delivery ID
+    async fn get(&self, id: &str) -> Result<Option<StoredEvent>>;
+
+    /// Events matching a query, oldest first
+    async fn list(&self, query: &EventQuery) -> Result<Vec<StoredEvent>>;
+}
+
+/// Filters for [`WebhookStore::list`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventQuery {
+    event_type: Option<String>,
+    project_id: Option<String>,
+    after: Option<i64>,
+    since: Option<DateTime<Utc>>,
+    limit: u32,
+}
+
+impl Default for EventQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventQuery {
+    /// Match all events, returning at most 1000
+    pub fn new() -> Self {
+        Self {
+            event_type: None,
+            project_id: None,
+            after: None,
+            since: None,
+            limit: MAX_LIMIT,
+        }
+    }
+
+    /// Only events of this type, e.g. `investment.created`
+    ///
+    /// A trailing `*` matches by prefix, so `investment.*` matches every investment
+    /// event.
+    pub fn event_type<S: Into<String>>(mut self, event_type: S) -> Self {
+        self.event_type = Some(event_type.into());
+        self
+    }
+
+    /// Only events whose data has this `project_id`
+    pub fn project<S: Into<String>>(mut self, project_id: S) -> Self {
+        self.project_id = Some(project_id.into());
+        self
+    }
+
+    /// Only events stored after the event with this sequence number
+    ///
+    /// See [`WebhookStore`] for why a cursor may need to overlap with concurrent
+    /// PostgreSQL writers.
+    pub fn after(mut self, sequence: i64) -> Self {
+        self.after = Some(sequence);
+        self
+    }
+
+    /// Only events stored at or after this time
+    pub fn since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Return at most this many events (capped at 1000)
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = limit.min(MAX_LIMIT);
+        self
+    }
+
+    /// Whether a stored event passes the filters
+    pub fn matches(&self, stored: &StoredEvent) -> bool {
+        let event_type = match self.event_type_prefix() {
+            Some(prefix) => stored.event.event_type.starts_with(prefix),
+            None => self
+                .event_type
+                .as_ref()
+                .map_or(true, |event_type| stored.event.event_type == *event_type),
+        };
+        let project = self.project_id.as_ref().map_or(true, |project_id| {
+            stored
+                .event
+                .data
+                .get("project_id")
+                .and_then(|id| id.as_str())
+                == Some(project_id)
+        });
+
+        event_type
+            && project
+            && self.after.map_or(true, |after| stored.sequence > after)
+            && self.since.map_or(true, |since| stored.received_at >= since)
+    }
+
+    /// The prefix to match when the event type ends in `*`
+    pub(crate) fn event_type_prefix(&self) -> Option<&str> {
+        self.event_type.as_deref()?.strip_suffix('*')
+    }
+}
+
+/// Verify a webhook, parse it, and store it
+///
+/// `validator` is a [`WebhookSignatureValidator`](crate::WebhookSignatureValidator), or
+/// a [`RotatingWebhookValidator`](crate::RotatingWebhookValidator) while the secret is
+/// rotated. Returns the event if it was stored, or `None` if it had already been
+/// delivered. Fails with [`Error::Unauthorized`] if the signature does not match.
+pub async fn store_verified<S, V>(
+    store: &S,
+    validator: &V,
+    payload: &str,
+    signature: &str,
+) -> Result<Option<WebhookEvent>>
+where
+    S: WebhookStore + ?Sized,
+    V: WebhookVerifier + ?Sized,
+{
+    if !validator.verify(payload, signature) {
+        return Err(Error::Unauthorized("Invalid webhook signature".to_string()));
+    }
+    let event: WebhookEvent = serde_json::from_str(payload)
+        .map_err(|e| Error::Parse(format!("Invalid webhook payload: {}", e)))?;
+
+    Ok(store.insert(&event).await?.then_some(event))
+}
+
+/// A [`WebhookStore`] kept in memory, for tests and short-lived processes
+#[derive(Debug)]
+pub struct InMemoryWebhookStore {
+    state: Mutex<MemoryState>,
+    clock: Arc<dyn Clock>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    events: Vec<StoredEvent>,
+    positions: HashMap<String, usize>,
+}
+
+impl InMemoryWebhookStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::default(),
+            clock: runtime::system_clock(),
+        }
+    }
+
+    /// Take `received_at` timestamps from `clock` instead of the system clock
+    pub fn with_clock<C: Clock>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Number of stored events
+    pub fn len(&self) -> usize {
+        self.lock().events.len()
+    }
+
+    /// Whether no events are stored
+    pub fn is_empty(&self) -> bool {
+        self.lock().events.is_empty()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MemoryState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for InMemoryWebhookStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl WebhookStore for InMemoryWebhookStore {
+    async fn insert(&self, event: &WebhookEvent) -> Result<bool> {
+        let mut state = self.lock();
+        if state.positions.contains_key(&event.id) {
+            return Ok(false);
+        }
+
+        let position = state.events.len();
+        state.positions.insert(event.id.clone(), position);
+        state.events.push(StoredEvent {
+            sequence: position as i64 + 1,
+            received_at: self.clock.utc_now(),
+            event: event.clone(),
+        });
+        Ok(true)
+    }
+
+    async fn get(&self, id: &str) -> Result<Option<StoredEvent>> {
+        let state = self.lock();
+        Ok(state
+            .positions
+            .get(id)
+            .map(|&position| state.events[position].clone()))
+    }
+
+    async fn list(&self, query: &EventQuery) -> Result<Vec<StoredEvent>> {
+        Ok(self
+            .lock()
+            .events
+            .iter()
+            .filter(|stored| query.matches(stored))
+            .take(query.limit as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+/// Check that a table name is a plain SQL identifier, since it is spliced into queries
+#[cfg(any(feature = "webhook-store-postgres", feature = "webhook-store-sqlite"))]
+fn validate_table(table: &str) -> Result<()> {
+    let mut chars = table.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::Configuration(format!(
+            "Invalid webhook store table name: {}",
+            table
+        )))
+    }
+}
+
+/// Build a stored event from the columns shared by the database stores
+#[cfg(any(feature = "webhook-store-postgres", feature = "webhook-store-sqlite"))]
+fn stored_event(
+    sequence: i64,
+    received_at: DateTime<Utc>,
+    payload: serde_json::Value,
+) -> Result<StoredEvent> {
+    let event = serde_json::from_value(payload)
+        .map_err(|e| Error::Parse(format!("Invalid stored webhook event: {}", e)))?;
+    Ok(StoredEvent {
+        sequence,
+        received_at,
+        event,
+    })
+}
+
+#[cfg(any(feature = "webhook-store-postgres", feature = "webhook-store-sqlite"))]
+fn database_error(error: sqlx::Error) -> Error {
+    Error::Storage(format!("Webhook store error: {}", error))
+}
@@ -0,0 +1,173 @@
+//! SQLite webhook store
+
+use super::{
+    database_error, stored_event, validate_table, EventQuery, StoredEvent, WebhookStore,
+    DEFAULT_TABLE,
+};
+use crate::{
+    error::Result,
+    runtime::{self, Clock},
+    webhook::WebhookEvent,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{types::Json, QueryBuilder, Row, Sqlite, SqlitePool};
+use std::sync::Arc;
+
+/// A [`WebhookStore`] backed by an SQLite table
+///
+/// Events are stored as JSON text, with a unique index on the delivery ID:
+///
+/// ```rust,no_run
+/// use xrplsale::webhook_store::SqliteWebhookStore;
+///
+/// # async fn open() -> Result<(), Box<dyn std::error::Error>> {
+/// let pool = sqlx::SqlitePool::connect("sqlite://webhooks.db?mode=rwc").await?;
+/// let store = SqliteWebhookStore::new(pool);
+/// store.migrate().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SqliteWebhookStore {
+    pool: SqlitePool,
+    table: String,
+    clock: Arc<dyn Clock>,
+}
+
+impl SqliteWebhookStore {
+    /// Store events in the `webhook_events` table
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            table: DEFAULT_TABLE.to_string(),
+            clock: runtime::system_clock(),
+        }
+    }
+
+    /// Store events in another table
+    ///
+    /// The name must be a plain identifier (letters, digits, and underscores).
+    pub fn with_table<S: Into<String>>(pool: SqlitePool, table: S) -> Result<Self> {
+        let table = table.into();
+        validate_table(&table)?;
+        Ok(Self {
+            pool,
+            table,
+            clock: runtime::system_clock(),
+        })
+    }
+
+    /// Take `received_at` timestamps from `clock` instead of the system clock
+    pub fn with_clock<C: Clock>(mut self, clock: C) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Create the table and its indexes if they do not exist
+    pub async fn migrate(&self) -> Result<()> {
+        let statements = [
+            format!(
+                "CREATE TABLE IF NOT EXISTS {table} (
+                    sequence INTEGER PRIMARY KEY AUTOINCREMENT,
+                    delivery_id TEXT NOT NULL UNIQUE,
+                    event_type TEXT NOT NULL,
+                    payload TEXT NOT NULL,
+                    received_at TEXT NOT NULL
+                )",
+                table = self.table
+            ),
+            format!(
+                "CREATE INDEX IF NOT EXISTS {table}_event_type_idx ON {table} (event_type, sequence)",
+                table = self.table
+            ),
+        ];
+        for statement in &statements {
+            sqlx::query(statement)
+                .execute(&self.pool)
+                .await
+                .map_err(database_error)?;
+        }
+        Ok(())
+    }
+
+    /// The underlying connection pool
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+}
+
+#[async_trait]
+impl WebhookStore for SqliteWebhookStore {
+    async fn insert(&self, event: &WebhookEvent) -> Result<bool> {
+        let sql = format!(
+            "INSERT INTO {} (delivery_id, event_type, payload, received_at) VALUES (?, ?, ?, ?)
+             ON CONFLICT (delivery_id) DO NOTHING",
+            self.table
+        );
+        let result = sqlx::query(&sql)
+            .bind(&event.id)
+            .bind(&event.event_type)
+            .bind(Json(event))
+            .bind(self.clock.utc_now())
+            .execute(&self.pool)
+            .await
+            .map_err(database_error)?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn get(&self, id: &str) -> Result<Option<StoredEvent>> {
+        let sql = format!(
+            "SELECT sequence, received_at, payload FROM {} WHERE delivery_id = ?",
+            self.table
+        );
+        let row = sqlx::query(&sql)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(database_error)?;
+        row.map(|row| from_row(&row)).transpose()
+    }
+
+    async fn list(&self, query: &EventQuery) -> Result<Vec<StoredEvent>> {
+        let mut sql = QueryBuilder::<Sqlite>::new(format!(
+            "SELECT sequence, received_at, payload FROM {} WHERE 1",
+            self.table
+        ));
+        if let Some(prefix) = query.event_type_prefix() {
+            sql.push(" AND substr(event_type, 1, ")
+                .push_bind(prefix.chars().count() as i64)
+                .push(") = ")
+                .push_bind(prefix);
+        } else if let Some(event_type) = &query.event_type {
+            sql.push(" AND event_type = ").push_bind(event_type);
+        }
+        if let Some(project_id) = &query.project_id {
+            sql.push(" AND json_extract(payload, '$.data.project_id') = ")
+                .push_bind(project_id);
+        }
+        if let Some(after) = query.after {
+            sql.push(" AND sequence > ").push_bind(after);
+        }
+        if let Some(since) = query.since {
+            sql.push(" AND received_at >= ").push_bind(since);
+        }
+        sql.push(" ORDER BY sequence LIMIT ")
+            .push_bind(i64::from(query.limit));
+
+        let rows = sql
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?;
+        rows.iter().map(from_row).collect()
+    }
+}
+
+fn from_row(row: &sqlx::sqlite::SqliteRow) -> Result<StoredEvent> {
+    let sequence: i64 = row.try_get("sequence").map_err(database_error)?;
+    let received_at: DateTime<Utc> = row.try_get("received_at").map_err(database_error)?;
+    let Json(payload): Json<Value> = row.try_get("payload").map_err(database_error)?;
+    stored_event(sequence, received_at, payload)
+}
@@ -0,0 +1,171 @@
+//! Following webhook events as they are delivered
+//!
+//! [`WebhookTail`] streams the events the platform delivers to an account's webhook
+//! endpoints, so an integration can be debugged without redeploying its receiver:
+//!
+//! ```rust,no_run
+//! use futures::StreamExt;
+//! use xrplsale::{webhook_tail::WebhookTail, Client};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let client = Client::builder().api_key("test").build()?;
+//! let mut events = Box::pin(
+//!     WebhookTail::new(client)
+//!         .event("investment.created")
+//!         .since(chrono::Utc::now() - chrono::Duration::hours(1))
+//!         .stream(),
+//! );
+//!
+//! while let Some(event) = events.next().await {
+//!     let event = event?;
+//!     println!("{} {}", event.event_type, event.data);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Events are fetched by long-polling `/webhooks/events`: each request waits up to
+//! [`wait`](WebhookTail::wait) for new events and the next one resumes after the last
+//! event received, so nothing is missed between polls.
+
+use crate::{
+    client::Client,
+    error::Result,
+    ids::ProjectId,
+    webhook::WebhookEvent,
+};
+use chrono::{DateTime, Utc};
+use futures::{stream, Stream, StreamExt};
+use serde::Serialize;
+use std::time::Duration;
+
+/// Default time each poll waits for new events
+const DEFAULT_WAIT: Duration = Duration::from_secs(25);
+
+/// Extra time allowed for a poll's response on top of the wait
+const WAIT_MARGIN: Duration = Duration::from_secs(10);
+
+/// Pause before polling again when the API answered early without events
+const MIN_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Builder for a stream of delivered webhook events
+#[derive(Debug, Clone)]
+pub struct WebhookTail {
+    client: Client,
+    event_types: Vec<String>,
+    project_id: Option<ProjectId>,
+    since: Option<DateTime<Utc>>,
+    after: Option<String>,
+    wait: Duration,
+}
+
+impl WebhookTail {
+    /// Follow events delivered from now on
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            event_types: Vec::new(),
+            project_id: None,
+            since: None,
+            after: None,
+            wait: DEFAULT_WAIT,
+        }
+    }
+
+    /// Only stream events of this type, e.g. `investment.created`
+    ///
+    /// Call repeatedly to stream several types.
+    pub fn event<S: Into<String>>(mut self, event_type: S) -> Self {
+        self.event_types.push(event_type.into());
+        self
+    }
+
+    /// Only stream events for one project
+    pub fn project(mut self, project_id: impl Into<ProjectId>) -> Self {
+        self.project_id = Some(project_id.into());
+        self
+    }
+
+    /// Start with events delivered since `since`
+    pub fn since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Resume after the event with this ID, e.g. the last one seen before a restart
+    pub fn after<S: Into<String>>(mut self, event_id: S) -> Self {
+        self.after = Some(event_id.into());
+        self
+    }
+
+    /// Set how long each poll waits for new events
+    pub fn wait(mut self, wait: Duration) -> Self {
+        self.wait = wait;
+        self
+    }
+
+    /// Stream events until an error occurs
+    ///
+    /// The stream yields the error and ends; start a new tail with
+    /// [`after`](Self::after) to resume. Pin the stream (e.g. with `Box::pin`) before
+    /// polling it.
+    pub fn stream(self) -> impl Stream<Item = Result<WebhookEvent>> {
+        let client = self.client.with_timeout(self.wait + WAIT_MARGIN);
+
+        stream::unfold(Some(self), move |tail| {
+            let client = client.clone();
+            async move {
+                let mut tail = tail?;
+                let started = client.clock().now();
+
+                match client
+                    .get_with_query::<Vec<WebhookEvent>, _>("/webhooks/events", &tail.query())
+                    .await
+                {
+                    Ok(events) => {
+                        if let Some(last) = events.last() {
+                            tail.after = Some(last.id.to_string());
+                            tail.since = None;
+                        } else if client.clock().now() < started + MIN_POLL_INTERVAL {
+                            client.clock().sleep(MIN_POLL_INTERVAL).await;
+                        }
+                        Some((
+                            stream::iter(events.into_iter().map(Ok)).left_stream(),
+                            Some(tail),
+                        ))
+                    }
+                    Err(e) => Some((stream::once(async { Err(e) }).right_stream(), None)),
+                }
+            }
+        })
+        .flatten()
+    }
+
+    fn query(&self) -> TailQuery<'_> {
+        let event_types = (!self.event_types.is_empty()).then(|| self.event_types.join(","));
+        TailQuery {
+            wait: self.wait.as_secs(),
+            event_types,
+            project_id: self.project_id.as_ref(),
+            after: self.after.as_deref(),
+            // A cursor supersedes the start time
+            since: if self.after.is_some() {
+                None
+            } else {
+                self.since
+            },
+        }
+    }
+}
+
+/// Query parameters of a poll
+#[derive(Serialize)]
+struct TailQuery<'a> {
+    wait: u64,
+    /// Comma-separated, as the endpoint expects
+    event_types: Option<String>,
+    project_id: Option<&'a ProjectId>,
+    after: Option<&'a str>,
+    since: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,629 @@
+//! End-to-end project launches
+//!
+//! [`LaunchOrchestrator`] takes a [`ProjectSpec`] from validation to a live sale:
+//!
+//! 1. validate the project definition
+//! 2. create the project
+//! 3. attach documents
+//! 4. register webhooks
+//! 5. create alerts
+//! 6. run the platform's launch checklist
+//! 7. launch the project
+//!
+//! Progress is tracked in a serializable [`LaunchState`]. If a step fails, persist
+//! [`LaunchOrchestrator::state`] and pass it to [`LaunchOrchestrator::resume`] later;
+//! completed steps (and individual documents, webhooks, and alerts) are not repeated.
+//! The project is created with an idempotency key saved in the state, so retrying the
+//! creation after a lost response doesn't create a second project.
+//!
+//! ```rust,no_run
+//! use xrplsale::{
+//!     types::projects::{CreateProjectRequest, ProjectDocument},
+//!     workflows::{LaunchOrchestrator, ProjectSpec},
+//!     Client,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() -> xrplsale::Result<()> {
+//! # let client = Client::builder().api_key("test").build()?;
+//! # let project = CreateProjectRequest::default();
+//! let spec = ProjectSpec::new(project).document(ProjectDocument {
+//!     name: "Whitepaper".to_string(),
+//!     document_type: "whitepaper".to_string(),
+//!     url: "https://example.com/whitepaper.pdf".to_string(),
+//! });
+//!
+//! let mut orchestrator = LaunchOrchestrator::new(client, spec)
+//!     .on_progress(|event| println!("{:?}: {:?}", event.step, event.status));
+//!
+//! match orchestrator.run().await {
+//!     Ok(report) => println!("Launched {:?}", report.project_id),
+//!     Err(e) => {
+//!         let saved = serde_json::to_string(orchestrator.state()).unwrap();
+//!         eprintln!("Launch failed ({}), resume with {}", e, saved);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{new_idempotency_key, ProgressCallback, StepEvent, StepStatus};
+use crate::{
+    client::Client,
+    error::{Error, Result},
+    ids::ProjectId,
+    models::{CreateProjectRequest, Project},
+    types::{
+        projects::{ProjectAlert, ProjectDocument},
+        webhooks::WebhookRegistration,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Everything needed to launch a project
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectSpec {
+    /// The project to create
+    pub project: CreateProjectRequest,
+    /// Documents to attach before launch
+    #[serde(default)]
+    pub documents: Vec<ProjectDocument>,
+    /// Webhooks to register for the project
+    #[serde(default)]
+    pub webhooks: Vec<WebhookRegistration>,
+    /// Alerts to create for the project
+    #[serde(default)]
+    pub alerts: Vec<ProjectAlert>,
+}
+
+impl ProjectSpec {
+    /// Create a spec for the given project
+    pub fn new(project: CreateProjectRequest) -> Self {
+        Self {
+            project,
+            ..Default::default()
+        }
+    }
+
+    /// Attach a document
+    pub fn document(mut self, document: ProjectDocument) -> Self {
+        self.documents.push(document);
+        self
+    }
+
+    /// Register a webhook; its `project_id` is filled in once the project exists
+    pub fn webhook(mut self, webhook: WebhookRegistration) -> Self {
+        self.webhooks.push(webhook);
+        self
+    }
+
+    /// Create an alert
+    pub fn alert(mut self, alert: ProjectAlert) -> Self {
+        self.alerts.push(alert);
+        self
+    }
+}
+
+/// A step of the launch workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchStep {
+    /// Check the project definition locally
+    Validate,
+    /// Create the project
+    CreateProject,
+    /// Attach documents
+    UploadDocuments,
+    /// Register webhooks
+    ConfigureWebhooks,
+    /// Create alerts
+    ConfigureAlerts,
+    /// Run the platform's launch checklist
+    RunChecklist,
+    /// Launch the project
+    Launch,
+}
+
+impl LaunchStep {
+    /// All steps in execution order
+    pub const ALL: [LaunchStep; 7] = [
+        LaunchStep::Validate,
+        LaunchStep::CreateProject,
+        LaunchStep::UploadDocuments,
+        LaunchStep::ConfigureWebhooks,
+        LaunchStep::ConfigureAlerts,
+        LaunchStep::RunChecklist,
+        LaunchStep::Launch,
+    ];
+}
+
+/// Resumable progress of a launch
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LaunchState {
+    /// ID of the created project, once it exists
+    pub project_id: Option<ProjectId>,
+    /// Steps that have finished
+    pub completed: Vec<LaunchStep>,
+    /// Number of documents already attached
+    pub documents_uploaded: usize,
+    /// Number of webhooks already registered
+    pub webhooks_configured: usize,
+    /// Number of alerts already created
+    pub alerts_configured: usize,
+    /// Idempotency key sent when creating the project
+    #[serde(default)]
+    pub create_key: Option<String>,
+}
+
+/// Outcome of a successful launch
+#[derive(Debug, Clone)]
+pub struct LaunchReport {
+    /// ID of the launched project
+    pub project_id: ProjectId,
+    /// The project as returned by the launch call
+    pub project: Project,
+    /// Final status of every step
+    pub steps: Vec<StepEvent<LaunchStep>>,
+}
+
+/// Runs a project launch step by step
+pub struct LaunchOrchestrator {
+    client: Client,
+    spec: ProjectSpec,
+    state: LaunchState,
+    on_progress: Option<ProgressCallback<LaunchStep>>,
+}
+
+impl LaunchOrchestrator {
+    /// Create an orchestrator for a fresh launch
+    pub fn new(client: Client, spec: ProjectSpec) -> Self {
+        Self {
+            client,
+            spec,
+            state: LaunchState::default(),
+            on_progress: None,
+        }
+    }
+
+    /// Continue from a previously saved state
+    pub fn resume(mut self, state: LaunchState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Call `callback` for every progress event
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&StepEvent<LaunchStep>) + Send + Sync + 'static,
+    {
+        self.on_progress = Some(Arc::new(callback));
+        self
+    }
+
+    /// Current progress, suitable for persisting and passing to [`resume`](Self::resume)
+    pub fn state(&self) -> &LaunchState {
+        &self.state
+    }
+
+    /// Run all remaining steps
+    ///
+    /// Stops at the first failing step and returns its error; the state up to that
+    /// point is kept.
+    pub async fn run(&mut self) -> Result<LaunchReport> {
+        let mut steps = Vec::with_capacity(LaunchStep::ALL.len());
+        let mut launched = None;
+
+        for step in LaunchStep::ALL {
+            if self.state.completed.contains(&step) {
+                steps.push(self.emit(step, StepStatus::Skipped));
+                continue;
+            }
+
+            self.emit(step, StepStatus::Started);
+            match self.run_step(step).await {
+                Ok(project) => {
+                    if project.is_some() {
+                        launched = project;
+                    }
+                    self.state.completed.push(step);
+                    steps.push(self.emit(step, StepStatus::Completed));
+                }
+                Err(e) => {
+                    self.emit(step, StepStatus::Failed(e.to_string()));
+                    return Err(e);
+                }
+            }
+        }
+
+        let project_id = self.project_id()?;
+        let project = match launched {
+            Some(project) => project,
+            None => self.client.projects().get(&project_id).await?,
+        };
+
+        Ok(LaunchReport {
+            project_id,
+            project,
+            steps,
+        })
+    }
+
+    /// Run a single step, returning the project if the step launched it
+    async fn run_step(&mut self, step: LaunchStep) -> Result<Option<Project>> {
+        let projects = self.client.projects();
+
+        match step {
+            LaunchStep::Validate => self
+                .spec
+                .project
+                .validate_at(self.client.clock().utc_now())
+                .into_result()?,
+            LaunchStep::CreateProject => {
+                let key = self
+                    .state
+                    .create_key
+                    .get_or_insert_with(new_idempotency_key);
+                let project = self
+                    .client
+                    .with_idempotency_key(key.as_str())
+                    .projects()
+                    .create(self.spec.project.clone())
+                    .await?;
+                self.state.project_id = Some(ProjectId::new(project.id));
+            }
+            LaunchStep::UploadDocuments => {
+                let project_id = self.project_id()?;
+                for document in self
+                    .spec
+                    .documents
+                    .iter()
+                    .skip(self.state.documents_uploaded)
+                {
+                    projects.add_document(&project_id, document.clone()).await?;
+                    self.state.documents_uploaded += 1;
+                }
+            }
+            LaunchStep::ConfigureWebhooks => {
+                let project_id = self.project_id()?;
+                for webhook in self
+                    .spec
+                    .webhooks
+                    .iter()
+                    .skip(self.state.webhooks_configured)
+                {
+                    let registration = WebhookRegistration {
+                        project_id: Some(project_id.clone()),
+                        ..webhook.clone()
+                    };
+                    let _: serde_json::Value =
+                        self.client.post("/webhooks", Some(&registration)).await?;
+                    self.state.webhooks_configured += 1;
+                }
+            }
+            LaunchStep::ConfigureAlerts => {
+                let project_id = self.project_id()?;
+                for alert in self.spec.alerts.iter().skip(self.state.alerts_configured) {
+                    projects.create_alert(&project_id, alert.clone()).await?;
+                    self.state.alerts_configured += 1;
+                }
+            }
+            LaunchStep::RunChecklist => {
+                let checklist = projects.launch_checklist(&self.project_id()?).await?;
+                if !checklist.ready {
+                    let failing: Vec<&str> = checklist
+                        .items
+                        .iter()
+                        .filter(|item| item.blocking && !item.passed)
+                        .map(|item| item.key.as_str())
+                        .collect();
+                    return Err(Error::Configuration(format!(
+                        "Launch checklist failed: {}",
+                        failing.join(", ")
+                    )));
+                }
+            }
+            LaunchStep::Launch => {
+                return projects.launch(&self.project_id()?).await.map(Some);
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn project_id(&self) -> Result<ProjectId> {
+        self.state
+            .project_id
+            .clone()
+            .ok_or_else(|| Error::Configuration("Launch state has no project ID".to_string()))
+    }
+
+    fn emit(&self, step: LaunchStep, status: StepStatus) -> StepEvent<LaunchStep> {
+        super::emit(self.client.clock(), self.on_progress.as_ref(), step, status)
+    }
+}
+
+impl std::fmt::Debug for LaunchOrchestrator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LaunchOrchestrator")
+            .field("spec", &self.spec)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+    use wiremock::{
+        matchers::{body_partial_json, header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    const PROJECT_ID: &str = "proj_launch1";
+
+    fn client(server: &MockServer) -> Client {
+        Client::builder()
+            .api_key("test")
+            .base_url(format!("{}/v1/", server.uri()))
+            .max_retries(0)
+            .build()
+            .unwrap()
+    }
+
+    fn spec() -> ProjectSpec {
+        let project = serde_json::from_value(json!({
+            "name": "Launch Test",
+            "description": "A project launched by the orchestrator",
+            "token_symbol": "LNCH",
+            "total_supply": "1000000",
+            "tiers": [{ "tier": 1, "price_per_token": "0.1", "total_tokens": "500000" }],
+            "sale_start_date": "2030-01-01T00:00:00Z",
+            "sale_end_date": "2030-02-01T00:00:00Z"
+        }))
+        .unwrap();
+
+        let webhook = |url: &str| WebhookRegistration {
+            url: url.to_string(),
+            events: vec!["investment.created".to_string()],
+            project_id: None,
+        };
+        ProjectSpec::new(project)
+            .document(document("whitepaper"))
+            .document(document("audit"))
+            .webhook(webhook("https://example.com/hooks/a"))
+            .webhook(webhook("https://example.com/hooks/b"))
+            .alert(alert())
+    }
+
+    fn document(name: &str) -> ProjectDocument {
+        ProjectDocument {
+            name: name.to_string(),
+            document_type: "whitepaper".to_string(),
+            url: format!("https://example.com/{}.pdf", name),
+        }
+    }
+
+    fn alert() -> ProjectAlert {
+        ProjectAlert {
+            metric: "total_raised_xrp".to_string(),
+            threshold: "10000".to_string(),
+            channel: "email".to_string(),
+            target: "team@example.com".to_string(),
+        }
+    }
+
+    fn project(status: &str) -> Value {
+        json!({ "id": PROJECT_ID, "name": "Launch Test", "status": status })
+    }
+
+    /// Expect `times` POSTs to `endpoint` whose body contains `body`; a `status` other
+    /// than 200 fails them, otherwise they are answered with `response`
+    async fn expect_post(
+        server: &MockServer,
+        endpoint: &str,
+        body: Value,
+        response: Value,
+        status: u16,
+        times: u64,
+    ) {
+        let response = match status {
+            200 => ResponseTemplate::new(200).set_body_json(response),
+            _ => ResponseTemplate::new(status).set_body_json(json!({ "message": "unavailable" })),
+        };
+        Mock::given(method("POST"))
+            .and(path(format!("/v1{}", endpoint)))
+            .and(body_partial_json(body))
+            .respond_with(response)
+            .expect(times)
+            .mount(server)
+            .await;
+    }
+
+    async fn expect_document(server: &MockServer, name: &str, status: u16, times: u64) {
+        let endpoint = format!("/projects/{}/documents", PROJECT_ID);
+        let document = serde_json::to_value(document(name)).unwrap();
+        let body = json!({ "name": name });
+        expect_post(server, &endpoint, body, document, status, times).await;
+    }
+
+    async fn expect_webhook(server: &MockServer, url: &str, status: u16, times: u64) {
+        let body = json!({ "url": url, "project_id": PROJECT_ID });
+        let webhook = json!({ "id": "wh_1", "url": url });
+        expect_post(server, "/webhooks", body, webhook, status, times).await;
+    }
+
+    /// The steps after webhooks, which all succeed
+    async fn expect_rest(server: &MockServer) {
+        let alerts = format!("/projects/{}/alerts", PROJECT_ID);
+        let alert = serde_json::to_value(alert()).unwrap();
+        expect_post(server, &alerts, alert.clone(), alert, 200, 1).await;
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/v1/projects/{}/launch-checklist",
+                PROJECT_ID
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "ready": true,
+                "items": [{ "key": "kyc", "description": "KYC configured", "passed": true }]
+            })))
+            .expect(1)
+            .mount(server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path(format!("/v1/projects/{}/launch", PROJECT_ID)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(project("active")))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    async fn expect_create(server: &MockServer, times: u64) {
+        Mock::given(method("POST"))
+            .and(path("/v1/projects"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(project("draft")))
+            .expect(times)
+            .mount(server)
+            .await;
+    }
+
+    /// Expect one project creation carrying the idempotency key `key`
+    async fn expect_keyed_create(server: &MockServer, key: &str) {
+        Mock::given(method("POST"))
+            .and(path("/v1/projects"))
+            .and(header("Idempotency-Key", key))
+            .respond_with(ResponseTemplate::new(200).set_body_json(project("draft")))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn runs_every_step() {
+        let server = MockServer::start().await;
+        expect_create(&server, 1).await;
+        expect_document(&server, "whitepaper", 200, 1).await;
+        expect_document(&server, "audit", 200, 1).await;
+        expect_webhook(&server, "https://example.com/hooks/a", 200, 1).await;
+        expect_webhook(&server, "https://example.com/hooks/b", 200, 1).await;
+        expect_rest(&server).await;
+
+        let mut orchestrator = LaunchOrchestrator::new(client(&server), spec());
+        let report = orchestrator.run().await.unwrap();
+
+        assert_eq!(report.project_id.as_str(), PROJECT_ID);
+        assert!(report
+            .steps
+            .iter()
+            .all(|event| event.status == StepStatus::Completed));
+        let state = orchestrator.state();
+        assert_eq!(state.completed, LaunchStep::ALL);
+        assert_eq!(
+            (
+                state.documents_uploaded,
+                state.webhooks_configured,
+                state.alerts_configured
+            ),
+            (2, 2, 1)
+        );
+        server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn resumes_from_saved_state() {
+        let server = MockServer::start().await;
+
+        // The second document fails
+        expect_create(&server, 1).await;
+        expect_document(&server, "whitepaper", 200, 1).await;
+        expect_document(&server, "audit", 503, 1).await;
+        let mut orchestrator = LaunchOrchestrator::new(client(&server), spec());
+        assert!(orchestrator.run().await.is_err());
+        let saved = serde_json::to_string(orchestrator.state()).unwrap();
+        server.verify().await;
+        server.reset().await;
+
+        // Resumed: only the remaining document is uploaded, then the second webhook fails
+        let state: LaunchState = serde_json::from_str(&saved).unwrap();
+        assert_eq!(
+            state.completed,
+            [LaunchStep::Validate, LaunchStep::CreateProject]
+        );
+        assert_eq!(state.documents_uploaded, 1);
+        expect_create(&server, 0).await;
+        expect_document(&server, "whitepaper", 200, 0).await;
+        expect_document(&server, "audit", 200, 1).await;
+        expect_webhook(&server, "https://example.com/hooks/a", 200, 1).await;
+        expect_webhook(&server, "https://example.com/hooks/b", 503, 1).await;
+        let mut orchestrator = LaunchOrchestrator::new(client(&server), spec()).resume(state);
+        assert!(orchestrator.run().await.is_err());
+        let state = orchestrator.state().clone();
+        server.verify().await;
+        server.reset().await;
+
+        // Resumed again: only the remaining webhook is registered before launching
+        assert_eq!(state.documents_uploaded, 2);
+        assert_eq!(state.webhooks_configured, 1);
+        expect_create(&server, 0).await;
+        expect_document(&server, "whitepaper", 200, 0).await;
+        expect_document(&server, "audit", 200, 0).await;
+        expect_webhook(&server, "https://example.com/hooks/a", 200, 0).await;
+        expect_webhook(&server, "https://example.com/hooks/b", 200, 1).await;
+        expect_rest(&server).await;
+        let mut orchestrator = LaunchOrchestrator::new(client(&server), spec()).resume(state);
+        let report = orchestrator.run().await.unwrap();
+
+        let skipped: Vec<LaunchStep> = report
+            .steps
+            .iter()
+            .filter(|event| event.status == StepStatus::Skipped)
+            .map(|event| event.step)
+            .collect();
+        assert_eq!(
+            skipped,
+            [
+                LaunchStep::Validate,
+                LaunchStep::CreateProject,
+                LaunchStep::UploadDocuments
+            ]
+        );
+        assert_eq!(orchestrator.state().webhooks_configured, 2);
+        server.verify().await;
+    }
+
+    #[tokio::test]
+    async fn retries_project_creation_with_the_same_key() {
+        let server = MockServer::start().await;
+        let mut orchestrator = LaunchOrchestrator::new(client(&server), spec());
+
+        // The key is saved before the call, so it survives a failed creation
+        Mock::given(method("POST"))
+            .and(path("/v1/projects"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+        assert!(orchestrator.run().await.is_err());
+        let state = orchestrator.state().clone();
+        let key = state.create_key.clone().unwrap();
+        assert_eq!(state.project_id, None);
+        server.verify().await;
+        server.reset().await;
+
+        expect_keyed_create(&server, &key).await;
+        expect_document(&server, "whitepaper", 200, 1).await;
+        expect_document(&server, "audit", 200, 1).await;
+        expect_webhook(&server, "https://example.com/hooks/a", 200, 1).await;
+        expect_webhook(&server, "https://example.com/hooks/b", 200, 1).await;
+        expect_rest(&server).await;
+        let mut orchestrator = LaunchOrchestrator::new(client(&server), spec()).resume(state);
+        orchestrator.run().await.unwrap();
+
+        assert_eq!(
+            orchestrator.state().create_key.as_deref(),
+            Some(key.as_str())
+        );
+        server.verify().await;
+    }
+}
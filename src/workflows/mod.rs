@@ -0,0 +1,69 @@
+//! Multi-step workflows built on top of the API services
+//!
+//! Workflows chain several API calls into one operation, report progress per step, and
+//! can be resumed from a saved state after a failure.
+
+pub mod launch;
+pub mod wrap_up;
+
+pub use launch::{LaunchOrchestrator, LaunchReport, LaunchState, LaunchStep, ProjectSpec};
+pub use wrap_up::{WrapUpOptions, WrapUpOrchestrator, WrapUpReport, WrapUpState, WrapUpStep};
+
+use crate::runtime::Clock;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Status of a workflow step
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepStatus {
+    /// The step has started
+    Started,
+    /// The step finished successfully
+    Completed,
+    /// The step was already completed in a previous run
+    Skipped,
+    /// The step failed with the given error message
+    Failed(String),
+}
+
+/// A progress event emitted while a workflow runs
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct StepEvent<S> {
+    /// The step the event refers to
+    pub step: S,
+    /// The step's new status
+    pub status: StepStatus,
+    /// When the event occurred
+    pub at: DateTime<Utc>,
+}
+
+/// Callback invoked for every progress event
+pub(crate) type ProgressCallback<S> = Arc<dyn Fn(&StepEvent<S>) + Send + Sync>;
+
+/// A fresh idempotency key for one mutating step
+///
+/// Workflows save it in their state before the call, so a resumed run repeating the
+/// step reuses it.
+pub(crate) fn new_idempotency_key() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// Build a progress event timestamped by `clock` and pass it to the callback, if any
+pub(crate) fn emit<S>(
+    clock: &dyn Clock,
+    callback: Option<&ProgressCallback<S>>,
+    step: S,
+    status: StepStatus,
+) -> StepEvent<S> {
+    let event = StepEvent {
+        step,
+        status,
+        at: clock.utc_now(),
+    };
+    if let Some(callback) = callback {
+        callback(&event);
+    }
+    event
+}
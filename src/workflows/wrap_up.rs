@@ -0,0 +1,440 @@
+//! End-of-sale wrap-up
+//!
+//! [`WrapUpOrchestrator`] runs the sequence that closes out a finished sale:
+//!
+//! 1. freeze the final statistics snapshot
+//! 2. generate the cap table
+//! 3. schedule the token distribution
+//! 4. settle the sale
+//! 5. notify investors
+//! 6. archive the project
+//!
+//! Like [`LaunchOrchestrator`](super::LaunchOrchestrator), progress is kept in a
+//! serializable [`WrapUpState`] that can be passed to [`WrapUpOrchestrator::resume`]
+//! after a failure. Scheduling the distribution and settling carry idempotency keys
+//! saved in the state, so a resumed run that repeats either call after a lost response
+//! doesn't apply it twice.
+//!
+//! ```rust,no_run
+//! use xrplsale::{
+//!     types::projects::InvestorMessage,
+//!     workflows::{WrapUpOptions, WrapUpOrchestrator},
+//!     Client,
+//! };
+//!
+//! # #[tokio::main]
+//! # async fn main() -> xrplsale::Result<()> {
+//! # let client = Client::builder().api_key("test").build()?;
+//! let options = WrapUpOptions::default().message(InvestorMessage {
+//!     subject: "The sale has ended".to_string(),
+//!     body: "Tokens will be distributed shortly.".to_string(),
+//! });
+//!
+//! let report = WrapUpOrchestrator::new(client, "proj_abc123", options)
+//!     .on_progress(|event| println!("{:?}: {:?}", event.step, event.status))
+//!     .run()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{new_idempotency_key, ProgressCallback, StepEvent, StepStatus};
+use crate::{
+    client::Client,
+    error::Result,
+    ids::ProjectId,
+    models::{Project, ProjectStats},
+    types::projects::{CapTable, Distribution, InvestorMessage, ScheduleDistributionRequest},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Settings for a wrap-up run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WrapUpOptions {
+    /// When to distribute tokens; immediately if unset
+    pub distribute_at: Option<DateTime<Utc>>,
+    /// Message sent to investors; no message is sent if unset
+    pub message: Option<InvestorMessage>,
+}
+
+impl WrapUpOptions {
+    /// Schedule the distribution for a specific time
+    pub fn distribute_at(mut self, at: DateTime<Utc>) -> Self {
+        self.distribute_at = Some(at);
+        self
+    }
+
+    /// Send a message to investors
+    pub fn message(mut self, message: InvestorMessage) -> Self {
+        self.message = Some(message);
+        self
+    }
+}
+
+/// A step of the wrap-up workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WrapUpStep {
+    /// Freeze the final statistics
+    SnapshotStats,
+    /// Generate the cap table
+    GenerateCapTable,
+    /// Schedule the token distribution
+    ScheduleDistribution,
+    /// Settle the sale
+    Settle,
+    /// Notify investors
+    NotifyInvestors,
+    /// Archive the project
+    Archive,
+}
+
+impl WrapUpStep {
+    /// All steps in execution order
+    pub const ALL: [WrapUpStep; 6] = [
+        WrapUpStep::SnapshotStats,
+        WrapUpStep::GenerateCapTable,
+        WrapUpStep::ScheduleDistribution,
+        WrapUpStep::Settle,
+        WrapUpStep::NotifyInvestors,
+        WrapUpStep::Archive,
+    ];
+}
+
+/// Resumable progress of a wrap-up
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WrapUpState {
+    /// Steps that have finished
+    pub completed: Vec<WrapUpStep>,
+    /// ID of the scheduled distribution, once it exists
+    pub distribution_id: Option<String>,
+    /// Idempotency key sent when scheduling the distribution
+    #[serde(default)]
+    pub distribution_key: Option<String>,
+    /// Idempotency key sent when settling the sale
+    #[serde(default)]
+    pub settlement_key: Option<String>,
+}
+
+/// Outcome of a successful wrap-up
+///
+/// Results of steps skipped on resume are `None`, except the distribution, which is
+/// fetched again by its saved ID.
+#[derive(Debug, Clone)]
+pub struct WrapUpReport {
+    /// The archived project
+    pub project: Project,
+    /// Final statistics snapshot
+    pub stats: Option<ProjectStats>,
+    /// Generated cap table
+    pub cap_table: Option<CapTable>,
+    /// Scheduled distribution
+    pub distribution: Option<Distribution>,
+    /// Final status of every step
+    pub steps: Vec<StepEvent<WrapUpStep>>,
+}
+
+/// Runs the end-of-sale sequence for a project step by step
+pub struct WrapUpOrchestrator {
+    client: Client,
+    project_id: ProjectId,
+    options: WrapUpOptions,
+    state: WrapUpState,
+    on_progress: Option<ProgressCallback<WrapUpStep>>,
+}
+
+impl WrapUpOrchestrator {
+    /// Create an orchestrator for a fresh wrap-up
+    pub fn new(client: Client, project_id: impl Into<ProjectId>, options: WrapUpOptions) -> Self {
+        Self {
+            client,
+            project_id: project_id.into(),
+            options,
+            state: WrapUpState::default(),
+            on_progress: None,
+        }
+    }
+
+    /// Continue from a previously saved state
+    pub fn resume(mut self, state: WrapUpState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Call `callback` for every progress event
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&StepEvent<WrapUpStep>) + Send + Sync + 'static,
+    {
+        self.on_progress = Some(Arc::new(callback));
+        self
+    }
+
+    /// Current progress, suitable for persisting and passing to [`resume`](Self::resume)
+    pub fn state(&self) -> &WrapUpState {
+        &self.state
+    }
+
+    /// Run all remaining steps
+    ///
+    /// Stops at the first failing step and returns its error; the state up to that
+    /// point is kept.
+    pub async fn run(&mut self) -> Result<WrapUpReport> {
+        let projects = self.client.projects();
+        let mut steps = Vec::with_capacity(WrapUpStep::ALL.len());
+        let mut stats = None;
+        let mut cap_table = None;
+        let mut distribution = None;
+        let mut archived = None;
+
+        for step in WrapUpStep::ALL {
+            if self.state.completed.contains(&step) {
+                if let (WrapUpStep::ScheduleDistribution, Some(id)) =
+                    (step, &self.state.distribution_id)
+                {
+                    distribution = Some(projects.get_distribution(&self.project_id, id).await?);
+                }
+                steps.push(self.emit(step, StepStatus::Skipped));
+                continue;
+            }
+
+            self.emit(step, StepStatus::Started);
+            let result = match step {
+                WrapUpStep::SnapshotStats => projects
+                    .snapshot_stats(&self.project_id)
+                    .await
+                    .map(|s| stats = Some(s)),
+                WrapUpStep::GenerateCapTable => projects
+                    .generate_cap_table(&self.project_id, None)
+                    .await
+                    .map(|c| cap_table = Some(c)),
+                WrapUpStep::ScheduleDistribution => {
+                    let request = ScheduleDistributionRequest {
+                        scheduled_for: self.options.distribute_at,
+                    };
+                    let key = self
+                        .state
+                        .distribution_key
+                        .get_or_insert_with(new_idempotency_key);
+                    self.client
+                        .with_idempotency_key(key.as_str())
+                        .projects()
+                        .schedule_distribution(&self.project_id, request)
+                        .await
+                        .map(|d| {
+                            self.state.distribution_id = Some(d.id.clone());
+                            distribution = Some(d);
+                        })
+                }
+                WrapUpStep::Settle => {
+                    let key = self
+                        .state
+                        .settlement_key
+                        .get_or_insert_with(new_idempotency_key);
+                    self.client
+                        .with_idempotency_key(key.as_str())
+                        .projects()
+                        .settle(&self.project_id)
+                        .await
+                        .map(|_| ())
+                }
+                WrapUpStep::NotifyInvestors => match &self.options.message {
+                    Some(message) => {
+                        projects
+                            .notify_investors(&self.project_id, message.clone())
+                            .await
+                    }
+                    None => Ok(()),
+                },
+                WrapUpStep::Archive => projects
+                    .archive(&self.project_id)
+                    .await
+                    .map(|p| archived = Some(p)),
+            };
+
+            match result {
+                Ok(()) => {
+                    self.state.completed.push(step);
+                    steps.push(self.emit(step, StepStatus::Completed));
+                }
+                Err(e) => {
+                    self.emit(step, StepStatus::Failed(e.to_string()));
+                    return Err(e);
+                }
+            }
+        }
+
+        let project = match archived {
+            Some(project) => project,
+            None => projects.get(&self.project_id).await?,
+        };
+
+        Ok(WrapUpReport {
+            project,
+            stats,
+            cap_table,
+            distribution,
+            steps,
+        })
+    }
+
+    fn emit(&self, step: WrapUpStep, status: StepStatus) -> StepEvent<WrapUpStep> {
+        super::emit(self.client.clock(), self.on_progress.as_ref(), step, status)
+    }
+}
+
+impl std::fmt::Debug for WrapUpOrchestrator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WrapUpOrchestrator")
+            .field("project_id", &self.project_id)
+            .field("options", &self.options)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+    use std::sync::Mutex;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, Request, ResponseTemplate,
+    };
+
+    const PROJECT_ID: &str = "proj_wrap1";
+
+    fn client(server: &MockServer) -> Client {
+        Client::builder()
+            .api_key("test")
+            .base_url(format!("{}/v1/", server.uri()))
+            .max_retries(0)
+            .build()
+            .unwrap()
+    }
+
+    fn project(status: &str) -> Value {
+        json!({ "id": PROJECT_ID, "name": "Wrap-up Test", "status": status })
+    }
+
+    fn distribution() -> Value {
+        json!({ "id": "dist_1", "project_id": PROJECT_ID, "status": "scheduled" })
+    }
+
+    async fn expect(
+        server: &MockServer,
+        verb: &str,
+        endpoint: &str,
+        status: u16,
+        response: Value,
+        times: u64,
+    ) {
+        let response = match status {
+            200 => ResponseTemplate::new(200).set_body_json(response),
+            _ => ResponseTemplate::new(status).set_body_json(json!({ "message": "unavailable" })),
+        };
+        Mock::given(method(verb))
+            .and(path(format!("/v1/projects/{}{}", PROJECT_ID, endpoint)))
+            .respond_with(response)
+            .expect(times)
+            .mount(server)
+            .await;
+    }
+
+    /// Expect `times` POSTs to `endpoint`, recording the idempotency key of each
+    async fn expect_keyed(
+        server: &MockServer,
+        endpoint: &str,
+        status: u16,
+        response: Value,
+        times: u64,
+    ) -> Arc<Mutex<Vec<Option<String>>>> {
+        let keys = Arc::new(Mutex::new(Vec::new()));
+        let seen = keys.clone();
+        let response = match status {
+            200 => ResponseTemplate::new(200).set_body_json(response),
+            _ => ResponseTemplate::new(status).set_body_json(json!({ "message": "unavailable" })),
+        };
+        Mock::given(method("POST"))
+            .and(path(format!("/v1/projects/{}{}", PROJECT_ID, endpoint)))
+            .and(move |request: &Request| {
+                let key = request
+                    .headers
+                    .get(&"Idempotency-Key".into())
+                    .map(|values| values.last().as_str().to_string());
+                seen.lock().unwrap().push(key);
+                true
+            })
+            .respond_with(response)
+            .expect(times)
+            .mount(server)
+            .await;
+        keys
+    }
+
+    async fn expect_first_steps(server: &MockServer) {
+        let stats =
+            json!({ "total_raised_xrp": "25000", "total_investors": 42, "tokens_sold": "250000" });
+        expect(server, "POST", "/stats/snapshot", 200, stats, 1).await;
+        let cap_table = json!({
+            "project_id": PROJECT_ID,
+            "entries": [],
+            "generated_at": "2030-02-01T00:00:00Z"
+        });
+        expect(server, "POST", "/cap-table", 200, cap_table, 1).await;
+    }
+
+    #[tokio::test]
+    async fn resumes_without_repeating_the_distribution() {
+        let server = MockServer::start().await;
+
+        // Settling fails after the distribution is scheduled
+        expect_first_steps(&server).await;
+        let scheduled = expect_keyed(&server, "/distributions", 200, distribution(), 1).await;
+        let settled = expect_keyed(&server, "/settlement", 503, Value::Null, 1).await;
+        let mut orchestrator =
+            WrapUpOrchestrator::new(client(&server), PROJECT_ID, WrapUpOptions::default());
+        assert!(orchestrator.run().await.is_err());
+        let saved = serde_json::to_string(orchestrator.state()).unwrap();
+        server.verify().await;
+        server.reset().await;
+
+        let state: WrapUpState = serde_json::from_str(&saved).unwrap();
+        assert_eq!(state.distribution_id.as_deref(), Some("dist_1"));
+        assert!(state.distribution_key.is_some());
+        assert_eq!(
+            *scheduled.lock().unwrap(),
+            vec![state.distribution_key.clone()]
+        );
+        let first_settlement = settled.lock().unwrap()[0].clone();
+        assert!(first_settlement.is_some());
+        assert_eq!(first_settlement, state.settlement_key);
+
+        // Resumed: the distribution is fetched, and settling retries with the same key
+        expect(&server, "POST", "/distributions", 200, distribution(), 0).await;
+        expect(
+            &server,
+            "GET",
+            "/distributions/dist_1",
+            200,
+            distribution(),
+            1,
+        )
+        .await;
+        let settled = expect_keyed(&server, "/settlement", 200, project("settled"), 1).await;
+        expect(&server, "POST", "/archive", 200, project("archived"), 1).await;
+        let mut orchestrator =
+            WrapUpOrchestrator::new(client(&server), PROJECT_ID, WrapUpOptions::default())
+                .resume(state);
+        let report = orchestrator.run().await.unwrap();
+
+        assert_eq!(*settled.lock().unwrap(), vec![first_settlement]);
+        assert_eq!(report.distribution.unwrap().id, "dist_1");
+        assert!(report.stats.is_none());
+        assert_eq!(orchestrator.state().completed, WrapUpStep::ALL);
+        server.verify().await;
+    }
+}
@@ -0,0 +1,169 @@
+//! Calls to an XRPL node's JSON-RPC API
+//!
+//! Shared by the helpers that read the ledger directly rather than through the
+//! platform API.
+
+use crate::{client::Client, error::Error, types::reconcile::LedgerPayment, Environment};
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+
+/// Seconds between the Unix epoch and the XRPL epoch (2000-01-01)
+const RIPPLE_EPOCH_OFFSET: i64 = 946_684_800;
+
+/// JSON-RPC connection to an XRPL node
+#[derive(Debug, Clone)]
+pub(crate) struct XrplRpc {
+    http: reqwest::Client,
+    url: String,
+}
+
+impl XrplRpc {
+    /// Connect to `url`, or to the public node for the client's environment
+    pub fn new(client: &Client, url: Option<&str>) -> Result<Self, RpcError> {
+        let url = match url {
+            Some(url) => url,
+            None => default_rpc_url(client.environment()).ok_or(RpcError::NoEndpoint)?,
+        };
+        Ok(Self {
+            http: client.http().clone(),
+            url: url.to_string(),
+        })
+    }
+
+    /// Call a method, returning its `result`
+    pub async fn call(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        let response = self
+            .http
+            .post(&self.url)
+            .json(&json!({ "method": method, "params": [params] }))
+            .send()
+            .await
+            .map_err(|e| RpcError::Transport(format!("XRPL {} request failed: {}", method, e)))?;
+        let body: Value = response
+            .json()
+            .await
+            .map_err(|e| RpcError::Transport(format!("Invalid XRPL {} response: {}", method, e)))?;
+
+        let result = body["result"].clone();
+        if result["status"] == "error" {
+            let code = result["error"].as_str().unwrap_or("unknown").to_string();
+            return Err(RpcError::Ledger(code));
+        }
+        Ok(result)
+    }
+}
+
+/// Failure of an XRPL JSON-RPC call
+#[derive(Debug)]
+pub(crate) enum RpcError {
+    /// No endpoint is known for the client's environment
+    NoEndpoint,
+    /// The request could not be sent or its response not read
+    Transport(String),
+    /// The node answered with an error code, e.g. `actMalformed`
+    Ledger(String),
+}
+
+impl RpcError {
+    /// The SDK error for a call about `account`; `setting` names the option that
+    /// picks the endpoint
+    pub fn into_error(self, account: &str, setting: &str) -> Error {
+        match self {
+            RpcError::NoEndpoint => Error::Configuration(format!(
+                "No public XRPL endpoint for a custom environment; set {}",
+                setting
+            )),
+            RpcError::Transport(message) => Error::HttpClient(message),
+            RpcError::Ledger(code) if code == "actMalformed" => {
+                Error::Configuration(format!("'{}' is not a valid XRPL account", account))
+            }
+            RpcError::Ledger(code) => {
+                Error::HttpClient(format!("XRPL node returned error '{}'", code))
+            }
+        }
+    }
+}
+
+/// Public JSON-RPC endpoint of the network an environment settles on
+fn default_rpc_url(environment: &Environment) -> Option<&'static str> {
+    match environment {
+        Environment::Production => Some("https://xrplcluster.com/"),
+        Environment::Testnet => Some("https://s.altnet.rippletest.net:51234/"),
+        Environment::Devnet => Some("https://s.devnet.rippletest.net:51234/"),
+        Environment::Custom { .. } => None,
+    }
+}
+
+/// A successful XRP payment to `account` from an `account_tx` entry
+///
+/// Handles both the API v1 (`tx`) and v2 (`tx_json` with a top-level `hash`) forms.
+pub(crate) fn payment(entry: &Value, account: &str) -> Option<LedgerPayment> {
+    let tx = entry.get("tx_json").or_else(|| entry.get("tx"))?;
+    let meta = entry.get("meta")?;
+
+    if tx["TransactionType"] != "Payment"
+        || tx["Destination"] != account
+        || meta["TransactionResult"] != "tesSUCCESS"
+        || entry.get("validated") == Some(&Value::Bool(false))
+    {
+        return None;
+    }
+
+    // Partial payments can deliver less than `Amount`; issued currencies are objects
+    let delivered = meta
+        .get("delivered_amount")
+        .or_else(|| meta.get("DeliveredAmount"))
+        .or_else(|| tx.get("Amount"))?
+        .as_str()?
+        .parse()
+        .ok()?;
+    let date = tx["date"]
+        .as_i64()
+        .or_else(|| entry["close_time_iso"].as_str().and_then(iso_seconds))?;
+
+    Some(LedgerPayment {
+        hash: entry
+            .get("hash")
+            .or_else(|| tx.get("hash"))?
+            .as_str()?
+            .to_ascii_uppercase(),
+        account: tx["Account"].as_str()?.to_string(),
+        destination: account.to_string(),
+        destination_tag: tx["DestinationTag"]
+            .as_u64()
+            .and_then(|tag| tag.try_into().ok()),
+        delivered_drops: delivered,
+        ledger_index: entry
+            .get("ledger_index")
+            .or_else(|| tx.get("ledger_index"))
+            .and_then(Value::as_u64)
+            .and_then(|index| index.try_into().ok())?,
+        executed_at: DateTime::from_timestamp(date + RIPPLE_EPOCH_OFFSET, 0)?,
+    })
+}
+
+/// Seconds since the XRPL epoch of an ISO 8601 close time
+fn iso_seconds(iso: &str) -> Option<i64> {
+    let time: DateTime<Utc> = iso.parse().ok()?;
+    Some(time.timestamp() - RIPPLE_EPOCH_OFFSET)
+}
+
+/// Convert a decimal XRP amount to drops without going through floating point
+pub(crate) fn xrp_to_drops(xrp: &str) -> Option<u64> {
+    let (whole, fraction) = xrp.split_once('.').unwrap_or((xrp, ""));
+    if fraction.len() > 6
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let whole: u64 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().ok()?
+    };
+    let fraction: u64 = format!("{:0<6}", fraction).parse().ok()?;
+    whole.checked_mul(1_000_000)?.checked_add(fraction)
+}